@@ -1,8 +1,11 @@
-#![feature(wrapping_next_power_of_two, int_roundings)]
+#![feature(wrapping_next_power_of_two, int_roundings, generic_const_exprs)]
+#![allow(incomplete_features)]
 
 use bnum::types::{U128, U512};
+use bnum::Float;
 // use bnum::prelude::*;
 use core::iter::Iterator;
+use core::ops::{Add, Div, Mul, Sub};
 use criterion::black_box;
 use rand::prelude::*;
 
@@ -95,6 +98,60 @@ macro_rules! bench_against_primitive {
     };
 }
 
+type F64 = Float<8, 52>;
+type F128 = Float<16, 112>;
+type F256 = Float<32, 236>;
+
+macro_rules! bench_floats {
+    { $($method: ident ($arity: tt);) * } => {
+        paste::paste! {
+            $(
+                fn [<bench_float_ $method>](c: &mut Criterion) {
+                    let mut group = c.benchmark_group(concat!("float_", stringify!($method)));
+                    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+                    let inputs: Vec<(f64, f64)> = (0..SAMPLE_SIZE).map(|_| rng.gen()).collect();
+                    bench_floats!(@bnum group, inputs, $method, $arity; F64, F128, F256);
+                    group.bench_with_input(BenchmarkId::new("core", "F64"), &inputs, |b, inputs| {
+                        b.iter(|| {
+                            for &(x, y) in inputs.iter() {
+                                let _ = bench_floats!(@call f64, $method, $arity, x, y);
+                            }
+                        })
+                    });
+                    group.finish();
+                }
+            )*
+            criterion_group!(float_benches, $([<bench_float_ $method>]), *);
+        }
+    };
+    (@bnum $group: ident, $inputs: ident, $method: ident, $arity: tt; $($Float: ident), *) => {
+        $(
+            let float_inputs: Vec<($Float, $Float)> = $inputs.iter().map(|&(x, y)| ($Float::from(x), $Float::from(y))).collect();
+            $group.bench_with_input(BenchmarkId::new("bnum", stringify!($Float)), &float_inputs, |b, inputs| {
+                b.iter(|| {
+                    for &(x, y) in inputs.iter() {
+                        let _ = bench_floats!(@call $Float, $method, $arity, x, y);
+                    }
+                })
+            });
+        )*
+    };
+    (@call $ty: ty, $method: ident, 1, $x: ident, $y: ident) => {
+        <$ty>::$method(black_box($x))
+    };
+    (@call $ty: ty, $method: ident, 2, $x: ident, $y: ident) => {
+        <$ty>::$method(black_box($x), black_box($y))
+    };
+}
+
+bench_floats! {
+    add(2);
+    sub(2);
+    mul(2);
+    div(2);
+    sqrt(1);
+}
+
 trait Format {
     fn display(self) -> String;
     fn debug(self) -> String;
@@ -251,4 +308,4 @@ bench_against_primitive! {
     // partial_cmp(a: ref &u128, b: ref &u128);
 }
 
-criterion_main!(u128_benches);
+criterion_main!(u128_benches, float_benches);
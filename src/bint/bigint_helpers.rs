@@ -3,18 +3,83 @@ macro_rules! bigint_helpers {
         #[doc = doc::bigint_helpers::impl_desc!()]
         impl<const N: usize> $BInt<N> {
             crate::int::bigint_helpers::impls!(I);
+
+            #[doc = doc::bigint_helpers::mul_wide!(I)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn mul_wide<const M: usize>(self, rhs: Self) -> $BInt<M> {
+                // the magnitude of the product is at most `2^(2 * BITS - 2)`, so it is representable with `2 * N` digits
+                let out = $BInt::from_bits(self.unsigned_abs().mul_wide::<M>(rhs.unsigned_abs()));
+                if self.is_negative() != rhs.is_negative() {
+                    out.wrapping_neg()
+                } else {
+                    out
+                }
+            }
+
+            #[doc = doc::bigint_helpers::square_wide!(I)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn square_wide<const M: usize>(self) -> $BInt<M> {
+                $BInt::from_bits(self.unsigned_abs().square_wide())
+            }
+
+            #[doc = doc::bigint_helpers::widening_add!(I)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn widening_add<const M: usize>(self, rhs: Self) -> $BInt<M> {
+                let _ = AssertDigits::<N, 1, M>::WIDEN;
+                self.resize::<M>().wrapping_add(rhs.resize())
+            }
+
+            #[doc = doc::bigint_helpers::mul_add_wide!(I)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn mul_add_wide<const M: usize>(self, rhs: Self, addend: Self) -> $BInt<M> {
+                self.mul_wide::<M>(rhs).wrapping_add(addend.resize())
+            }
         }
 
         #[cfg(test)]
         paste::paste! {
             mod [<$Digit _digit_tests>] {
                 use crate::test::types::big_types::$Digit::*;
+                use crate::cast::As;
+
                 crate::int::bigint_helpers::tests!(itest);
+
+                type I64 = crate::$BInt::<{64 / $Digit::BITS as usize}>;
+                type I128 = crate::$BInt::<D128>;
+
+                const D128: usize = 128 / $Digit::BITS as usize;
+                const D192: usize = 192 / $Digit::BITS as usize;
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_mul_wide(a: i64, b: i64, c: i64) -> bool {
+                        let (x, y, z): (I64, I64, I64) = (a.as_(), b.as_(), c.as_());
+                        let product = a as i128 * b as i128;
+                        let p: I128 = product.as_();
+                        x.mul_wide::<D128>(y) == p
+                            && x.mul_wide::<D192>(y) == p.sign_extend::<D192>()
+                            && x.square_wide::<D128>() == (a as i128 * a as i128).as_::<I128>()
+                            && x.widening_add::<D128>(y) == (a as i128 + b as i128).as_::<I128>()
+                            && x.mul_add_wide::<D128>(y, z) == (product + c as i128).as_::<I128>()
+                    }
+                }
+
+                #[test]
+                fn mul_wide_min() {
+                    assert_eq!(I64::MIN.mul_wide::<D128>(I64::MIN), (i64::MIN as i128 * i64::MIN as i128).as_::<I128>());
+                    assert_eq!(I64::MIN.mul_wide::<D128>(I64::MAX), (i64::MIN as i128 * i64::MAX as i128).as_::<I128>());
+                    assert_eq!(I64::MIN.square_wide::<D128>(), I128::ONE << 126u32);
+                    assert_eq!(I64::MIN.mul_add_wide::<D128>(I64::MIN, I64::MIN), (i64::MIN as i128 * i64::MIN as i128 + i64::MIN as i128).as_::<I128>());
+                }
             }
         }
     };
 }
 
 use crate::doc;
+use crate::int::resize::AssertDigits;
 
 crate::macro_impl!(bigint_helpers);
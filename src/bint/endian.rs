@@ -1,5 +1,7 @@
 use crate::digit;
 use crate::doc;
+use crate::int::endian;
+use alloc::vec::Vec;
 // use core::mem::MaybeUninit;
 
 macro_rules! endian {
@@ -164,6 +166,105 @@ macro_rules! endian {
                 }
             }
 
+            /// Returns the number of bits needed to represent `self` in two's complement, including the sign bit.
+            #[inline]
+            const fn signed_bits(self) -> usize {
+                let magnitude = if self.is_negative() { self.not() } else { self };
+                magnitude.bits.bits() as usize + 1
+            }
+
+            crate::nightly::const_fns! {
+                #[doc = doc::endian::to_signed_be_bytes_minimal!(I)]
+                #[inline]
+                pub const fn to_signed_be_bytes_minimal(self, buf: &mut [u8]) -> Option<usize> {
+                    let len = (self.signed_bits() + 7) / 8;
+                    if buf.len() < len {
+                        return None;
+                    }
+                    let mut i = 0;
+                    while i < len {
+                        buf[i] = self.bits.byte_at((len - 1 - i) * 8);
+                        i += 1;
+                    }
+                    Some(len)
+                }
+
+                #[doc = doc::endian::write_sleb128!(I)]
+                #[inline]
+                pub const fn write_sleb128(self, buf: &mut [u8]) -> Option<usize> {
+                    let len = (self.signed_bits() + 6) / 7;
+                    if buf.len() < len {
+                        return None;
+                    }
+                    let mut i = 0;
+                    while i < len {
+                        let bit = i * 7;
+                        let mut group = self.bits.byte_at(bit);
+                        // sign extend the last group, which may extend beyond the width of the integer
+                        if self.is_negative() && bit + 7 > Self::BITS as usize {
+                            group |= 0xff << (Self::BITS as usize - bit);
+                        }
+                        let continuation = if i + 1 < len { 0x80 } else { 0 };
+                        buf[i] = (group & 0x7f) | continuation;
+                        i += 1;
+                    }
+                    Some(len)
+                }
+            }
+
+            #[doc = doc::endian::read_sleb128!(I)]
+            #[must_use]
+            pub const fn read_sleb128(bytes: &[u8]) -> Option<(Self, usize)> {
+                let mut len = 0;
+                while len < bytes.len() && bytes[len] & 0x80 != 0 {
+                    len += 1;
+                }
+                if len == bytes.len() {
+                    return None;
+                }
+                len += 1;
+                let negative = bytes[len - 1] & 0x40 != 0;
+                // the sign bit of the integer and all bits above it must equal the sign bit of the encoding
+                let sign_bit = Self::BITS as usize - 1;
+                let mut out = $BUint::ZERO;
+                let mut i = 0;
+                while i < len {
+                    let group = bytes[i] & 0x7f;
+                    let bit = i * 7;
+                    if bit + 7 > sign_bit {
+                        let low = if bit < sign_bit { sign_bit - bit } else { 0 };
+                        let mask = (0x7f >> low) << low;
+                        if group & mask != if negative { mask } else { 0 } {
+                            return None;
+                        }
+                    }
+                    out = out.with_byte_at(bit, group);
+                    i += 1;
+                }
+                if negative && len * 7 < Self::BITS as usize {
+                    out = out.bitor($BUint::MAX.wrapping_shl((len * 7) as crate::ExpType));
+                }
+                Some((Self::from_bits(out), len))
+            }
+
+            #[doc = doc::endian::to_der_integer_bytes!(I)]
+            #[must_use = doc::must_use_op!()]
+            pub fn to_der_integer_bytes(self) -> Vec<u8> {
+                let mut bytes = vec![0; Self::BYTES as usize];
+                let len = self.to_signed_be_bytes_minimal(&mut bytes).unwrap();
+                bytes.truncate(len);
+                bytes
+            }
+
+            #[doc = doc::endian::from_der_integer_bytes!(I)]
+            #[must_use = doc::must_use_op!()]
+            pub const fn from_der_integer_bytes(bytes: &[u8]) -> Option<Self> {
+                if !endian::is_minimal_der_integer(bytes) {
+                    return None;
+                }
+                Self::from_be_slice(bytes)
+            }
+
             #[cfg(feature = "nightly")]
             #[doc = doc::endian::to_be_bytes!(I)]
             #[doc = doc::requires_feature!("nightly")]
@@ -227,6 +328,42 @@ macro_rules! endian {
                 use crate::test::types::itest;
 
                 crate::int::endian::tests!($Digit; itest);
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_signed_be_bytes_minimal(a: itest, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % itest::BITS);
+                        let x = ITEST::from(a);
+                        let minimal = crate::int::endian::der_integer_bytes(&a.to_be_bytes(), a < 0);
+                        let mut buf = [0; ITEST::BYTES as usize];
+                        x.to_signed_be_bytes_minimal(&mut buf) == Some(minimal.len())
+                            && buf[..minimal.len()] == minimal[..]
+                            && x.to_signed_be_bytes_minimal(&mut buf[..minimal.len() - 1]).is_none()
+                    }
+
+                    fn quickcheck_sleb128(a: itest, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % itest::BITS);
+                        let x = ITEST::from(a);
+                        let mut buf = [0; ITEST::BITS as usize / 7 + 2];
+                        let len = x.write_sleb128(&mut buf).unwrap();
+                        let mut passed = buf[..len] == crate::int::endian::sleb128(a as i128)[..];
+                        passed &= x.write_sleb128(&mut buf[..len - 1]).is_none();
+                        passed &= ITEST::read_sleb128(&buf) == Some((x, len));
+                        passed &= ITEST::read_sleb128(&buf[..len - 1]).is_none();
+
+                        // redundant trailing bytes which extend the sign are accepted
+                        buf[len - 1] |= 0x80;
+                        buf[len] = if a < 0 { 0x7f } else { 0 };
+                        passed &= ITEST::read_sleb128(&buf) == Some((x, len + 1));
+                        passed
+                    }
+
+                    fn quickcheck_read_sleb128_wide(a: i128, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % i128::BITS);
+                        let bytes = crate::int::endian::sleb128(a);
+                        let expected = itest::try_from(a).ok().map(|a| (ITEST::from(a), bytes.len()));
+                        ITEST::read_sleb128(&bytes) == expected
+                    }
+                }
             }
         }
     };
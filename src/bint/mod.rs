@@ -22,9 +22,6 @@ use crate::digit;
 use crate::ExpType;
 use crate::{doc, errors};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "borsh")]
 use ::{
     alloc::string::ToString,
@@ -48,10 +45,11 @@ macro_rules! mod_impl {
         #[doc = doc::arithmetic_doc!($BInt)]
 
         #[derive(Clone, Copy, Hash, PartialEq, Eq)]
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
         #[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize, BorshSchema))]                
+        #[cfg_attr(feature = "scale", derive(parity_scale_codec::Encode, parity_scale_codec::Decode, parity_scale_codec::MaxEncodedLen, scale_info::TypeInfo))]
         #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
         #[cfg_attr(feature = "valuable", derive(valuable::Valuable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
         #[repr(transparent)]
         pub struct $BInt<const N: usize> {
             pub(crate) bits: $BUint<N>,
@@ -60,6 +58,15 @@ macro_rules! mod_impl {
         #[cfg(feature = "zeroize")]
         impl<const N: usize> zeroize::DefaultIsZeroes for $BInt<N> {}
 
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<const N: usize> bytemuck::Zeroable for $BInt<N> {}
+
+        /// The bytes of the integer are the bytes of its two's complement representation as a
+        #[doc = concat!("[`", stringify!($BUint), "`](crate::", stringify!($BUint), ")")]
+        /// , so are only in little endian order on little endian targets.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<const N: usize> bytemuck::Pod for $BInt<N> {}
+
         impl<const N: usize> $BInt<N> {
             #[doc = doc::count_ones!(I 256)]
             #[must_use = doc::must_use_op!()]
@@ -504,6 +511,24 @@ macro_rules! mod_impl {
                     assert_eq!(ITEST::SIX, v.iter().copied().sum());
                     assert_eq!(ITEST::SIX, v.into_iter().sum());
                 }
+
+                #[cfg(feature = "bytemuck")]
+                quickcheck::quickcheck! {
+                    fn quickcheck_bytemuck_cast(a: ITEST) -> bool {
+                        let bytes = bytemuck::bytes_of(&a);
+                        bytes == bytemuck::bytes_of(&a.to_bits()) && bytemuck::pod_read_unaligned::<ITEST>(bytes) == a
+                    }
+                }
+
+                #[cfg(feature = "zerocopy")]
+                quickcheck::quickcheck! {
+                    fn quickcheck_zerocopy_cast(a: ITEST) -> bool {
+                        use zerocopy::{FromBytes, IntoBytes};
+
+                        let bytes = a.as_bytes();
+                        bytes == a.to_bits().as_bytes() && ITEST::read_from_bytes(bytes) == Ok(a)
+                    }
+                }
             }
         }
     };
@@ -525,6 +550,7 @@ mod numtraits;
 mod ops;
 mod overflowing;
 mod radix;
+mod resize;
 mod saturating;
 mod strict;
 mod unchecked;
@@ -204,7 +204,7 @@ macro_rules! numtraits {
 
                 #[inline]
                 fn gcd(&self, other: &Self) -> Self {
-                    let gcd = self.unsigned_abs().gcd(&other.unsigned_abs());
+                    let gcd = self.unsigned_abs().gcd(other.unsigned_abs());
                     let out = Self::from_bits(gcd);
                     out.abs()
                 }
@@ -0,0 +1,99 @@
+use crate::digit;
+use crate::doc;
+use crate::int::resize::AssertDigits;
+
+macro_rules! resize {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        #[doc = doc::resize::impl_desc!()]
+        impl<const N: usize> $BInt<N> {
+            #[doc = doc::resize::resize!(I)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn resize<const M: usize>(self) -> $BInt<M> {
+                let mut out = if self.is_negative() {
+                    $BUint::<M>::MAX
+                } else {
+                    $BUint::<M>::ZERO
+                };
+                let mut i = 0;
+                while i < N && i < M {
+                    out.digits[i] = self.bits.digits[i];
+                    i += 1;
+                }
+                $BInt::from_bits(out)
+            }
+
+            #[doc = doc::resize::checked_resize!(I)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_resize<const M: usize>(self) -> Option<$BInt<M>> {
+                if M >= N {
+                    return Some(self.resize());
+                }
+                let sign_digit = if self.is_negative() { $Digit::MAX } else { 0 };
+                if M == 0 {
+                    return if self.is_zero() {
+                        Some(self.resize())
+                    } else {
+                        None
+                    };
+                }
+                if (self.bits.digits[M - 1] as digit::$Digit::SignedDigit).is_negative()
+                    != self.is_negative()
+                {
+                    return None;
+                }
+                let mut i = M;
+                while i < N {
+                    if self.bits.digits[i] != sign_digit {
+                        return None;
+                    }
+                    i += 1;
+                }
+                Some(self.resize())
+            }
+
+            #[doc = doc::resize::sign_extend!()]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn sign_extend<const M: usize>(self) -> $BInt<M> {
+                let _ = AssertDigits::<N, M, 0>::EXTEND;
+                self.resize()
+            }
+
+            #[doc = doc::resize::truncate!(I)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn truncate<const M: usize>(self) -> $BInt<M> {
+                let _ = AssertDigits::<N, M, 0>::TRUNCATE;
+                self.resize()
+            }
+        }
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use crate::$BInt;
+                use crate::cast::As;
+
+                const D64: usize = 64 / $Digit::BITS as usize;
+                const D128: usize = 128 / $Digit::BITS as usize;
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_resize(a: i128) -> bool {
+                        let big: $BInt<D128> = a.as_();
+                        let small: $BInt<D64> = (a as i64).as_();
+                        big.resize::<D64>() == small
+                            && big.truncate::<D64>() == small
+                            && small.resize::<D128>() == (a as i64 as i128).as_::<$BInt<D128>>()
+                            && small.sign_extend::<D128>().resize::<D64>() == small
+                            && big.checked_resize::<D64>().is_some() == i64::try_from(a).is_ok()
+                            && big.resize::<D128>() == big
+                    }
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(resize);
@@ -1,5 +1,6 @@
 use crate::digit;
 use crate::doc;
+use crate::int::resize::AssertDigits;
 
 macro_rules! bigint_helpers {
     ($BUint: ident, $BInt: ident, $Digit: ident) => {
@@ -56,6 +57,57 @@ macro_rules! bigint_helpers {
                     (low, high)
                 }
             }
+
+            #[doc = doc::bigint_helpers::mul_wide!(U)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn mul_wide<const M: usize>(self, rhs: Self) -> $BUint<M> {
+                let _ = AssertDigits::<N, N, M>::WIDEN;
+                let mut out = $BUint::<M>::ZERO;
+                let mut i = 0;
+                while i < N {
+                    let mut carry: $Digit = 0;
+                    let mut j = 0;
+                    while j < N {
+                        let index = i + j;
+                        let (new_digit, new_carry) = digit::$Digit::carrying_mul(
+                            self.digits[i],
+                            rhs.digits[j],
+                            carry,
+                            out.digits[index],
+                        );
+                        out.digits[index] = new_digit;
+                        carry = new_carry;
+                        j += 1;
+                    }
+                    out.digits[i + N] = carry;
+                    i += 1;
+                }
+                out
+            }
+
+            #[doc = doc::bigint_helpers::square_wide!(U)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn square_wide<const M: usize>(self) -> $BUint<M> {
+                self.mul_wide(self)
+            }
+
+            #[doc = doc::bigint_helpers::widening_add!(U)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn widening_add<const M: usize>(self, rhs: Self) -> $BUint<M> {
+                let _ = AssertDigits::<N, 1, M>::WIDEN;
+                self.resize::<M>().wrapping_add(rhs.resize())
+            }
+
+            #[doc = doc::bigint_helpers::mul_add_wide!(U)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn mul_add_wide<const M: usize>(self, rhs: Self, addend: Self) -> $BUint<M> {
+                // `self * rhs + addend` is at most `(2^BITS - 1)^2 + 2^BITS - 1 < 2^(2 * BITS)`, so it fits in `2 * N` digits
+                self.mul_wide::<M>(rhs).wrapping_add(addend.resize())
+            }
         }
 
         #[cfg(test)]
@@ -65,16 +117,35 @@ macro_rules! bigint_helpers {
                 use crate::test::types::big_types::$Digit::*;
 
                 type U64 = crate::$BUint::<{64 / $Digit::BITS as usize}>;
+                type U128 = crate::$BUint::<D128>;
 
-                crate::int::bigint_helpers::tests!(utest);
+                const D128: usize = 128 / $Digit::BITS as usize;
+                const D192: usize = 192 / $Digit::BITS as usize;
 
-                test_bignum! {
-                    function: <u64>::widening_mul(a: u64, b: u64),
-                    cases: [
-                        (u64::MAX, u64::MAX)
-                    ]
+                use crate::cast::As;
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_mul_wide(a: u64, b: u64, c: u64) -> bool {
+                        let (x, y, z): (U64, U64, U64) = (a.as_(), b.as_(), c.as_());
+                        let product = a as u128 * b as u128;
+                        let p: U128 = product.as_();
+                        x.mul_wide::<D128>(y) == p
+                            && x.mul_wide::<D192>(y) == p.zero_extend::<D192>()
+                            && x.square_wide::<D128>() == (a as u128 * a as u128).as_::<U128>()
+                            && x.widening_add::<D128>(y) == (a as u128 + b as u128).as_::<U128>()
+                            && x.mul_add_wide::<D128>(y, z) == (product + c as u128).as_::<U128>()
+                    }
+
+                    fn quickcheck_mul_wide_split(a: u128, b: u128) -> bool {
+                        let (x, y): (U128, U128) = (a.as_(), b.as_());
+                        let (low, high) = x.widening_mul(y);
+                        crate::$BUint::<{ 2 * D128 }>::concat(high, low) == x.mul_wide(y)
+                    }
                 }
 
+                crate::int::bigint_helpers::tests!(utest);
+
+
                 test_bignum! {
                     function: <u64>::carrying_mul(a: u64, b: u64, c: u64),
                     cases: [
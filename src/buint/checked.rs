@@ -208,29 +208,8 @@ macro_rules! checked {
                     
                     let u_jn = u.digit(j + n);
                     
-                    #[inline]
-                    const fn tuple_gt(a: ($Digit, $Digit), b: ($Digit, $Digit)) -> bool {
-                        a.1 > b.1 || a.1 == b.1 && a.0 > b.0
-                    }
-                    
                     // q_hat will be either `q` or `q + 1`
-                    let mut q_hat = if u_jn < v_n_m1 {
-                        let (mut q_hat, r_hat) = digit::$Digit::div_rem_wide(u.digit(j + n - 1), u_jn, v_n_m1); // D3
-                        
-                        if tuple_gt(digit::$Digit::widening_mul(q_hat, v_n_m2), (u.digit(j + n - 2), r_hat as $Digit)) {
-                            q_hat -= 1;
-                            
-                            if let Some(r_hat) = r_hat.checked_add(v_n_m1) { // this checks if `r_hat <= b`, where `b` is the digit base
-                                if tuple_gt(digit::$Digit::widening_mul(q_hat, v_n_m2), (u.digit(j + n - 2), r_hat as $Digit)) {
-                                    q_hat -= 1;
-                                }
-                            }
-                        }
-                        q_hat
-                    } else {
-                        // `u[j + n - 1] >= v[n - 1]` so we know that estimate for q_hat would be larger than `Digit::MAX`. This is either equal to `q` or `q + 1` (very unlikely to be `q + 1`).
-                        $Digit::MAX
-                    };
+                    let mut q_hat = digit::$Digit::estimate_quotient(u_jn, u.digit(j + n - 1), u.digit(j + n - 2), v_n_m1, v_n_m2); // D3
                     let (u_new, overflow) = u.sub(Mul::new(v, q_hat), j, n); // D4
                     u = u_new;
                     
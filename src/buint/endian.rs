@@ -1,5 +1,7 @@
 use crate::digit;
 use crate::doc;
+use crate::int::endian;
+use alloc::vec::Vec;
 // use core::mem::MaybeUninit;
 
 macro_rules! endian {
@@ -170,6 +172,142 @@ macro_rules! endian {
                 }
             }
 
+            /// Returns the eight bits of `self` starting at bit `bit`, with bits beyond the width of `self` taken as zero.
+            #[inline]
+            pub(crate) const fn byte_at(self, bit: usize) -> u8 {
+                let index = bit / digit::$Digit::BITS as usize;
+                if index >= N {
+                    return 0;
+                }
+                let offset = bit % digit::$Digit::BITS as usize;
+                let mut byte = (self.digits[index] >> offset) as u8;
+                if offset > digit::$Digit::BITS as usize - 8 && index + 1 < N {
+                    byte |= (self.digits[index + 1] << (digit::$Digit::BITS as usize - offset)) as u8;
+                }
+                byte
+            }
+
+            /// Returns `self` with the bits of `byte` set at bit `bit` and above, ignoring any bits beyond the width of `self`.
+            #[inline]
+            pub(crate) const fn with_byte_at(mut self, bit: usize, byte: u8) -> Self {
+                let index = bit / digit::$Digit::BITS as usize;
+                if index >= N {
+                    return self;
+                }
+                let offset = bit % digit::$Digit::BITS as usize;
+                self.digits[index] |= (byte as $Digit) << offset;
+                if offset > digit::$Digit::BITS as usize - 8 && index + 1 < N {
+                    self.digits[index + 1] |= (byte as $Digit) >> (digit::$Digit::BITS as usize - offset);
+                }
+                self
+            }
+
+            crate::nightly::const_fns! {
+                #[doc = doc::endian::to_be_bytes_trimmed!(U)]
+                #[inline]
+                pub const fn to_be_bytes_trimmed(self, buf: &mut [u8]) -> Option<usize> {
+                    let len = (self.bits() as usize + 7) / 8;
+                    if buf.len() < len {
+                        return None;
+                    }
+                    let mut i = 0;
+                    while i < len {
+                        buf[i] = self.byte_at((len - 1 - i) * 8);
+                        i += 1;
+                    }
+                    Some(len)
+                }
+
+                #[doc = doc::endian::to_le_bytes_trimmed!(U)]
+                #[inline]
+                pub const fn to_le_bytes_trimmed(self, buf: &mut [u8]) -> Option<usize> {
+                    let len = (self.bits() as usize + 7) / 8;
+                    if buf.len() < len {
+                        return None;
+                    }
+                    let mut i = 0;
+                    while i < len {
+                        buf[i] = self.byte_at(i * 8);
+                        i += 1;
+                    }
+                    Some(len)
+                }
+
+                #[doc = doc::endian::to_signed_be_bytes_minimal!(U)]
+                #[inline]
+                pub const fn to_signed_be_bytes_minimal(self, buf: &mut [u8]) -> Option<usize> {
+                    // one more than the number of bytes needed for the magnitude, unless the extra byte is redundant
+                    let len = self.bits() as usize / 8 + 1;
+                    if buf.len() < len {
+                        return None;
+                    }
+                    let mut i = 0;
+                    while i < len {
+                        buf[i] = self.byte_at((len - 1 - i) * 8);
+                        i += 1;
+                    }
+                    Some(len)
+                }
+
+                #[doc = doc::endian::write_uleb128!(U)]
+                #[inline]
+                pub const fn write_uleb128(self, buf: &mut [u8]) -> Option<usize> {
+                    let bits = self.bits() as usize;
+                    let len = if bits == 0 { 1 } else { (bits + 6) / 7 };
+                    if buf.len() < len {
+                        return None;
+                    }
+                    let mut i = 0;
+                    while i < len {
+                        let continuation = if i + 1 < len { 0x80 } else { 0 };
+                        buf[i] = (self.byte_at(i * 7) & 0x7f) | continuation;
+                        i += 1;
+                    }
+                    Some(len)
+                }
+            }
+
+            #[doc = doc::endian::read_uleb128!(U)]
+            #[must_use]
+            pub const fn read_uleb128(bytes: &[u8]) -> Option<(Self, usize)> {
+                let mut out = Self::ZERO;
+                let mut i = 0;
+                while i < bytes.len() {
+                    let group = bytes[i] & 0x7f;
+                    let bit = i * 7;
+                    if group != 0 {
+                        // the position of the most significant bit of the group must be within the width of the integer
+                        if bit + (u8::BITS - group.leading_zeros()) as usize > Self::BITS as usize {
+                            return None;
+                        }
+                        out = out.with_byte_at(bit, group);
+                    }
+                    if bytes[i] & 0x80 == 0 {
+                        return Some((out, i + 1));
+                    }
+                    i += 1;
+                }
+                None
+            }
+
+            #[doc = doc::endian::to_der_integer_bytes!(U)]
+            #[must_use = doc::must_use_op!()]
+            pub fn to_der_integer_bytes(self) -> Vec<u8> {
+                let mut bytes = vec![0; Self::BYTES as usize + 1];
+                let len = self.to_signed_be_bytes_minimal(&mut bytes).unwrap();
+                bytes.truncate(len);
+                bytes
+            }
+
+            #[doc = doc::endian::from_der_integer_bytes!(U)]
+            #[must_use = doc::must_use_op!()]
+            pub const fn from_der_integer_bytes(bytes: &[u8]) -> Option<Self> {
+                if !endian::is_minimal_der_integer(bytes) || bytes[0] & 0x80 != 0 {
+                    return None;
+                }
+                Self::from_be_slice(bytes)
+            }
+
             #[cfg(feature = "nightly")]
             #[doc = doc::endian::to_be_bytes!(U)]
             #[doc = doc::requires_feature!("nightly")]
@@ -293,6 +431,52 @@ macro_rules! endian {
                 use crate::test::{test_bignum, types::utest};
 
                 crate::int::endian::tests!($Digit; utest);
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_bytes_trimmed(a: utest, shift: u8) -> bool {
+                        use crate::int::endian;
+
+                        let a = a >> (shift as u32 % utest::BITS);
+                        let x = UTEST::from(a);
+                        let be = a.to_be_bytes();
+                        let trimmed = &be[a.leading_zeros() as usize / 8..];
+                        let mut buf = [0; UTEST::BYTES as usize + 1];
+                        let mut passed = x.to_be_bytes_trimmed(&mut buf) == Some(trimmed.len())
+                            && buf[..trimmed.len()] == *trimmed;
+                        passed &= x.to_le_bytes_trimmed(&mut buf) == Some(trimmed.len())
+                            && buf[..trimmed.len()].iter().eq(trimmed.iter().rev());
+                        let minimal = endian::der_integer_bytes(&be, false);
+                        passed &= x.to_signed_be_bytes_minimal(&mut buf) == Some(minimal.len())
+                            && buf[..minimal.len()] == minimal[..];
+                        passed &= x.to_signed_be_bytes_minimal(&mut buf[..minimal.len() - 1]).is_none();
+                        passed
+                    }
+
+                    fn quickcheck_uleb128(a: utest, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % utest::BITS);
+                        let x = UTEST::from(a);
+                        let mut buf = [0; UTEST::BITS as usize / 7 + 2];
+                        let len = x.write_uleb128(&mut buf).unwrap();
+                        let mut passed = buf[..len] == crate::int::endian::uleb128(a as u128)[..];
+                        passed &= x.write_uleb128(&mut buf[..len - 1]).is_none();
+                        passed &= UTEST::read_uleb128(&buf) == Some((x, len));
+                        passed &= UTEST::read_uleb128(&buf[..len - 1]).is_none();
+
+                        // redundant trailing bytes are accepted
+                        buf[len - 1] |= 0x80;
+                        buf[len] = 0;
+                        passed &= UTEST::read_uleb128(&buf) == Some((x, len + 1));
+                        passed
+                    }
+
+                    fn quickcheck_read_uleb128_wide(a: crate::$BUint<{ 2 * UTEST::BITS as usize / $Digit::BITS as usize }>) -> bool {
+                        let mut buf = [0; 2 * UTEST::BITS as usize / 7 + 1];
+                        let len = a.write_uleb128(&mut buf).unwrap();
+                        let mut le = [0; 2 * UTEST::BYTES as usize];
+                        let le_len = a.to_le_bytes_trimmed(&mut le).unwrap();
+                        UTEST::read_uleb128(&buf[..len]) == UTEST::from_le_slice(&le[..le_len]).map(|x| (x, len))
+                    }
+                }
             }
         }
     };
@@ -5,12 +5,6 @@ use crate::doc;
 use crate::ExpType;
 // use core::mem::MaybeUninit;
 
-#[cfg(feature = "serde")]
-use ::{
-    serde::{Deserialize, Serialize},
-    serde_big_array::BigArray,
-};
-
 #[cfg(feature = "borsh")]
 use ::{
     alloc::string::ToString,
@@ -34,19 +28,26 @@ macro_rules! mod_impl {
         #[doc = doc::arithmetic_doc!($BUint)]
 
         #[derive(Clone, Copy, Hash, PartialEq, Eq)]
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
         #[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize, BorshSchema))]
+        #[cfg_attr(feature = "scale", derive(parity_scale_codec::Encode, parity_scale_codec::Decode, parity_scale_codec::MaxEncodedLen, scale_info::TypeInfo))]
         #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
         #[cfg_attr(feature = "valuable", derive(valuable::Valuable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
         #[repr(transparent)]
         pub struct $BUint<const N: usize> {
-            #[cfg_attr(feature = "serde", serde(with = "BigArray"))]
             pub(crate) digits: [$Digit; N],
         }
 
         #[cfg(feature = "zeroize")]
         impl<const N: usize> zeroize::DefaultIsZeroes for $BUint<N> {}
 
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<const N: usize> bytemuck::Zeroable for $BUint<N> {}
+
+        /// The bytes of the integer are its digits in little endian order (least significant digit first), with each digit in the target's native byte order. They are only in little endian order on little endian targets.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<const N: usize> bytemuck::Pod for $BUint<N> {}
+
         impl<const N: usize> $BUint<N> {
             #[doc = doc::count_ones!(U 1024)]
             #[must_use = doc::must_use_op!()]
@@ -459,6 +460,43 @@ macro_rules! mod_impl {
                 Self::unchecked_shr_pad_internal::<false>(u, rhs)
             }
 
+            /// Returns the greatest common divisor of `self` and `rhs`, which is zero only if both are zero.
+            pub(crate) const fn gcd(self, rhs: Self) -> Self {
+                // Paul E. Black, "binary GCD", in Dictionary of Algorithms and Data Structures [online], Paul E. Black, ed. 2 November 2020. (accessed 15th June 2022) Available from: https://www.nist.gov/dads/HTML/binaryGCD.html
+                // https://en.wikipedia.org/wiki/Binary_GCD_algorithm#Implementation
+
+                let (mut a, mut b) = (self, rhs);
+                if a.is_zero() {
+                    return b;
+                }
+                if b.is_zero() {
+                    return a;
+                }
+                let a_tz = a.trailing_zeros();
+                let b_tz = b.trailing_zeros();
+                // Normalise `a` and `b` so that both of them has no leading zeros, so both must be odd.
+                unsafe {
+                    a = Self::unchecked_shr_internal(a, a_tz);
+                    b = Self::unchecked_shr_internal(b, b_tz);
+                }
+                let tz = if a_tz < b_tz { a_tz } else { b_tz };
+                loop {
+                    if a.lt(&b) {
+                        // Ensure `a >= b`
+                        let t = a;
+                        a = b;
+                        b = t;
+                    }
+                    a = a.wrapping_sub(b);
+                    if a.is_zero() {
+                        return unsafe { Self::unchecked_shl_internal(b, tz) };
+                    }
+                    unsafe {
+                        a = Self::unchecked_shr_internal(a, a.trailing_zeros());
+                    }
+                }
+            }
+
             #[doc = doc::bits!(U 256)]
             #[must_use]
             #[inline]
@@ -718,6 +756,32 @@ macro_rules! mod_impl {
                     assert_eq!(UTEST::SIX, v.iter().copied().sum());
                     assert_eq!(UTEST::SIX, v.into_iter().sum());
                 }
+
+                #[cfg(feature = "bytemuck")]
+                quickcheck::quickcheck! {
+                    fn quickcheck_bytemuck_cast(a: UTEST, b: UTEST) -> bool {
+                        let ints = [a, b];
+                        let bytes: &[u8] = bytemuck::cast_slice(&ints);
+                        let native = a.digits().iter().chain(b.digits()).flat_map(|d| d.to_ne_bytes());
+                        bytes.iter().copied().eq(native)
+                            && bytemuck::cast_slice::<u8, UTEST>(bytes) == ints
+                            && bytemuck::pod_read_unaligned::<UTEST>(&bytes[UTEST::BYTES as usize..]) == b
+                    }
+                }
+
+                #[cfg(feature = "zerocopy")]
+                quickcheck::quickcheck! {
+                    fn quickcheck_zerocopy_cast(a: UTEST, b: UTEST) -> bool {
+                        use zerocopy::{FromBytes, IntoBytes};
+
+                        let ints = [a, b];
+                        let bytes = ints.as_bytes();
+                        let native = a.digits().iter().chain(b.digits()).flat_map(|d| d.to_ne_bytes());
+                        bytes.iter().copied().eq(native)
+                            && <[UTEST]>::ref_from_bytes(bytes) == Ok(&ints[..])
+                            && UTEST::read_from_bytes(&bytes[UTEST::BYTES as usize..]) == Ok(b)
+                    }
+                }
             }
         }
     };
@@ -741,6 +805,7 @@ mod numtraits;
 mod ops;
 mod overflowing;
 mod radix;
+mod resize;
 mod saturating;
 mod strict;
 mod unchecked;
@@ -172,41 +172,7 @@ macro_rules! numtraits {
 
             #[inline]
             fn gcd(&self, other: &Self) -> Self {
-                // Paul E. Black, "binary GCD", in Dictionary of Algorithms and Data Structures [online], Paul E. Black, ed. 2 November 2020. (accessed 15th June 2022) Available from: https://www.nist.gov/dads/HTML/binaryGCD.html
-                // https://en.wikipedia.org/wiki/Binary_GCD_algorithm#Implementation
-
-                let (mut a, mut b) = (*self, *other);
-                if a.is_zero() {
-                    return b;
-                }
-                if b.is_zero() {
-                    return a;
-                }
-                let mut a_tz = a.trailing_zeros();
-                let mut b_tz = b.trailing_zeros();
-                // Normalise `a` and `b` so that both of them has no leading zeros, so both must be odd.
-                unsafe {
-                    a = Self::unchecked_shr_internal(a, a_tz);
-                    b = Self::unchecked_shr_internal(b, b_tz);
-                }
-
-                if b_tz > a_tz {
-                    // Ensure `a_tz >= b_tz`
-                    core::mem::swap(&mut a_tz, &mut b_tz);
-                }
-                loop {
-                    if a < b {
-                        // Ensure `a >= b`
-                        core::mem::swap(&mut a, &mut b);
-                    }
-                    a -= b;
-                    if a.is_zero() {
-                        return unsafe { Self::unchecked_shl_internal(b, b_tz) };
-                    }
-                    unsafe {
-                        a = Self::unchecked_shr_internal(a, a.trailing_zeros());
-                    }
-                }
+                Self::gcd(*self, *other)
             }
 
             #[inline]
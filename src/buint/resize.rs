@@ -0,0 +1,129 @@
+use crate::doc;
+use crate::int::resize::AssertDigits;
+
+macro_rules! resize {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        #[doc = doc::resize::impl_desc!()]
+        impl<const N: usize> $BUint<N> {
+            #[doc = doc::resize::resize!(U)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn resize<const M: usize>(self) -> $BUint<M> {
+                let mut out = $BUint::<M>::ZERO;
+                let mut i = 0;
+                while i < N && i < M {
+                    out.digits[i] = self.digits[i];
+                    i += 1;
+                }
+                out
+            }
+
+            #[doc = doc::resize::checked_resize!(U)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_resize<const M: usize>(self) -> Option<$BUint<M>> {
+                let mut i = M;
+                while i < N {
+                    if self.digits[i] != 0 {
+                        return None;
+                    }
+                    i += 1;
+                }
+                Some(self.resize())
+            }
+
+            #[doc = doc::resize::zero_extend!()]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn zero_extend<const M: usize>(self) -> $BUint<M> {
+                let _ = AssertDigits::<N, M, 0>::EXTEND;
+                self.resize()
+            }
+
+            #[doc = doc::resize::truncate!(U)]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn truncate<const M: usize>(self) -> $BUint<M> {
+                let _ = AssertDigits::<N, M, 0>::TRUNCATE;
+                self.resize()
+            }
+
+            #[doc = doc::resize::concatenate!()]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn concat<const H: usize, const L: usize>(
+                hi: $BUint<H>,
+                lo: $BUint<L>,
+            ) -> Self {
+                let _ = AssertDigits::<L, H, N>::SPLIT;
+                let mut out = Self::ZERO;
+                let mut i = 0;
+                while i < L {
+                    out.digits[i] = lo.digits[i];
+                    i += 1;
+                }
+                while i < N {
+                    out.digits[i] = hi.digits[i - L];
+                    i += 1;
+                }
+                out
+            }
+
+            #[doc = doc::resize::split!()]
+            #[must_use = doc::must_use_op!()]
+            #[inline]
+            pub const fn split<const L: usize, const H: usize>(self) -> ($BUint<L>, $BUint<H>) {
+                let _ = AssertDigits::<L, H, N>::SPLIT;
+                let mut lo = $BUint::<L>::ZERO;
+                let mut hi = $BUint::<H>::ZERO;
+                let mut i = 0;
+                while i < L {
+                    lo.digits[i] = self.digits[i];
+                    i += 1;
+                }
+                while i < N {
+                    hi.digits[i - L] = self.digits[i];
+                    i += 1;
+                }
+                (lo, hi)
+            }
+        }
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use crate::$BUint;
+                use crate::cast::As;
+
+                const D64: usize = 64 / $Digit::BITS as usize;
+                const D128: usize = 128 / $Digit::BITS as usize;
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_resize(a: u128) -> bool {
+                        let big: $BUint<D128> = a.as_();
+                        let small: $BUint<D64> = (a as u64).as_();
+                        big.resize::<D64>() == small
+                            && big.truncate::<D64>() == small
+                            && small.resize::<D128>() == (a as u64 as u128).as_::<$BUint<D128>>()
+                            && small.zero_extend::<D128>().resize::<D64>() == small
+                            && big.checked_resize::<D64>().is_some() == (a <= u64::MAX as u128)
+                            && big.resize::<D128>() == big
+                    }
+
+                    fn quickcheck_split_concat(a: u128) -> bool {
+                        let big: $BUint<D128> = a.as_();
+                        let (lo, hi) = big.split::<D64, D64>();
+                        let (lo2, hi2) = big.split::<1, { D128 - 1 }>();
+                        lo == (a as u64).as_::<$BUint<D64>>()
+                            && hi == ((a >> 64) as u64).as_::<$BUint<D64>>()
+                            && $BUint::<D128>::concat(hi, lo) == big
+                            && $BUint::<D128>::concat(hi2, lo2) == big
+                            && lo2.digits()[0] == a as $Digit
+                    }
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(resize);
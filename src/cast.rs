@@ -34,7 +34,7 @@ use bnum::cast::As;
 // Cast `u64` to `U256`:
 let a = 399872465243u64;
 let b: U256 = a.as_();
-assert_eq!(a.as_::<u16>(), b.as_());
+assert_eq!(a.as_::<u16>(), b.as_::<u16>());
 
 // Cast `i128` to `I512`:
 let c = -2098409234529234584094i128;
@@ -129,6 +129,17 @@ macro_rules! as_trait {
 
 as_trait!();
 
+/// Trait which is implemented for each integer type that a [`Float`](crate::Float) can be converted to with [`Float::to_int_unchecked`](crate::Float::to_int_unchecked).
+#[cfg(feature = "nightly")]
+pub trait FloatToInt<Int> {
+    /// Rounds `self` toward zero and converts it to `Int`, assuming that the result is representable by `Int`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be NaN or infinite, and must be representable by `Int` after its fractional part is truncated.
+    unsafe fn to_int_unchecked(self) -> Int;
+}
+
 macro_rules! primitive_cast_impl {
     ($from: ty as [$($ty: ty), *]) => {
         $(crate::nightly::const_impl! {
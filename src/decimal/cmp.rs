@@ -0,0 +1,125 @@
+use super::{digit_count, pow10, Decimal};
+use core::cmp::Ordering;
+
+impl<const W: usize> Decimal<W> {
+    /// Compares the magnitudes of two finite values numerically.
+    const fn cmp_magnitude(self, other: Self) -> Ordering {
+        let (_, a_coeff, a_exp) = self.decode();
+        let (_, b_coeff, b_exp) = other.decode();
+        match (a_coeff.is_zero(), b_coeff.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+        // compare the exponents of the most significant digits first, then align the coefficients
+        let a_adjusted = a_exp + digit_count(a_coeff) as i64;
+        let b_adjusted = b_exp + digit_count(b_coeff) as i64;
+        if a_adjusted != b_adjusted {
+            return if a_adjusted > b_adjusted {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+        // as the adjusted exponents are equal, the difference in exponents is less than `PRECISION`
+        if a_exp > b_exp {
+            a_coeff.mul(pow10((a_exp - b_exp) as u32)).cmp(&b_coeff)
+        } else {
+            a_coeff.cmp(&b_coeff.mul(pow10((b_exp - a_exp) as u32)))
+        }
+    }
+
+    /// Compares `self` and `other` numerically, returning `None` if either is NaN. Members of the same cohort, such as `1.0` and `1.00`, compare equal, as do `+0` and `-0`.
+    const fn cmp_numeric(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        let (a, b) = (*self, *other);
+        if a.is_zero() && b.is_zero() {
+            return Some(Ordering::Equal);
+        }
+        let (a_neg, b_neg) = (a.is_sign_negative(), b.is_sign_negative());
+        if a_neg != b_neg {
+            // the values have different signs and aren't both zero
+            return Some(if a_neg {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            });
+        }
+        let ord = match (a.is_infinite(), b.is_infinite()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.cmp_magnitude(b),
+        };
+        Some(if a_neg { ord.reverse() } else { ord })
+    }
+}
+
+crate::nightly::impl_const! {
+    impl<const W: usize> const PartialEq for Decimal<W> {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            matches!(self.cmp_numeric(other), Some(Ordering::Equal))
+        }
+    }
+}
+
+crate::nightly::impl_const! {
+    impl<const W: usize> const PartialOrd for Decimal<W> {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.cmp_numeric(other)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::D64;
+    use core::cmp::Ordering;
+    use core::str::FromStr;
+
+    fn d(s: &str) -> D64 {
+        D64::from_str(s).unwrap()
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_cmp_sub(a: D64, b: D64) -> quickcheck::TestResult {
+            if !a.is_finite() || !b.is_finite() {
+                return quickcheck::TestResult::discard();
+            }
+            // the sign of an exact difference determines the ordering
+            let (diff, status) = a.sub_round(b, crate::RoundingMode::TowardZero);
+            if status.contains(crate::Status::OVERFLOW) {
+                return quickcheck::TestResult::discard();
+            }
+            let expected = if diff.is_zero() {
+                Ordering::Equal
+            } else if diff.is_sign_negative() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+            quickcheck::TestResult::from_bool(a.partial_cmp(&b) == Some(expected))
+        }
+    }
+
+    #[test]
+    fn cmp() {
+        assert_eq!(d("1.0"), d("1.00"));
+        assert_eq!(d("0"), d("-0E+10"));
+        assert_eq!(d("12E+1"), d("120"));
+        assert!(d("1.01") > d("1"));
+        assert!(d("-1.01") < d("-1"));
+        assert!(d("9E-398") < d("1E-397"));
+        assert!(d("-9E+369") < D64::MAX);
+        assert!(D64::MAX < D64::INFINITY);
+        assert!(D64::NEG_INFINITY < D64::MIN);
+        assert!(D64::NAN != D64::NAN);
+        assert_eq!(D64::NAN.partial_cmp(&D64::ONE), None);
+        assert_eq!(D64::INFINITY, D64::INFINITY);
+    }
+}
@@ -0,0 +1,46 @@
+use super::{pow10, Decimal};
+use crate::BUint;
+
+impl<const W: usize> Decimal<W> {
+    /// Positive zero, with an exponent of zero.
+    pub const ZERO: Self = Self::encode(false, BUint::ZERO, 0);
+
+    /// Negative zero, with an exponent of zero.
+    pub const NEG_ZERO: Self = Self::encode(true, BUint::ZERO, 0);
+
+    /// One, with an exponent of zero.
+    pub const ONE: Self = Self::encode(false, BUint::ONE, 0);
+
+    /// Negative one, with an exponent of zero.
+    pub const NEG_ONE: Self = Self::encode(true, BUint::ONE, 0);
+
+    /// The difference between one and the next larger representable number, `10^(1 - PRECISION)`.
+    pub const EPSILON: Self = Self::encode(false, BUint::ONE, 1 - Self::PRECISION as i64);
+
+    /// The largest finite value, `(10^PRECISION - 1) * 10^(EMAX - PRECISION + 1)`.
+    pub const MAX: Self = Self::encode(false, Self::MAX_COEFF.sub(BUint::ONE), Self::Q_MAX);
+
+    /// The smallest finite value, `-MAX`.
+    pub const MIN: Self = Self::MAX.neg();
+
+    /// The smallest positive normal value, `10^EMIN`.
+    pub const MIN_POSITIVE: Self = Self::encode(false, BUint::ONE, Self::EMIN);
+
+    /// The smallest positive subnormal value, `10^(EMIN - PRECISION + 1)`.
+    pub const MIN_POSITIVE_SUBNORMAL: Self = Self::encode(false, BUint::ONE, Self::Q_MIN);
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Self::from_top_byte(0x78);
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self::from_top_byte(0xF8);
+
+    /// A quiet NaN with a positive sign and a payload of zero.
+    pub const NAN: Self = Self::from_top_byte(0x7C);
+
+    /// A signalling NaN with a positive sign and a payload of zero.
+    pub const SNAN: Self = Self::from_top_byte(0x7E);
+
+    /// Ten, with an exponent of zero.
+    pub const TEN: Self = Self::encode(false, pow10(1), 0);
+}
@@ -0,0 +1,348 @@
+use super::{digit_count, pow10, round_digits, Decimal};
+use crate::cast::CastFrom;
+use crate::errors::TryFromFloatError;
+use crate::words::{bytes_to_words, words_to_bytes};
+use crate::{RoundingMode, Status};
+use crate::{BUint, BUintD8, ExpType};
+
+impl<const W: usize> Decimal<W> {
+    /// Returns the raw BID encoding of `self`.
+    #[inline(always)]
+    pub const fn to_bits(self) -> BUintD8<W> {
+        self.bits
+    }
+
+    /// Creates a value from its raw BID encoding.
+    #[inline(always)]
+    pub const fn from_bits(v: BUintD8<W>) -> Self {
+        Self { bits: v }
+    }
+
+    /// Returns the value `(-1)^negative * coefficient * 10^exponent`, rounded to nearest with ties to even.
+    ///
+    /// The exponent of the result is `exponent` if the value is exactly representable with it, otherwise it is as close to `exponent` as possible.
+    #[inline]
+    pub const fn from_parts(negative: bool, coefficient: BUintD8<W>, exponent: i64) -> Self {
+        Self::from_parts_round(
+            negative,
+            coefficient,
+            exponent,
+            RoundingMode::NearestTiesToEven,
+        )
+        .0
+    }
+
+    /// Returns the value `(-1)^negative * coefficient * 10^exponent`, rounded according to `mode`. Returns the result along with the exception flags raised by the rounding.
+    pub const fn from_parts_round(
+        negative: bool,
+        coefficient: BUintD8<W>,
+        exponent: i64,
+        mode: RoundingMode,
+    ) -> (Self, Status) {
+        let coeff = bytes_to_words::<W, W>(coefficient);
+        Self::round(negative, coeff, exponent, false, mode)
+    }
+
+    /// Returns the sign, coefficient and exponent of `self`, such that `self` is equal to `(-1)^negative * coefficient * 10^exponent`, or `None` if `self` is infinite or NaN.
+    #[must_use]
+    #[inline]
+    pub const fn to_parts(self) -> Option<(bool, BUintD8<W>, i64)> {
+        if !self.is_finite() {
+            return None;
+        }
+        let (negative, coeff, exp) = self.decode();
+        Some((negative, words_to_bytes::<W, W>(coeff), exp))
+    }
+
+    /// Returns `self` with its fractional digits removed, or `self` if it is not finite.
+    const fn trunc_digits(self) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        let (negative, coeff, exp) = self.decode();
+        if exp >= 0 {
+            return self;
+        }
+        let drop = if -exp > digit_count(coeff) as i64 {
+            digit_count(coeff) + 1
+        } else {
+            -exp as u32
+        };
+        let (coeff, _) = round_digits(coeff, drop, false, negative, RoundingMode::TowardZero);
+        Self::encode(negative, coeff, 0)
+    }
+
+    /// Rounds `(-1)^negative * value` to `Self`, where `value` has `digits` decimal digits and `div_rem` divides it by a power of ten.
+    fn from_int<T>(
+        negative: bool,
+        value: T,
+        digits: u32,
+        div_rem: impl FnOnce(T, u32) -> (T, bool),
+    ) -> Self
+    where
+        BUint<W>: CastFrom<T>,
+    {
+        // `PRECISION + 2` digits always fit in a `BUint<W>`, and are enough to round correctly
+        let keep = Self::PRECISION + 2;
+        let (value, exp, sticky) = if digits > keep {
+            let (q, sticky) = div_rem(value, digits - keep);
+            (q, (digits - keep) as i64, sticky)
+        } else {
+            (value, 0, false)
+        };
+        Self::round(
+            negative,
+            BUint::cast_from(value),
+            exp,
+            sticky,
+            RoundingMode::NearestTiesToEven,
+        )
+        .0
+    }
+}
+
+macro_rules! decimal_int_conversions {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const W: usize, const N: usize> CastFrom<$BUint<N>> for Decimal<W> {
+            #[inline]
+            fn cast_from(from: $BUint<N>) -> Self {
+                let digits = if from.is_zero() { 0 } else { from.ilog10() + 1 };
+                Self::from_int(false, from, digits, |v, k| {
+                    let p = $BUint::<N>::from_digit(10).pow(k as ExpType);
+                    (v / p, !(v % p).is_zero())
+                })
+            }
+        }
+
+        impl<const W: usize, const N: usize> CastFrom<$BInt<N>> for Decimal<W> {
+            #[inline]
+            fn cast_from(from: $BInt<N>) -> Self {
+                let d = Self::cast_from(from.unsigned_abs());
+                if from.is_negative() {
+                    d.neg()
+                } else {
+                    d
+                }
+            }
+        }
+
+        impl<const W: usize, const N: usize> TryFrom<Decimal<W>> for $BUint<N> {
+            type Error = TryFromFloatError;
+
+            fn try_from(d: Decimal<W>) -> Result<Self, Self::Error> {
+                if !d.is_finite() {
+                    return Err(TryFromFloatError(()));
+                }
+                let (negative, mut coeff, exp) = d.decode();
+                if coeff.is_zero() {
+                    return Ok(Self::ZERO);
+                }
+                if negative {
+                    return Err(TryFromFloatError(()));
+                }
+                let mut exp = exp;
+                if exp < 0 {
+                    if -exp >= digit_count(coeff) as i64 {
+                        return Err(TryFromFloatError(()));
+                    }
+                    let (q, r) = coeff.div_rem(pow10(-exp as u32));
+                    if !r.is_zero() {
+                        return Err(TryFromFloatError(()));
+                    }
+                    coeff = q;
+                    exp = 0;
+                }
+                if coeff.bits() > Self::BITS {
+                    return Err(TryFromFloatError(()));
+                }
+                Self::from_digit(10)
+                    .checked_pow(exp as ExpType)
+                    .and_then(|p| Self::cast_from(coeff).checked_mul(p))
+                    .ok_or(TryFromFloatError(()))
+            }
+        }
+
+        impl<const W: usize, const N: usize> TryFrom<Decimal<W>> for $BInt<N> {
+            type Error = TryFromFloatError;
+
+            fn try_from(d: Decimal<W>) -> Result<Self, Self::Error> {
+                let magnitude = $BUint::<N>::try_from(d.abs())?;
+                if d.is_sign_negative() {
+                    // the magnitude of `Self::MIN` is one more than the magnitude of `Self::MAX`
+                    if magnitude.gt(&Self::MIN.unsigned_abs()) {
+                        return Err(TryFromFloatError(()));
+                    }
+                    Ok(Self::from_bits(magnitude).wrapping_neg())
+                } else if magnitude.gt(&Self::MAX.to_bits()) {
+                    Err(TryFromFloatError(()))
+                } else {
+                    Ok(Self::from_bits(magnitude))
+                }
+            }
+        }
+
+        impl<const W: usize, const N: usize> CastFrom<Decimal<W>> for $BUint<N> {
+            /// Converts `from` to an integer, rounding toward zero. NaN is converted to zero, and out of range values saturate.
+            #[inline]
+            fn cast_from(from: Decimal<W>) -> Self {
+                if from.is_nan() {
+                    return Self::ZERO;
+                }
+                match Self::try_from(from.trunc_digits()) {
+                    Ok(n) => n,
+                    Err(_) if from.is_sign_negative() => Self::ZERO,
+                    Err(_) => Self::MAX,
+                }
+            }
+        }
+
+        impl<const W: usize, const N: usize> CastFrom<Decimal<W>> for $BInt<N> {
+            /// Converts `from` to an integer, rounding toward zero. NaN is converted to zero, and out of range values saturate.
+            #[inline]
+            fn cast_from(from: Decimal<W>) -> Self {
+                if from.is_nan() {
+                    return Self::ZERO;
+                }
+                match Self::try_from(from.trunc_digits()) {
+                    Ok(n) => n,
+                    Err(_) if from.is_sign_negative() => Self::MIN,
+                    Err(_) => Self::MAX,
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(decimal_int_conversions);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{D128, D32, D64};
+    use crate::cast::CastFrom;
+    use crate::errors::TryFromFloatError;
+    use crate::{RoundingMode, Status};
+    use crate::types::{I128, U128, U256};
+    use crate::{BIntD8, BUintD8};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    fn d(s: &str) -> D64 {
+        D64::from_str(s).unwrap()
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_parts_round_trip(d: D128) -> bool {
+            match d.to_parts() {
+                Some((negative, coeff, exp)) => {
+                    let out = D128::from_parts(negative, coeff, exp);
+                    out.to_bits() == d.to_bits() || (coeff.is_zero() && out.is_zero() && out.to_parts() == d.to_parts())
+                }
+                None => !d.is_finite(),
+            }
+        }
+
+        fn quickcheck_u128_round_trip(a: u128) -> bool {
+            let d = D128::cast_from(U128::from(a));
+            let b = u128::cast_from(U128::try_from(d).unwrap());
+            // values with more than 34 digits are rounded to 34 significant digits
+            if a < 10u128.pow(34) {
+                a == b
+            } else {
+                a.abs_diff(b) <= 50000
+            }
+        }
+
+        fn quickcheck_i64_round_trip(a: i64) -> bool {
+            let d = D64::cast_from(I128::from(a));
+            let b = i128::cast_from(I128::try_from(d).unwrap());
+            if a.unsigned_abs() < 10u64.pow(16) {
+                a as i128 == b
+            } else {
+                (a as i128).abs_diff(b) <= 500
+            }
+        }
+    }
+
+    #[test]
+    fn from_parts() {
+        assert_eq!(
+            D64::from_parts(false, BUintD8::from(123u32), -2).to_string(),
+            "1.23"
+        );
+        assert_eq!(
+            D64::from_parts_round(
+                false,
+                BUintD8::from(12345678901234567u64),
+                0,
+                RoundingMode::NearestTiesToEven
+            ),
+            (d("1234567890123457E+1"), Status::INEXACT)
+        );
+        assert_eq!(
+            D64::from_parts_round(
+                true,
+                BUintD8::ONE,
+                i64::MAX,
+                RoundingMode::NearestTiesToEven
+            ),
+            (D64::NEG_INFINITY, Status::OVERFLOW | Status::INEXACT)
+        );
+        assert_eq!(
+            D64::from_parts_round(false, BUintD8::ONE, i64::MIN, RoundingMode::TowardPositive),
+            (
+                D64::MIN_POSITIVE_SUBNORMAL,
+                Status::UNDERFLOW | Status::INEXACT
+            )
+        );
+        assert_eq!(
+            D64::from_parts(false, BUintD8::ZERO, i64::MAX).to_string(),
+            "0E+369"
+        );
+        assert_eq!(
+            D32::from_parts(false, BUintD8::from(10u32), 90).to_string(),
+            "1.0E+91"
+        );
+    }
+
+    #[test]
+    fn int_conversions() {
+        assert_eq!(
+            D64::cast_from(U256::MAX).to_string(),
+            "1.157920892373162E+77"
+        );
+        assert_eq!(
+            D64::cast_from(BIntD8::<16>::MIN).to_string(),
+            "-1.701411834604692E+38"
+        );
+        assert_eq!(
+            D64::cast_from(U128::from(12345678901234565u64)).to_string(),
+            "1.234567890123456E+16"
+        );
+        assert_eq!(
+            D64::cast_from(U128::from(12345678901234565001u64)).to_string(),
+            "1.234567890123457E+19"
+        );
+
+        assert_eq!(U128::try_from(d("1.2E+3")), Ok(U128::from(1200u32)));
+        assert_eq!(U128::try_from(d("1200.00")), Ok(U128::from(1200u32)));
+        assert_eq!(U128::try_from(d("-0.00")), Ok(U128::ZERO));
+        assert_eq!(U128::try_from(d("-1")), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(d("0.5")), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(d("1E+39")), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(D64::NAN), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(D64::INFINITY), Err(TryFromFloatError(())));
+        assert_eq!(I128::try_from(d("-1E+10")), Ok(I128::from(-10000000000i64)));
+        assert_eq!(BIntD8::<1>::try_from(d("-128")), Ok(BIntD8::<1>::MIN));
+        assert_eq!(BIntD8::<1>::try_from(d("128")), Err(TryFromFloatError(())));
+        assert_eq!(BIntD8::<1>::try_from(d("-129")), Err(TryFromFloatError(())));
+
+        assert_eq!(U128::cast_from(d("12.99")), U128::from(12u32));
+        assert_eq!(U128::cast_from(d("-12.99")), U128::ZERO);
+        assert_eq!(U128::cast_from(D64::MAX), U128::MAX);
+        assert_eq!(U128::cast_from(D64::NAN), U128::ZERO);
+        assert_eq!(I128::cast_from(d("-12.99")), I128::from(-12i32));
+        assert_eq!(I128::cast_from(D64::NEG_INFINITY), I128::MIN);
+        assert_eq!(I128::cast_from(D64::MIN_POSITIVE_SUBNORMAL), I128::ZERO);
+        assert_eq!(BIntD8::<1>::cast_from(d("300")), BIntD8::<1>::MAX);
+    }
+}
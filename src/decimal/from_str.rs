@@ -0,0 +1,217 @@
+use super::{pow10, Decimal};
+use crate::errors::{FloatErrorKind, ParseFloatError};
+use crate::words::words_to_bytes;
+use crate::RoundingMode;
+use crate::BUint;
+use core::str::FromStr;
+
+const INVALID: ParseFloatError = ParseFloatError {
+    kind: FloatErrorKind::Invalid,
+};
+
+const EMPTY: ParseFloatError = ParseFloatError {
+    kind: FloatErrorKind::Empty,
+};
+
+/// Exponents are clamped to this magnitude while parsing, which is far beyond the point at which any decimal overflows or underflows.
+const EXP_LIMIT: i64 = 1 << 40;
+
+/// Parses a decimal exponent with an optional sign, saturating at `EXP_LIMIT`.
+fn parse_exponent(src: &[u8]) -> Result<i64, ParseFloatError> {
+    let (negative, digits) = match src.first() {
+        Some(b'+') => (false, &src[1..]),
+        Some(b'-') => (true, &src[1..]),
+        _ => (false, src),
+    };
+    if digits.is_empty() {
+        return Err(INVALID);
+    }
+    let mut exp = 0i64;
+    for &byte in digits {
+        if !byte.is_ascii_digit() {
+            return Err(INVALID);
+        }
+        exp = (exp * 10 + (byte - b'0') as i64).min(EXP_LIMIT);
+    }
+    Ok(if negative { -exp } else { exp })
+}
+
+impl<const W: usize> Decimal<W> {
+    /// Parses the payload of a NaN, which must have fewer than `PRECISION` digits.
+    fn parse_nan(src: &[u8], signalling: bool) -> Result<Self, ParseFloatError> {
+        if src.len() >= Self::PRECISION as usize {
+            return Err(INVALID);
+        }
+        let mut payload = BUint::<W>::ZERO;
+        for &byte in src {
+            if !byte.is_ascii_digit() {
+                return Err(INVALID);
+            }
+            payload = payload
+                .mul(pow10(1))
+                .add(BUint::from_digit((byte - b'0') as u64));
+        }
+        let nan = if signalling { Self::SNAN } else { Self::NAN };
+        Ok(Self::from_bits(
+            nan.bits.bitor(words_to_bytes::<W, W>(payload)),
+        ))
+    }
+}
+
+impl<const W: usize> FromStr for Decimal<W> {
+    type Err = ParseFloatError;
+
+    /// Parses a decimal string, rounding to nearest with ties to even if it has more than [`PRECISION`](Self::PRECISION) significant digits.
+    ///
+    /// The accepted syntax is the IEEE 754 decimal character sequence: an optional sign, followed by digits with an optional decimal point and an optional exponent introduced by `e` or `E`, or by one of `inf`, `infinity`, `nan` or `snan` (ignoring case). A NaN may be followed by the digits of its payload. The exponent of the result is determined by the position of the decimal point and the exponent, so `"1.20"` is parsed as `120 * 10^-2`, and is displayed as `1.20`.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let src = src.as_bytes();
+        let (negative, src) = match src.first() {
+            None => return Err(EMPTY),
+            Some(b'+') => (false, &src[1..]),
+            Some(b'-') => (true, &src[1..]),
+            _ => (false, src),
+        };
+        let out = |d: Self| if negative { d.neg() } else { d };
+        if src.eq_ignore_ascii_case(b"inf") || src.eq_ignore_ascii_case(b"infinity") {
+            return Ok(out(Self::INFINITY));
+        }
+        if src.len() >= 3 && src[..3].eq_ignore_ascii_case(b"nan") {
+            return Self::parse_nan(&src[3..], false).map(out);
+        }
+        if src.len() >= 4 && src[..4].eq_ignore_ascii_case(b"snan") {
+            return Self::parse_nan(&src[4..], true).map(out);
+        }
+
+        let (mantissa, exp) = match src.iter().position(|&b| b == b'e' || b == b'E') {
+            Some(i) => (&src[..i], parse_exponent(&src[i + 1..])?),
+            None => (src, 0),
+        };
+        // digits beyond the first `PRECISION + 2` significant digits only affect the rounding through the sticky flag
+        let max_digits = Self::PRECISION + 2;
+        let mut coeff = BUint::<W>::ZERO;
+        let mut digits = 0;
+        let mut exp = exp;
+        let mut sticky = false;
+        let mut seen_digit = false;
+        let mut seen_point = false;
+        for &byte in mantissa {
+            match byte {
+                b'.' if !seen_point => seen_point = true,
+                b'0'..=b'9' => {
+                    seen_digit = true;
+                    let digit = (byte - b'0') as u64;
+                    if digits < max_digits {
+                        coeff = coeff.mul(pow10(1)).add(BUint::from_digit(digit));
+                        if !coeff.is_zero() {
+                            digits += 1;
+                        }
+                        if seen_point {
+                            exp -= 1;
+                        }
+                    } else {
+                        sticky |= digit != 0;
+                        if !seen_point {
+                            exp += 1;
+                        }
+                    }
+                }
+                _ => return Err(INVALID),
+            }
+        }
+        if !seen_digit {
+            return Err(INVALID);
+        }
+        Ok(Self::round(
+            negative,
+            coeff,
+            exp,
+            sticky,
+            RoundingMode::NearestTiesToEven,
+        )
+        .0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{D128, D32, D64};
+    use super::{EMPTY, INVALID};
+    use crate::BUintD8;
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_display_round_trip(a: D128) -> bool {
+            let b = D128::from_str(&a.to_string()).unwrap();
+            // non-canonical encodings are converted to their canonical equivalent
+            a.is_nan() && b.is_nan() || a.to_parts() == b.to_parts() && a.is_infinite() == b.is_infinite() && a.is_sign_negative() == b.is_sign_negative()
+        }
+
+        fn quickcheck_from_str_u64(a: u64, exp: i16) -> bool {
+            let s = format!("{}E{}", a, exp);
+            let d = D64::from_str(&s).unwrap();
+            d == D64::from_parts(false, BUintD8::from(a), exp as i64)
+        }
+    }
+
+    #[test]
+    fn from_str_cases() {
+        let cases = [
+            ("0", "0"),
+            ("-0.00", "-0.00"),
+            ("+1.20", "1.20"),
+            ("00012.3400", "12.3400"),
+            ("1E+3", "1E+3"),
+            ("1e-3", "0.001"),
+            (".5", "0.5"),
+            ("5.", "5"),
+            ("123456789012345678", "1.234567890123457E+17"),
+            ("1234567890123456.5", "1234567890123456"),
+            ("1234567890123457.5", "1234567890123458"),
+            ("1234567890123456.50000000000000000001", "1234567890123457"),
+            (
+                "0.000000000000000000001234567890123456789",
+                "1.234567890123457E-21",
+            ),
+            ("1E+385", "Infinity"),
+            ("1E-399", "0E-398"),
+            ("6E-399", "1E-398"),
+            ("1E+99999999999999999999", "Infinity"),
+            ("0E+99999999999999999999", "0E+369"),
+            ("inf", "Infinity"),
+            ("-Infinity", "-Infinity"),
+            ("NaN", "NaN"),
+            ("-nan123", "-NaN123"),
+            ("sNaN", "sNaN"),
+        ];
+        for (src, expected) in cases {
+            assert_eq!(D64::from_str(src).unwrap().to_string(), expected, "{}", src);
+        }
+        assert_eq!(D32::from_str("1234567.5").unwrap().to_string(), "1234568");
+        assert_eq!(
+            D128::from_str("0.1").unwrap().to_parts(),
+            Some((false, BUintD8::ONE, -1))
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert_eq!(D64::from_str(""), Err(EMPTY));
+        for src in [
+            "+",
+            ".",
+            "e5",
+            "1e",
+            "1e+",
+            "1.2.3",
+            "1_000",
+            "infinit",
+            "nan1234567890123456",
+            " 1",
+            "0x10",
+        ] {
+            assert_eq!(D64::from_str(src), Err(INVALID), "{}", src);
+        }
+    }
+}
@@ -0,0 +1,530 @@
+//! Fixed size IEEE 754 decimal floating point numbers, and utilities for working with them.
+
+use crate::words::{buint_from_u64, buint_to_i64, bytes_to_words, words_to_bytes};
+use crate::{RoundingMode, Status};
+use crate::{BUint, BUintD8, ExpType};
+use core::cmp::Ordering;
+use core::num::FpCategory;
+
+mod cmp;
+mod consts;
+mod convert;
+mod from_str;
+mod ops;
+mod to_str;
+
+#[cfg(feature = "borsh")]
+use ::{
+    alloc::string::ToString,
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+};
+
+/// The IEEE 754 decimal32 format.
+pub type D32 = Decimal<4>;
+
+/// The IEEE 754 decimal64 format.
+pub type D64 = Decimal<8>;
+
+/// The IEEE 754 decimal128 format.
+pub type D128 = Decimal<16>;
+
+/// An IEEE 754 decimal floating point number, stored in `W` bytes using the binary integer decimal (BID) encoding.
+///
+/// A finite `Decimal` represents the value `(-1)^s * c * 10^q`, where the coefficient `c` is an integer with at most [`PRECISION`](Self::PRECISION) decimal digits. Unlike binary floats, decimal floats can represent decimal fractions such as `0.1` exactly. A number may have several representations, which form a *cohort*: for example, `1.0` (`10 * 10^-1`) and `1.00` (`100 * 10^-2`) compare equal, but are distinct values which are displayed differently. Arithmetic follows the IEEE 754-2019 rules for choosing which member of a cohort to return: when the result is exact, its exponent is as close as possible to the *preferred exponent* of the operation.
+///
+/// `W` must be a multiple of 4 between 4 and 64 inclusive, which gives the IEEE 754 decimal interchange format with `8 * W` bits. [`D32`], [`D64`] and [`D128`] are the standard decimal32, decimal64 and decimal128 formats.
+///
+/// The arithmetic methods with a `_round` suffix take a [`RoundingMode`] and return the IEEE 754 exception flags raised by the operation as a [`Status`]. The other arithmetic methods and the operator traits round to nearest with ties to even.
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(BorshSerialize, BorshDeserialize, BorshSchema)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[repr(transparent)]
+pub struct Decimal<const W: usize> {
+    bits: BUintD8<W>,
+}
+
+#[cfg(feature = "zeroize")]
+impl<const W: usize> zeroize::DefaultIsZeroes for Decimal<W> {}
+
+#[cfg(any(test, feature = "quickcheck"))]
+impl<const W: usize> quickcheck::Arbitrary for Decimal<W> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        // special values are chosen often, as they are unlikely to be produced from random bits
+        if <u8 as quickcheck::Arbitrary>::arbitrary(g) % 4 == 0 {
+            return *g
+                .choose(&[
+                    Self::ZERO,
+                    Self::NEG_ZERO,
+                    Self::ONE,
+                    Self::NEG_ONE,
+                    Self::INFINITY,
+                    Self::NEG_INFINITY,
+                    Self::NAN,
+                    Self::MIN,
+                    Self::MAX,
+                    Self::MIN_POSITIVE,
+                    Self::MIN_POSITIVE_SUBNORMAL,
+                ])
+                .unwrap();
+        }
+        Self::from_bits(<BUintD8<W> as quickcheck::Arbitrary>::arbitrary(g))
+    }
+}
+
+/// Returns `10^n`, which must fit in a `BUint<W>`.
+#[inline]
+const fn pow10<const W: usize>(n: u32) -> BUint<W> {
+    BUint::from_digit(10).pow(n)
+}
+
+/// Returns the number of decimal digits of `c`, or zero if `c` is zero.
+#[inline]
+const fn digit_count<const W: usize>(c: BUint<W>) -> u32 {
+    if c.is_zero() {
+        0
+    } else {
+        c.ilog10() + 1
+    }
+}
+
+/// Divides `c` by `10^drop`, rounding the quotient to an integer according to `mode`. Returns the rounded quotient and whether it is inexact.
+///
+/// If `sticky` is set, the exact value being rounded is strictly between `c` and `c + 1`, in which case `drop` must be positive.
+const fn round_digits<const W: usize>(
+    c: BUint<W>,
+    drop: u32,
+    sticky: bool,
+    negative: bool,
+    mode: RoundingMode,
+) -> (BUint<W>, bool) {
+    if drop == 0 {
+        debug_assert!(!sticky);
+        return (c, false);
+    }
+    let (quotient, rem, half_cmp) = if drop > digit_count(c) {
+        // `c < 10^(drop - 1)`, which is less than half of `10^drop`
+        (BUint::ZERO, c, Ordering::Less)
+    } else {
+        let (quotient, rem) = c.div_rem(pow10(drop));
+        let half = BUint::from_digit(5).mul(pow10(drop - 1));
+        (quotient, rem, rem.cmp(&half))
+    };
+    let inexact = sticky || !rem.is_zero();
+    let half_cmp = match half_cmp {
+        Ordering::Equal if sticky => Ordering::Greater,
+        ord => ord,
+    };
+    let round_up = match mode {
+        RoundingMode::NearestTiesToEven => match half_cmp {
+            Ordering::Greater => true,
+            Ordering::Equal => quotient.bit(0),
+            Ordering::Less => false,
+        },
+        RoundingMode::NearestTiesToAway => !matches!(half_cmp, Ordering::Less),
+        RoundingMode::TowardZero => false,
+        RoundingMode::TowardPositive => inexact && !negative,
+        RoundingMode::TowardNegative => inexact && negative,
+    };
+    if round_up {
+        (quotient.add(BUint::ONE), inexact)
+    } else {
+        (quotient, inexact)
+    }
+}
+
+impl<const W: usize> Decimal<W> {
+    const FORMAT_CHECK: () = assert!(
+        W % 4 == 0 && W >= 4 && W <= 64,
+        crate::errors::err_msg!("`Decimal<W>` requires `W` to be a multiple of 4 between 4 and 64")
+    );
+
+    /// The width of the biased exponent field.
+    const EXP_BITS: ExpType = W as ExpType / 2 + 6;
+
+    /// The width of the trailing significand field.
+    const TRAILING_BITS: ExpType = 15 * W as ExpType / 2 - 10;
+
+    /// The number of decimal digits in the coefficient.
+    pub const PRECISION: u32 = 9 * W as u32 / 4 - 2;
+
+    /// The maximum exponent: the largest finite value is less than `10^(EMAX + 1)`.
+    pub const EMAX: i64 = 3 << (W / 2 + 3);
+
+    /// The minimum exponent of a normal number: the smallest positive normal value is `10^EMIN`.
+    pub const EMIN: i64 = 1 - Self::EMAX;
+
+    const BIAS: i64 = Self::EMAX + Self::PRECISION as i64 - 2;
+
+    /// The smallest exponent of the least significant digit of the coefficient.
+    const Q_MIN: i64 = -Self::BIAS;
+
+    /// The largest exponent of the least significant digit of the coefficient.
+    const Q_MAX: i64 = Self::EMAX - Self::PRECISION as i64 + 1;
+
+    /// Returns the most significant byte of `self`, which determines whether `self` is finite, infinite or NaN.
+    #[inline(always)]
+    const fn top_byte(self) -> u8 {
+        self.bits.digits()[W - 1]
+    }
+
+    /// Returns a value with all bits zero except for the most significant byte.
+    #[inline]
+    const fn from_top_byte(byte: u8) -> Self {
+        let mut digits = [0; W];
+        digits[W - 1] = byte;
+        Self::from_bits(BUintD8::from_digits(digits))
+    }
+
+    #[inline]
+    const fn field(self, shift: ExpType, width: ExpType) -> BUintD8<W> {
+        self.bits
+            .shr(shift)
+            .bitand(BUintD8::ONE.shl(width).sub(BUintD8::ONE))
+    }
+
+    /// Splits a finite value into its sign, coefficient and exponent. Non-canonical coefficients, which are larger than the maximum coefficient, are treated as zero.
+    const fn decode(self) -> (bool, BUint<W>, i64) {
+        debug_assert!(self.is_finite());
+        let negative = self.is_sign_negative();
+        let (exp, coeff) = if self.top_byte() & 0x60 == 0x60 {
+            let exp = self.field(Self::TRAILING_BITS + 1, Self::EXP_BITS);
+            let coeff = self
+                .field(0, Self::TRAILING_BITS + 1)
+                .bitor(BUintD8::ONE.shl(Self::TRAILING_BITS + 3));
+            (exp, coeff)
+        } else {
+            let exp = self.field(Self::TRAILING_BITS + 3, Self::EXP_BITS);
+            (exp, self.field(0, Self::TRAILING_BITS + 3))
+        };
+        let coeff = bytes_to_words::<W, W>(coeff);
+        let coeff = if coeff.ge(&Self::MAX_COEFF) {
+            BUint::ZERO
+        } else {
+            coeff
+        };
+        (negative, coeff, buint_to_i64(exp) - Self::BIAS)
+    }
+
+    /// Encodes a finite value. `coeff` must be less than `10^PRECISION`, and `exp` must be between `Q_MIN` and `Q_MAX` inclusive.
+    const fn encode(negative: bool, coeff: BUint<W>, exp: i64) -> Self {
+        debug_assert!(coeff.lt(&Self::MAX_COEFF));
+        debug_assert!(exp >= Self::Q_MIN && exp <= Self::Q_MAX);
+        let coeff = words_to_bytes::<W, W>(coeff);
+        let exp = buint_from_u64::<W>((exp + Self::BIAS) as u64);
+        let bits = if coeff.bits() <= Self::TRAILING_BITS + 3 {
+            exp.shl(Self::TRAILING_BITS + 3).bitor(coeff)
+        } else {
+            let low = coeff.bitand(BUintD8::ONE.shl(Self::TRAILING_BITS + 1).sub(BUintD8::ONE));
+            Self::from_top_byte(0x60)
+                .bits
+                .bitor(exp.shl(Self::TRAILING_BITS + 1))
+                .bitor(low)
+        };
+        let out = Self::from_bits(bits);
+        if negative {
+            out.neg()
+        } else {
+            out
+        }
+    }
+
+    /// Returns the NaN which results from an operation on `self` and `rhs`, at least one of which is NaN, along with the invalid operation flag if either of them is a signalling NaN. The payload of the first NaN operand is preserved.
+    const fn nan_status(self, rhs: Self) -> (Self, Status) {
+        let nan = if self.is_nan() { self } else { rhs };
+        let status = if self.is_signalling_nan() || rhs.is_signalling_nan() {
+            Status::INVALID_OP
+        } else {
+            Status::OK
+        };
+        (nan.quiet(), status)
+    }
+
+    /// Returns `self` with the signalling bit of a NaN cleared, and a non-canonical payload replaced with zero.
+    const fn quiet(self) -> Self {
+        let payload = bytes_to_words::<W, W>(self.field(0, Self::TRAILING_BITS));
+        let payload = if payload.ge(&pow10(Self::PRECISION - 1)) {
+            BUint::ZERO
+        } else {
+            payload
+        };
+        let nan = Self::NAN.bits.bitor(words_to_bytes::<W, W>(payload));
+        let nan = Self::from_bits(nan);
+        if self.is_sign_negative() {
+            nan.neg()
+        } else {
+            nan
+        }
+    }
+
+    /// Returns the infinite or finite value which an overflowing result with the given sign is rounded to.
+    const fn overflow(negative: bool, mode: RoundingMode) -> (Self, Status) {
+        let infinite = match mode {
+            RoundingMode::NearestTiesToEven | RoundingMode::NearestTiesToAway => true,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !negative,
+            RoundingMode::TowardNegative => negative,
+        };
+        let out = if infinite { Self::INFINITY } else { Self::MAX };
+        let out = if negative { out.neg() } else { out };
+        (out, Status::OVERFLOW.union(Status::INEXACT))
+    }
+
+    /// Rounds `(-1)^negative * coeff * 10^exp` to the nearest representable value according to `mode`, keeping the exponent `exp` if the value is exactly representable with it. Returns the result along with the exception flags raised by the rounding. Tininess is detected before rounding.
+    ///
+    /// If `sticky` is set, the exact coefficient is strictly between `coeff` and `coeff + 1`, in which case `coeff` must have more than `PRECISION` digits.
+    const fn round(
+        negative: bool,
+        coeff: BUint<W>,
+        exp: i64,
+        sticky: bool,
+        mode: RoundingMode,
+    ) -> (Self, Status) {
+        let precision = Self::PRECISION as i64;
+        let digits = digit_count(coeff) as i64;
+        debug_assert!(!sticky || digits > precision);
+        // bring the exponent into a range where the calculations below can't overflow
+        let limit = Self::EMAX + 2 * precision + digits;
+        if exp > limit {
+            if coeff.is_zero() {
+                return (Self::encode(negative, coeff, Self::Q_MAX), Status::OK);
+            }
+            return Self::overflow(negative, mode);
+        }
+        let exp = if exp < -limit { -limit } else { exp };
+        let mut drop = digits - precision;
+        if Self::Q_MIN - exp > drop {
+            drop = Self::Q_MIN - exp;
+        }
+        if drop < 0 {
+            drop = 0;
+        }
+        let tiny = !coeff.is_zero() && digits - 1 + exp < Self::EMIN;
+        // dropping more than `digits + 1` digits has the same effect on the coefficient as dropping `digits + 1` digits
+        let capped = if drop > digits + 1 { digits + 1 } else { drop };
+        let (mut coeff, inexact) = round_digits(coeff, capped as u32, sticky, negative, mode);
+        let mut exp = exp + drop;
+        if coeff.eq(&Self::MAX_COEFF) {
+            coeff = pow10(Self::PRECISION - 1);
+            exp += 1;
+        }
+        if exp > Self::Q_MAX {
+            // pad the coefficient with zeros if it is small enough, otherwise the value overflows
+            let shift = exp - Self::Q_MAX;
+            if coeff.is_zero() {
+                exp = Self::Q_MAX;
+            } else if digit_count(coeff) as i64 + shift <= precision {
+                coeff = coeff.mul(pow10(shift as u32));
+                exp = Self::Q_MAX;
+            } else {
+                return Self::overflow(negative, mode);
+            }
+        }
+        let status = if !inexact {
+            Status::OK
+        } else if tiny {
+            Status::UNDERFLOW.union(Status::INEXACT)
+        } else {
+            Status::INEXACT
+        };
+        (Self::encode(negative, coeff, exp), status)
+    }
+
+    /// Returns the largest coefficient plus one, `10^PRECISION`.
+    const MAX_COEFF: BUint<W> = {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FORMAT_CHECK;
+        pow10(Self::PRECISION)
+    };
+}
+
+impl<const W: usize> Decimal<W> {
+    /// Returns `true` if `self` has a negative sign, including `-0.0`, NaNs with a negative sign bit and negative infinity.
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        self.top_byte() & 0x80 != 0
+    }
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`, NaNs with a positive sign bit and positive infinity.
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    /// Returns `true` if `self` is NaN.
+    #[must_use]
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.top_byte() & 0x7C == 0x7C
+    }
+
+    /// Returns `true` if `self` is a signalling NaN.
+    #[must_use]
+    #[inline]
+    pub const fn is_signalling_nan(self) -> bool {
+        self.top_byte() & 0x7E == 0x7E
+    }
+
+    /// Returns `true` if `self` is positive or negative infinity.
+    #[must_use]
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.top_byte() & 0x7C == 0x78
+    }
+
+    /// Returns `true` if `self` is neither infinite nor NaN.
+    #[must_use]
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.top_byte() & 0x78 != 0x78
+    }
+
+    /// Returns `true` if `self` is positive or negative zero, with any exponent.
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        self.is_finite() && self.decode().1.is_zero()
+    }
+
+    /// Returns `true` if `self` is subnormal: that is, it is non-zero and its magnitude is less than `10^EMIN`.
+    #[must_use]
+    #[inline]
+    pub const fn is_subnormal(self) -> bool {
+        matches!(self.classify(), FpCategory::Subnormal)
+    }
+
+    /// Returns `true` if `self` is neither zero, infinite, subnormal nor NaN.
+    #[must_use]
+    #[inline]
+    pub const fn is_normal(self) -> bool {
+        matches!(self.classify(), FpCategory::Normal)
+    }
+
+    /// Returns the floating point category of `self`.
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        if self.is_nan() {
+            return FpCategory::Nan;
+        }
+        if self.is_infinite() {
+            return FpCategory::Infinite;
+        }
+        let (_, coeff, exp) = self.decode();
+        if coeff.is_zero() {
+            FpCategory::Zero
+        } else if digit_count(coeff) as i64 - 1 + exp < Self::EMIN {
+            FpCategory::Subnormal
+        } else {
+            FpCategory::Normal
+        }
+    }
+
+    /// Returns `self` with its sign flipped. This is exact, and is the `const` equivalent of the [`Neg`](core::ops::Neg) implementation.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub const fn neg(self) -> Self {
+        let mut bits = self.bits;
+        bits.digits[W - 1] ^= 0x80;
+        Self::from_bits(bits)
+    }
+
+    /// Returns the absolute value of `self`.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            self.neg()
+        } else {
+            self
+        }
+    }
+
+    /// Returns `self` with the sign of `sign`.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub const fn copysign(self, sign: Self) -> Self {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            self.neg()
+        }
+    }
+}
+
+impl<const W: usize> Default for Decimal<W> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{D128, D32, D64};
+    use crate::BUintD8;
+    use core::num::FpCategory;
+
+    #[test]
+    fn format_parameters() {
+        assert_eq!((D32::PRECISION, D32::EMAX, D32::BIAS), (7, 96, 101));
+        assert_eq!((D64::PRECISION, D64::EMAX, D64::BIAS), (16, 384, 398));
+        assert_eq!((D128::PRECISION, D128::EMAX, D128::BIAS), (34, 6144, 6176));
+        assert_eq!((D64::EXP_BITS, D64::TRAILING_BITS), (10, 50));
+        assert_eq!((D128::EXP_BITS, D128::TRAILING_BITS), (14, 110));
+    }
+
+    #[test]
+    fn bid_encoding() {
+        let d64 = |bits: u64| D64::from_bits(BUintD8::from(bits));
+        assert_eq!(D64::ONE.to_bits(), BUintD8::from(0x31C0000000000001u64));
+        assert_eq!(
+            D64::NEG_ZERO.to_bits(),
+            BUintD8::from(0xB1C0000000000000u64)
+        );
+        assert_eq!(
+            D64::INFINITY.to_bits(),
+            BUintD8::from(0x7800000000000000u64)
+        );
+        assert_eq!(D64::NAN.to_bits(), BUintD8::from(0x7C00000000000000u64));
+        assert_eq!(D64::MAX.to_bits(), BUintD8::from(0x77FB86F26FC0FFFFu64));
+        assert_eq!(D64::MIN_POSITIVE_SUBNORMAL.to_bits(), BUintD8::from(1u64));
+        assert_eq!(
+            D128::ONE.to_bits(),
+            BUintD8::from(0x3040_0000_0000_0000_0000_0000_0000_0001u128)
+        );
+        assert_eq!(D32::ONE.to_bits(), BUintD8::from(0x32800001u32));
+
+        // 0.1 and 9999999999999999, which uses the second form of the encoding
+        assert_eq!(
+            d64(0x31A0000000000001).to_parts(),
+            Some((false, BUintD8::ONE, -1))
+        );
+        assert_eq!(
+            d64(0x6C7386F26FC0FFFF).to_parts(),
+            Some((false, BUintD8::from(9999999999999999u64), 0))
+        );
+        // non-canonical coefficients are treated as zero
+        assert!(d64(0x6C7FFFFFFFFFFFFF).is_zero());
+        assert!(d64(0x7E00000000000000).is_signalling_nan());
+        assert!(d64(0xFC00000000000000).is_nan());
+        assert!(d64(0xF800000000000000).is_infinite());
+    }
+
+    #[test]
+    fn classify() {
+        assert_eq!(D64::ZERO.classify(), FpCategory::Zero);
+        assert_eq!(D64::ONE.classify(), FpCategory::Normal);
+        assert_eq!(D64::MIN_POSITIVE.classify(), FpCategory::Normal);
+        assert_eq!(
+            D64::MIN_POSITIVE_SUBNORMAL.classify(),
+            FpCategory::Subnormal
+        );
+        assert_eq!(D64::NEG_INFINITY.classify(), FpCategory::Infinite);
+        assert_eq!(D64::NAN.classify(), FpCategory::Nan);
+        assert!(D64::MIN.is_sign_negative() && D64::MIN.is_finite());
+    }
+}
@@ -0,0 +1,598 @@
+use super::{digit_count, pow10, round_digits, Decimal};
+use crate::doc;
+use crate::{RoundingMode, Status};
+use crate::BUint;
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+impl<const W: usize> Decimal<W> {
+    /// Returns a zero with the given sign and exponent, clamping the exponent to the representable range.
+    #[inline]
+    const fn zero_with_exp(negative: bool, exp: i64) -> Self {
+        Self::round(
+            negative,
+            BUint::ZERO,
+            exp,
+            false,
+            RoundingMode::NearestTiesToEven,
+        )
+        .0
+    }
+
+    /// Computes `self + rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    ///
+    /// If the result is exact, its exponent is the smaller of the exponents of `self` and `rhs`, or as close to this as the precision allows.
+    pub const fn add_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status) {
+        if self.is_nan() || rhs.is_nan() {
+            return self.nan_status(rhs);
+        }
+        match (self.is_infinite(), rhs.is_infinite()) {
+            (true, true) => {
+                if self.is_sign_negative() == rhs.is_sign_negative() {
+                    return (self, Status::OK);
+                }
+                return (Self::NAN, Status::INVALID_OP);
+            }
+            (true, false) => return (self, Status::OK),
+            (false, true) => return (rhs, Status::OK),
+            (false, false) => {}
+        }
+        let (x, y) = (self.decode(), rhs.decode());
+        // `a` is the operand with the larger exponent
+        let ((a_neg, a_coeff, a_exp), (b_neg, b_coeff, b_exp)) =
+            if x.2 >= y.2 { (x, y) } else { (y, x) };
+        let precision = Self::PRECISION as i64;
+        if b_coeff.is_zero() {
+            if a_coeff.is_zero() {
+                // an exact zero sum is positive unless both operands are negative, or the rounding mode is toward negative infinity
+                let negative = if a_neg == b_neg {
+                    a_neg
+                } else {
+                    matches!(mode, RoundingMode::TowardNegative)
+                };
+                return (Self::encode(negative, BUint::ZERO, b_exp), Status::OK);
+            }
+            // the result is `a`, with its exponent reduced toward the exponent of `b` as far as the precision allows
+            let mut shift = precision - digit_count(a_coeff) as i64;
+            if a_exp - b_exp < shift {
+                shift = a_exp - b_exp;
+            }
+            return (
+                Self::encode(a_neg, a_coeff.mul(pow10(shift as u32)), a_exp - shift),
+                Status::OK,
+            );
+        }
+        if a_coeff.is_zero() {
+            return (Self::encode(b_neg, b_coeff, b_exp), Status::OK);
+        }
+
+        let (mut a_coeff, mut a_exp) = (a_coeff, a_exp);
+        let mut diff = a_exp - b_exp;
+        if diff > precision + 2 {
+            // padding `a` with zeros doesn't change the result, but may allow it to be computed exactly
+            let mut pad = precision - digit_count(a_coeff) as i64;
+            if diff - (precision + 2) < pad {
+                pad = diff - (precision + 2);
+            }
+            a_coeff = a_coeff.mul(pow10(pad as u32));
+            a_exp -= pad;
+            diff -= pad;
+        }
+        if diff > precision + 2 {
+            // `a` has `PRECISION` digits, and `b` is less than one unit in the last place of `a * 10^3`, so only affects the rounding as a sticky digit
+            let a_coeff = a_coeff.mul(pow10(3));
+            return if a_neg == b_neg {
+                Self::round(a_neg, a_coeff, a_exp - 3, true, mode)
+            } else {
+                Self::round(a_neg, a_coeff.sub(BUint::ONE), a_exp - 3, true, mode)
+            };
+        }
+        // the aligned coefficients have at most `2 * PRECISION + 2` digits, so fit in a `BUint<W>`
+        let a_coeff = a_coeff.mul(pow10(diff as u32));
+        if a_neg == b_neg {
+            return Self::round(a_neg, a_coeff.add(b_coeff), b_exp, false, mode);
+        }
+        match a_coeff.cmp(&b_coeff) {
+            Ordering::Greater => Self::round(a_neg, a_coeff.sub(b_coeff), b_exp, false, mode),
+            Ordering::Less => Self::round(b_neg, b_coeff.sub(a_coeff), b_exp, false, mode),
+            Ordering::Equal => {
+                let negative = matches!(mode, RoundingMode::TowardNegative);
+                (Self::encode(negative, BUint::ZERO, b_exp), Status::OK)
+            }
+        }
+    }
+
+    /// Computes `self - rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    ///
+    /// If the result is exact, its exponent is the smaller of the exponents of `self` and `rhs`, or as close to this as the precision allows.
+    #[inline]
+    pub const fn sub_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status) {
+        if rhs.is_nan() {
+            return self.nan_status(rhs);
+        }
+        self.add_round(rhs.neg(), mode)
+    }
+
+    /// Computes `self * rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    ///
+    /// If the result is exact, its exponent is the sum of the exponents of `self` and `rhs`, or as close to this as the precision allows.
+    pub const fn mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status) {
+        if self.is_nan() || rhs.is_nan() {
+            return self.nan_status(rhs);
+        }
+        let negative = self.is_sign_negative() ^ rhs.is_sign_negative();
+        if self.is_infinite() || rhs.is_infinite() {
+            if self.is_zero() || rhs.is_zero() {
+                return (Self::NAN, Status::INVALID_OP);
+            }
+            let out = if negative {
+                Self::NEG_INFINITY
+            } else {
+                Self::INFINITY
+            };
+            return (out, Status::OK);
+        }
+        let (_, x_coeff, x_exp) = self.decode();
+        let (_, y_coeff, y_exp) = rhs.decode();
+        // the product has at most `2 * PRECISION` digits, so fits in a `BUint<W>`
+        Self::round(negative, x_coeff.mul(y_coeff), x_exp + y_exp, false, mode)
+    }
+
+    /// Computes `self / rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    ///
+    /// If the result is exact, its exponent is the difference of the exponents of `self` and `rhs`, or as close to this as the precision allows.
+    pub const fn div_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status) {
+        if self.is_nan() || rhs.is_nan() {
+            return self.nan_status(rhs);
+        }
+        let negative = self.is_sign_negative() ^ rhs.is_sign_negative();
+        match (self.is_infinite(), rhs.is_infinite()) {
+            (true, true) => return (Self::NAN, Status::INVALID_OP),
+            (true, false) => {
+                let out = if negative {
+                    Self::NEG_INFINITY
+                } else {
+                    Self::INFINITY
+                };
+                return (out, Status::OK);
+            }
+            (false, true) => return (Self::zero_with_exp(negative, Self::Q_MIN), Status::OK),
+            (false, false) => {}
+        }
+        let (_, x_coeff, x_exp) = self.decode();
+        let (_, y_coeff, y_exp) = rhs.decode();
+        if y_coeff.is_zero() {
+            if x_coeff.is_zero() {
+                return (Self::NAN, Status::INVALID_OP);
+            }
+            let out = if negative {
+                Self::NEG_INFINITY
+            } else {
+                Self::INFINITY
+            };
+            return (out, Status::DIV_BY_ZERO);
+        }
+        let ideal_exp = x_exp - y_exp;
+        if x_coeff.is_zero() {
+            return (Self::zero_with_exp(negative, ideal_exp), Status::OK);
+        }
+        // scale the dividend so that the quotient has `PRECISION + 1` or `PRECISION + 2` digits, which is enough to round it correctly using the remainder as a sticky digit
+        let scale = Self::PRECISION + 1 + digit_count(y_coeff) - digit_count(x_coeff);
+        let (mut quotient, rem) = x_coeff.mul(pow10(scale)).div_rem(y_coeff);
+        let mut exp = ideal_exp - scale as i64;
+        if !rem.is_zero() {
+            return Self::round(negative, quotient, exp, true, mode);
+        }
+        // the quotient is exact, so remove trailing zeros to bring the exponent toward the ideal exponent
+        while exp < ideal_exp {
+            let (q, r) = quotient.div_rem_digit(10);
+            if r != 0 {
+                break;
+            }
+            quotient = q;
+            exp += 1;
+        }
+        Self::round(negative, quotient, exp, false, mode)
+    }
+
+    /// Returns `self` rounded according to `mode` to a value with the same exponent as `exp`. Returns the result along with the exception flags raised by the operation.
+    ///
+    /// This is the IEEE 754 `quantize` operation, which can be used to round a value to a fixed number of decimal places. For example, quantizing `2.17` to the exponent of `0.01` gives `2.17`, to the exponent of `0.1` gives `2.2` when rounding to nearest, and to the exponent of `0.001` gives `2.170`. The invalid operation flag is raised and NaN is returned if the result would need more than [`PRECISION`](Self::PRECISION) digits, or if exactly one of `self` and `exp` is infinite. The underflow flag is never raised.
+    pub const fn quantize_round(self, exp: Self, mode: RoundingMode) -> (Self, Status) {
+        if self.is_nan() || exp.is_nan() {
+            return self.nan_status(exp);
+        }
+        if self.is_infinite() || exp.is_infinite() {
+            if self.is_infinite() && exp.is_infinite() {
+                return (self, Status::OK);
+            }
+            return (Self::NAN, Status::INVALID_OP);
+        }
+        let (negative, coeff, x_exp) = self.decode();
+        let (_, _, exp) = exp.decode();
+        let digits = digit_count(coeff) as i64;
+        if x_exp >= exp {
+            let shift = x_exp - exp;
+            if coeff.is_zero() {
+                return (Self::encode(negative, coeff, exp), Status::OK);
+            }
+            if digits + shift > Self::PRECISION as i64 {
+                return (Self::NAN, Status::INVALID_OP);
+            }
+            return (
+                Self::encode(negative, coeff.mul(pow10(shift as u32)), exp),
+                Status::OK,
+            );
+        }
+        let mut drop = exp - x_exp;
+        if drop > digits + 1 {
+            drop = digits + 1;
+        }
+        let (coeff, inexact) = round_digits(coeff, drop as u32, false, negative, mode);
+        if coeff.eq(&Self::MAX_COEFF) {
+            return (Self::NAN, Status::INVALID_OP);
+        }
+        let status = if inexact { Status::INEXACT } else { Status::OK };
+        (Self::encode(negative, coeff, exp), status)
+    }
+
+    /// Computes `self + rhs`, rounding to nearest with ties to even. This is the `const` equivalent of the [`Add`] implementation.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn add(self, rhs: Self) -> Self {
+        self.add_round(rhs, RoundingMode::NearestTiesToEven).0
+    }
+
+    /// Computes `self - rhs`, rounding to nearest with ties to even. This is the `const` equivalent of the [`Sub`] implementation.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sub(self, rhs: Self) -> Self {
+        self.sub_round(rhs, RoundingMode::NearestTiesToEven).0
+    }
+
+    /// Computes `self * rhs`, rounding to nearest with ties to even. This is the `const` equivalent of the [`Mul`] implementation.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn mul(self, rhs: Self) -> Self {
+        self.mul_round(rhs, RoundingMode::NearestTiesToEven).0
+    }
+
+    /// Computes `self / rhs`, rounding to nearest with ties to even. This is the `const` equivalent of the [`Div`] implementation.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn div(self, rhs: Self) -> Self {
+        self.div_round(rhs, RoundingMode::NearestTiesToEven).0
+    }
+
+    /// Returns `self` rounded to nearest with ties to even, to a value with the same exponent as `exp`. See [`quantize_round`](Self::quantize_round) for details.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn quantize(self, exp: Self) -> Self {
+        self.quantize_round(exp, RoundingMode::NearestTiesToEven).0
+    }
+}
+
+macro_rules! op_trait {
+    ($($Trait: ident, $method: ident); *) => {
+        $(
+            impl<const W: usize> $Trait for Decimal<W> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self::$method(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+op_trait!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl<const W: usize> Neg for Decimal<W> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::D64;
+    use crate::{RoundingMode, Status};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestTiesToEven,
+        RoundingMode::NearestTiesToAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+    ];
+
+    fn d(s: &str) -> D64 {
+        D64::from_str(s).unwrap()
+    }
+
+    /// Rounds `(-1)^negative * coeff * 10^exp` to 16 digits, where `sticky` indicates that the exact coefficient is slightly larger than `coeff`. The exponent must be well within the range of `D64`.
+    fn round_ref(
+        negative: bool,
+        mut coeff: u128,
+        mut exp: i64,
+        sticky: bool,
+        mode: RoundingMode,
+    ) -> (bool, u64, i64, bool) {
+        let mut rem = 0;
+        let mut scale = 1;
+        while coeff >= 10u128.pow(16) {
+            rem += (coeff % 10) * scale;
+            scale *= 10;
+            coeff /= 10;
+            exp += 1;
+        }
+        let inexact = rem != 0 || sticky;
+        let half = scale / 2;
+        let above = rem > half || (rem == half && sticky && scale > 1);
+        let tie = rem == half && !sticky && scale > 1;
+        let up = match mode {
+            RoundingMode::NearestTiesToEven => above || (tie && coeff % 2 == 1),
+            RoundingMode::NearestTiesToAway => above || tie,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => inexact && !negative,
+            RoundingMode::TowardNegative => inexact && negative,
+        };
+        if up {
+            coeff += 1;
+            if coeff == 10u128.pow(16) {
+                coeff /= 10;
+                exp += 1;
+            }
+        }
+        (negative, coeff as u64, exp, inexact)
+    }
+
+    fn check(result: (D64, Status), expected: (bool, u64, i64, bool)) -> bool {
+        let (negative, coeff, exp, inexact) = expected;
+        let parts = result.0.to_parts().unwrap();
+        let status = if inexact { Status::INEXACT } else { Status::OK };
+        (parts.0 == negative || coeff == 0)
+            && parts.1 == coeff.into()
+            && parts.2 == exp
+            && result.1 == status
+    }
+
+    fn operands(a: u64, b: u64, qa: i8, qb: i8) -> (u128, u128, i64, i64) {
+        let max = 10u64.pow(16);
+        (
+            (a % max) as u128,
+            (b % max) as u128,
+            (qa % 12) as i64,
+            (qb % 12) as i64,
+        )
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_add_round(a: u64, b: u64, qa: i8, qb: i8, neg_a: bool, neg_b: bool) -> quickcheck::TestResult {
+            let (a, b, qa, qb) = operands(a, b, qa, qb);
+            if a == 0 || b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let q = qa.min(qb);
+            let a_aligned = a * 10u128.pow((qa - q) as u32);
+            let b_aligned = b * 10u128.pow((qb - q) as u32);
+            let (negative, sum) = if neg_a == neg_b {
+                (neg_a, a_aligned + b_aligned)
+            } else if a_aligned >= b_aligned {
+                (neg_a, a_aligned - b_aligned)
+            } else {
+                (neg_b, b_aligned - a_aligned)
+            };
+            let x = D64::from_parts(neg_a, (a as u64).into(), qa);
+            let y = D64::from_parts(neg_b, (b as u64).into(), qb);
+            quickcheck::TestResult::from_bool(MODES.iter().all(|&mode| {
+                check(x.add_round(y, mode), round_ref(negative, sum, q, false, mode))
+                    && check(x.sub_round(y.neg(), mode), round_ref(negative, sum, q, false, mode))
+            }))
+        }
+
+        fn quickcheck_mul_round(a: u64, b: u64, qa: i8, qb: i8, neg_a: bool, neg_b: bool) -> bool {
+            let (a, b, qa, qb) = operands(a, b, qa, qb);
+            let x = D64::from_parts(neg_a, (a as u64).into(), qa);
+            let y = D64::from_parts(neg_b, (b as u64).into(), qb);
+            MODES.iter().all(|&mode| {
+                check(x.mul_round(y, mode), round_ref(neg_a ^ neg_b, a * b, qa + qb, false, mode))
+            })
+        }
+
+        fn quickcheck_div_round(a: u64, b: u64, qa: i8, qb: i8, neg_a: bool, neg_b: bool) -> quickcheck::TestResult {
+            let (a, b, qa, qb) = operands(a, b, qa, qb);
+            if a == 0 || b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let x = D64::from_parts(neg_a, (a as u64).into(), qa);
+            let y = D64::from_parts(neg_b, (b as u64).into(), qb);
+            // divide with 18 significant digits, then remove trailing zeros if the quotient is exact
+            let digits = |n: u128| n.to_string().len() as i64;
+            let scale = 17 + digits(b) - digits(a);
+            let num = a * 10u128.pow(scale as u32);
+            let (mut quotient, rem) = (num / b, num % b);
+            let mut exp = qa - qb - scale;
+            while rem == 0 && exp < qa - qb && quotient % 10 == 0 {
+                quotient /= 10;
+                exp += 1;
+            }
+            quickcheck::TestResult::from_bool(MODES.iter().all(|&mode| {
+                check(x.div_round(y, mode), round_ref(neg_a ^ neg_b, quotient, exp, rem != 0, mode))
+            }))
+        }
+    }
+
+    #[test]
+    fn arithmetic_cases() {
+        assert_eq!((d("0.1") + d("0.2")).to_string(), "0.3");
+        assert_eq!((d("1.30") + d("1.20")).to_string(), "2.50");
+        assert_eq!((d("1.3") - d("1.07")).to_string(), "0.23");
+        assert_eq!((d("1.3") - d("1.30")).to_string(), "0.00");
+        assert_eq!((d("1.3") - d("2.07")).to_string(), "-0.77");
+        assert_eq!((d("1.20") * d("3")).to_string(), "3.60");
+        assert_eq!((d("0.9") * d("0.8")).to_string(), "0.72");
+        assert_eq!((d("1") / d("3")).to_string(), "0.3333333333333333");
+        assert_eq!((d("2") / d("3")).to_string(), "0.6666666666666667");
+        assert_eq!((d("5") / d("2")).to_string(), "2.5");
+        assert_eq!((d("1") / d("10")).to_string(), "0.1");
+        assert_eq!((d("12") / d("12")).to_string(), "1");
+        assert_eq!((d("8.00") / d("2")).to_string(), "4.00");
+        assert_eq!((d("2.400") / d("2.0")).to_string(), "1.20");
+        assert_eq!((d("1000") / d("100")).to_string(), "10");
+        assert_eq!((d("2.40E+6") / d("2")).to_string(), "1.20E+6");
+        assert_eq!((d("1E+16") + d("1")).to_string(), "1.000000000000000E+16");
+        assert_eq!(
+            d("1E+16").add_round(d("1"), RoundingMode::TowardPositive),
+            (d("1.000000000000001E+16"), Status::INEXACT)
+        );
+        assert_eq!((d("1") - d("1E-40")).to_string(), "1.000000000000000");
+        assert_eq!(
+            d("1").sub_round(d("1E-40"), RoundingMode::TowardZero),
+            (d("0.9999999999999999"), Status::INEXACT)
+        );
+        assert_eq!((d("1") + d("1E-40")).to_string(), "1.000000000000000");
+        assert_eq!((d("12345") + d("0E-30")).to_string(), "12345.00000000000");
+        assert_eq!(
+            d("1")
+                .sub_round(d("1"), RoundingMode::TowardNegative)
+                .0
+                .to_string(),
+            "-0"
+        );
+        assert_eq!((d("-0") + d("-0.00")).to_string(), "-0.00");
+    }
+
+    #[test]
+    fn special_cases() {
+        assert_eq!(
+            D64::INFINITY
+                .add_round(D64::NEG_INFINITY, RoundingMode::NearestTiesToEven)
+                .1,
+            Status::INVALID_OP
+        );
+        assert_eq!(
+            D64::ZERO
+                .mul_round(D64::INFINITY, RoundingMode::NearestTiesToEven)
+                .1,
+            Status::INVALID_OP
+        );
+        assert_eq!(
+            D64::ONE.div_round(D64::ZERO, RoundingMode::NearestTiesToEven),
+            (D64::INFINITY, Status::DIV_BY_ZERO)
+        );
+        assert_eq!(
+            D64::ZERO
+                .div_round(D64::NEG_ZERO, RoundingMode::NearestTiesToEven)
+                .1,
+            Status::INVALID_OP
+        );
+        assert_eq!(D64::ONE.div(D64::NEG_INFINITY).to_string(), "-0E-398");
+        let (nan, status) = D64::SNAN
+            .neg()
+            .add_round(D64::ONE, RoundingMode::NearestTiesToEven);
+        assert!(nan.is_nan() && !nan.is_signalling_nan() && nan.is_sign_negative());
+        assert_eq!(status, Status::INVALID_OP);
+        assert!((D64::NAN * D64::ONE).is_nan());
+
+        assert_eq!(
+            D64::MAX.add_round(D64::MAX, RoundingMode::NearestTiesToEven),
+            (D64::INFINITY, Status::OVERFLOW | Status::INEXACT)
+        );
+        assert_eq!(
+            D64::MAX.add_round(D64::MAX, RoundingMode::TowardZero),
+            (D64::MAX, Status::OVERFLOW | Status::INEXACT)
+        );
+        assert_eq!(
+            D64::MIN.mul_round(D64::TEN, RoundingMode::TowardPositive),
+            (D64::MIN, Status::OVERFLOW | Status::INEXACT)
+        );
+        assert_eq!(
+            D64::MIN_POSITIVE.div_round(D64::TEN, RoundingMode::NearestTiesToEven),
+            (d("1E-384"), Status::OK)
+        );
+        assert_eq!(
+            D64::MIN_POSITIVE_SUBNORMAL.div_round(d("3"), RoundingMode::NearestTiesToEven),
+            (
+                D64::ZERO.quantize(D64::MIN_POSITIVE_SUBNORMAL),
+                Status::UNDERFLOW | Status::INEXACT
+            )
+        );
+        assert_eq!(
+            D64::MIN_POSITIVE_SUBNORMAL.div_round(d("3"), RoundingMode::TowardPositive),
+            (
+                D64::MIN_POSITIVE_SUBNORMAL,
+                Status::UNDERFLOW | Status::INEXACT
+            )
+        );
+        // large exponents are clamped by padding the coefficient with zeros
+        assert_eq!((d("1E+369") * D64::TEN).to_string(), "1.0E+370");
+    }
+
+    #[test]
+    fn quantize() {
+        let cases = [
+            ("2.17", "0.001", "2.170"),
+            ("2.17", "0.01", "2.17"),
+            ("2.17", "0.1", "2.2"),
+            ("2.17", "1E+0", "2"),
+            ("2.17", "1E+1", "0E+1"),
+            ("-0.1", "1", "-0"),
+            ("-0", "1E+5", "-0E+5"),
+            ("217", "1E-1", "217.0"),
+            ("217", "1E+1", "2.2E+2"),
+            ("217", "1E+2", "2E+2"),
+            ("0.5", "1", "0"),
+            ("1.5", "1", "2"),
+        ];
+        for (x, exp, expected) in cases {
+            assert_eq!(d(x).quantize(d(exp)).to_string(), expected, "{} {}", x, exp);
+        }
+        assert_eq!(
+            d("2.17").quantize_round(d("0.1"), RoundingMode::TowardZero),
+            (d("2.1"), Status::INEXACT)
+        );
+        assert_eq!(
+            d("2.15")
+                .quantize_round(d("0.1"), RoundingMode::NearestTiesToAway)
+                .0
+                .to_string(),
+            "2.2"
+        );
+        assert_eq!(
+            d("2.25")
+                .quantize_round(d("0.1"), RoundingMode::NearestTiesToEven)
+                .0
+                .to_string(),
+            "2.2"
+        );
+        assert_eq!(
+            d("-2.11")
+                .quantize_round(d("0.1"), RoundingMode::TowardNegative)
+                .0
+                .to_string(),
+            "-2.2"
+        );
+        assert_eq!(
+            d("1E+16")
+                .quantize_round(d("1"), RoundingMode::NearestTiesToEven)
+                .1,
+            Status::INVALID_OP
+        );
+        assert_eq!(
+            d("9999999999999999.5")
+                .quantize_round(d("1"), RoundingMode::NearestTiesToEven)
+                .1,
+            Status::INVALID_OP
+        );
+        assert_eq!(D64::INFINITY.quantize(D64::NEG_INFINITY), D64::INFINITY);
+        assert!(D64::INFINITY.quantize(D64::ONE).is_nan());
+    }
+}
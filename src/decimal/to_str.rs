@@ -0,0 +1,113 @@
+use super::{pow10, Decimal};
+use crate::words::bytes_to_words;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Debug, Display, Formatter, Write};
+
+impl<const W: usize> Decimal<W> {
+    /// Writes the magnitude of `self` using the IEEE 754 to-scientific-string conversion.
+    fn write_magnitude(self, buf: &mut String) -> fmt::Result {
+        if self.is_nan() {
+            buf.push_str(if self.is_signalling_nan() {
+                "sNaN"
+            } else {
+                "NaN"
+            });
+            let payload = bytes_to_words::<W, W>(self.field(0, Self::TRAILING_BITS));
+            // non-canonical payloads are treated as zero
+            if !payload.is_zero() && payload.lt(&pow10(Self::PRECISION - 1)) {
+                write!(buf, "{}", payload)?;
+            }
+            return Ok(());
+        }
+        if self.is_infinite() {
+            buf.push_str("Infinity");
+            return Ok(());
+        }
+        let (_, coeff, exp) = self.decode();
+        let digits = coeff.to_string();
+        let len = digits.len() as i64;
+        // the exponent of the most significant digit
+        let adjusted = exp + len - 1;
+        if exp <= 0 && adjusted >= -6 {
+            // plain notation, with the decimal point placed according to the exponent
+            if exp == 0 {
+                buf.push_str(&digits);
+            } else if len > -exp {
+                let (int, frac) = digits.split_at((len + exp) as usize);
+                write!(buf, "{}.{}", int, frac)?;
+            } else {
+                buf.push_str("0.");
+                for _ in 0..(-exp - len) {
+                    buf.push('0');
+                }
+                buf.push_str(&digits);
+            }
+        } else {
+            let (first, rest) = digits.split_at(1);
+            buf.push_str(first);
+            if !rest.is_empty() {
+                write!(buf, ".{}", rest)?;
+            }
+            write!(buf, "E{:+}", adjusted)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const W: usize> Display for Decimal<W> {
+    /// Formats the value using the IEEE 754 to-scientific-string conversion, which shows every digit of the coefficient, so that the exponent is preserved when the string is parsed. Values with an exponent of at most zero are written in positional notation, such as `1.20` or `0.0001`, unless they are very small; other values are written in scientific notation, such as `1.20E+5`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut buf = String::new();
+        self.write_magnitude(&mut buf)?;
+        f.pad_integral(self.is_sign_positive(), "", &buf)
+    }
+}
+
+impl<const W: usize> Debug for Decimal<W> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{D128, D32, D64};
+    use crate::BUintD8;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        let cases: [(bool, u64, i64, &str); 14] = [
+            (false, 123, 0, "123"),
+            (true, 123, 0, "-123"),
+            (false, 123, 1, "1.23E+3"),
+            (false, 123, 3, "1.23E+5"),
+            (false, 123, -1, "12.3"),
+            (false, 123, -5, "0.00123"),
+            (false, 123, -10, "1.23E-8"),
+            (true, 123, -12, "-1.23E-10"),
+            (false, 0, 0, "0"),
+            (false, 0, -2, "0.00"),
+            (false, 0, 2, "0E+2"),
+            (true, 0, 0, "-0"),
+            (false, 5, -6, "0.000005"),
+            (false, 50, -9, "5.0E-8"),
+        ];
+        for (negative, coeff, exp, expected) in cases {
+            assert_eq!(
+                D64::from_parts(negative, BUintD8::from(coeff), exp).to_string(),
+                expected
+            );
+        }
+        assert_eq!(D64::MAX.to_string(), "9.999999999999999E+384");
+        assert_eq!(D64::MIN_POSITIVE_SUBNORMAL.to_string(), "1E-398");
+        assert_eq!(D32::MAX.to_string(), "9.999999E+96");
+        assert_eq!(D128::EPSILON.to_string(), "1E-33");
+        assert_eq!(D64::NEG_INFINITY.to_string(), "-Infinity");
+        assert_eq!(D64::SNAN.neg().to_string(), "-sNaN");
+        assert_eq!(format!("{:>8}", D64::ONE), "       1");
+        assert_eq!(format!("{:+}", D64::ONE), "+1");
+        assert_eq!(format!("{:06}", D64::NEG_ONE), "-00001");
+        assert_eq!(format!("{:?}", D64::TEN), "10");
+    }
+}
@@ -0,0 +1,192 @@
+use crate::int::endian::is_minimal_der_integer;
+use ::der::{DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Tag, Writer};
+
+/// Reads the contents of an `INTEGER` of `len` bytes, returning an error if they are not in canonical form. `push_byte` is called with each byte in big-endian order, and returns `false` if the value is out of range.
+fn read_integer<'a, R: Reader<'a>>(
+    reader: &mut R,
+    header: Header,
+    mut push_byte: impl FnMut(u8) -> bool,
+) -> ::der::Result<()> {
+    let len = usize::try_from(header.length)?;
+    let mut first = 0;
+    for i in 0..len {
+        let byte = reader.read_byte()?;
+        match i {
+            0 => first = byte,
+            1 if !is_minimal_der_integer(&[first, byte]) => {
+                return Err(Tag::Integer.non_canonical_error())
+            }
+            _ => {}
+        }
+        if !push_byte(byte) {
+            return Err(Tag::Integer.value_error());
+        }
+    }
+    if len == 0 {
+        return Err(Tag::Integer.non_canonical_error());
+    }
+    Ok(())
+}
+
+macro_rules! der {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> FixedTag for $BUint<N> {
+            const TAG: Tag = Tag::Integer;
+        }
+
+        /// The integer is encoded as the minimal big-endian two's complement bytes of its value, with a leading zero byte if its most significant bit is set. See [`to_der_integer_bytes`](Self::to_der_integer_bytes).
+        impl<const N: usize> EncodeValue for $BUint<N> {
+            #[inline]
+            fn value_len(&self) -> ::der::Result<Length> {
+                Length::try_from(self.bits() as usize / 8 + 1)
+            }
+
+            fn encode_value(&self, writer: &mut impl Writer) -> ::der::Result<()> {
+                for i in (0..self.bits() as usize / 8 + 1).rev() {
+                    let bit = i * 8;
+                    let byte = if bit >= Self::BITS as usize {
+                        0
+                    } else {
+                        (self.digits[bit / $Digit::BITS as usize] >> (bit % $Digit::BITS as usize)) as u8
+                    };
+                    writer.write_byte(byte)?;
+                }
+                Ok(())
+            }
+        }
+
+        /// Only canonical encodings are accepted: decoding fails if the bytes are empty or have a redundant leading byte, or if the value is negative or too large to fit in the integer.
+        impl<'a, const N: usize> DecodeValue<'a> for $BUint<N> {
+            fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> ::der::Result<Self> {
+                let mut out = Self::ZERO;
+                let mut first = true;
+                read_integer(reader, header, |byte| {
+                    if core::mem::take(&mut first) && byte & 0x80 != 0 {
+                        return false;
+                    }
+                    if out.leading_zeros() < 8 {
+                        return false;
+                    }
+                    out = (out << 8u32) | Self::from_digit(byte as $Digit);
+                    true
+                })?;
+                Ok(out)
+            }
+        }
+
+        impl<const N: usize> FixedTag for $BInt<N> {
+            const TAG: Tag = Tag::Integer;
+        }
+
+        /// The integer is encoded as the minimal big-endian two's complement bytes of its value. See [`to_der_integer_bytes`](Self::to_der_integer_bytes).
+        impl<const N: usize> EncodeValue for $BInt<N> {
+            #[inline]
+            fn value_len(&self) -> ::der::Result<Length> {
+                let magnitude = if self.is_negative() { !*self } else { *self };
+                Length::try_from(magnitude.bits.bits() as usize / 8 + 1)
+            }
+
+            fn encode_value(&self, writer: &mut impl Writer) -> ::der::Result<()> {
+                let len = usize::try_from(self.value_len()?)?;
+                for i in (0..len).rev() {
+                    let bit = i * 8;
+                    writer.write_byte((self.bits.digits[bit / $Digit::BITS as usize] >> (bit % $Digit::BITS as usize)) as u8)?;
+                }
+                Ok(())
+            }
+        }
+
+        /// Only canonical encodings are accepted: decoding fails if the bytes are empty or have a redundant leading byte, or if the value is too large in magnitude to fit in the integer.
+        impl<'a, const N: usize> DecodeValue<'a> for $BInt<N> {
+            fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> ::der::Result<Self> {
+                let mut out = $BUint::<N>::ZERO;
+                let mut len = 0;
+                read_integer(reader, header, |byte| {
+                    if len == Self::BYTES {
+                        return false;
+                    }
+                    if len == 0 && byte & 0x80 != 0 {
+                        out = $BUint::MAX;
+                    }
+                    len += 1;
+                    out = (out << 8u32) | $BUint::from_digit(byte as $Digit);
+                    true
+                })?;
+                Ok(Self::from_bits(out))
+            }
+        }
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use crate::test::types::big_types::$Digit::*;
+                use crate::test::types::{itest, utest};
+                use crate::$BUint;
+                use ::der::{Decode, Encode, ErrorKind, Tag};
+                use alloc::vec::Vec;
+
+                fn encode<T: Encode>(value: &T) -> Vec<u8> {
+                    let mut buf = vec![0; 1024];
+                    let len = value.encode_to_slice(&mut buf).unwrap().len();
+                    buf.truncate(len);
+                    buf
+                }
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_encode_unsigned(a: utest, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % utest::BITS);
+                        let x: UTEST = a.into();
+                        let bytes = encode(&x);
+                        bytes[0] == 0x02
+                            && bytes[2..] == x.to_der_integer_bytes()
+                            && bytes == encode(&a)
+                            && UTEST::from_der(&bytes) == Ok(x)
+                    }
+
+                    fn quickcheck_encode_signed(a: itest, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % itest::BITS);
+                        let x: ITEST = a.into();
+                        let bytes = encode(&x);
+                        bytes[0] == 0x02
+                            && bytes[2..] == x.to_der_integer_bytes()
+                            && bytes == encode(&a)
+                            && ITEST::from_der(&bytes) == Ok(x)
+                    }
+
+                    fn quickcheck_encode_long(a: $BUint<{ 1024 / $Digit::BITS as usize }>) -> bool {
+                        $BUint::from_der(&encode(&a)) == Ok(a)
+                    }
+                }
+
+                #[test]
+                fn decode_invalid() {
+                    let kind = |result: ::der::Result<UTEST>| result.unwrap_err().kind();
+                    let non_canonical = ErrorKind::Noncanonical { tag: Tag::Integer };
+                    let value = ErrorKind::Value { tag: Tag::Integer };
+                    assert_eq!(kind(UTEST::from_der(&[0x02, 0x00])), non_canonical);
+                    assert_eq!(kind(UTEST::from_der(&[0x02, 0x02, 0x00, 0x7f])), non_canonical);
+                    assert_eq!(kind(UTEST::from_der(&[0x02, 0x01, 0x80])), value);
+                    assert!(matches!(kind(UTEST::from_der(&[0x04, 0x01, 0x01])), ErrorKind::TagUnexpected { .. }));
+                    assert_eq!(ITEST::from_der(&[0x02, 0x01, 0x80]), Ok(ITEST::from(-128i16)));
+                    assert_eq!(ITEST::from_der(&[0x02, 0x02, 0xff, 0x80]).unwrap_err().kind(), non_canonical);
+
+                    let mut too_big = vec![0x02, UTEST::BYTES as u8 + 2, 0x01];
+                    too_big.extend(core::iter::repeat(0xff).take(UTEST::BYTES as usize + 1));
+                    assert_eq!(kind(UTEST::from_der(&too_big)), value);
+                    too_big[1] -= 1;
+                    too_big[2] = 0x00;
+                    too_big.pop();
+                    assert_eq!(UTEST::from_der(&too_big), Ok(UTEST::MAX));
+                    assert_eq!(ITEST::from_der(&too_big).unwrap_err().kind(), value);
+                    too_big.remove(2);
+                    too_big[1] -= 1;
+                    assert_eq!(ITEST::from_der(&too_big).unwrap_err().kind(), non_canonical);
+                    too_big[2] = 0x7f;
+                    assert_eq!(ITEST::from_der(&too_big), Ok(ITEST::MAX));
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(der);
@@ -115,6 +115,41 @@ macro_rules! digit_module {
                 )
             }
 
+            /// Estimates the quotient digit of step D3 of Knuth's Algorithm D, where `u_2`, `u_1` and `u_0` are the three most significant digits of the current remainder and `v_1` and `v_0` are the two most significant digits of the normalised divisor. The estimate is either the quotient digit or one more than it.
+            #[inline]
+            pub const fn estimate_quotient(
+                u_2: Digit,
+                u_1: Digit,
+                u_0: Digit,
+                v_1: Digit,
+                v_0: Digit,
+            ) -> Digit {
+                #[inline]
+                const fn tuple_gt(a: (Digit, Digit), b: (Digit, Digit)) -> bool {
+                    a.1 > b.1 || a.1 == b.1 && a.0 > b.0
+                }
+
+                let (mut q_hat, mut r_hat) = if u_2 < v_1 {
+                    div_rem_wide(u_1, u_2, v_1)
+                } else {
+                    // `u_2 == v_1`, so the estimate is at least the digit base and is reduced to `Digit::MAX`
+                    match u_1.checked_add(v_1) {
+                        Some(r_hat) => (Digit::MAX, r_hat),
+                        None => return Digit::MAX,
+                    }
+                };
+                // this runs at most twice
+                while tuple_gt(widening_mul(q_hat, v_0), (u_0, r_hat)) {
+                    q_hat -= 1;
+                    // if `r_hat` is at least the digit base, the test can't succeed again
+                    match r_hat.checked_add(v_1) {
+                        Some(r) => r_hat = r,
+                        None => break,
+                    }
+                }
+                q_hat
+            }
+
             pub const HEX_PADDING: usize = BITS as usize / 4;
         }
     };
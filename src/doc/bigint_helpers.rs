@@ -9,3 +9,109 @@ macro_rules! impl_desc {
 pub(crate) use impl_desc;
 
 doc::link_doc_comment!(carrying_add, borrowing_sub, widening_mul, carrying_mul);
+
+macro_rules! mul_wide {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Computes the full product of `self` and `rhs` as an integer with `M` digits, so the product cannot overflow. The result can be passed directly to the methods of the wider type, such as division.\n\n"
+            "This fails to compile if `M` is less than `2 * N`.",
+
+            doc::resize::imports!(U)
+            "let a = U256::MAX;\n"
+            "let p: U512 = a.mul_wide(a);\n"
+            "let b: U512 = a.zero_extend();\n"
+            "assert_eq!(p / b, b);\n"
+            "assert_eq!(p % b, U512::ZERO);"
+        }
+    };
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Computes the full product of `self` and `rhs` as an integer with `M` digits, so the product cannot overflow. The result can be passed directly to the methods of the wider type, such as division.\n\n"
+            "This fails to compile if `M` is less than `2 * N`.",
+
+            doc::resize::imports!(I)
+            "let a = I256::MIN;\n"
+            "let p: I512 = a.mul_wide(a);\n"
+            "let b: I512 = a.sign_extend();\n"
+            "assert_eq!(p / b, b);\n"
+            "assert_eq!(p % b, I512::ZERO);"
+        }
+    };
+}
+
+pub(crate) use mul_wide;
+
+macro_rules! square_wide {
+    ($sign: ident) => {
+        doc::doc_comment! {
+            $sign 256,
+            "Computes the full square of `self` as an integer with `M` digits. This is equivalent to `self.mul_wide(self)`.\n\n"
+            "This fails to compile if `M` is less than `2 * N`.",
+
+            doc::resize::imports!($sign)
+            "let a = " doc::type_str!($sign 256) "::MAX;\n"
+            "let s: " doc::type_str!($sign 512) " = a.square_wide();\n"
+            "assert_eq!(s, a.mul_wide::<8>(a));"
+        }
+    };
+}
+
+pub(crate) use square_wide;
+
+macro_rules! widening_add {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Computes the full sum of `self` and `rhs` as an integer with `M` digits, so the sum cannot overflow.\n\n"
+            "This fails to compile if `M` is less than `N + 1`.",
+
+            doc::resize::imports!(U)
+            "let s: U512 = U256::MAX.widening_add(U256::ONE);\n"
+            "assert_eq!(s, U512::ONE << 256u32);"
+        }
+    };
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Computes the full sum of `self` and `rhs` as an integer with `M` digits, so the sum cannot overflow.\n\n"
+            "This fails to compile if `M` is less than `N + 1`.",
+
+            doc::resize::imports!(I)
+            "let s: I512 = I256::MIN.widening_add(I256::MIN);\n"
+            "assert_eq!(s, I512::MIN >> 255u32);"
+        }
+    };
+}
+
+pub(crate) use widening_add;
+
+macro_rules! mul_add_wide {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Computes `self * rhs + addend` as an integer with `M` digits, so the result cannot overflow.\n\n"
+            "This fails to compile if `M` is less than `2 * N`.",
+
+            doc::resize::imports!(U)
+            "let a = U256::MAX;\n"
+            "let r: U512 = a.mul_add_wide(a, a);\n"
+            "assert_eq!(r, (U512::MAX >> 256u32) << 256u32);"
+        }
+    };
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Computes `self * rhs + addend` as an integer with `M` digits, so the result cannot overflow.\n\n"
+            "This fails to compile if `M` is less than `2 * N`.",
+
+            doc::resize::imports!(I)
+            "let a = I256::from(-3i8);\n"
+            "let r: I512 = a.mul_add_wide(I256::from(5u8), I256::from(2u8));\n"
+            "assert_eq!(r, I512::from(-13i8));"
+        }
+    };
+}
+
+pub(crate) use mul_add_wide;
@@ -71,3 +71,196 @@ crate::doc::link_doc_comment! {
     from_le_bytes,
     from_ne_bytes
 }
+
+macro_rules! to_der_integer_bytes {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Returns the contents of the ASN.1 DER encoding of `self` as an `INTEGER`, which are the big-endian two's complement bytes of `self` with no redundant leading bytes. Since DER integers are signed, a leading zero byte is added if the most significant bit would otherwise be set.",
+
+            "assert_eq!(U256::ZERO.to_der_integer_bytes(), [0x00]);\n"
+            "assert_eq!(U256::from(0x7fu8).to_der_integer_bytes(), [0x7f]);\n"
+            "assert_eq!(U256::from(0x80u8).to_der_integer_bytes(), [0x00, 0x80]);\n"
+            "assert_eq!(U256::MAX.to_der_integer_bytes().len(), 33);"
+        }
+    };
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Returns the contents of the ASN.1 DER encoding of `self` as an `INTEGER`, which are the big-endian two's complement bytes of `self` with no redundant leading bytes.",
+
+            "assert_eq!(I256::ZERO.to_der_integer_bytes(), [0x00]);\n"
+            "assert_eq!(I256::from(128i16).to_der_integer_bytes(), [0x00, 0x80]);\n"
+            "assert_eq!(I256::from(-128i16).to_der_integer_bytes(), [0x80]);\n"
+            "assert_eq!(I256::from(-129i16).to_der_integer_bytes(), [0xff, 0x7f]);"
+        }
+    };
+}
+
+pub(crate) use to_der_integer_bytes;
+
+macro_rules! from_der_integer_bytes {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Creates an integer from the contents of the ASN.1 DER encoding of an `INTEGER`, which are big-endian two's complement bytes. `None` is returned if `bytes` is empty, if `bytes` has a redundant leading byte (the first nine bits are all zeros or all ones), or if the value is negative or too large to be represented by the type.",
+
+            "assert_eq!(U256::from_der_integer_bytes(&[0x00, 0x80]), Some(U256::from(0x80u8)));\n"
+            "assert_eq!(U256::from_der_integer_bytes(&[0x80]), None); // negative\n"
+            "assert_eq!(U256::from_der_integer_bytes(&[0x00, 0x7f]), None); // redundant leading zero\n"
+            "assert_eq!(U256::from_der_integer_bytes(&[]), None);"
+        }
+    };
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Creates an integer from the contents of the ASN.1 DER encoding of an `INTEGER`, which are big-endian two's complement bytes. `None` is returned if `bytes` is empty, if `bytes` has a redundant leading byte (the first nine bits are all zeros or all ones), or if the value is too large in magnitude to be represented by the type.",
+
+            "assert_eq!(I256::from_der_integer_bytes(&[0xff, 0x7f]), Some(I256::from(-129i16)));\n"
+            "assert_eq!(I256::from_der_integer_bytes(&[0xff, 0x80]), None); // redundant leading ones\n"
+            "assert_eq!(I256::from_der_integer_bytes(&[0x01; 33]), None); // too large\n"
+            "assert_eq!(I256::from_der_integer_bytes(&[]), None);"
+        }
+    };
+}
+
+pub(crate) use from_der_integer_bytes;
+
+macro_rules! to_be_bytes_trimmed {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Writes the big-endian bytes of `self` with leading zero bytes removed to the start of `buf`, and returns the number of bytes written. Zero is written as no bytes. `None` is returned and `buf` is left unchanged if `buf` is too short.",
+
+            "let mut buf = [0; 32];\n"
+            "assert_eq!(U256::from(0x1234u16).to_be_bytes_trimmed(&mut buf), Some(2));\n"
+            "assert_eq!(buf[..2], [0x12, 0x34]);\n"
+            "assert_eq!(U256::ZERO.to_be_bytes_trimmed(&mut buf), Some(0));\n"
+            "assert_eq!(U256::MAX.to_be_bytes_trimmed(&mut buf[..31]), None);"
+        }
+    };
+}
+
+pub(crate) use to_be_bytes_trimmed;
+
+macro_rules! to_le_bytes_trimmed {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Writes the little-endian bytes of `self` with trailing zero bytes removed to the start of `buf`, and returns the number of bytes written. Zero is written as no bytes. `None` is returned and `buf` is left unchanged if `buf` is too short.",
+
+            "let mut buf = [0; 32];\n"
+            "assert_eq!(U256::from(0x1234u16).to_le_bytes_trimmed(&mut buf), Some(2));\n"
+            "assert_eq!(buf[..2], [0x34, 0x12]);\n"
+            "assert_eq!(U256::ZERO.to_le_bytes_trimmed(&mut buf), Some(0));\n"
+            "assert_eq!(U256::MAX.to_le_bytes_trimmed(&mut buf[..31]), None);"
+        }
+    };
+}
+
+pub(crate) use to_le_bytes_trimmed;
+
+macro_rules! to_signed_be_bytes_minimal {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Writes the shortest big-endian two's complement bytes which represent `self` to the start of `buf`, and returns the number of bytes written. This is always at least one byte, and a leading zero byte is written if the most significant bit would otherwise be set. `None` is returned and `buf` is left unchanged if `buf` is too short.",
+
+            "let mut buf = [0; 33];\n"
+            "assert_eq!(U256::from(0x7fu8).to_signed_be_bytes_minimal(&mut buf), Some(1));\n"
+            "assert_eq!(buf[..1], [0x7f]);\n"
+            "assert_eq!(U256::from(0x80u8).to_signed_be_bytes_minimal(&mut buf), Some(2));\n"
+            "assert_eq!(buf[..2], [0x00, 0x80]);\n"
+            "assert_eq!(U256::MAX.to_signed_be_bytes_minimal(&mut buf[..32]), None);"
+        }
+    };
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Writes the shortest big-endian two's complement bytes which represent `self` to the start of `buf`, and returns the number of bytes written. This is always at least one byte. `None` is returned and `buf` is left unchanged if `buf` is too short.",
+
+            "let mut buf = [0; 32];\n"
+            "assert_eq!(I256::from(-128i16).to_signed_be_bytes_minimal(&mut buf), Some(1));\n"
+            "assert_eq!(buf[..1], [0x80]);\n"
+            "assert_eq!(I256::from(128i16).to_signed_be_bytes_minimal(&mut buf), Some(2));\n"
+            "assert_eq!(buf[..2], [0x00, 0x80]);\n"
+            "assert_eq!(I256::MIN.to_signed_be_bytes_minimal(&mut buf[..31]), None);"
+        }
+    };
+}
+
+pub(crate) use to_signed_be_bytes_minimal;
+
+macro_rules! write_uleb128 {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Writes the unsigned LEB128 encoding of `self` to the start of `buf`, and returns the number of bytes written. The encoding has no redundant trailing bytes, so is at least one byte. `None` is returned and `buf` is left unchanged if `buf` is too short.",
+
+            "let mut buf = [0; 37];\n"
+            "assert_eq!(U256::from(624485u32).write_uleb128(&mut buf), Some(3));\n"
+            "assert_eq!(buf[..3], [0xe5, 0x8e, 0x26]);\n"
+            "assert_eq!(U256::ZERO.write_uleb128(&mut buf), Some(1));\n"
+            "assert_eq!(U256::MAX.write_uleb128(&mut buf), Some(37));\n"
+            "assert_eq!(U256::MAX.write_uleb128(&mut buf[..36]), None);"
+        }
+    };
+}
+
+pub(crate) use write_uleb128;
+
+macro_rules! read_uleb128 {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Reads an unsigned LEB128 encoded integer from the start of `bytes`, and returns it along with the number of bytes read. Redundant trailing `0x80` bytes before the final `0x00` byte are accepted. `None` is returned if `bytes` ends before the last byte of the encoding, or if the value is too large to be represented by the type.",
+
+            "let bytes = [0xe5, 0x8e, 0x26, 0xff];\n"
+            "assert_eq!(U256::read_uleb128(&bytes), Some((U256::from(624485u32), 3)));\n"
+            "assert_eq!(U256::read_uleb128(&[0x80, 0x80, 0x00]), Some((U256::ZERO, 3)));\n"
+            "assert_eq!(U256::read_uleb128(&[0xe5, 0x8e]), None);\n\n"
+            "let mut too_large = [0xff; 37];\n"
+            "too_large[36] = 0x1f;\n"
+            "assert_eq!(U256::read_uleb128(&too_large), None);"
+        }
+    };
+}
+
+pub(crate) use read_uleb128;
+
+macro_rules! write_sleb128 {
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Writes the signed LEB128 encoding of `self` to the start of `buf`, and returns the number of bytes written. The encoding has no redundant trailing bytes, so is at least one byte. `None` is returned and `buf` is left unchanged if `buf` is too short.",
+
+            "let mut buf = [0; 37];\n"
+            "assert_eq!(I256::from(-123456i32).write_sleb128(&mut buf), Some(3));\n"
+            "assert_eq!(buf[..3], [0xc0, 0xbb, 0x78]);\n"
+            "assert_eq!(I256::from(63i8).write_sleb128(&mut buf), Some(1));\n"
+            "assert_eq!(I256::from(64i8).write_sleb128(&mut buf), Some(2));\n"
+            "assert_eq!(I256::MIN.write_sleb128(&mut buf[..36]), None);"
+        }
+    };
+}
+
+pub(crate) use write_sleb128;
+
+macro_rules! read_sleb128 {
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Reads a signed LEB128 encoded integer from the start of `bytes`, and returns it along with the number of bytes read. Redundant trailing bytes which only extend the sign are accepted. `None` is returned if `bytes` ends before the last byte of the encoding, or if the value is too large in magnitude to be represented by the type.",
+
+            "let bytes = [0xc0, 0xbb, 0x78, 0xff];\n"
+            "assert_eq!(I256::read_sleb128(&bytes), Some((I256::from(-123456i32), 3)));\n"
+            "assert_eq!(I256::read_sleb128(&[0xff, 0x7f]), Some((I256::NEG_ONE, 2)));\n"
+            "assert_eq!(I256::read_sleb128(&[0xc0, 0xbb]), None);\n\n"
+            "let mut too_large = [0xff; 37];\n"
+            "too_large[36] = 0x08;\n"
+            "assert_eq!(I256::read_sleb128(&too_large), None);"
+        }
+    };
+}
+
+pub(crate) use read_sleb128;
@@ -5,6 +5,7 @@ pub mod consts;
 pub mod endian;
 pub mod overflowing;
 pub mod radix;
+pub mod resize;
 pub mod saturating;
 pub mod strict;
 pub mod unchecked;
@@ -0,0 +1,176 @@
+macro_rules! impl_desc {
+    () => {
+        "Methods which convert between integers of the same signedness with different numbers of digits. The numbers of digits are checked at compile time where the conversion requires it, so the methods below fail to compile rather than panic when given incompatible sizes."
+    };
+}
+
+pub(crate) use impl_desc;
+
+macro_rules! imports {
+    ($sign: ident) => {
+        concat!(
+            "use bnum::types::{",
+            doc::type_str!($sign 128),
+            ", ",
+            doc::type_str!($sign 512),
+            "};\n\n"
+        )
+    };
+}
+
+pub(crate) use imports;
+
+macro_rules! resize {
+    (U) => {
+        doc::doc_comment! {
+            U 256,
+            "Converts `self` to an integer with `M` digits, keeping the least significant digits if `M` is less than `N` and filling the most significant digits with zeros if `M` is greater than `N`. This is equivalent to an `as` cast between primitive unsigned integers.",
+
+            doc::resize::imports!(U)
+            "let a = U256::MAX;\n"
+            "let b: U512 = a.resize();\n"
+            "assert_eq!(b.count_ones(), 256);\n"
+            "let c: U128 = a.resize();\n"
+            "assert_eq!(c, U128::MAX);"
+        }
+    };
+    (I) => {
+        doc::doc_comment! {
+            I 256,
+            "Converts `self` to an integer with `M` digits, keeping the least significant digits if `M` is less than `N` and sign extending if `M` is greater than `N`. This is equivalent to an `as` cast between primitive signed integers.",
+
+            doc::resize::imports!(I)
+            "let a = I256::from(-2i8);\n"
+            "let b: I512 = a.resize();\n"
+            "assert_eq!(b, I512::from(-2i8));\n"
+            "let c: I128 = I256::MAX.resize();\n"
+            "assert_eq!(c, I128::from(-1i8));"
+        }
+    };
+}
+
+pub(crate) use resize;
+
+macro_rules! checked_resize {
+    ($sign: ident) => {
+        doc::doc_comment! {
+            $sign 256,
+            "Converts `self` to an integer with `M` digits, returning `None` if the value of `self` is not representable with `M` digits.",
+
+            doc::resize::imports!($sign)
+            "let a = " doc::type_str!($sign 256) "::from(200u8);\n"
+            "assert_eq!(a.checked_resize(), Some(" doc::type_str!($sign 128) "::from(200u8)));\n"
+            "assert_eq!(" doc::type_str!($sign 256) "::MAX.checked_resize::<2>(), None);"
+        }
+    };
+}
+
+pub(crate) use checked_resize;
+
+macro_rules! zero_extend {
+    () => {
+        doc::doc_comment! {
+            U 256,
+            "Converts `self` to an integer with `M` digits by filling the most significant digits with zeros. This conversion is lossless.\n\n"
+            "This fails to compile if `M` is less than `N`.",
+
+            doc::resize::imports!(U)
+            "let a = U256::MAX;\n"
+            "let b: U512 = a.zero_extend();\n"
+            "assert_eq!(b, U512::MAX >> 256u32);\n"
+            "```\n\n"
+            "```compile_fail\n"
+            "use bnum::types::{U128, U256};\n\n"
+            "let a: U128 = U256::MAX.zero_extend();"
+        }
+    };
+}
+
+pub(crate) use zero_extend;
+
+macro_rules! sign_extend {
+    () => {
+        doc::doc_comment! {
+            I 256,
+            "Converts `self` to an integer with `M` digits by filling the most significant digits with copies of the sign bit. This conversion is lossless.\n\n"
+            "This fails to compile if `M` is less than `N`.",
+
+            doc::resize::imports!(I)
+            "let a = I256::MIN;\n"
+            "let b: I512 = a.sign_extend();\n"
+            "assert_eq!(b, I512::MIN >> 256u32);\n"
+            "```\n\n"
+            "```compile_fail\n"
+            "use bnum::types::{I128, I256};\n\n"
+            "let a: I128 = I256::MIN.sign_extend();"
+        }
+    };
+}
+
+pub(crate) use sign_extend;
+
+macro_rules! truncate {
+    ($sign: ident) => {
+        doc::doc_comment! {
+            $sign 256,
+            "Converts `self` to an integer with `M` digits by keeping only the least significant `M` digits.\n\n"
+            "This fails to compile if `M` is greater than `N`.",
+
+            doc::resize::imports!($sign)
+            "let a = (" doc::type_str!($sign 256) "::from(5u8) << 128u32) | " doc::type_str!($sign 256) "::from(3u8);\n"
+            "let b: " doc::type_str!($sign 128) " = a.truncate();\n"
+            "assert_eq!(b, " doc::type_str!($sign 128) "::from(3u8));\n"
+            "```\n\n"
+            "```compile_fail\n"
+            "use bnum::types::{" doc::type_str!($sign 256) ", " doc::type_str!($sign 512) "};\n\n"
+            "let a: " doc::type_str!($sign 512) " = " doc::type_str!($sign 256) "::MAX.truncate();"
+        }
+    };
+}
+
+pub(crate) use truncate;
+
+macro_rules! concatenate {
+    () => {
+        doc::doc_comment! {
+            U 256,
+            "Creates an integer whose most significant `H` digits are the digits of `hi` and whose least significant `L` digits are the digits of `lo`.\n\n"
+            "This fails to compile if `H + L` is not equal to `N`.",
+
+            doc::resize::imports!(U)
+            "let a = U256::concat(U128::ONE, U128::MAX);\n"
+            "assert_eq!(a, (U256::ONE << 129u32) - U256::ONE);\n"
+            "let b = U512::concat(U256::MAX, U256::ZERO);\n"
+            "assert_eq!(b, U512::MAX << 256u32);\n"
+            "```\n\n"
+            "```compile_fail\n"
+            "use bnum::types::{U128, U256};\n\n"
+            "let a = U256::concat(U128::ONE, U256::ONE);"
+        }
+    };
+}
+
+pub(crate) use concatenate;
+
+macro_rules! split {
+    () => {
+        doc::doc_comment! {
+            U 256,
+            "Splits `self` into its least significant `L` digits and its most significant `H` digits, returning them in that order. This is the inverse of [`concat`](Self::concat).\n\n"
+            "This fails to compile if `L + H` is not equal to `N`.",
+
+            doc::resize::imports!(U)
+            "let a = (U256::ONE << 129u32) - U256::ONE;\n"
+            "let (lo, hi): (U128, U128) = a.split();\n"
+            "assert_eq!(lo, U128::MAX);\n"
+            "assert_eq!(hi, U128::ONE);\n"
+            "assert_eq!(U256::concat(hi, lo), a);\n"
+            "```\n\n"
+            "```compile_fail\n"
+            "use bnum::types::{U128, U256};\n\n"
+            "let (lo, hi): (U128, U256) = U256::MAX.split();"
+        }
+    };
+}
+
+pub(crate) use split;
@@ -0,0 +1,360 @@
+//! Integers stored as byte arrays with an explicit byte order, for use in network packets and on-disk formats.
+//!
+//! [`BigEndian<T>`] and [`LittleEndian<T>`] store an integer `T` as its big-endian or little-endian bytes respectively. They have an alignment of `1` and no padding, so they can be used as the fields of `#[repr(C)]` structs which are overlaid onto unaligned byte buffers (for example, with the `bytemuck` or `zerocopy` features). Their size is the same as that of `T`. The value is read with `get` and written with `set`.
+//!
+//! Equality and hashing compare the stored bytes, which is equivalent to comparing the values. Ordering compares the values. Both wrappers can also be compared directly with values of type `T`.
+//!
+//! With the `rkyv` feature, [`LittleEndian<T>`] is the archived form of `T`, so archived integers have the same representation on every target and can be compared with unarchived ones without deserialising.
+//!
+//! # Examples
+//!
+//! ```
+//! use bnum::endian::{BigEndian, LittleEndian};
+//! use bnum::types::{I256, U256};
+//!
+//! #[repr(C)]
+//! struct Record {
+//!     id: BigEndian<U256>,
+//!     delta: LittleEndian<I256>,
+//! }
+//!
+//! assert_eq!(core::mem::align_of::<Record>(), 1);
+//! assert_eq!(core::mem::size_of::<Record>(), 64);
+//!
+//! let mut record = Record {
+//!     id: BigEndian::new(U256::from(0x0102u16)),
+//!     delta: LittleEndian::new(I256::from(-2i8)),
+//! };
+//! assert_eq!(record.id.as_bytes()[30..], [0x01, 0x02]);
+//! assert_eq!(record.delta.as_bytes()[..2], [0xfe, 0xff]);
+//!
+//! record.id.set(record.id.get() + U256::ONE);
+//! assert_eq!(record.id, U256::from(0x0103u16));
+//! assert!(record.delta < LittleEndian::new(I256::ZERO));
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+
+mod sealed {
+    use core::hash::Hash;
+
+    pub trait Sealed: Copy + Ord {
+        type Bytes: Copy + Eq + Hash;
+
+        fn zero() -> Self;
+
+        fn to_be(self) -> Self::Bytes;
+
+        fn from_be(bytes: Self::Bytes) -> Self;
+
+        fn to_le(self) -> Self::Bytes;
+
+        fn from_le(bytes: Self::Bytes) -> Self;
+
+        fn as_bytes(bytes: &Self::Bytes) -> &[u8];
+    }
+}
+
+/// Integer types which can be stored in a [`BigEndian`] or [`LittleEndian`]. This trait is sealed and is implemented for all of `bnum`'s fixed size integer types.
+pub trait Integer: sealed::Sealed {}
+
+macro_rules! endian_type {
+    ($Endian: ident, $to: ident, $from: ident, $order: literal) => {
+        #[doc = concat!("An integer stored as its ", $order, " bytes, with an alignment of `1`. See the [module-level documentation](self) for details.")]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout, zerocopy::Unaligned))]
+        #[repr(transparent)]
+        pub struct $Endian<T: Integer>(T::Bytes);
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T: Integer> bytemuck::Zeroable for $Endian<T> where T::Bytes: bytemuck::Zeroable {}
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T: Integer + 'static> bytemuck::Pod for $Endian<T> where T::Bytes: bytemuck::Pod {}
+
+        impl<T: Integer> $Endian<T> {
+            #[doc = concat!("Stores `value` as its ", $order, " bytes.")]
+            #[must_use]
+            #[inline]
+            pub fn new(value: T) -> Self {
+                Self(value.$to())
+            }
+
+            /// Returns the stored value.
+            #[must_use]
+            #[inline]
+            pub fn get(self) -> T {
+                T::$from(self.0)
+            }
+
+            /// Replaces the stored value with `value`.
+            #[inline]
+            pub fn set(&mut self, value: T) {
+                self.0 = value.$to();
+            }
+
+            #[doc = concat!("Returns the stored ", $order, " bytes.")]
+            #[must_use]
+            #[inline]
+            pub fn as_bytes(&self) -> &[u8] {
+                T::as_bytes(&self.0)
+            }
+        }
+
+        impl<T: Integer> Default for $Endian<T> {
+            #[inline]
+            fn default() -> Self {
+                Self::new(T::zero())
+            }
+        }
+
+        impl<T: Integer> PartialOrd for $Endian<T> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T: Integer> Ord for $Endian<T> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl<T: Integer> PartialEq<T> for $Endian<T> {
+            #[inline]
+            fn eq(&self, other: &T) -> bool {
+                self.get() == *other
+            }
+        }
+
+        impl<T: Integer> PartialOrd<T> for $Endian<T> {
+            #[inline]
+            fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+                Some(self.get().cmp(other))
+            }
+        }
+
+        impl<T: Integer> From<T> for $Endian<T> {
+            #[inline]
+            fn from(value: T) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<T: Integer + Debug> Debug for $Endian<T> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($Endian)).field(&self.get()).finish()
+            }
+        }
+
+        impl<T: Integer + Display> Display for $Endian<T> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Display::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+endian_type!(BigEndian, to_be, from_be, "big-endian");
+endian_type!(LittleEndian, to_le, from_le, "little-endian");
+
+impl<T: Integer> From<BigEndian<T>> for LittleEndian<T> {
+    #[inline]
+    fn from(value: BigEndian<T>) -> Self {
+        Self::new(value.get())
+    }
+}
+
+impl<T: Integer> From<LittleEndian<T>> for BigEndian<T> {
+    #[inline]
+    fn from(value: LittleEndian<T>) -> Self {
+        Self::new(value.get())
+    }
+}
+
+macro_rules! endian {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> sealed::Sealed for $BUint<N> {
+            type Bytes = [[u8; crate::digit::$Digit::BYTES as usize]; N];
+
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn to_be(self) -> Self::Bytes {
+                let mut bytes = [[0; crate::digit::$Digit::BYTES as usize]; N];
+                let mut i = 0;
+                while i < N {
+                    bytes[N - 1 - i] = self.digits[i].to_be_bytes();
+                    i += 1;
+                }
+                bytes
+            }
+
+            #[inline]
+            fn from_be(bytes: Self::Bytes) -> Self {
+                let mut out = Self::ZERO;
+                let mut i = 0;
+                while i < N {
+                    out.digits[i] = $Digit::from_be_bytes(bytes[N - 1 - i]);
+                    i += 1;
+                }
+                out
+            }
+
+            #[inline]
+            fn to_le(self) -> Self::Bytes {
+                let mut bytes = [[0; crate::digit::$Digit::BYTES as usize]; N];
+                let mut i = 0;
+                while i < N {
+                    bytes[i] = self.digits[i].to_le_bytes();
+                    i += 1;
+                }
+                bytes
+            }
+
+            #[inline]
+            fn from_le(bytes: Self::Bytes) -> Self {
+                let mut out = Self::ZERO;
+                let mut i = 0;
+                while i < N {
+                    out.digits[i] = $Digit::from_le_bytes(bytes[i]);
+                    i += 1;
+                }
+                out
+            }
+
+            #[inline]
+            fn as_bytes(bytes: &Self::Bytes) -> &[u8] {
+                // SAFETY: `[[u8; BYTES]; N]` has the same layout as `[u8; N * BYTES]`
+                unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast(), N * crate::digit::$Digit::BYTES as usize) }
+            }
+        }
+
+        impl<const N: usize> Integer for $BUint<N> {}
+
+        impl<const N: usize> sealed::Sealed for $BInt<N> {
+            type Bytes = [[u8; crate::digit::$Digit::BYTES as usize]; N];
+
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn to_be(self) -> Self::Bytes {
+                <$BUint<N> as sealed::Sealed>::to_be(self.bits)
+            }
+
+            #[inline]
+            fn from_be(bytes: Self::Bytes) -> Self {
+                Self::from_bits(<$BUint<N> as sealed::Sealed>::from_be(bytes))
+            }
+
+            #[inline]
+            fn to_le(self) -> Self::Bytes {
+                <$BUint<N> as sealed::Sealed>::to_le(self.bits)
+            }
+
+            #[inline]
+            fn from_le(bytes: Self::Bytes) -> Self {
+                Self::from_bits(<$BUint<N> as sealed::Sealed>::from_le(bytes))
+            }
+
+            #[inline]
+            fn as_bytes(bytes: &Self::Bytes) -> &[u8] {
+                <$BUint<N> as sealed::Sealed>::as_bytes(bytes)
+            }
+        }
+
+        impl<const N: usize> Integer for $BInt<N> {}
+
+        impl<const N: usize> From<BigEndian<$BUint<N>>> for $BUint<N> {
+            #[inline]
+            fn from(value: BigEndian<Self>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<const N: usize> From<LittleEndian<$BUint<N>>> for $BUint<N> {
+            #[inline]
+            fn from(value: LittleEndian<Self>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<const N: usize> From<BigEndian<$BInt<N>>> for $BInt<N> {
+            #[inline]
+            fn from(value: BigEndian<Self>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<const N: usize> From<LittleEndian<$BInt<N>>> for $BInt<N> {
+            #[inline]
+            fn from(value: LittleEndian<Self>) -> Self {
+                value.get()
+            }
+        }
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use super::{BigEndian, LittleEndian};
+                use crate::test::types::big_types::$Digit::*;
+                use crate::test::types::{itest, utest};
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_unsigned(a: utest, b: utest) -> bool {
+                        let (x, y): (UTEST, UTEST) = (a.into(), b.into());
+                        let (mut be, le) = (BigEndian::new(x), LittleEndian::new(x));
+                        let agrees = be.as_bytes() == a.to_be_bytes()
+                            && le.as_bytes() == a.to_le_bytes()
+                            && be.get() == x
+                            && UTEST::from(le) == x
+                            && LittleEndian::from(be) == le
+                            && be.cmp(&BigEndian::new(y)) == a.cmp(&b)
+                            && le.partial_cmp(&y) == Some(a.cmp(&b));
+                        be.set(y);
+                        agrees && be == y && be.as_bytes() == b.to_be_bytes()
+                    }
+
+                    fn quickcheck_signed(a: itest, b: itest) -> bool {
+                        let (x, y): (ITEST, ITEST) = (a.into(), b.into());
+                        let (be, le) = (BigEndian::new(x), LittleEndian::new(x));
+                        be.as_bytes() == a.to_be_bytes()
+                            && le.as_bytes() == a.to_le_bytes()
+                            && ITEST::from(be) == x
+                            && BigEndian::from(le) == be
+                            && be.cmp(&BigEndian::new(y)) == a.cmp(&b)
+                            && le.cmp(&LittleEndian::new(y)) == a.cmp(&b)
+                    }
+                }
+
+                #[test]
+                fn layout() {
+                    assert_eq!(core::mem::align_of::<BigEndian<UTEST>>(), 1);
+                    assert_eq!(core::mem::size_of::<LittleEndian<ITEST>>(), core::mem::size_of::<ITEST>());
+                    assert_eq!(BigEndian::<ITEST>::default(), ITEST::ZERO);
+
+                    #[cfg(feature = "bytemuck")]
+                    {
+                        let mut buf = [0u8; 1 + UTEST::BYTES as usize];
+                        buf[UTEST::BYTES as usize] = 1;
+                        let be: &BigEndian<UTEST> = bytemuck::from_bytes(&buf[1..]);
+                        assert_eq!(be.get(), UTEST::ONE);
+                    }
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(endian);
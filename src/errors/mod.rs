@@ -5,8 +5,17 @@ mod macros;
 #[allow(unused_imports)]
 pub use macros::*;
 
+mod parsefloat;
+pub use parsefloat::*;
+
+mod parsefixed;
+pub use parsefixed::*;
+
 mod parseint;
 pub use parseint::*;
 
+mod parseratio;
+pub use parseratio::*;
+
 mod tryfrom;
 pub use tryfrom::*;
@@ -0,0 +1,45 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use core::num::IntErrorKind;
+
+/// The error type that is returned when parsing a fixed-point number from an invalid source.
+///
+/// This error can occur when the [`FromStr::from_str`](https://doc.rust-lang.org/core/str/trait.FromStr.html#tymethod.from_str) method of [`FixedU`](crate::fixed::FixedU), [`FixedI`](crate::fixed::FixedI) or [`DecimalFixed`](crate::fixed::DecimalFixed) is called with an invalid input string.
+#[derive(PartialEq, Eq, Clone)]
+pub struct ParseFixedError {
+    pub(crate) kind: IntErrorKind,
+}
+
+impl ParseFixedError {
+    /// Returns the enum [`IntErrorKind`](https://doc.rust-lang.org/core/num/enum.IntErrorKind.html), which shows the reason that the parsing input was invalid.
+    pub const fn kind(&self) -> &IntErrorKind {
+        &self.kind
+    }
+
+    pub(crate) const fn description(&self) -> &str {
+        match &self.kind {
+            IntErrorKind::Empty => "attempt to parse fixed-point number from empty string",
+            IntErrorKind::InvalidDigit => {
+                "attempt to parse fixed-point number from string containing invalid digit"
+            }
+            IntErrorKind::PosOverflow => {
+                "attempt to parse fixed-point number too large to be represented by the target type"
+            }
+            IntErrorKind::NegOverflow => {
+                "attempt to parse fixed-point number too small to be represented by the target type"
+            }
+            _ => panic!("unsupported `IntErrorKind` variant"),
+        }
+    }
+}
+
+impl Display for ParseFixedError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
+
+impl Debug for ParseFixedError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
@@ -0,0 +1,36 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub(crate) enum FloatErrorKind {
+    Empty,
+    Invalid,
+}
+
+/// The error type that is returned when parsing a `Float` or a [`Decimal`](crate::Decimal) from an invalid source.
+///
+/// This error can occur when the `from_str_radix` method of `Float`, or the [`FromStr::from_str`](https://doc.rust-lang.org/core/str/trait.FromStr.html#tymethod.from_str) method of `Float` or `Decimal`, is called with an invalid input string.
+#[derive(PartialEq, Eq, Clone)]
+pub struct ParseFloatError {
+    pub(crate) kind: FloatErrorKind,
+}
+
+impl ParseFloatError {
+    pub(crate) const fn description(&self) -> &str {
+        match self.kind {
+            FloatErrorKind::Empty => "cannot parse float from empty string",
+            FloatErrorKind::Invalid => "invalid float literal",
+        }
+    }
+}
+
+impl Display for ParseFloatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
+
+impl Debug for ParseFloatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
@@ -0,0 +1,46 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use core::num::IntErrorKind;
+
+/// The error type that is returned when parsing a rational number from an invalid source.
+///
+/// This error can occur when the [`FromStr::from_str`](https://doc.rust-lang.org/core/str/trait.FromStr.html#tymethod.from_str) method of [`Ratio`](crate::Ratio) is called with an invalid input string.
+#[derive(PartialEq, Eq, Clone)]
+pub struct ParseRatioError {
+    pub(crate) kind: IntErrorKind,
+}
+
+impl ParseRatioError {
+    /// Returns the enum [`IntErrorKind`](https://doc.rust-lang.org/core/num/enum.IntErrorKind.html), which shows the reason that the parsing input was invalid.
+    pub const fn kind(&self) -> &IntErrorKind {
+        &self.kind
+    }
+
+    pub(crate) const fn description(&self) -> &str {
+        match &self.kind {
+            IntErrorKind::Empty => "attempt to parse rational number from empty string",
+            IntErrorKind::InvalidDigit => {
+                "attempt to parse rational number from string containing invalid digit"
+            }
+            IntErrorKind::PosOverflow => {
+                "attempt to parse rational number too large to be represented by the target type"
+            }
+            IntErrorKind::NegOverflow => {
+                "attempt to parse rational number too small to be represented by the target type"
+            }
+            IntErrorKind::Zero => "attempt to parse rational number with zero denominator",
+            _ => panic!("unsupported `IntErrorKind` variant"),
+        }
+    }
+}
+
+impl Display for ParseRatioError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
+
+impl Debug for ParseRatioError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
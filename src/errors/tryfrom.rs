@@ -17,3 +17,21 @@ impl Display for TryFromIntError {
         write!(f, "{}", ERROR_MESSAGE)
     }
 }
+
+/// The error type that is returned when a failed conversion from a `Float` or a [`Decimal`](crate::Decimal) to an integer occurs.
+///
+/// This error will occur when using the [`TryFrom`](https://doc.rust-lang.org/core/convert/trait.TryFrom.html) trait to convert a float which is NaN, infinite, has a fractional part, or is out of range of the integer type.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct TryFromFloatError(pub(crate) ());
+
+const FLOAT_ERROR_MESSAGE: &str = concat!(
+    super::err_prefix!(),
+    "non-integral or out of range float to integer conversion attempted"
+);
+
+impl Display for TryFromFloatError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", FLOAT_ERROR_MESSAGE)
+    }
+}
@@ -0,0 +1,67 @@
+use super::{DecimalFixed, FixedI, FixedU};
+use crate::int::checked::tuple_to_option;
+
+macro_rules! checked {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                tuple_to_option(self.overflowing_add(rhs))
+            }
+
+            /// Checked subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                tuple_to_option(self.overflowing_sub(rhs))
+            }
+
+            /// Checked multiplication. Computes `self * rhs`, rounded toward zero, returning `None` if overflow occurred.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                tuple_to_option(self.overflowing_mul(rhs))
+            }
+
+            /// Checked division. Computes `self / rhs`, rounded toward zero, returning `None` if `rhs` is zero or if overflow occurred.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() {
+                    None
+                } else {
+                    tuple_to_option(self.overflowing_div(rhs))
+                }
+            }
+        }
+    };
+}
+
+checked!(FixedU, FRAC);
+checked!(FixedI, FRAC);
+checked!(DecimalFixed, SCALE);
+
+macro_rules! checked_signed {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Checked negation. Computes `-self`, returning `None` if `self` is [`MIN`](Self::MIN).
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_neg(self) -> Option<Self> {
+                tuple_to_option(self.overflowing_neg())
+            }
+
+            /// Checked absolute value. Computes `self.abs()`, returning `None` if `self` is [`MIN`](Self::MIN).
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_abs(self) -> Option<Self> {
+                tuple_to_option(self.overflowing_abs())
+            }
+        }
+    };
+}
+
+checked_signed!(FixedI, FRAC);
+checked_signed!(DecimalFixed, SCALE);
@@ -0,0 +1,24 @@
+use super::{DecimalFixed, FixedI, FixedU};
+use crate::{BInt, BUint};
+
+macro_rules! consts {
+    ($Fixed: ident, $Int: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Zero.
+            pub const ZERO: Self = Self::from_bits($Int::ZERO);
+
+            /// The smallest positive value that can be represented by this type, whose underlying integer is one.
+            pub const DELTA: Self = Self::from_bits($Int::ONE);
+
+            /// The smallest value that can be represented by this type.
+            pub const MIN: Self = Self::from_bits($Int::MIN);
+
+            /// The largest value that can be represented by this type.
+            pub const MAX: Self = Self::from_bits($Int::MAX);
+        }
+    };
+}
+
+consts!(FixedU, BUint, FRAC);
+consts!(FixedI, BInt, FRAC);
+consts!(DecimalFixed, BInt, SCALE);
@@ -0,0 +1,112 @@
+use super::{DecimalFixed, FixedI, FixedU};
+use crate::cast::CastFrom;
+use crate::{BInt, BUint};
+use alloc::string::ToString;
+use core::str::FromStr;
+
+macro_rules! convert {
+    ($Fixed: ident, $Int: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Converts an integer to a fixed-point number, returning `None` if it is out of range.
+            #[must_use]
+            #[inline]
+            pub const fn checked_from_int(int: $Int<N>) -> Option<Self> {
+                match int.checked_mul(Self::ONE.bits) {
+                    Some(bits) => Some(Self::from_bits(bits)),
+                    None => None,
+                }
+            }
+
+            /// Returns the integer part of `self`, rounding toward zero.
+            #[must_use]
+            #[inline]
+            pub const fn to_int(self) -> $Int<N> {
+                self.bits.wrapping_div(Self::ONE.bits)
+            }
+        }
+
+        impl<const N: usize, const $P: u32> CastFrom<$Fixed<N, $P>> for f32 {
+            /// Converts the fixed-point number to the nearest `f32`, with ties to even.
+            #[inline]
+            fn cast_from(from: $Fixed<N, $P>) -> Self {
+                // the decimal representation is exact, and parsing it is correctly rounded
+                f32::from_str(&from.to_string()).unwrap()
+            }
+        }
+
+        impl<const N: usize, const $P: u32> CastFrom<$Fixed<N, $P>> for f64 {
+            /// Converts the fixed-point number to the nearest `f64`, with ties to even.
+            #[inline]
+            fn cast_from(from: $Fixed<N, $P>) -> Self {
+                f64::from_str(&from.to_string()).unwrap()
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<const N: usize, const $P: u32, const W: usize, const MB: usize> CastFrom<$Fixed<N, $P>>
+            for crate::Float<W, MB>
+        where
+            crate::float::storage::Width<W>: crate::float::storage::Storage,
+        {
+            /// Converts the fixed-point number to the nearest `Float`, with ties to even.
+            #[inline]
+            fn cast_from(from: $Fixed<N, $P>) -> Self {
+                Self::from_str(&from.to_string()).unwrap()
+            }
+        }
+    };
+}
+
+convert!(FixedU, BUint, FRAC);
+convert!(FixedI, BInt, FRAC);
+convert!(DecimalFixed, BInt, SCALE);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{DecimalFixed, FixedI, FixedU};
+    use crate::cast::As;
+    use crate::{BInt, BUint};
+    use core::str::FromStr;
+
+    type I = FixedI<1, 32>;
+    type D = DecimalFixed<2, 18>;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_fixed_i_to_f64(a: i64) -> bool {
+            let a = I::from_bits(BInt::from(a));
+            // `i64 as f64` is correctly rounded, and dividing by a power of two is exact
+            a.as_::<f64>() == a.to_bits().as_::<i64>() as f64 / 4294967296.0
+        }
+
+        fn quickcheck_int_round_trip(a: i32) -> bool {
+            let int = BInt::from(a);
+            I::checked_from_int(int).map(I::to_int) == Some(int)
+        }
+    }
+
+    #[test]
+    fn convert() {
+        assert_eq!(D::from_str("0.1").unwrap().as_::<f64>(), 0.1);
+        assert_eq!(D::from_str("-123.456").unwrap().as_::<f32>(), -123.456);
+        assert_eq!(D::from_str("-1.99").unwrap().to_int(), BInt::NEG_ONE);
+        assert_eq!(FixedU::<1, 16>::MAX.to_int(), BUint::from(u64::MAX >> 16));
+        assert_eq!(
+            FixedU::<1, 16>::checked_from_int(BUint::from(1u64 << 48)),
+            None
+        );
+        assert_eq!(I::checked_from_int(BInt::from(i32::MIN)), Some(I::MIN));
+        assert_eq!(FixedU::<1, 0>::MAX.as_::<f64>(), u64::MAX as f64);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn to_float() {
+        type F64 = crate::float::Float<8, 52>;
+
+        let a = D::from_str("1.375").unwrap();
+        assert_eq!(
+            a.as_::<F64>().to_bits(),
+            BUint::<1>::from(1.375f64.to_bits()).as_()
+        );
+    }
+}
@@ -0,0 +1,394 @@
+use super::{div_digit, mul_digit, DecimalFixed, FixedI, FixedU};
+use crate::errors::ParseFixedError;
+use crate::{BUint, ExpType};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::num::IntErrorKind;
+use core::str::FromStr;
+
+/// Returns the decimal digits of the binary fraction `frac / 2^bits`, which terminate after at most `bits` digits.
+fn binary_frac_digits<const N: usize>(mut frac: BUint<N>, bits: ExpType) -> Vec<u8> {
+    let mask = BUint::<N>::MAX.shr(BUint::<N>::BITS - bits);
+    let mut digits = Vec::new();
+    while !frac.is_zero() {
+        let (lo, carry) = mul_digit(frac, 10);
+        // the integer part of `frac * 10 / 2^bits`, whose top bits are in the carry if `bits` is close to `BITS`
+        let mut digit = lo.shr(bits).digits()[0];
+        if carry != 0 {
+            digit |= carry << (BUint::<N>::BITS - bits);
+        }
+        digits.push(b'0' + digit as u8);
+        frac = lo.bitand(mask);
+    }
+    digits
+}
+
+/// Writes `int.frac` to the formatter, rounding the fractional digits to nearest with ties to even if a precision is specified.
+fn write_fixed<const N: usize>(
+    f: &mut Formatter,
+    negative: bool,
+    mut int: BUint<N>,
+    mut frac: Vec<u8>,
+) -> fmt::Result {
+    if let Some(precision) = f.precision() {
+        if frac.len() > precision {
+            let round_digit = frac[precision];
+            let sticky = frac[precision + 1..].iter().any(|&d| d != b'0');
+            frac.truncate(precision);
+            let odd = match frac.last() {
+                Some(&d) => (d - b'0') % 2 == 1,
+                None => int.bit(0),
+            };
+            if round_digit > b'5' || (round_digit == b'5' && (sticky || odd)) {
+                // propagate the carry through the fractional digits, and into the integer part if they are all nines
+                let mut carry = true;
+                for d in frac.iter_mut().rev() {
+                    if *d == b'9' {
+                        *d = b'0';
+                    } else {
+                        *d += 1;
+                        carry = false;
+                        break;
+                    }
+                }
+                if carry {
+                    int = int.add(BUint::ONE);
+                }
+            }
+        } else {
+            frac.resize(precision, b'0');
+        }
+    }
+    let mut buf = int.to_string();
+    if !frac.is_empty() {
+        buf.push('.');
+        // the digits are all ASCII
+        buf.extend(frac.iter().map(|&d| d as char));
+    }
+    f.pad_integral(!negative, "", &buf)
+}
+
+const fn parse_error(kind: IntErrorKind) -> ParseFixedError {
+    ParseFixedError { kind }
+}
+
+/// Splits a fixed-point number string into its sign, integer digits and fractional digits.
+fn split_parts(src: &str) -> Result<(bool, &[u8], &[u8]), ParseFixedError> {
+    let src = src.as_bytes();
+    let (negative, src) = match src.first() {
+        None => return Err(parse_error(IntErrorKind::Empty)),
+        Some(b'+') => (false, &src[1..]),
+        Some(b'-') => (true, &src[1..]),
+        _ => (false, src),
+    };
+    let (int, frac) = match src.iter().position(|&b| b == b'.') {
+        Some(i) => (&src[..i], &src[i + 1..]),
+        None => (src, &[][..]),
+    };
+    if int.is_empty() && frac.is_empty() || !int.iter().chain(frac).all(u8::is_ascii_digit) {
+        return Err(parse_error(IntErrorKind::InvalidDigit));
+    }
+    Ok((negative, int, frac))
+}
+
+/// Parses a string of decimal digits, returning `None` if the value overflows.
+fn parse_digits<const N: usize>(digits: &[u8]) -> Option<BUint<N>> {
+    let mut out = BUint::<N>::ZERO;
+    for &d in digits {
+        let (lo, carry) = mul_digit(out, 10);
+        if carry != 0 {
+            return None;
+        }
+        out = lo.checked_add(BUint::from_digit((d - b'0') as u64))?;
+    }
+    Some(out)
+}
+
+/// Returns `int + 0.frac` multiplied by `2^bits`, rounded to nearest with ties to even, and whether it overflowed.
+fn parse_binary<const N: usize>(int: BUint<N>, frac: &[u8], bits: ExpType) -> (BUint<N>, bool) {
+    // compute `floor(0.frac * 2^(bits + 1))` one digit at a time, starting from the least significant digit, so that the lowest bit of the result is the rounding bit
+    let unit = BUint::<N>::ONE.shl(bits);
+    let mut q = BUint::<N>::ZERO;
+    let mut sticky = false;
+    for &d in frac.iter().rev() {
+        // `q = floor((d * 2^(bits + 1) + q) / 10)`, where `q < 2^(bits + 1)`, so the numerator is less than `10 * 2^BITS`
+        let (lo, hi) = mul_digit(unit, 2 * (d - b'0') as u64);
+        let (lo, carry) = lo.overflowing_add(q);
+        let (quotient, rem) = div_digit(hi + carry as u64, lo, 10);
+        q = quotient;
+        sticky |= rem != 0;
+    }
+    let overflow = bits != 0 && !int.shr(BUint::<N>::BITS - bits).is_zero();
+    let magnitude = int.shl(bits).bitor(q.shr(1));
+    if q.bit(0) && (sticky || magnitude.bit(0)) {
+        let (magnitude, carry) = magnitude.overflowing_add(BUint::ONE);
+        (magnitude, overflow || carry)
+    } else {
+        (magnitude, overflow)
+    }
+}
+
+/// Returns `int + 0.frac` multiplied by `10^scale`, rounded to nearest with ties to even, and whether it overflowed. `factor` must be equal to `10^scale`.
+fn parse_decimal<const N: usize>(
+    int: BUint<N>,
+    frac: &[u8],
+    scale: ExpType,
+    factor: BUint<N>,
+) -> (BUint<N>, bool) {
+    let (kept, rest) = frac.split_at(frac.len().min(scale as usize));
+    // the kept digits, padded with zeros to `scale` digits, are less than `10^scale`
+    let mut frac_value = parse_digits::<N>(kept).unwrap();
+    for _ in kept.len()..scale as usize {
+        frac_value = mul_digit(frac_value, 10).0;
+    }
+    let (magnitude, overflow) = match int.checked_mul(factor) {
+        Some(m) => m.overflowing_add(frac_value),
+        None => return (BUint::ZERO, true),
+    };
+    let round_up = match rest.split_first() {
+        Some((&d, rest)) => {
+            d > b'5' || (d == b'5' && (rest.iter().any(|&d| d != b'0') || magnitude.bit(0)))
+        }
+        None => false,
+    };
+    if round_up {
+        let (magnitude, carry) = magnitude.overflowing_add(BUint::ONE);
+        (magnitude, overflow || carry)
+    } else {
+        (magnitude, overflow)
+    }
+}
+
+macro_rules! binary_parts {
+    ($Fixed: ident) => {
+        impl<const N: usize, const FRAC: u32> $Fixed<N, FRAC> {
+            /// Returns the integer part of the magnitude of `self`, and the decimal digits of its fractional part.
+            fn decimal_parts(self) -> (BUint<N>, Vec<u8>) {
+                let magnitude = self.magnitude();
+                if FRAC == 0 {
+                    return (magnitude, Vec::new());
+                }
+                let frac = magnitude.bitand(BUint::MAX.shr(BUint::<N>::BITS - FRAC));
+                (magnitude.shr(FRAC), binary_frac_digits(frac, FRAC))
+            }
+
+            #[inline]
+            fn parse_magnitude(int: BUint<N>, frac: &[u8]) -> (BUint<N>, bool) {
+                parse_binary(int, frac, FRAC)
+            }
+        }
+    };
+}
+
+binary_parts!(FixedU);
+binary_parts!(FixedI);
+
+impl<const N: usize, const SCALE: u32> DecimalFixed<N, SCALE> {
+    /// Returns the integer part of the magnitude of `self`, and the decimal digits of its fractional part, which always has `SCALE` digits.
+    fn decimal_parts(self) -> (BUint<N>, Vec<u8>) {
+        let magnitude = self.magnitude();
+        if SCALE == 0 {
+            return (magnitude, Vec::new());
+        }
+        let (int, frac) = magnitude.div_rem(Self::SCALE_FACTOR);
+        let digits = frac.to_string();
+        let mut frac = vec![b'0'; SCALE as usize - digits.len()];
+        frac.extend_from_slice(digits.as_bytes());
+        (int, frac)
+    }
+
+    #[inline]
+    fn parse_magnitude(int: BUint<N>, frac: &[u8]) -> (BUint<N>, bool) {
+        parse_decimal(int, frac, SCALE, Self::SCALE_FACTOR)
+    }
+}
+
+macro_rules! fmt_parse {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> Display for $Fixed<N, $P> {
+            /// Formats the value with the exact decimal digits of its fractional part, or rounded to nearest with ties to even if a precision is specified.
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let (int, frac) = self.decimal_parts();
+                write_fixed(f, self.is_negative(), int, frac)
+            }
+        }
+
+        impl<const N: usize, const $P: u32> Debug for $Fixed<N, $P> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Display::fmt(self, f)
+            }
+        }
+
+        impl<const N: usize, const $P: u32> FromStr for $Fixed<N, $P> {
+            type Err = ParseFixedError;
+
+            /// Parses a string consisting of an optional sign, followed by decimal digits with an optional decimal point, rounding to the nearest representable value with ties to even.
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                let (negative, int, frac) = split_parts(src)?;
+                // unsigned types don't accept a minus sign, in the same way as the primitive unsigned integers
+                if negative && !Self::MIN.is_negative() {
+                    return Err(parse_error(IntErrorKind::InvalidDigit));
+                }
+                let (magnitude, overflow) = match parse_digits::<N>(int) {
+                    Some(int) => Self::parse_magnitude(int, frac),
+                    None => (BUint::ZERO, true),
+                };
+                match Self::from_magnitude(negative, magnitude, overflow) {
+                    (out, false) => Ok(out),
+                    (_, true) if negative => Err(parse_error(IntErrorKind::NegOverflow)),
+                    (_, true) => Err(parse_error(IntErrorKind::PosOverflow)),
+                }
+            }
+        }
+    };
+}
+
+fmt_parse!(FixedU, FRAC);
+fmt_parse!(FixedI, FRAC);
+fmt_parse!(DecimalFixed, SCALE);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{DecimalFixed, FixedI, FixedU};
+    use crate::errors::ParseFixedError;
+    use crate::{BInt, BUint};
+    use alloc::string::ToString;
+    use core::num::IntErrorKind;
+    use core::str::FromStr;
+
+    type U = FixedU<1, 32>;
+    type I = FixedI<1, 32>;
+    type D = DecimalFixed<1, 4>;
+
+    fn kind<T>(result: Result<T, ParseFixedError>) -> IntErrorKind {
+        *result.err().unwrap().kind()
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_fixed_u_round_trip(a: FixedU<2, 65>) -> bool {
+            FixedU::from_str(&a.to_string()) == Ok(a)
+        }
+
+        fn quickcheck_fixed_i_round_trip(a: FixedI<2, 100>) -> bool {
+            FixedI::from_str(&a.to_string()) == Ok(a)
+        }
+
+        fn quickcheck_decimal_fixed_round_trip(a: DecimalFixed<4, 18>) -> bool {
+            DecimalFixed::from_str(&a.to_string()) == Ok(a)
+        }
+
+        fn quickcheck_fixed_u_parse_rounding(a: u64) -> bool {
+            // parsing the exact value of a `FixedU<1, 32>` as a `FixedU<1, 16>` rounds it to nearest with ties to even
+            let (q, r) = (a >> 16, a & 0xFFFF);
+            let rounded = if r > 0x8000 || (r == 0x8000 && q & 1 == 1) { q + 1 } else { q };
+            let s = U::from_bits(BUint::from(a)).to_string();
+            FixedU::<1, 16>::from_str(&s) == Ok(FixedU::from_bits(BUint::from(rounded)))
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(U::ONE.to_string(), "1");
+        assert_eq!(U::DELTA.to_string(), "0.00000000023283064365386962890625");
+        assert_eq!(
+            U::MAX.to_string(),
+            "4294967295.99999999976716935634613037109375"
+        );
+        assert_eq!(I::MIN.to_string(), "-2147483648");
+        assert_eq!(
+            I::DELTA.wrapping_neg().to_string(),
+            "-0.00000000023283064365386962890625"
+        );
+        assert_eq!(
+            FixedU::<1, 63>::MAX.to_string(),
+            "1.999999999999999999891579782751449556599254719913005828857421875"
+        );
+        assert_eq!(FixedU::<1, 0>::MAX.to_string(), "18446744073709551615");
+        assert_eq!(D::ONE.to_string(), "1.0000");
+        assert_eq!(D::DELTA.wrapping_neg().to_string(), "-0.0001");
+        assert_eq!(D::MIN.to_string(), "-922337203685477.5808");
+        assert_eq!(DecimalFixed::<1, 0>::ONE.to_string(), "1");
+        assert_eq!(format!("{:.2}", U::from_str("2.125").unwrap()), "2.12");
+        assert_eq!(format!("{:.2}", U::from_str("2.375").unwrap()), "2.38");
+        assert_eq!(format!("{:.0}", U::from_str("2.5").unwrap()), "2");
+        assert_eq!(format!("{:.1}", I::from_str("-9.96").unwrap()), "-10.0");
+        assert_eq!(format!("{:.6}", D::from_str("-1.5").unwrap()), "-1.500000");
+        assert_eq!(format!("{:>8.1}", D::ONE), "     1.0");
+        assert_eq!(format!("{:+}", U::ONE), "+1");
+        assert_eq!(format!("{:?}", I::ONE.wrapping_neg()), "-1");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            U::from_str("1.5"),
+            Ok(U::from_bits(BUint::from(3u64 << 31)))
+        );
+        assert_eq!(
+            U::from_str("+.5"),
+            Ok(U::from_bits(BUint::from(1u64 << 31)))
+        );
+        assert_eq!(U::from_str("7."), Ok(U::from_bits(BUint::from(7u64 << 32))));
+        assert_eq!(I::from_str("-2147483648"), Ok(I::MIN));
+        assert_eq!(
+            I::from_str("-0.5"),
+            Ok(I::from_bits(BInt::from(-1i64 << 31)))
+        );
+        // halfway between `0` and `DELTA` rounds to even, anything larger rounds up
+        assert_eq!(
+            U::from_str("0.000000000116415321826934814453125"),
+            Ok(U::ZERO)
+        );
+        assert_eq!(
+            U::from_str("0.0000000001164153218269348144531251"),
+            Ok(U::DELTA)
+        );
+        assert_eq!(
+            U::from_str("0.000000000349245965480804443359375"),
+            Ok(U::from_bits(BUint::TWO))
+        );
+        assert_eq!(
+            U::from_str("4294967295.9999999999"),
+            Err(ParseFixedError {
+                kind: IntErrorKind::PosOverflow
+            })
+        );
+        assert_eq!(
+            FixedU::<1, 63>::from_str("0.99999999999999999999999"),
+            Ok(FixedU::ONE)
+        );
+        assert_eq!(
+            FixedU::<1, 0>::from_str("2.5"),
+            Ok(FixedU::from_bits(BUint::TWO))
+        );
+        assert_eq!(
+            FixedU::<1, 0>::from_str("3.5"),
+            Ok(FixedU::from_bits(BUint::FOUR))
+        );
+        assert_eq!(D::from_str("0.00005"), Ok(D::ZERO));
+        assert_eq!(D::from_str("0.00015"), Ok(D::from_bits(BInt::TWO)));
+        assert_eq!(D::from_str("0.000050001"), Ok(D::DELTA));
+        assert_eq!(D::from_str("12"), Ok(D::from_bits(BInt::from(120000))));
+        assert_eq!(D::from_str("-922337203685477.5808"), Ok(D::MIN));
+        assert_eq!(
+            kind(D::from_str("-922337203685477.5809")),
+            IntErrorKind::NegOverflow
+        );
+        assert_eq!(kind(I::from_str("2147483648")), IntErrorKind::PosOverflow);
+        assert_eq!(
+            kind(I::from_str("99999999999999999999999")),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(kind(U::from_str("")), IntErrorKind::Empty);
+        for src in ["-1", "-0", "+", ".", "1.2.3", "1e5", " 1", "0x1", "1_000"] {
+            assert_eq!(
+                kind(U::from_str(src)),
+                IntErrorKind::InvalidDigit,
+                "{}",
+                src
+            );
+        }
+        assert_eq!(kind(I::from_str("-")), IntErrorKind::InvalidDigit);
+    }
+}
@@ -0,0 +1,389 @@
+//! Fixed-point numbers stored as big integers.
+//!
+//! [`FixedU`] and [`FixedI`] are binary fixed-point numbers: they wrap a [`BUint<N>`] or [`BInt<N>`] whose least significant `FRAC` bits are the fractional part, so the value of a `FixedU<N, FRAC>` is its underlying integer divided by `2^FRAC`. [`DecimalFixed`] wraps a [`BInt<N>`] which is scaled by a power of ten instead, so the value of a `DecimalFixed<N, SCALE>` is its underlying integer divided by `10^SCALE`. This means that it can represent decimal fractions such as `0.1` exactly, which makes it suitable for representing amounts of money or token balances.
+//!
+//! Addition and subtraction are exact unless they overflow. Multiplication and division are calculated using a double width intermediate value, so they only overflow if the final result is out of range, and the result is rounded toward zero. As with the primitive integer types, the arithmetic operators panic on overflow when debug assertions are enabled, and wrap otherwise. The `checked_`, `wrapping_`, `saturating_` and `overflowing_` methods can be used to handle overflow explicitly.
+
+use crate::{BInt, BUint, ExpType};
+
+mod checked;
+mod consts;
+mod convert;
+mod fmt;
+mod ops;
+mod overflowing;
+mod saturating;
+mod wrapping;
+
+#[cfg(feature = "serde")]
+use ::serde::{Deserialize, Serialize};
+
+#[cfg(feature = "borsh")]
+use ::{
+    alloc::string::ToString,
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+};
+
+/// An unsigned binary fixed-point number with `FRAC` fractional bits, stored as a [`BUint<N>`].
+///
+/// `FRAC` must be less than the number of bits of `BUint<N>`, so that [`ONE`](Self::ONE) can be represented. Other values of `FRAC` fail to compile:
+///
+/// ```compile_fail
+/// use bnum::fixed::FixedU;
+/// use bnum::BUint;
+///
+/// let _ = FixedU::<1, 64>::from_bits(BUint::ONE);
+/// ```
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(BorshSerialize, BorshDeserialize, BorshSchema)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[repr(transparent)]
+pub struct FixedU<const N: usize, const FRAC: u32> {
+    bits: BUint<N>,
+}
+
+/// A signed binary fixed-point number with `FRAC` fractional bits, stored as a [`BInt<N>`].
+///
+/// `FRAC` must be at most two less than the number of bits of `BInt<N>`, so that [`ONE`](Self::ONE) can be represented. Other values of `FRAC` fail to compile.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(BorshSerialize, BorshDeserialize, BorshSchema)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[repr(transparent)]
+pub struct FixedI<const N: usize, const FRAC: u32> {
+    bits: BInt<N>,
+}
+
+/// A signed decimal fixed-point number with `SCALE` decimal places, stored as a [`BInt<N>`] which is equal to the number multiplied by `10^SCALE`.
+///
+/// `10^SCALE` must be representable by `BInt<N>`. Other values of `SCALE` fail to compile.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(BorshSerialize, BorshDeserialize, BorshSchema)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[repr(transparent)]
+pub struct DecimalFixed<const N: usize, const SCALE: u32> {
+    bits: BInt<N>,
+}
+
+/// Multiplies `x` by the digit `m`, returning the low `N` digits of the product and the carry digit.
+const fn mul_digit<const N: usize>(x: BUint<N>, m: u64) -> (BUint<N>, u64) {
+    let mut digits = *x.digits();
+    let mut carry = 0;
+    let mut i = 0;
+    while i < N {
+        let product = digits[i] as u128 * m as u128 + carry as u128;
+        digits[i] = product as u64;
+        carry = (product >> 64) as u64;
+        i += 1;
+    }
+    (BUint::from_digits(digits), carry)
+}
+
+/// Divides `hi * 2^BITS + lo` by the digit `d`, where `hi < d`, returning the quotient and remainder.
+const fn div_digit<const N: usize>(hi: u64, lo: BUint<N>, d: u64) -> (BUint<N>, u64) {
+    let mut digits = *lo.digits();
+    let mut rem = hi;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let n = (rem as u128) << 64 | digits[i] as u128;
+        digits[i] = (n / d as u128) as u64;
+        rem = (n % d as u128) as u64;
+    }
+    (BUint::from_digits(digits), rem)
+}
+
+/// Divides `hi * 2^BITS + lo` by `d`, returning the low `BITS` bits of the quotient and whether the quotient overflowed. `d` must be non-zero.
+const fn div_wide<const N: usize>(hi: BUint<N>, lo: BUint<N>, d: BUint<N>) -> (BUint<N>, bool) {
+    if hi.is_zero() {
+        return (lo.div_rem(d).0, false);
+    }
+    // the high bits of the quotient are discarded, so only the remainder of `hi` is needed
+    let (mut rem, overflow) = if hi.lt(&d) {
+        (hi, false)
+    } else {
+        (hi.div_rem(d).1, true)
+    };
+    let mut quotient = BUint::ZERO;
+    let mut i = BUint::<N>::BITS;
+    while i > 0 {
+        i -= 1;
+        // restoring division: shift the next bit of `lo` into the remainder, and subtract `d` if possible
+        let carry = rem.bit(BUint::<N>::BITS - 1);
+        rem = rem.shl(1);
+        if lo.bit(i) {
+            rem = rem.bitor(BUint::ONE);
+        }
+        quotient = quotient.shl(1);
+        if carry || rem.ge(&d) {
+            rem = rem.wrapping_sub(d);
+            quotient = quotient.bitor(BUint::ONE);
+        }
+    }
+    (quotient, overflow)
+}
+
+impl<const N: usize, const FRAC: u32> FixedU<N, FRAC> {
+    const FORMAT_CHECK: () = assert!(
+        FRAC < BUint::<N>::BITS,
+        crate::errors::err_msg!(
+            "`FixedU<N, FRAC>` requires `FRAC` to be less than the number of bits of `BUint<N>`"
+        )
+    );
+
+    /// One.
+    pub const ONE: Self = {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FORMAT_CHECK;
+        Self::from_bits(BUint::ONE.shl(FRAC))
+    };
+
+    /// Unsigned numbers are never negative: this exists so that the arithmetic methods can be shared with the signed types.
+    #[inline(always)]
+    const fn is_negative(self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    const fn magnitude(self) -> BUint<N> {
+        self.bits
+    }
+
+    #[inline(always)]
+    const fn from_magnitude(_negative: bool, magnitude: BUint<N>, overflow: bool) -> (Self, bool) {
+        (Self::from_bits(magnitude), overflow)
+    }
+
+    /// Computes the low `BITS` bits of `a * b / 2^FRAC`, rounded toward zero, and whether it overflowed.
+    const fn mul_magnitude(a: BUint<N>, b: BUint<N>) -> (BUint<N>, bool) {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FORMAT_CHECK;
+        let (lo, hi) = a.widening_mul(b);
+        if FRAC == 0 {
+            return (lo, !hi.is_zero());
+        }
+        let bits = hi.shl(BUint::<N>::BITS - FRAC).bitor(lo.shr(FRAC));
+        (bits, !hi.shr(FRAC).is_zero())
+    }
+
+    /// Computes the low `BITS` bits of `a * 2^FRAC / b`, rounded toward zero, and whether it overflowed.
+    const fn div_magnitude(a: BUint<N>, b: BUint<N>) -> (BUint<N>, bool) {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FORMAT_CHECK;
+        if FRAC == 0 {
+            return (a.div_rem(b).0, false);
+        }
+        div_wide(a.shr(BUint::<N>::BITS - FRAC), a.shl(FRAC), b)
+    }
+}
+
+impl<const N: usize, const FRAC: u32> FixedI<N, FRAC> {
+    const FORMAT_CHECK: () = assert!(
+        FRAC < BInt::<N>::BITS - 1,
+        crate::errors::err_msg!("`FixedI<N, FRAC>` requires `FRAC` to be at most two less than the number of bits of `BInt<N>`")
+    );
+
+    /// One.
+    pub const ONE: Self = {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FORMAT_CHECK;
+        Self::from_bits(BInt::ONE.shl(FRAC))
+    };
+
+    #[inline]
+    const fn mul_magnitude(a: BUint<N>, b: BUint<N>) -> (BUint<N>, bool) {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FORMAT_CHECK;
+        FixedU::<N, FRAC>::mul_magnitude(a, b)
+    }
+
+    #[inline]
+    const fn div_magnitude(a: BUint<N>, b: BUint<N>) -> (BUint<N>, bool) {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FORMAT_CHECK;
+        FixedU::<N, FRAC>::div_magnitude(a, b)
+    }
+}
+
+impl<const N: usize, const SCALE: u32> DecimalFixed<N, SCALE> {
+    /// `10^SCALE`, or `None` if it can't be represented by `BInt<N>`.
+    const POW10: Option<BUint<N>> = match BUint::<N>::from_digit(10).checked_pow(SCALE as ExpType) {
+        Some(pow) if pow.bits() < BUint::<N>::BITS => Some(pow),
+        _ => None,
+    };
+
+    /// `10^SCALE`, the underlying integer of [`ONE`](Self::ONE).
+    const SCALE_FACTOR: BUint<N> = crate::errors::option_expect!(
+        Self::POW10,
+        crate::errors::err_msg!(
+            "`DecimalFixed<N, SCALE>` requires `10^SCALE` to be representable by `BInt<N>`"
+        )
+    );
+
+    const FORMAT_CHECK: () = {
+        let _ = Self::SCALE_FACTOR;
+    };
+
+    /// One.
+    pub const ONE: Self = Self::from_bits(BInt::from_bits(Self::SCALE_FACTOR));
+
+    /// Computes the low `BITS` bits of `a * b / 10^SCALE`, rounded toward zero, and whether it overflowed.
+    const fn mul_magnitude(a: BUint<N>, b: BUint<N>) -> (BUint<N>, bool) {
+        let (lo, hi) = a.widening_mul(b);
+        div_wide(hi, lo, Self::SCALE_FACTOR)
+    }
+
+    /// Computes the low `BITS` bits of `a * 10^SCALE / b`, rounded toward zero, and whether it overflowed.
+    const fn div_magnitude(a: BUint<N>, b: BUint<N>) -> (BUint<N>, bool) {
+        let (lo, hi) = a.widening_mul(Self::SCALE_FACTOR);
+        div_wide(hi, lo, b)
+    }
+}
+
+macro_rules! signed {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Returns `true` if `self` is negative and `false` if it is zero or positive.
+            #[must_use]
+            #[inline]
+            pub const fn is_negative(self) -> bool {
+                self.bits.is_negative()
+            }
+
+            /// Returns `true` if `self` is positive and `false` if it is zero or negative.
+            #[must_use]
+            #[inline]
+            pub const fn is_positive(self) -> bool {
+                self.bits.is_positive()
+            }
+
+            #[inline(always)]
+            const fn magnitude(self) -> BUint<N> {
+                self.bits.unsigned_abs()
+            }
+
+            /// Returns the number with the given sign and magnitude, wrapping it if it is out of range, along with whether it overflowed. `overflow` indicates that the magnitude has already overflowed.
+            const fn from_magnitude(
+                negative: bool,
+                magnitude: BUint<N>,
+                overflow: bool,
+            ) -> (Self, bool) {
+                let bits = BInt::from_bits(magnitude);
+                if negative {
+                    // the magnitude of `MIN` is one greater than the magnitude of `MAX`
+                    let overflow = overflow || magnitude.gt(&BInt::<N>::MIN.unsigned_abs());
+                    (Self::from_bits(bits.wrapping_neg()), overflow)
+                } else {
+                    (Self::from_bits(bits), overflow || bits.is_negative())
+                }
+            }
+        }
+    };
+}
+
+signed!(FixedI, FRAC);
+signed!(DecimalFixed, SCALE);
+
+macro_rules! common {
+    ($Fixed: ident, $Int: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Creates a fixed-point number from its underlying integer representation.
+            #[must_use]
+            #[inline(always)]
+            pub const fn from_bits(bits: $Int<N>) -> Self {
+                #[allow(clippy::let_unit_value)]
+                let _ = Self::FORMAT_CHECK;
+                Self { bits }
+            }
+
+            /// Returns the underlying integer representation of `self`.
+            #[must_use]
+            #[inline(always)]
+            pub const fn to_bits(self) -> $Int<N> {
+                self.bits
+            }
+
+            /// Returns `true` if `self` is zero.
+            #[must_use]
+            #[inline]
+            pub const fn is_zero(self) -> bool {
+                self.bits.is_zero()
+            }
+
+            /// Returns the value which an overflowing result saturates to: `MIN` if the exact result is negative, and `MAX` otherwise.
+            #[inline]
+            const fn saturate(negative: bool) -> Self {
+                if negative {
+                    Self::MIN
+                } else {
+                    Self::MAX
+                }
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl<const N: usize, const $P: u32> zeroize::DefaultIsZeroes for $Fixed<N, $P> {}
+
+        #[cfg(any(test, feature = "quickcheck"))]
+        impl<const N: usize, const $P: u32> quickcheck::Arbitrary for $Fixed<N, $P> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                Self::from_bits(<$Int<N> as quickcheck::Arbitrary>::arbitrary(g))
+            }
+        }
+    };
+}
+
+common!(FixedU, BUint, FRAC);
+common!(FixedI, BInt, FRAC);
+common!(DecimalFixed, BInt, SCALE);
+
+#[cfg(test)]
+mod tests {
+    use super::{div_digit, div_wide, mul_digit, DecimalFixed, FixedI, FixedU};
+    use crate::{BInt, BUint};
+
+    quickcheck::quickcheck! {
+        fn quickcheck_digit_ops(a: u128, m: u64) -> bool {
+            let m = m.max(1);
+            let (lo, carry) = mul_digit(BUint::<2>::from(a), m);
+            let (q, r) = div_digit(carry, lo, m);
+            q == BUint::from(a) && r == 0
+        }
+
+        fn quickcheck_div_wide(hi: u64, lo: u64, d: u64) -> quickcheck::TestResult {
+            if d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let n = (hi as u128) << 64 | lo as u128;
+            let q = n / d as u128;
+            let expected = (BUint::<1>::from(q as u64), q > u64::MAX as u128);
+            quickcheck::TestResult::from_bool(div_wide(BUint::from(hi), BUint::from(lo), BUint::from(d)) == expected)
+        }
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(FixedU::<1, 0>::ONE.to_bits(), BUint::ONE);
+        assert_eq!(FixedU::<1, 63>::ONE.to_bits(), BUint::ONE << 63u32);
+        assert_eq!(FixedI::<2, 126>::ONE.to_bits(), BInt::ONE << 126u32);
+        assert_eq!(
+            DecimalFixed::<4, 18>::ONE.to_bits(),
+            BInt::from(10u64.pow(18))
+        );
+        assert_eq!(
+            DecimalFixed::<1, 18>::ONE.to_bits(),
+            BInt::from(10u64.pow(18))
+        );
+    }
+}
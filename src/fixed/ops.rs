@@ -0,0 +1,124 @@
+use super::{DecimalFixed, FixedI, FixedU};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+macro_rules! op_trait {
+    ($Fixed: ident, $P: ident; $($Trait: ident, $method: ident, $AssignTrait: ident, $assign: ident, $checked: ident, $wrapping: ident, $msg: literal); *) => {
+        $(
+            impl<const N: usize, const $P: u32> $Trait for $Fixed<N, $P> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    #[cfg(debug_assertions)]
+                    return crate::errors::option_expect!(self.$checked(rhs), crate::errors::err_msg!($msg));
+
+                    #[cfg(not(debug_assertions))]
+                    self.$wrapping(rhs)
+                }
+            }
+
+            impl<const N: usize, const $P: u32> $AssignTrait for $Fixed<N, $P> {
+                #[inline]
+                fn $assign(&mut self, rhs: Self) {
+                    *self = $Trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! ops {
+    ($Fixed: ident, $P: ident) => {
+        op_trait!(
+            $Fixed, $P;
+            Add, add, AddAssign, add_assign, checked_add, wrapping_add, "attempt to add with overflow";
+            Sub, sub, SubAssign, sub_assign, checked_sub, wrapping_sub, "attempt to subtract with overflow";
+            Mul, mul, MulAssign, mul_assign, checked_mul, wrapping_mul, "attempt to multiply with overflow"
+        );
+
+        impl<const N: usize, const $P: u32> Div for $Fixed<N, $P> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                if rhs.is_zero() {
+                    crate::errors::div_zero!()
+                }
+
+                #[cfg(debug_assertions)]
+                return crate::errors::option_expect!(self.checked_div(rhs), crate::errors::err_msg!("attempt to divide with overflow"));
+
+                #[cfg(not(debug_assertions))]
+                self.wrapping_div(rhs)
+            }
+        }
+
+        impl<const N: usize, const $P: u32> DivAssign for $Fixed<N, $P> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = Div::div(*self, rhs);
+            }
+        }
+    };
+}
+
+ops!(FixedU, FRAC);
+ops!(FixedI, FRAC);
+ops!(DecimalFixed, SCALE);
+
+macro_rules! neg {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> Neg for $Fixed<N, $P> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                #[cfg(debug_assertions)]
+                return crate::errors::option_expect!(
+                    self.checked_neg(),
+                    crate::errors::err_msg!("attempt to negate with overflow")
+                );
+
+                #[cfg(not(debug_assertions))]
+                self.wrapping_neg()
+            }
+        }
+    };
+}
+
+neg!(FixedI, FRAC);
+neg!(DecimalFixed, SCALE);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{DecimalFixed, FixedI};
+
+    type I = FixedI<2, 64>;
+    type D = DecimalFixed<2, 18>;
+
+    #[test]
+    fn ops() {
+        let two = I::ONE + I::ONE;
+        let mut x = two * two;
+        x -= I::ONE;
+        x /= two;
+        assert_eq!(x, I::ONE + I::ONE / two);
+        assert_eq!(-x * two, -(two + I::ONE));
+        let mut y = D::ONE / (D::ONE + D::ONE + D::ONE);
+        y *= D::ONE + D::ONE + D::ONE;
+        assert_eq!(y + D::DELTA, D::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = D::ONE / D::ZERO;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn mul_overflow() {
+        let _ = I::MAX * (I::ONE + I::ONE);
+    }
+}
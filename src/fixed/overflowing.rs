@@ -0,0 +1,171 @@
+use super::{DecimalFixed, FixedI, FixedU};
+
+macro_rules! overflowing {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Calculates `self + rhs`. Returns a tuple of the result along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (bits, overflow) = self.bits.overflowing_add(rhs.bits);
+                (Self::from_bits(bits), overflow)
+            }
+
+            /// Calculates `self - rhs`. Returns a tuple of the result along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (bits, overflow) = self.bits.overflowing_sub(rhs.bits);
+                (Self::from_bits(bits), overflow)
+            }
+
+            /// Calculates `self * rhs`, rounded toward zero. Returns a tuple of the result along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                let negative = self.is_negative() != rhs.is_negative();
+                let (magnitude, overflow) = Self::mul_magnitude(self.magnitude(), rhs.magnitude());
+                Self::from_magnitude(negative, magnitude, overflow)
+            }
+
+            /// Calculates `self / rhs`, rounded toward zero. Returns a tuple of the result along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                if rhs.is_zero() {
+                    crate::errors::div_zero!()
+                }
+                let negative = self.is_negative() != rhs.is_negative();
+                let (magnitude, overflow) = Self::div_magnitude(self.magnitude(), rhs.magnitude());
+                Self::from_magnitude(negative, magnitude, overflow)
+            }
+        }
+    };
+}
+
+overflowing!(FixedU, FRAC);
+overflowing!(FixedI, FRAC);
+overflowing!(DecimalFixed, SCALE);
+
+macro_rules! overflowing_signed {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Negates `self`. Returns a tuple of the result along with a boolean indicating whether an arithmetic overflow would occur. The only case where an overflow can occur is when `self` is [`MIN`](Self::MIN), in which case `MIN` is returned.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn overflowing_neg(self) -> (Self, bool) {
+                let (bits, overflow) = self.bits.overflowing_neg();
+                (Self::from_bits(bits), overflow)
+            }
+
+            /// Computes the absolute value of `self`. Returns a tuple of the result along with a boolean indicating whether an arithmetic overflow would occur. The only case where an overflow can occur is when `self` is [`MIN`](Self::MIN), in which case `MIN` is returned.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn overflowing_abs(self) -> (Self, bool) {
+                let (bits, overflow) = self.bits.overflowing_abs();
+                (Self::from_bits(bits), overflow)
+            }
+        }
+    };
+}
+
+overflowing_signed!(FixedI, FRAC);
+overflowing_signed!(DecimalFixed, SCALE);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{DecimalFixed, FixedI, FixedU};
+    use crate::{BInt, BUint};
+
+    type U = FixedU<1, 32>;
+    type I = FixedI<1, 32>;
+    type D = DecimalFixed<1, 4>;
+
+    const SCALE: i128 = 10_000;
+
+    fn u(bits: u64) -> U {
+        U::from_bits(BUint::from(bits))
+    }
+
+    fn i(bits: i64) -> I {
+        I::from_bits(BInt::from(bits))
+    }
+
+    fn d(bits: i64) -> D {
+        D::from_bits(BInt::from(bits))
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_fixed_u_mul(a: u64, b: u64) -> bool {
+            let exact = (a as u128 * b as u128) >> 32;
+            u(a).overflowing_mul(u(b)) == (u(exact as u64), exact > u64::MAX as u128)
+        }
+
+        fn quickcheck_fixed_u_div(a: u64, b: u64) -> quickcheck::TestResult {
+            if b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let exact = ((a as u128) << 32) / b as u128;
+            quickcheck::TestResult::from_bool(u(a).overflowing_div(u(b)) == (u(exact as u64), exact > u64::MAX as u128))
+        }
+
+        fn quickcheck_fixed_i_mul(a: i64, b: i64) -> bool {
+            // the product of the underlying integers divided by `2^32`, rounded toward zero
+            let exact = a as i128 * b as i128 / (1 << 32);
+            i(a).overflowing_mul(i(b)) == (i(exact as i64), exact != exact as i64 as i128)
+        }
+
+        fn quickcheck_fixed_i_div(a: i64, b: i64) -> quickcheck::TestResult {
+            if b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let exact = ((a as i128) << 32) / b as i128;
+            quickcheck::TestResult::from_bool(i(a).overflowing_div(i(b)) == (i(exact as i64), exact != exact as i64 as i128))
+        }
+
+        fn quickcheck_decimal_fixed_mul(a: i64, b: i64) -> bool {
+            let exact = a as i128 * b as i128 / SCALE;
+            d(a).overflowing_mul(d(b)) == (d(exact as i64), exact != exact as i64 as i128)
+        }
+
+        fn quickcheck_decimal_fixed_div(a: i64, b: i64) -> quickcheck::TestResult {
+            if b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let exact = a as i128 * SCALE / b as i128;
+            quickcheck::TestResult::from_bool(d(a).overflowing_div(d(b)) == (d(exact as i64), exact != exact as i64 as i128))
+        }
+
+        fn quickcheck_fixed_i_add_sub(a: i64, b: i64) -> bool {
+            let (add, add_overflow) = a.overflowing_add(b);
+            let (sub, sub_overflow) = a.overflowing_sub(b);
+            i(a).overflowing_add(i(b)) == (i(add), add_overflow) && i(a).overflowing_sub(i(b)) == (i(sub), sub_overflow)
+        }
+    }
+
+    #[test]
+    fn overflowing_edge_cases() {
+        assert_eq!(I::MIN.overflowing_neg(), (I::MIN, true));
+        assert_eq!(I::MIN.overflowing_abs(), (I::MIN, true));
+        assert_eq!(
+            I::MIN.overflowing_div(I::ONE.overflowing_neg().0),
+            (I::MIN, true)
+        );
+        assert_eq!(I::MIN.overflowing_mul(I::ONE), (I::MIN, false));
+        assert_eq!(
+            I::MAX.overflowing_mul(I::ONE.overflowing_neg().0),
+            (i(-i64::MAX), false)
+        );
+        assert_eq!(D::MIN.overflowing_div(D::ONE), (D::MIN, false));
+        assert_eq!(U::MAX.overflowing_mul(U::ONE), (U::MAX, false));
+        assert_eq!(U::ZERO.overflowing_sub(U::DELTA), (U::MAX, true));
+        assert_eq!(
+            FixedU::<1, 0>::MAX.overflowing_div(FixedU::<1, 0>::ONE),
+            (FixedU::<1, 0>::MAX, false)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use super::{DecimalFixed, FixedI, FixedU};
+
+macro_rules! saturating {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Saturating addition. Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                match self.checked_add(rhs) {
+                    Some(add) => add,
+                    None => Self::saturate(rhs.is_negative()),
+                }
+            }
+
+            /// Saturating subtraction. Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    Some(sub) => sub,
+                    None => Self::saturate(!rhs.is_negative()),
+                }
+            }
+
+            /// Saturating multiplication. Computes `self * rhs`, rounded toward zero, saturating at the numeric bounds instead of overflowing.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                match self.checked_mul(rhs) {
+                    Some(mul) => mul,
+                    None => Self::saturate(self.is_negative() != rhs.is_negative()),
+                }
+            }
+
+            /// Saturating division. Computes `self / rhs`, rounded toward zero, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn saturating_div(self, rhs: Self) -> Self {
+                let (div, overflow) = self.overflowing_div(rhs);
+                if overflow {
+                    Self::saturate(self.is_negative() != rhs.is_negative())
+                } else {
+                    div
+                }
+            }
+        }
+    };
+}
+
+saturating!(FixedU, FRAC);
+saturating!(FixedI, FRAC);
+saturating!(DecimalFixed, SCALE);
+
+macro_rules! saturating_signed {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Saturating negation. Computes `-self`, returning [`MAX`](Self::MAX) if `self` is [`MIN`](Self::MIN) instead of overflowing.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn saturating_neg(self) -> Self {
+                match self.checked_neg() {
+                    Some(neg) => neg,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Saturating absolute value. Computes `self.abs()`, returning [`MAX`](Self::MAX) if `self` is [`MIN`](Self::MIN) instead of overflowing.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn saturating_abs(self) -> Self {
+                match self.checked_abs() {
+                    Some(abs) => abs,
+                    None => Self::MAX,
+                }
+            }
+        }
+    };
+}
+
+saturating_signed!(FixedI, FRAC);
+saturating_signed!(DecimalFixed, SCALE);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{DecimalFixed, FixedI, FixedU};
+
+    type U = FixedU<2, 64>;
+    type I = FixedI<2, 64>;
+    type D = DecimalFixed<2, 18>;
+
+    #[test]
+    fn saturating() {
+        assert_eq!(U::MAX.saturating_add(U::DELTA), U::MAX);
+        assert_eq!(U::ZERO.saturating_sub(U::ONE), U::ZERO);
+        assert_eq!(U::MAX.saturating_mul(U::MAX), U::MAX);
+        assert_eq!(U::MAX.saturating_div(U::DELTA), U::MAX);
+        assert_eq!(I::MAX.saturating_add(I::ONE), I::MAX);
+        assert_eq!(I::MIN.saturating_add(I::ONE.wrapping_neg()), I::MIN);
+        assert_eq!(I::MIN.saturating_sub(I::ONE), I::MIN);
+        assert_eq!(I::MAX.saturating_sub(I::ONE.wrapping_neg()), I::MAX);
+        assert_eq!(I::MIN.saturating_mul(I::MIN), I::MAX);
+        assert_eq!(I::MIN.saturating_mul(I::MAX), I::MIN);
+        assert_eq!(I::MIN.saturating_neg(), I::MAX);
+        assert_eq!(D::MIN.saturating_abs(), D::MAX);
+        assert_eq!(D::MAX.saturating_div(D::DELTA.wrapping_neg()), D::MIN);
+    }
+}
@@ -0,0 +1,66 @@
+use super::{DecimalFixed, FixedI, FixedU};
+
+macro_rules! wrapping {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn wrapping_add(self, rhs: Self) -> Self {
+                self.overflowing_add(rhs).0
+            }
+
+            /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                self.overflowing_sub(rhs).0
+            }
+
+            /// Wrapping (modular) multiplication. Computes `self * rhs`, rounded toward zero, wrapping around at the boundary of the type.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                self.overflowing_mul(rhs).0
+            }
+
+            /// Wrapping (modular) division. Computes `self / rhs`, rounded toward zero, wrapping around at the boundary of the type.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn wrapping_div(self, rhs: Self) -> Self {
+                self.overflowing_div(rhs).0
+            }
+        }
+    };
+}
+
+wrapping!(FixedU, FRAC);
+wrapping!(FixedI, FRAC);
+wrapping!(DecimalFixed, SCALE);
+
+macro_rules! wrapping_signed {
+    ($Fixed: ident, $P: ident) => {
+        impl<const N: usize, const $P: u32> $Fixed<N, $P> {
+            /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type, so that the negation of [`MIN`](Self::MIN) is `MIN`.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn wrapping_neg(self) -> Self {
+                self.overflowing_neg().0
+            }
+
+            /// Wrapping (modular) absolute value. Computes `self.abs()`, wrapping around at the boundary of the type, so that the absolute value of [`MIN`](Self::MIN) is `MIN`.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn wrapping_abs(self) -> Self {
+                self.overflowing_abs().0
+            }
+        }
+    };
+}
+
+wrapping_signed!(FixedI, FRAC);
+wrapping_signed!(DecimalFixed, SCALE);
@@ -0,0 +1,263 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::BUint;
+
+/// A heap allocated arbitrary precision natural number, used for the exact conversions between floats and strings of digits. Only the operations needed by these conversions are implemented.
+///
+/// The number is stored as little endian `u32` words, with no trailing zero words (so zero is stored as an empty vector).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Big {
+    words: Vec<u32>,
+}
+
+impl Big {
+    #[inline]
+    pub fn zero() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    #[inline]
+    pub fn from_u64(n: u64) -> Self {
+        let mut out = Self {
+            words: vec![n as u32, (n >> 32) as u32],
+        };
+        out.trim();
+        out
+    }
+
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        let mut out = Self {
+            words: bytes
+                .chunks(4)
+                .map(|chunk| {
+                    chunk
+                        .iter()
+                        .rev()
+                        .fold(0, |word, &byte| (word << 8) | byte as u32)
+                })
+                .collect(),
+        };
+        out.trim();
+        out
+    }
+
+    #[inline]
+    fn trim(&mut self) {
+        while let Some(&0) = self.words.last() {
+            self.words.pop();
+        }
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the number of bits needed to represent `self`.
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        match self.words.last() {
+            Some(top) => self.words.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Returns `true` if the `n` least significant bits of `self` are all zero.
+    pub fn low_bits_zero(&self, n: u64) -> bool {
+        let full = (n / 32) as usize;
+        if self.words.iter().take(full).any(|&w| w != 0) {
+            return false;
+        }
+        let rem = n % 32;
+        rem == 0 || self.words.get(full).map_or(true, |&w| w << (32 - rem) == 0)
+    }
+
+    pub fn mul_small(&mut self, rhs: u32) -> &mut Self {
+        let mut carry = 0u64;
+        for w in self.words.iter_mut() {
+            let prod = *w as u64 * rhs as u64 + carry;
+            *w = prod as u32;
+            carry = prod >> 32;
+        }
+        if carry != 0 {
+            self.words.push(carry as u32);
+        }
+        if rhs == 0 {
+            self.words.clear();
+        }
+        self
+    }
+
+    pub fn add_small(&mut self, rhs: u32) -> &mut Self {
+        let mut carry = rhs;
+        for w in self.words.iter_mut() {
+            let (sum, overflow) = w.overflowing_add(carry);
+            *w = sum;
+            if !overflow {
+                return self;
+            }
+            carry = 1;
+        }
+        if carry != 0 {
+            self.words.push(carry);
+        }
+        self
+    }
+
+    /// Multiplies `self` by `base^exp`.
+    pub fn mul_pow(&mut self, base: u32, mut exp: u64) -> &mut Self {
+        debug_assert!(base >= 2);
+        // multiply by the largest power of `base` that fits in a `u32` as many times as possible
+        let mut chunk = base;
+        let mut chunk_exp = 1;
+        while let Some(next) = chunk.checked_mul(base) {
+            chunk = next;
+            chunk_exp += 1;
+        }
+        while exp >= chunk_exp {
+            self.mul_small(chunk);
+            exp -= chunk_exp;
+        }
+        if exp > 0 {
+            self.mul_small(base.pow(exp as u32));
+        }
+        self
+    }
+
+    pub fn shl(&mut self, rhs: u64) -> &mut Self {
+        if self.is_zero() {
+            return self;
+        }
+        let word_shift = (rhs / 32) as usize;
+        let bit_shift = (rhs % 32) as u32;
+        if bit_shift != 0 {
+            let mut carry = 0;
+            for w in self.words.iter_mut() {
+                let next_carry = *w >> (32 - bit_shift);
+                *w = (*w << bit_shift) | carry;
+                carry = next_carry;
+            }
+            if carry != 0 {
+                self.words.push(carry);
+            }
+        }
+        self.words.splice(0..0, core::iter::repeat(0).take(word_shift));
+        self
+    }
+
+    fn shr1(&mut self) {
+        let mut carry = 0;
+        for w in self.words.iter_mut().rev() {
+            let next_carry = *w << 31;
+            *w = (*w >> 1) | carry;
+            carry = next_carry;
+        }
+        self.trim();
+    }
+
+    pub fn add(&mut self, rhs: &Self) -> &mut Self {
+        if self.words.len() < rhs.words.len() {
+            self.words.resize(rhs.words.len(), 0);
+        }
+        let mut carry = false;
+        for (i, w) in self.words.iter_mut().enumerate() {
+            let r = rhs.words.get(i).copied().unwrap_or(0);
+            if r == 0 && !carry && i >= rhs.words.len() {
+                break;
+            }
+            let (sum, o1) = w.overflowing_add(r);
+            let (sum, o2) = sum.overflowing_add(carry as u32);
+            *w = sum;
+            carry = o1 || o2;
+        }
+        if carry {
+            self.words.push(1);
+        }
+        self
+    }
+
+    /// Subtracts `rhs` from `self`. `rhs` must not be greater than `self`.
+    pub fn sub(&mut self, rhs: &Self) -> &mut Self {
+        debug_assert!(*self >= *rhs);
+        let mut borrow = false;
+        for (i, w) in self.words.iter_mut().enumerate() {
+            let r = rhs.words.get(i).copied().unwrap_or(0);
+            if r == 0 && !borrow && i >= rhs.words.len() {
+                break;
+            }
+            let (diff, o1) = w.overflowing_sub(r);
+            let (diff, o2) = diff.overflowing_sub(borrow as u32);
+            *w = diff;
+            borrow = o1 || o2;
+        }
+        self.trim();
+        self
+    }
+
+    /// Sets `self` to `self % rhs` and returns `self / rhs`, which must fit in a `u32`. Intended for small quotients, such as when generating digits.
+    pub fn div_rem_small(&mut self, rhs: &Self) -> u32 {
+        let mut q = 0;
+        while *self >= *rhs {
+            self.sub(rhs);
+            q += 1;
+        }
+        q
+    }
+
+    /// Sets `self` to `self % rhs` and returns `self / rhs`. `rhs` must be non-zero.
+    pub fn div_rem(&mut self, rhs: &Self) -> Self {
+        debug_assert!(!rhs.is_zero());
+        let mut q = Self::zero();
+        if *self < *rhs {
+            return q;
+        }
+        let q_bits = self.bits() - rhs.bits() + 1;
+        let mut d = rhs.clone();
+        d.shl(q_bits - 1);
+        q.words.resize(((q_bits + 31) / 32) as usize, 0);
+        for i in (0..q_bits).rev() {
+            if *self >= d {
+                self.sub(&d);
+                q.words[(i / 32) as usize] |= 1 << (i % 32);
+            }
+            d.shr1();
+        }
+        q.trim();
+        q
+    }
+
+    /// Returns bits `shift` to `shift + 64 * N` of `self`.
+    pub fn window<const N: usize>(&self, shift: u64) -> BUint<N> {
+        let mut digits = [0u64; N];
+        for (i, digit) in digits.iter_mut().enumerate() {
+            let bit = shift + i as u64 * 64;
+            let word = (bit / 32) as usize;
+            let offset = bit % 32;
+            // combine three consecutive words, which always contain the 64 bits starting at `offset`
+            let mut wide = 0u128;
+            for j in 0..3 {
+                let w = self.words.get(word + j).copied().unwrap_or(0);
+                wide |= (w as u128) << (32 * j);
+            }
+            *digit = (wide >> offset) as u64;
+        }
+        BUint::from_digits(digits)
+    }
+}
+
+impl PartialOrd for Big {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.words
+            .len()
+            .cmp(&other.words.len())
+            .then_with(|| self.words.iter().rev().cmp(other.words.iter().rev()))
+    }
+}
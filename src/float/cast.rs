@@ -1,6 +1,7 @@
 // TODO: implement casts from and to float for primitive types and buint, bint
-use super::Float;
-use crate::cast::CastFrom;
+use super::{Float, RoundingMode};
+use super::storage::{Storage, Width};
+use crate::cast::{As, CastFrom, FloatToInt};
 use crate::doc;
 use crate::{BUintD8, BUintD16, BUintD32, BUint, BIntD8, BIntD16, BIntD32, BInt};
 use crate::ExpType;
@@ -10,7 +11,10 @@ use crate::buint::as_float;
 macro_rules! uint_as_float {
     ($($uint: ident $(<$N: ident>)?), *) => {
         $(
-            impl<const W: usize, const MB: usize $(, const $N: usize)?> CastFrom<$uint $(<$N>)?> for Float<W, MB> {
+            impl<const W: usize, const MB: usize $(, const $N: usize)?> CastFrom<$uint $(<$N>)?> for Float<W, MB>
+            where
+                Width<W>: Storage,
+            {
                 #[must_use = doc::must_use_op!()]
                 #[inline]
                 fn cast_from(from: $uint $(<$N>)?) -> Self {
@@ -26,7 +30,10 @@ uint_as_float!(u8, u16, u32, u64, u128, usize, BUintD8<N>, BUintD16<N>, BUintD32
 macro_rules! int_as_float {
     ($($int: ty), *) => {
         $(
-            impl<const W: usize, const MB: usize> CastFrom<$int> for Float<W, MB> {
+            impl<const W: usize, const MB: usize> CastFrom<$int> for Float<W, MB>
+            where
+                Width<W>: Storage,
+            {
                 fn cast_from(from: $int) -> Self {
                     let pos_cast = Self::cast_from(from.unsigned_abs());
                     if from.is_negative() {
@@ -45,7 +52,10 @@ int_as_float!(i8, i16, i32, i64, i128, isize);
 macro_rules! bint_as_float {
     ($($bint: ident), *) => {
         $(
-            impl<const W: usize, const MB: usize, const N: usize> CastFrom<$bint<N>> for Float<W, MB> {
+            impl<const W: usize, const MB: usize, const N: usize> CastFrom<$bint<N>> for Float<W, MB>
+            where
+                Width<W>: Storage,
+            {
                 fn cast_from(from: $bint<N>) -> Self {
                     let pos_cast = Self::cast_from(from.unsigned_abs());
                     if from.is_negative() {
@@ -61,9 +71,47 @@ macro_rules! bint_as_float {
 
 bint_as_float!(BIntD8, BIntD16, BIntD32, BInt);
 
+macro_rules! primitive_float_as_float {
+    ($($float: ty: $W: literal, $MB: literal), *) => {
+        $(
+            impl<const W: usize, const MB: usize> CastFrom<$float> for Float<W, MB>
+            where
+                Width<W>: Storage,
+            {
+                #[must_use = doc::must_use_op!()]
+                #[inline]
+                fn cast_from(from: $float) -> Self {
+                    Float::<$W, $MB>::from_bits(BUintD8::from(from.to_bits()))
+                        .convert_in::<W, W, MB>(RoundingMode::NearestTiesToEven)
+                        .0
+                }
+            }
+
+            impl<const W: usize, const MB: usize> CastFrom<Float<W, MB>> for $float
+            where
+                Width<W>: Storage,
+            {
+                #[must_use = doc::must_use_op!()]
+                #[inline]
+                fn cast_from(from: Float<W, MB>) -> Self {
+                    let f = from
+                        .convert_in::<W, $W, $MB>(RoundingMode::NearestTiesToEven)
+                        .0;
+                    <$float>::from_bits(f.to_bits().as_())
+                }
+            }
+        )*
+    };
+}
+
+primitive_float_as_float!(f32: 4, 23, f64: 8, 52);
+
 macro_rules! float_as_bint {
     ($BUint: ident, $BInt: ident, $Digit: ident) => {
-        impl<const W: usize, const MB: usize, const N: usize> CastFrom<Float<W, MB>> for $BInt<N> {
+        impl<const W: usize, const MB: usize, const N: usize> CastFrom<Float<W, MB>> for $BInt<N>
+        where
+            Width<W>: Storage,
+        {
             crate::bint::cast::bint_cast_from_float!(Float<W, MB>, $BUint<N>);
         }
     };
@@ -74,7 +122,10 @@ crate::macro_impl!(float_as_bint);
 macro_rules! float_as_int {
     ($($int: ty; $uint: ty), *) => {
         $(
-            impl<const W: usize, const MB: usize> CastFrom<Float<W, MB>> for $int {
+            impl<const W: usize, const MB: usize> CastFrom<Float<W, MB>> for $int
+            where
+                Width<W>: Storage,
+            {
                 #[inline]
                 fn cast_from(from: Float<W, MB>) -> Self {
                     if from.is_sign_negative() {
@@ -139,11 +190,6 @@ macro_rules! impl_mantissa_for_buint {
                 Self::leading_zeros(self)
             }
 
-            #[inline]
-            fn bitand(self, rhs: Self) -> Self {
-                Self::bitand(self, rhs)
-            }
-
             #[inline]
             fn gt(&self, rhs: &Self) -> bool {
                 Self::gt(&self, &rhs)
@@ -158,7 +204,10 @@ crate::macro_impl!(impl_mantissa_for_buint);
 
 use crate::buint::float_as::{uint_cast_from_float, CastUintFromFloatHelper};
 
-impl<const W: usize, const MB: usize> CastUintFromFloatHelper for Float<W, MB> {
+impl<const W: usize, const MB: usize> CastUintFromFloatHelper for Float<W, MB>
+where
+    Width<W>: Storage,
+{
     type M = BUintD8<W>;
     type E = BIntD8<W>;
 
@@ -185,7 +234,10 @@ impl<const W: usize, const MB: usize> CastUintFromFloatHelper for Float<W, MB> {
     }
 }
 
-impl<const W: usize, const MB: usize> CastToFloatConsts for Float<W, MB> {
+impl<const W: usize, const MB: usize> CastToFloatConsts for Float<W, MB>
+where
+    Width<W>: Storage,
+{
     type M = BUintD8<W>;
 
     const ZERO: Self = Self::ZERO;
@@ -201,7 +253,10 @@ impl<const W: usize, const MB: usize> CastToFloatConsts for Float<W, MB> {
 macro_rules! float_as_uint {
     ($($uint: ident $(<$N: ident>)?), *) => {
         $(
-            impl<const W: usize, const MB: usize $(, const $N: usize)?> CastFrom<Float<W, MB>> for $uint $(<$N>)? {
+            impl<const W: usize, const MB: usize $(, const $N: usize)?> CastFrom<Float<W, MB>> for $uint $(<$N>)?
+            where
+                Width<W>: Storage,
+            {
                 #[must_use = doc::must_use_op!()]
                 #[inline]
                 fn cast_from(from: Float<W, MB>) -> Self {
@@ -214,6 +269,44 @@ macro_rules! float_as_uint {
 
 float_as_uint!(BUintD8<N>, BUintD16<N>, BUintD32<N>, BUint<N>, u8, u16, u32, u64, u128, usize);
 
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Rounds `self` toward zero and converts it to any integer type, assuming that the value is finite and fits in that type.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be NaN or infinite, and must be representable by `Int` after its fractional part is truncated.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub unsafe fn to_int_unchecked<Int>(self) -> Int
+    where
+        Self: FloatToInt<Int>,
+    {
+        unsafe { FloatToInt::to_int_unchecked(self) }
+    }
+}
+
+macro_rules! float_to_int {
+    ($($int: ident $(<$N: ident>)?), *) => {
+        $(
+            impl<const W: usize, const MB: usize $(, const $N: usize)?> FloatToInt<$int $(<$N>)?> for Float<W, MB>
+            where
+                Width<W>: Storage,
+            {
+                #[inline]
+                unsafe fn to_int_unchecked(self) -> $int $(<$N>)? {
+                    debug_assert!(self.is_finite(), "float to int conversion of non-finite value");
+                    <$int $(<$N>)?>::cast_from(self)
+                }
+            }
+        )*
+    };
+}
+
+float_to_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, BUintD8<N>, BUintD16<N>, BUintD32<N>, BUint<N>, BIntD8<N>, BIntD16<N>, BIntD32<N>, BInt<N>);
+
 #[cfg(test)]
 mod tests {
     use super::CastFrom;
@@ -232,12 +325,12 @@ mod tests {
 
     test_from! {
         function: <ftest as CastFrom>::cast_from,
-        from_types: (u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, UTESTD8, UTESTD16, UTESTD32, UTESTD64, TestUint1, TestUint2, TestUint3, TestUint4, TestUint5, TestUint6, TestUint7, TestUint8, TestUint9, TestUint10, ITESTD8, ITESTD16, ITESTD32, ITESTD64, TestInt1, TestInt2, TestInt3, TestInt4, TestInt5, TestInt6, TestInt7, TestInt8)
+        from_types: (f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, UTESTD8, UTESTD16, UTESTD32, UTESTD64, TestUint1, TestUint2, TestUint3, TestUint4, TestUint5, TestUint6, TestUint7, TestUint8, TestUint9, TestUint10, ITESTD8, ITESTD16, ITESTD32, ITESTD64, TestInt1, TestInt2, TestInt3, TestInt4, TestInt5, TestInt6, TestInt7, TestInt8)
     }
 
     test_into! {
         function: <ftest as CastTo>::cast_to,
-        into_types: (u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize)
+        into_types: (f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize)
     }
 
     crate::int::cast::test_cast_to_bigint!(ftest; UTESTD8, UTESTD16, UTESTD32, UTESTD64, TestUint1, TestUint2, TestUint3, TestUint4, TestUint5, TestUint6, TestUint7, TestUint8, ITESTD8, ITESTD16, ITESTD32, ITESTD64, TestInt1, TestInt2, TestInt3, TestInt4, TestInt5, TestInt6, TestInt7, TestInt8);
@@ -1,4 +1,5 @@
 use super::Float;
+use super::storage::{Storage, Width};
 use crate::BUintD8;
 use core::num::FpCategory;
 
@@ -6,12 +7,18 @@ type Digit = u8;
 
 struct Masks<const W: usize, const MB: usize>;
 
-impl<const W: usize, const MB: usize> Masks<W, MB> {
+impl<const W: usize, const MB: usize> Masks<W, MB>
+where
+    Width<W>: Storage,
+{
     //const Q_NAN_MASK: BUintD8<W> = Float::<W, MB>::NAN.to_bits();
     const FINITE_MASK: BUintD8<W> = Float::<W, MB>::INFINITY.to_bits();
 }
 
-impl<const W: usize, const MB: usize> Float<W, MB> {
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     #[inline]
     pub const fn is_sign_positive(self) -> bool {
         !self.is_sign_negative()
@@ -74,14 +81,15 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 
     #[inline]
     pub const fn is_zero(&self) -> bool {
+        let words = self.words();
         let mut i = 0;
         while i < W - 1 {
-            if self.words()[i] != 0 {
+            if words[i] != 0 {
                 return false;
             }
             i += 1;
         }
-        let last = self.words()[W - 1];
+        let last = words[W - 1];
         last.trailing_zeros() >= Digit::BITS - 1
     }
 
@@ -1,9 +1,14 @@
 use super::Float;
+use super::storage::{Storage, Width};
 use crate::{BIntD8, BUintD8};
 use core::cmp::{Ordering, PartialEq, PartialOrd};
 
-impl<const W: usize, const MB: usize> Float<W, MB> {
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     crate::nightly::const_fns! {
+        /// Returns the maximum of `self` and `other`, as specified by the IEEE 754-2019 `maximumNumber` operation: if exactly one argument is NaN, the other argument is returned, and `-0.0` is treated as less than `+0.0`.
         #[inline]
         pub const fn max(self, other: Self) -> Self {
             handle_nan!(other; self);
@@ -15,6 +20,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
             }
         }
 
+        /// Returns the minimum of `self` and `other`, as specified by the IEEE 754-2019 `minimumNumber` operation: if exactly one argument is NaN, the other argument is returned, and `-0.0` is treated as less than `+0.0`.
         #[inline]
         pub const fn min(self, other: Self) -> Self {
             handle_nan!(other; self);
@@ -27,6 +33,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         }
     }
 
+    /// Returns the maximum of `self` and `other`, as specified by the IEEE 754-2019 `maximum` operation: if either argument is NaN, NaN is returned, and `-0.0` is treated as less than `+0.0`.
     #[inline]
     pub const fn maximum(self, other: Self) -> Self {
         handle_nan!(self; self);
@@ -38,6 +45,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         }
     }
 
+    /// Returns the minimum of `self` and `other`, as specified by the IEEE 754-2019 `minimum` operation: if either argument is NaN, NaN is returned, and `-0.0` is treated as less than `+0.0`.
     #[inline]
     pub const fn minimum(self, other: Self) -> Self {
         handle_nan!(self; self);
@@ -50,6 +58,11 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     }
 
     //crate::nightly::const_fns! {
+    /// Restricts `self` to the interval `[min, max]`, in the same way as the primitive float types: `min` is returned if `self < min`, `max` is returned if `self > max`, and `self` is returned otherwise. In particular, NaN is returned if `self` is NaN.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min > max`, or if either `min` or `max` is NaN.
     #[inline]
     pub fn clamp(self, min: Self, max: Self) -> Self {
         assert!(min <= max);
@@ -64,6 +77,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     }
     //}
 
+    /// Compares `self` and `other` according to the IEEE 754 `totalOrder` predicate, in the same way as the `total_cmp` method of the primitive float types. Two floats compare equal under this ordering exactly when they have the same bit pattern. The [`Ordered`](super::Ordered) wrapper uses this ordering to implement [`Ord`].
     #[inline]
     pub const fn total_cmp(&self, other: &Self) -> Ordering {
         let left = self.to_int();
@@ -77,7 +91,10 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 }
 
 crate::nightly::impl_const! {
-    impl<const W: usize, const MB: usize> const PartialEq for Float<W, MB> {
+    impl<const W: usize, const MB: usize> const PartialEq for Float<W, MB>
+    where
+        Width<W>: Storage,
+    {
         #[inline]
         fn eq(&self, other: &Self) -> bool {
             handle_nan!(false; self, other);
@@ -87,7 +104,10 @@ crate::nightly::impl_const! {
 }
 
 crate::nightly::impl_const! {
-    impl<const W: usize, const MB: usize> const PartialOrd for Float<W, MB> {
+    impl<const W: usize, const MB: usize> const PartialOrd for Float<W, MB>
+    where
+        Width<W>: Storage,
+    {
         #[inline]
         fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
             handle_nan!(None; self, other);
@@ -104,13 +124,14 @@ mod tests {
     use crate::test::test_bignum;
     use crate::test::types::{ftest, FTEST};
 
+    // the primitive `max` and `min` methods may return either zero when comparing `0.0` and `-0.0`, so these cases are tested separately
     test_bignum! {
         function: <ftest>::max(a: ftest, b: ftest),
-        cases: [(0.0, -0.0), (-0.0, 0.0)]
+        skip: a == 0.0 && b == 0.0
     }
     test_bignum! {
         function: <ftest>::min(a: ftest, b: ftest),
-        cases: [(0.0, -0.0), (-0.0, 0.0)]
+        skip: a == 0.0 && b == 0.0
     }
     test_bignum! {
         function: <ftest>::maximum(a: ftest, b: ftest),
@@ -126,12 +147,26 @@ mod tests {
     }
 
     test_bignum! {
-        function: <ftest>::total_cmp(a: ref &ftest, b: ref &ftest)
+        function: <ftest>::total_cmp(a: ref &ftest, b: ref &ftest),
+        cases: [
+            (ref &(0.0 as ftest), ref &(-0.0 as ftest)), (ref &-ftest::NAN, ref &ftest::NEG_INFINITY), (ref &ftest::NAN, ref &ftest::INFINITY),
+            (ref &(-1.0 as ftest), ref &(-2.0 as ftest)), (ref &ftest::from_bits(1), ref &(0.0 as ftest))
+        ]
     }
     test_bignum! {
         function: <ftest>::partial_cmp(a: ref &ftest, b: ref &ftest)
     }
     test_bignum! {
-        function: <ftest>::eq(a: ref &ftest, b: ref &ftest)
+        function: <ftest as PartialEq>::eq(a: ref &ftest, b: ref &ftest)
+    }
+
+    #[test]
+    fn min_max_signed_zeros() {
+        for (a, b) in [(FTEST::ZERO, FTEST::NEG_ZERO), (FTEST::NEG_ZERO, FTEST::ZERO)] {
+            assert!(a.max(b).is_sign_positive());
+            assert!(a.min(b).is_sign_negative());
+        }
+        assert_eq!(FTEST::NAN.max(FTEST::ONE), FTEST::ONE);
+        assert_eq!(FTEST::ONE.min(FTEST::NAN), FTEST::ONE);
     }
 }
@@ -1,4 +1,7 @@
+use super::extended::Extended;
+use super::tables::{self, BitTable};
 use super::Float;
+use super::storage::{Storage, Width};
 use crate::bint::BIntD8;
 use crate::buint::BUintD8;
 
@@ -16,7 +19,49 @@ const fn buint_from_usize<const N: usize>(u: usize) -> BUintD8<N> {
     out
 }
 
-impl<const W: usize, const MB: usize> Float<W, MB> {
+/// Rounds the constant stored in `table`, multiplied by `2^pow2`, to the nearest `Float<W, MB>`. The tables store `64 * tables::CONST_WORDS` bits of each constant, so this fails to compile if `MB` is so large that these are not enough to determine the rounding.
+const fn round_table<const W: usize, const MB: usize>(table: &BitTable, pow2: i64) -> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    // a round bit and a sticky bit are needed below the `MB + 1` bits of the result
+    assert!(
+        MB + 3 <= Extended::<{ tables::CONST_WORDS }>::BITS as usize,
+        "the tables of constants are not precise enough for this number of mantissa bits"
+    );
+    Extended::<{ tables::CONST_WORDS }>::from_table(table).mul_pow2(pow2).to_float()
+}
+
+/// Mathematical constants, correctly rounded to the precision of the type. Using these constants fails to compile for types with more than 8189 mantissa bits, as the constants are only stored to 8192 bits.
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    pub const E: Self = round_table(&tables::E, 0);
+    pub const FRAC_1_PI: Self = round_table(&tables::TWO_OVER_PI, -1);
+    pub const FRAC_1_SQRT_2: Self = round_table(&tables::SQRT_2, -1);
+    pub const FRAC_2_PI: Self = round_table(&tables::TWO_OVER_PI, 0);
+    pub const FRAC_2_SQRT_PI: Self = round_table(&tables::FRAC_2_SQRT_PI, 0);
+    pub const FRAC_PI_2: Self = round_table(&tables::PI, -1);
+    pub const FRAC_PI_3: Self = round_table(&tables::FRAC_PI_3, 0);
+    pub const FRAC_PI_4: Self = round_table(&tables::PI, -2);
+    pub const FRAC_PI_6: Self = round_table(&tables::FRAC_PI_6, 0);
+    pub const FRAC_PI_8: Self = round_table(&tables::PI, -3);
+    pub const LN_10: Self = round_table(&tables::LN_10, 0);
+    pub const LN_2: Self = round_table(&tables::LN_2, 0);
+    pub const LOG10_E: Self = round_table(&tables::LOG10_E, 0);
+    pub const LOG10_2: Self = round_table(&tables::LOG10_2, 0);
+    pub const LOG2_E: Self = round_table(&tables::LOG2_E, 0);
+    pub const LOG2_10: Self = round_table(&tables::LOG2_10, 0);
+    pub const PI: Self = round_table(&tables::PI, 0);
+    pub const SQRT_2: Self = round_table(&tables::SQRT_2, 0);
+    pub const TAU: Self = round_table(&tables::PI, 1);
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     pub const RADIX: u32 = 2;
 
     pub const MANTISSA_DIGITS: u32 = MB as u32 + 1;
@@ -114,7 +159,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         Self::from_exp_mant(false, exp.sub(BUintD8::TWO), BUintD8::ZERO)
     };
 
-    pub const NEG_ONE: Self = Self::from_bits(Self::ONE.bits.bitor(Self::NEG_ZERO.bits));
+    pub const NEG_ONE: Self = Self::from_bits(Self::ONE.to_bits().bitor(Self::NEG_ZERO.to_bits()));
 }
 
 #[cfg(test)]
@@ -173,6 +218,19 @@ mod tests {
         (NEG_ONE, -1)
     ];
 
+    macro_rules! test_maths_constants {
+        {$($constant: ident), *} => {
+            $(
+                test_constant!(F64::$constant == core::f64::consts::$constant);
+                test_constant!(F32::$constant == core::f32::consts::$constant);
+            )*
+        };
+    }
+
+    test_maths_constants! {
+        E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG10_2, LOG2_E, LOG2_10, PI, SQRT_2, TAU
+    }
+
     test_constant!(F64::BITS == 64 as ExpType);
     test_constant!(F32::BITS == 32 as ExpType);
     test_constant!(F64::EXPONENT_BITS == 11 as ExpType);
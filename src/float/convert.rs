@@ -1,23 +1,184 @@
-use super::Float;
-use crate::BUintD8;
+use super::extended::Extended;
+use crate::words::buint_to_i64;
+use super::{Float, RoundingMode, Status};
+use super::storage::{self, Storage, Width};
+use crate::cast::CastFrom;
+use crate::doc;
+use crate::errors::TryFromFloatError;
+use crate::{BUintD8, ExpType};
 
-impl<const W: usize, const MB: usize> Float<W, MB> {
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     #[inline(always)]
     pub const fn to_bits(self) -> BUintD8<W> {
-        self.bits
+        storage::to_bytes::<W>(self.bits)
     }
 
     #[inline(always)]
     pub const fn from_bits(v: BUintD8<W>) -> Self {
-        Self { bits: v }
+        Self {
+            bits: storage::from_bytes::<W>(v),
+        }
     }
+
+    /// Converts `self` to a float with a different size and/or number of mantissa bits, rounding to the nearest representable value with ties rounding to even.
+    ///
+    /// NaNs keep their sign, and their payload is shifted so that its most significant bit stays just below the exponent, truncating its least significant bits if the target type has fewer mantissa bits. Signalling NaNs are converted to quiet NaNs.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn convert<const W2: usize, const MB2: usize>(self) -> Float<W2, MB2>
+    where
+        Width<W2>: Storage,
+        [(); (W + W2 + 7) / 8]:,
+    {
+        self.convert_round(RoundingMode::NearestTiesToEven).0
+    }
+
+    /// Converts `self` to a float with a different size and/or number of mantissa bits, rounding the result according to `mode`. Returns the result along with the exception flags raised by the conversion.
+    ///
+    /// NaNs are converted in the same way as by [`convert`](Self::convert). The invalid operation flag is raised if `self` is a signalling NaN.
+    #[inline]
+    pub const fn convert_round<const W2: usize, const MB2: usize>(
+        self,
+        mode: RoundingMode,
+    ) -> (Float<W2, MB2>, Status)
+    where
+        Width<W2>: Storage,
+        [(); (W + W2 + 7) / 8]:,
+    {
+        self.convert_in::<{ (W + W2 + 7) / 8 }, W2, MB2>(mode)
+    }
+
+    /// Converts `self` to `Float<W2, MB2>` via `Extended<N>`. `8 * N` must be at least `W` and at least `W2`.
+    pub(super) const fn convert_in<const N: usize, const W2: usize, const MB2: usize>(
+        self,
+        mode: RoundingMode,
+    ) -> (Float<W2, MB2>, Status)
+    where
+        Width<W2>: Storage,
+    {
+        let negative = self.is_sign_negative();
+        let out = if self.is_nan() {
+            let payload = self.to_bits().bitand(Self::MANTISSA_MASK);
+            let payload = if MB2 >= MB {
+                payload.resize::<W2>().shl((MB2 - MB) as ExpType)
+            } else {
+                payload.shr((MB - MB2) as ExpType).resize::<W2>()
+            };
+            Float::from_bits(Float::<W2, MB2>::NAN.to_bits().bitor(payload))
+        } else if self.is_infinite() {
+            Float::INFINITY
+        } else {
+            return Extended::<N>::from_float(self).to_float_round(mode);
+        };
+        let out = if negative { out.neg() } else { out };
+        let status = if self.is_signalling_nan() {
+            Status::INVALID_OP
+        } else {
+            Status::OK
+        };
+        (out, status)
+    }
+
+    /// Returns the number of bits needed to represent the magnitude of `self` and whether that magnitude is a power of two, or an error if `self` is not a finite integer.
+    fn int_bits(self) -> Result<(ExpType, bool), TryFromFloatError> {
+        if !self.is_finite() {
+            return Err(TryFromFloatError(()));
+        }
+        if self.is_zero() {
+            return Ok((0, false));
+        }
+        let (_, exp, mant) = self.to_parts_biased();
+        let exp = buint_to_i64(exp) - buint_to_i64(Self::EXP_BIAS.to_bits()) - MB as i64;
+        if exp < 0 && (mant.trailing_zeros() as i64) < -exp {
+            return Err(TryFromFloatError(()));
+        }
+        let bits = (Self::BITS - mant.leading_zeros()) as i64 + exp;
+        let bits = if bits > ExpType::MAX as i64 {
+            ExpType::MAX
+        } else {
+            bits as ExpType
+        };
+        Ok((bits, mant.is_power_of_two()))
+    }
+}
+
+macro_rules! from_primitive_float {
+    ($($float: ty: $W: literal, $MB: literal => [$(($W2: literal, $MB2: literal)), *]); *) => {
+        $(
+            $(
+                impl From<$float> for Float<$W2, $MB2> {
+                    #[inline]
+                    fn from(f: $float) -> Self {
+                        let bits = BUintD8::<$W>::from(f.to_bits());
+                        if $W2 == $W && $MB2 == $MB {
+                            // the formats are identical, so copy the bits to preserve signalling NaNs
+                            return Self::from_bits(bits.resize());
+                        }
+                        Float::<$W, $MB>::from_bits(bits)
+                            .convert_in::<{ ($W2 + 7) / 8 }, $W2, $MB2>(RoundingMode::NearestTiesToEven)
+                            .0
+                    }
+                }
+            )*
+        )*
+    };
 }
 
+// lossless conversions into the IEEE 754 binary32, binary64, binary128 and binary256 formats
+from_primitive_float!(
+    f32: 4, 23 => [(4, 23), (8, 52), (16, 112), (32, 236)];
+    f64: 8, 52 => [(8, 52), (16, 112), (32, 236)]
+);
+
+macro_rules! try_int_from_float {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const W: usize, const MB: usize, const N: usize> TryFrom<Float<W, MB>> for $BUint<N>
+        where
+            Width<W>: Storage,
+        {
+            type Error = TryFromFloatError;
+
+            #[inline]
+            fn try_from(f: Float<W, MB>) -> Result<Self, Self::Error> {
+                let (bits, _) = f.int_bits()?;
+                if bits > Self::BITS || (bits != 0 && f.is_sign_negative()) {
+                    return Err(TryFromFloatError(()));
+                }
+                Ok(Self::cast_from(f))
+            }
+        }
+
+        impl<const W: usize, const MB: usize, const N: usize> TryFrom<Float<W, MB>> for $BInt<N>
+        where
+            Width<W>: Storage,
+        {
+            type Error = TryFromFloatError;
+
+            #[inline]
+            fn try_from(f: Float<W, MB>) -> Result<Self, Self::Error> {
+                let (bits, power_of_two) = f.int_bits()?;
+                // `Self::MIN` is the only value which needs `Self::BITS` bits to represent its magnitude
+                if bits < Self::BITS || (bits == Self::BITS && power_of_two && f.is_sign_negative()) {
+                    Ok(Self::cast_from(f))
+                } else {
+                    Err(TryFromFloatError(()))
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(try_int_from_float);
+
 #[cfg(test)]
 mod tests {
-    use super::super::{F32, F64};
+    use super::super::{Float, RoundingMode, Status, F32, F64};
     use crate::test::test_bignum;
-    use crate::test::types::{ftest, FTEST};
+    use crate::test::types::{ftest, itest, utest, FTEST};
+    use crate::BUintD8;
 
     test_bignum! {
         function: <ftest>::to_bits(a: ftest)
@@ -28,4 +189,185 @@ mod tests {
     test_bignum! {
         function: <f32>::from_bits(a: u32)
     }
+
+    fn to_f32(f: F32) -> f32 {
+        f32::from_bits(f.to_bits().as_())
+    }
+
+    use crate::cast::As;
+
+    /// Rounds `f` to an `f32` according to `mode`, using the primitive conversion, which rounds to nearest, ties to even.
+    fn round_to_f32(f: f64, mode: RoundingMode) -> f32 {
+        let nearest = f as f32;
+        if nearest as f64 == f {
+            return nearest;
+        }
+        let below = (nearest as f64) < f;
+        let neighbour = if below {
+            nearest.next_up()
+        } else {
+            nearest.next_down()
+        };
+        match mode {
+            RoundingMode::NearestTiesToEven => nearest,
+            RoundingMode::NearestTiesToAway => {
+                let tie = (nearest as f64 + neighbour as f64) / 2.0 == f;
+                if tie && neighbour.abs() > nearest.abs() {
+                    neighbour
+                } else {
+                    nearest
+                }
+            }
+            RoundingMode::TowardZero => {
+                if below == f.is_sign_negative() {
+                    neighbour
+                } else {
+                    nearest
+                }
+            }
+            RoundingMode::TowardPositive => {
+                if below {
+                    neighbour
+                } else {
+                    nearest
+                }
+            }
+            RoundingMode::TowardNegative => {
+                if below {
+                    nearest
+                } else {
+                    neighbour
+                }
+            }
+        }
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_convert_f64_to_f32(f: f64, mode: RoundingMode) -> bool {
+            let (converted, status) = F64::from(f).convert_round::<4, 23>(mode);
+            let converted = to_f32(converted);
+            if f.is_nan() {
+                return converted.is_nan() && converted.is_sign_negative() == f.is_sign_negative() && status.is_ok();
+            }
+            let expected = round_to_f32(f, mode);
+            let inexact = expected as f64 != f;
+            converted.to_bits() == expected.to_bits()
+                && status.contains(Status::INEXACT) == inexact
+                && status.contains(Status::OVERFLOW) == (inexact && f.abs() > f32::MAX as f64)
+                && F64::from(f).convert::<4, 23>() == F32::from(f as f32)
+        }
+
+        fn quickcheck_convert_f32_to_f64(f: f32) -> bool {
+            let (converted, status) = F32::from(f).convert_round::<8, 52>(RoundingMode::TowardZero);
+            status.is_ok() && converted.to_bits() == F64::from(f as f64).to_bits() && F64::from(f).to_bits() == converted.to_bits()
+        }
+
+        fn quickcheck_convert_round_trip(f: ftest) -> bool {
+            let wide = FTEST::from(f).convert::<16, 100>();
+            wide.convert::<{ core::mem::size_of::<ftest>() }, { ftest::MANTISSA_DIGITS as usize - 1 }>().to_bits() == FTEST::from(f).to_bits()
+        }
+    }
+
+    #[test]
+    fn convert_subnormal() {
+        // the smallest subnormal `f64` rounds to zero as an `f32`, but the largest subnormal `f32` is a normal `f64`
+        assert_eq!(F64::from(f64::from_bits(1)).convert::<4, 23>(), F32::ZERO);
+        assert_eq!(
+            F64::from(-f64::from_bits(1)).convert_round::<4, 23>(RoundingMode::TowardNegative),
+            (F32::from(-f32::from_bits(1)), Status::INEXACT | Status::UNDERFLOW)
+        );
+        let largest_subnormal = f32::from_bits(0x007f_ffff);
+        assert_eq!(F32::from(largest_subnormal).convert::<8, 52>(), F64::from(largest_subnormal as f64));
+        // `Float<4, 25>` has six exponent bits, so its smallest positive normal number is `2^-30`
+        let x = F32::from(1.5 * 2f32.powi(-40));
+        let y = x.convert::<4, 25>();
+        assert!(y.is_subnormal());
+        assert_eq!(y.convert::<4, 23>(), x);
+        assert_eq!(
+            F32::from(2f32.powi(-60)).convert_round::<4, 25>(RoundingMode::NearestTiesToEven),
+            (Float::ZERO, Status::INEXACT | Status::UNDERFLOW)
+        );
+        assert_eq!(
+            F32::from(f32::MAX).convert_round::<4, 25>(RoundingMode::TowardZero),
+            (Float::MAX, Status::INEXACT | Status::OVERFLOW)
+        );
+    }
+
+    #[test]
+    fn convert_nan() {
+        let signalling = f32::from_bits(0xff80_0001 | 0x0020_0000);
+        let (quiet, status) = F32::from(signalling).convert_round::<8, 52>(RoundingMode::NearestTiesToEven);
+        assert_eq!(status, Status::INVALID_OP);
+        assert_eq!(quiet.to_bits(), BUintD8::from(0xfff0_0000_0000_0000u64 | (0x0060_0001u64 << 29)));
+        let (narrowed, status) = quiet.convert_round::<4, 23>(RoundingMode::NearestTiesToEven);
+        assert!(status.is_ok());
+        assert_eq!(narrowed.to_bits(), BUintD8::from(0xffc0_0001u32 | 0x0020_0000));
+        assert_eq!(F32::from(f32::NAN).convert::<8, 52>().to_bits(), F64::from(f64::NAN).to_bits());
+    }
+
+    macro_rules! test_try_from_float {
+        ($($digit: ident), *) => {
+            paste::paste! {
+                quickcheck::quickcheck! {
+                    $(
+                        fn [<quickcheck_ $digit _try_from_float>](f: ftest) -> bool {
+                            use crate::test::types::big_types::$digit::{UTEST, ITEST};
+
+                            let integral = f.is_finite() && f.trunc() == f;
+                            let unsigned = UTEST::try_from(FTEST::from(f)).ok();
+                            let expected = if integral && f >= 0.0 && f <= utest::MAX as ftest && (f as utest) as ftest == f {
+                                Some(UTEST::from(f as utest))
+                            } else {
+                                None
+                            };
+                            let signed = ITEST::try_from(FTEST::from(f)).ok();
+                            let expected_signed = if integral && f >= itest::MIN as ftest && f < -(itest::MIN as ftest) {
+                                Some(ITEST::from(f as itest))
+                            } else {
+                                None
+                            };
+                            unsigned == expected && signed == expected_signed
+                        }
+                    )*
+                }
+            }
+        };
+    }
+
+    test_try_from_float!(u8, u16, u32, u64);
+
+    #[test]
+    fn try_from_float_cases() {
+        use crate::errors::TryFromFloatError;
+        use crate::types::{I128, U128};
+
+        let f = F64::from;
+        assert_eq!(U128::try_from(f(0.0)), Ok(U128::ZERO));
+        assert_eq!(U128::try_from(f(-0.0)), Ok(U128::ZERO));
+        assert_eq!(U128::try_from(f(-1.0)), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(f(0.5)), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(f(f64::MIN_POSITIVE)), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(f(f64::NAN)), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(f(f64::INFINITY)), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(f(2f64.powi(128))), Err(TryFromFloatError(())));
+        assert_eq!(U128::try_from(f(2f64.powi(128) - 2f64.powi(75))), Ok(U128::MAX << 75u32));
+        assert_eq!(I128::try_from(f(-2f64.powi(127))), Ok(I128::MIN));
+        assert_eq!(I128::try_from(f(2f64.powi(127))), Err(TryFromFloatError(())));
+        assert_eq!(I128::try_from(f(-2f64.powi(127) - 2f64.powi(75))), Err(TryFromFloatError(())));
+        assert_eq!(I128::try_from(f(-12345.0)), Ok(I128::from(-12345i32)));
+        assert_eq!(I128::try_from(f(f64::MAX)), Err(TryFromFloatError(())));
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_to_int_unchecked(f: ftest) -> quickcheck::TestResult {
+            let t = f.trunc();
+            if !(t >= i32::MIN as ftest && t <= i32::MAX as ftest) {
+                return quickcheck::TestResult::discard();
+            }
+            let f = FTEST::from(f);
+            let i: i32 = unsafe { f.to_int_unchecked() };
+            let u: crate::BUintD32<4> = unsafe { f.abs().to_int_unchecked() };
+            quickcheck::TestResult::from_bool(i == unsafe { t.to_int_unchecked::<i32>() } && u == crate::BUintD32::from(i.unsigned_abs()))
+        }
+    }
 }
@@ -1,9 +1,13 @@
 use super::Float;
+use super::storage::{Storage, Width};
 use crate::digit::u8 as digit;
 use crate::BUintD8;
 
 #[cfg(feature = "nightly")]
-impl<const W: usize, const MB: usize> Float<W, MB> {
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     #[inline]
     pub const fn to_be_bytes(self) -> [u8; W * digit::BYTES as usize] {
         self.to_bits().to_be_bytes()
@@ -52,12 +56,12 @@ mod tests {
         function: <ftest>::to_ne_bytes(a: ftest)
     }
     test_bignum! {
-        function: <ftest>::from_be_bytes(a: U8ArrayWrapper<{FTEST::BITS as usize / 8}>)
+        function: <ftest>::from_be_bytes(a: U8ArrayWrapper<{ core::mem::size_of::<ftest>() }>)
     }
     test_bignum! {
-        function: <ftest>::from_le_bytes(a: U8ArrayWrapper<{FTEST::BITS as usize / 8}>)
+        function: <ftest>::from_le_bytes(a: U8ArrayWrapper<{ core::mem::size_of::<ftest>() }>)
     }
     test_bignum! {
-        function: <ftest>::from_ne_bytes(a: U8ArrayWrapper<{FTEST::BITS as usize / 8}>)
+        function: <ftest>::from_ne_bytes(a: U8ArrayWrapper<{ core::mem::size_of::<ftest>() }>)
     }
 }
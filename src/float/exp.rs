@@ -0,0 +1,291 @@
+use super::extended::Extended;
+use crate::words::buint_to_i64;
+use super::Float;
+use super::storage::{Storage, Width};
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Returns `true` if `|self| >= 2^EXPONENT_BITS`. Since `e^(2^EXPONENT_BITS)` is larger than `Self::MAX`, the exponential of any such number either overflows to infinity or underflows to zero.
+    #[inline]
+    pub(super) fn exp_out_of_range(self) -> bool {
+        let (_, exp, _) = self.to_raw_parts();
+        let threshold = Self::EXP_BIAS.to_bits().add(Self::EXPONENT_BITS.into());
+        exp >= threshold
+    }
+
+    /// Returns `true` if `self` is a finite odd integer.
+    fn is_odd_integer(self) -> bool {
+        if !self.is_finite() || self.is_zero() {
+            return false;
+        }
+        let (_, exp, mant) = self.to_parts_biased();
+        // the place value of the least significant bit of the mantissa is `2^(exp - MB)`, so `self` is an odd integer exactly when bit `MB - exp` is its lowest set bit
+        let exp = buint_to_i64(exp) - buint_to_i64(Self::EXP_BIAS.to_bits());
+        if exp < 0 || exp > MB as i64 {
+            return false;
+        }
+        mant.trailing_zeros() as i64 == MB as i64 - exp
+    }
+
+    /// Computes `e^self`, the exponential function.
+    pub fn exp(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_infinite() || self.exp_out_of_range() {
+            return if self.is_sign_negative() {
+                Self::ZERO
+            } else {
+                Self::INFINITY
+            };
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self).exp().to_float()
+    }
+
+    /// Computes `2^self`.
+    pub fn exp2(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_infinite() || self.exp_out_of_range() {
+            return if self.is_sign_negative() {
+                Self::ZERO
+            } else {
+                Self::INFINITY
+            };
+        }
+        // 2^x = 2^n e^(f ln(2)), where n is the integer nearest to x and f = x - n, so the result is exact when x is an integer
+        let x = Extended::<{ (W + 3) / 4 }>::from_float(self);
+        let n = x.round_to_i64();
+        let f = x.sub(Extended::from_i64(n));
+        f.mul(Extended::LN_2).exp().mul_pow2(n).to_float()
+    }
+
+    /// Computes `e^self - 1`. The result is accurate even when `self` is close to zero.
+    pub fn exp_m1(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return self;
+        }
+        if self.is_infinite() || self.exp_out_of_range() {
+            return if self.is_sign_negative() {
+                Self::NEG_ONE
+            } else {
+                Self::INFINITY
+            };
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self).exp_m1().to_float()
+    }
+
+    /// Computes the natural logarithm of `self`. Returns NaN if `self` is negative, and negative infinity if `self` is zero.
+    pub fn ln(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return Self::NEG_INFINITY;
+        }
+        if self.is_sign_negative() {
+            return Self::NAN;
+        }
+        if self.is_infinite() {
+            return self;
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self).ln().to_float()
+    }
+
+    /// Computes `ln(1 + self)`. The result is accurate even when `self` is close to zero.
+    pub fn ln_1p(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return self;
+        }
+        if self == Self::NEG_ONE {
+            return Self::NEG_INFINITY;
+        }
+        if self < Self::NEG_ONE {
+            return Self::NAN;
+        }
+        if self.is_infinite() {
+            return self;
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self).ln_1p().to_float()
+    }
+
+    /// Computes the base 2 logarithm of `self`.
+    pub fn log2(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if self.is_nan() || self.is_sign_negative() || self.is_zero() || self.is_infinite() {
+            return self.ln();
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .ln()
+            .mul(Extended::LOG2_E)
+            .to_float()
+    }
+
+    /// Computes the base 10 logarithm of `self`.
+    pub fn log10(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if self.is_nan() || self.is_sign_negative() || self.is_zero() || self.is_infinite() {
+            return self.ln();
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .ln()
+            .mul(Extended::LOG10_E)
+            .to_float()
+    }
+
+    /// Computes the logarithm of `self` with respect to an arbitrary base. Special values are handled in the same way as `self.ln() / base.ln()`.
+    pub fn log(self, base: Self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        let positive = |f: Self| f.is_finite() && !f.is_zero() && f.is_sign_positive();
+        if positive(self) && positive(base) && base != Self::ONE {
+            let ln = Extended::<{ (W + 3) / 4 }>::from_float(self).ln();
+            let ln_base = Extended::from_float(base).ln();
+            return ln.div(ln_base).to_float();
+        }
+        self.ln() / base.ln()
+    }
+
+    /// Raises `self` to the power of `n`.
+    ///
+    /// Special values are handled as specified by the IEEE 754 `pow` operation, in the same way as the primitive float types. In particular, `x.powf(±0)` and `1.powf(y)` are `1` for any `x` and `y`, even NaN, and a negative number raised to a non-integer power is NaN.
+    pub fn powf(self, n: Self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if n.is_zero() || self == Self::ONE {
+            return Self::ONE;
+        }
+        handle_nan!(Self::NAN; self, n);
+        // the sign of the result, if it is not NaN
+        let negative = self.is_sign_negative() && n.is_odd_integer();
+        let signed = |f: Self| if negative { f.neg() } else { f };
+        if self.is_zero() {
+            return signed(if n.is_sign_negative() {
+                Self::INFINITY
+            } else {
+                Self::ZERO
+            });
+        }
+        if n.is_infinite() {
+            let abs = self.abs();
+            if abs == Self::ONE {
+                return Self::ONE;
+            }
+            return if (abs > Self::ONE) == n.is_sign_positive() {
+                Self::INFINITY
+            } else {
+                Self::ZERO
+            };
+        }
+        if self.is_infinite() {
+            return signed(if n.is_sign_negative() {
+                Self::ZERO
+            } else {
+                Self::INFINITY
+            });
+        }
+        if self.is_sign_negative() && n.trunc() != n {
+            return Self::NAN;
+        }
+        // |x|^n = e^(n ln|x|)
+        let t = Extended::<{ (W + 3) / 4 }>::from_float(n)
+            .mul(Extended::from_float(self.abs()).ln());
+        let abs = if !t.is_zero() && t.ilog2() >= Self::EXPONENT_BITS as i64 {
+            if t.is_sign_negative() {
+                Self::ZERO
+            } else {
+                Self::INFINITY
+            }
+        } else {
+            t.exp().to_float()
+        };
+        signed(abs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::test_float_ulps;
+    use crate::test::types::{ftest, FTEST};
+
+    // the primitive float functions are not guaranteed to be correctly rounded, so allow an error of one ulp
+    test_float_ulps! {
+        function: <ftest>::exp(f: ftest),
+        ulps: 1,
+        cases: [(0.0), (-0.0), (1.0), (-1e-30), (88.7), (-103.9), (1e30), (-1e30), (ftest::INFINITY), (ftest::NEG_INFINITY)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::exp2(f: ftest),
+        ulps: 1,
+        cases: [(0.0), (3.0), (-149.0), (-149.5), (127.99), (128.0), (-0.5), (1e-30)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::exp_m1(f: ftest),
+        ulps: 1,
+        cases: [(0.0), (-0.0), (1e-10), (-1e-10), (0.5), (-200.0), (ftest::NEG_INFINITY), (ftest::INFINITY)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::ln(f: ftest),
+        ulps: 1,
+        cases: [(0.0), (-0.0), (1.0), (-1.0), (1.0000001), (1e-40), (ftest::MAX), (ftest::INFINITY)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::ln_1p(f: ftest),
+        ulps: 1,
+        cases: [(0.0), (-0.0), (-1.0), (-2.0), (1e-20), (-0.5), (1e30), (ftest::INFINITY), (ftest::NEG_INFINITY)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::log2(f: ftest),
+        ulps: 1,
+        cases: [(0.0), (-1.0), (1.0), (8.0), (1e-45), (3.0), (ftest::INFINITY)]
+    }
+
+    // the primitive `f32::log10` can be out by two ulps
+    test_float_ulps! {
+        function: <ftest>::log10(f: ftest),
+        ulps: 2,
+        cases: [(0.0), (-1.0), (1.0), (1000.0), (1e-45), (3.0), (ftest::INFINITY)]
+    }
+
+    // the primitive `log` is computed as `self.ln() / base.ln()`, which is rounded three times, so its error can be larger
+    test_float_ulps! {
+        function: <ftest>::log(f1: ftest, f2: ftest),
+        ulps: 2,
+        cases: [(8.0, 2.0), (1.0, 10.0), (0.0, 2.0), (2.0, 1.0), (2.0, 0.0), (-1.0, 2.0), (ftest::INFINITY, 0.5), (3.0, 1e-40)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::powf(f1: ftest, f2: ftest),
+        ulps: 1,
+        cases: [
+            (ftest::NAN, 0.0), (1.0, ftest::NAN), (-0.0, -3.0), (-0.0, -2.0), (0.0, 3.0), (-0.0, 3.0), (-1.0, ftest::INFINITY),
+            (0.5, ftest::NEG_INFINITY), (2.0, ftest::NEG_INFINITY), (ftest::NEG_INFINITY, -3.0), (ftest::NEG_INFINITY, 3.0),
+            (ftest::NEG_INFINITY, 2.5), (-2.0, 0.5), (-2.0, 3.0), (-2.0, 1e30), (2.0, 10.0), (10.0, -40.0), (1e30, 1e30), (1e-30, 1e30), (1.0000001, 1e20)
+        ]
+    }
+}
@@ -0,0 +1,1060 @@
+use crate::rounding::{RoundingMode, Status};
+use super::tables::{self, BitTable};
+use super::storage::{self, Storage, Width};
+use super::Float;
+use crate::words::{buint_to_i64, bytes_to_words};
+use crate::{BUint, BUintD8, ExpType};
+use core::cmp::Ordering;
+
+/// An unpacked binary floating point number with an `64 * N` bit mantissa, stored as 64 bit words and an `i64` exponent, used as an intermediate representation when a `Float` result needs to be computed to more precision than the `Float` type has before being rounded.
+///
+/// The value represented is `(-1)^negative * mant * 2^exp`. Non-zero values are normalised so that the most significant bit of `mant` is set. Any non-zero bits which an operation discards are "jammed" into the least significant bit of the mantissa, so that this bit also acts as a sticky bit: if the result of a sequence of operations is exact apart from the discarded bits, then rounding it to a precision of at most `64 * N - 2` bits gives the correctly rounded result.
+///
+/// Exponents are stored as `i64`s, so `Float` types with more than 61 exponent bits are not supported.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Extended<const N: usize> {
+    negative: bool,
+    exp: i64,
+    mant: BUint<N>,
+}
+
+/// Shifts `u` right by `rhs` bits, setting the least significant bit of the result if any of the discarded bits were set.
+#[inline]
+const fn shr_sticky<const N: usize>(u: BUint<N>, rhs: ExpType) -> BUint<N> {
+    if rhs == 0 {
+        return u;
+    }
+    let sticky = !u.is_zero() && u.trailing_zeros() < rhs;
+    let shifted = if rhs >= BUint::<N>::BITS {
+        BUint::ZERO
+    } else {
+        u.shr(rhs)
+    };
+    if sticky {
+        shifted.bitor(BUint::ONE)
+    } else {
+        shifted
+    }
+}
+
+/// Shifts the double width integer `hi * 2^BITS + lo` right by `rhs` bits, setting the least significant bit of the result if any of the discarded bits were set.
+#[inline]
+const fn shr_wide<const N: usize>(
+    hi: BUint<N>,
+    lo: BUint<N>,
+    rhs: ExpType,
+) -> (BUint<N>, BUint<N>) {
+    let bits = BUint::<N>::BITS;
+    if rhs == 0 {
+        (hi, lo)
+    } else if rhs < bits {
+        let mut new_lo = lo.shr(rhs).bitor(hi.shl(bits - rhs));
+        if lo.trailing_zeros() < rhs {
+            new_lo = new_lo.bitor(BUint::ONE);
+        }
+        (hi.shr(rhs), new_lo)
+    } else {
+        let new_lo = shr_sticky(hi, rhs - bits);
+        if lo.is_zero() {
+            (BUint::ZERO, new_lo)
+        } else {
+            (BUint::ZERO, new_lo.bitor(BUint::ONE))
+        }
+    }
+}
+
+/// Shifts the double width integer `hi * 2^BITS + lo` left by `rhs` bits.
+#[inline]
+const fn shl_wide<const N: usize>(
+    hi: BUint<N>,
+    lo: BUint<N>,
+    rhs: ExpType,
+) -> (BUint<N>, BUint<N>) {
+    let bits = BUint::<N>::BITS;
+    if rhs == 0 {
+        (hi, lo)
+    } else if rhs < bits {
+        (hi.shl(rhs).bitor(lo.shr(bits - rhs)), lo.shl(rhs))
+    } else if rhs < bits * 2 {
+        (lo.shl(rhs - bits), BUint::ZERO)
+    } else {
+        (BUint::ZERO, BUint::ZERO)
+    }
+}
+
+#[inline]
+const fn leading_zeros_wide<const N: usize>(hi: BUint<N>, lo: BUint<N>) -> ExpType {
+    if hi.is_zero() {
+        BUint::<N>::BITS + lo.leading_zeros()
+    } else {
+        hi.leading_zeros()
+    }
+}
+
+/// Returns `hi`, with the least significant bit set if `lo` is non-zero.
+#[inline]
+const fn jam<const N: usize>(hi: BUint<N>, lo: BUint<N>) -> BUint<N> {
+    if lo.is_zero() {
+        hi
+    } else {
+        hi.bitor(BUint::ONE)
+    }
+}
+
+/// Returns the value of the least significant 63 bits of `u`.
+#[inline]
+const fn word_to_i64<const N: usize>(u: BUint<N>) -> i64 {
+    (u.digits[0] & (i64::MAX as u64)) as i64
+}
+
+/// Returns bit `index` of the triple width integer `words[0] + words[1] * 2^BITS + words[2] * 2^(2 * BITS)`.
+#[inline]
+const fn triple_bit<const N: usize>(words: &[BUint<N>; 3], index: i64) -> bool {
+    let bits = BUint::<N>::BITS as i64;
+    if index < 0 || index >= bits * 3 {
+        return false;
+    }
+    words[(index / bits) as usize].bit((index % bits) as ExpType)
+}
+
+#[inline]
+const fn triple_word<const N: usize>(words: &[BUint<N>; 3], index: i64) -> BUint<N> {
+    if index < 0 || index >= 3 {
+        BUint::ZERO
+    } else {
+        words[index as usize]
+    }
+}
+
+/// Clears the bits of the triple width integer `words` from position `from` onwards.
+const fn triple_truncate<const N: usize>(words: &mut [BUint<N>; 3], from: i64) {
+    let bits = BUint::<N>::BITS as i64;
+    let mut i = 0;
+    while i < 3 {
+        let word_start = i as i64 * bits;
+        if word_start >= from {
+            words[i] = BUint::ZERO;
+        } else if word_start + bits > from {
+            words[i] = words[i].bitand(BUint::MAX.shr((word_start + bits - from) as ExpType));
+        }
+        i += 1;
+    }
+}
+
+/// Returns `BITS` bits of the table, starting from bit `start` (where the first bit is bit `0`), with the first bit as the most significant bit.
+const fn table_window<const N: usize>(table: &BitTable, start: usize) -> BUint<N> {
+    let mut out = BUint::ZERO;
+    let mut i = 0;
+    while i < N {
+        out.digits[N - 1 - i] = table.word(start + i * 64);
+        i += 1;
+    }
+    out
+}
+
+/// Returns bits `lo` to `lo + BITS - 1` of the triple width integer `words[0] + words[1] * 2^BITS + words[2] * 2^(2 * BITS)`, with the least significant bit set if any of the bits below `lo` are set.
+const fn triple_window<const N: usize>(words: &[BUint<N>; 3], lo: i64) -> BUint<N> {
+    let bits = BUint::<N>::BITS as i64;
+    let (index, offset) = (lo.div_euclid(bits), lo.rem_euclid(bits) as ExpType);
+    let mut out = triple_word(words, index).shr(offset);
+    if offset != 0 {
+        out = out.bitor(triple_word(words, index + 1).shl(bits as ExpType - offset));
+    }
+    let mut sticky = offset != 0 && triple_word(words, index).trailing_zeros() < offset;
+    let mut i = 0;
+    while i < index && i < 3 {
+        sticky |= !words[i as usize].is_zero();
+        i += 1;
+    }
+    if sticky {
+        out.bitor(BUint::ONE)
+    } else {
+        out
+    }
+}
+
+impl<const N: usize> Extended<N> {
+    pub const BITS: ExpType = BUint::<N>::BITS;
+
+    pub const ZERO: Self = Self {
+        negative: false,
+        exp: 0,
+        mant: BUint::ZERO,
+    };
+    pub const ONE: Self = Self::from_u64(1);
+    pub const TWO: Self = Self::from_u64(2);
+
+    /// Returns `(-1)^negative * mant * 2^exp`.
+    #[inline]
+    pub const fn normalised(negative: bool, exp: i64, mant: BUint<N>) -> Self {
+        if mant.is_zero() {
+            return Self {
+                negative,
+                exp: 0,
+                mant,
+            };
+        }
+        let lz = mant.leading_zeros();
+        Self {
+            negative,
+            exp: exp - lz as i64,
+            mant: mant.shl(lz),
+        }
+    }
+
+    #[inline]
+    pub const fn from_u64(n: u64) -> Self {
+        Self::normalised(false, 0, BUint::from_digit(n))
+    }
+
+    #[inline]
+    pub const fn from_i64(n: i64) -> Self {
+        Self::from_u64(n.unsigned_abs()).with_sign(n < 0)
+    }
+
+    /// Returns the value of the constant stored in `table`, truncated to `64 * N` bits. Since all the tables store irrational numbers, the sticky bit is always set. Panics if the table stores fewer than `64 * N` bits, which is a compile time error when used in a constant.
+    pub const fn from_table(table: &BitTable) -> Self {
+        assert!(
+            Self::BITS as usize <= table.bit_len(),
+            "the precision of this type is higher than that of the tables of constants"
+        );
+        let mut mant = table_window::<N>(table, 0);
+        mant.digits[0] |= 1;
+        Self {
+            negative: false,
+            exp: table.exp - Self::BITS as i64,
+            mant,
+        }
+    }
+
+    /// Converts a finite float to its exact extended representation. `8 * N` must be at least `W`.
+    pub const fn from_float<const W: usize, const MB: usize>(f: Float<W, MB>) -> Self
+    where
+        Width<W>: Storage,
+    {
+        debug_assert!(f.is_finite());
+        // the fields are extracted from the stored bits directly, as `8 * N` is at least `W`
+        let bits = storage::to_words::<W, N>(f.bits);
+        let negative = bits.bit(W as ExpType * 8 - 1);
+        let exp_mask = BUint::<N>::MAX.shr(BUint::<N>::BITS - Float::<W, MB>::EXPONENT_BITS);
+        let exp = word_to_i64(bits.shr(MB as ExpType).bitand(exp_mask));
+        let mut mant = bits.bitand(BUint::MAX.shr(BUint::<N>::BITS - MB as ExpType));
+        let bias = buint_to_i64(Float::<W, MB>::EXP_BIAS.to_bits());
+        let exp = if exp == 0 {
+            1
+        } else {
+            mant = mant.bitor(BUint::power_of_two(MB as ExpType));
+            exp
+        };
+        Self::normalised(negative, exp - bias - MB as i64, mant)
+    }
+
+    /// Returns `(-1)^negative * mant * 2^exp`. `8 * N` must be at least `W`.
+    #[inline]
+    pub const fn from_parts<const W: usize>(negative: bool, exp: i64, mant: BUintD8<W>) -> Self {
+        Self::normalised(negative, exp, bytes_to_words(mant))
+    }
+
+    /// Rounds `self` to the nearest value representable by `Float<W, MB>`, with ties rounding to even. `8 * N` must be at least `W`.
+    #[inline]
+    pub const fn to_float<const W: usize, const MB: usize>(self) -> Float<W, MB>
+    where
+        Width<W>: Storage,
+    {
+        self.to_float_round(RoundingMode::NearestTiesToEven).0
+    }
+
+    /// Rounds `self` to a value representable by `Float<W, MB>` according to `mode`, returning the rounded value along with the inexact, overflow and underflow flags raised by the rounding. Tininess is detected before rounding. `8 * N` must be at least `W`.
+    pub const fn to_float_round<const W: usize, const MB: usize>(
+        self,
+        mode: RoundingMode,
+    ) -> (Float<W, MB>, Status)
+    where
+        Width<W>: Storage,
+    {
+        if self.mant.is_zero() {
+            let zero = if self.negative {
+                Float::NEG_ZERO
+            } else {
+                Float::ZERO
+            };
+            return (zero, Status::OK);
+        }
+        let bias = buint_to_i64(Float::<W, MB>::EXP_BIAS.to_bits());
+        // the biased exponent the result would have if it was a normal number
+        let biased_exp = self.exp + (Self::BITS as i64 - 1) + bias;
+        if biased_exp > bias * 2 {
+            return (
+                self.overflow(mode),
+                Status::OVERFLOW.union(Status::INEXACT),
+            );
+        }
+        // number of bits to discard so that `MB + 1` bits are left, or fewer if the result is subnormal
+        let mut shift = Self::BITS as i64 - 1 - MB as i64;
+        if biased_exp < 1 {
+            shift += 1 - biased_exp;
+        }
+        let (mut mant, round_bit, sticky) = if shift > Self::BITS as i64 {
+            (BUint::ZERO, false, true)
+        } else {
+            let shift = shift as ExpType;
+            let mant = if shift == Self::BITS {
+                BUint::ZERO
+            } else {
+                self.mant.shr(shift)
+            };
+            (
+                mant,
+                self.mant.bit(shift - 1),
+                self.mant.trailing_zeros() < shift - 1,
+            )
+        };
+        let inexact = round_bit || sticky;
+        let round_up = match mode {
+            RoundingMode::NearestTiesToEven => round_bit && (sticky || mant.bit(0)),
+            RoundingMode::NearestTiesToAway => round_bit,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => inexact && !self.negative,
+            RoundingMode::TowardNegative => inexact && self.negative,
+        };
+        if round_up {
+            mant = mant.add(BUint::ONE);
+        }
+        let mut status = if inexact { Status::INEXACT } else { Status::OK };
+        if inexact && biased_exp < 1 {
+            status = status.union(Status::UNDERFLOW);
+        }
+        // `mant` includes the implicit leading bit if the result is normal, so the exponent is offset by one. If rounding carried into bit `MB + 1`, this correctly increments the exponent
+        let mut bits = mant;
+        if biased_exp > 1 {
+            bits = bits.add(BUint::from_digit(biased_exp as u64 - 1).shl(MB as ExpType));
+        }
+        let infinity = BUint::<N>::MAX
+            .shr(BUint::<N>::BITS - Float::<W, MB>::EXPONENT_BITS)
+            .shl(MB as ExpType);
+        if let Ordering::Less = bits.cmp(&infinity) {
+            if self.negative {
+                bits = bits.bitor(BUint::power_of_two(W as ExpType * 8 - 1));
+            }
+            (Float { bits: storage::from_words::<W, N>(bits) }, status)
+        } else {
+            (
+                self.overflow(mode),
+                Status::OVERFLOW.union(Status::INEXACT),
+            )
+        }
+    }
+
+    /// Returns the result of rounding a number with the same sign as `self` which is too large to be represented by `Float<W, MB>`: either infinity or the largest finite value, depending on `mode`.
+    #[inline]
+    const fn overflow<const W: usize, const MB: usize>(&self, mode: RoundingMode) -> Float<W, MB>
+    where
+        Width<W>: Storage,
+    {
+        let to_infinity = match mode {
+            RoundingMode::NearestTiesToEven | RoundingMode::NearestTiesToAway => true,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !self.negative,
+            RoundingMode::TowardNegative => self.negative,
+        };
+        let f = if to_infinity {
+            Float::INFINITY
+        } else {
+            Float::MAX
+        };
+        if self.negative {
+            f.neg()
+        } else {
+            f
+        }
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.mant.is_zero()
+    }
+
+    #[inline]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the sign, exponent and mantissa of `self`, such that `self` is equal to `(-1)^negative * mant * 2^exp`.
+    #[inline]
+    pub const fn to_parts(self) -> (bool, i64, BUint<N>) {
+        (self.negative, self.exp, self.mant)
+    }
+
+    /// Returns `floor(log2(|self|))`. `self` must be non-zero.
+    #[inline]
+    pub const fn ilog2(&self) -> i64 {
+        self.exp + Self::BITS as i64 - 1
+    }
+
+    #[inline]
+    pub const fn neg(mut self) -> Self {
+        self.negative = !self.negative;
+        self
+    }
+
+    #[inline]
+    pub const fn abs(mut self) -> Self {
+        self.negative = false;
+        self
+    }
+
+    #[inline]
+    pub const fn with_sign(mut self, negative: bool) -> Self {
+        self.negative = negative;
+        self
+    }
+
+    /// Multiplies `self` by `2^n`.
+    #[inline]
+    pub const fn mul_pow2(mut self, n: i64) -> Self {
+        if !self.mant.is_zero() {
+            self.exp += n;
+        }
+        self
+    }
+
+    pub const fn cmp_abs(&self, other: &Self) -> Ordering {
+        match (self.mant.is_zero(), other.mant.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => {
+                if self.exp > other.exp {
+                    Ordering::Greater
+                } else if self.exp < other.exp {
+                    Ordering::Less
+                } else {
+                    self.mant.cmp(&other.mant)
+                }
+            }
+        }
+    }
+
+    pub const fn add(self, rhs: Self) -> Self {
+        if rhs.mant.is_zero() {
+            if self.mant.is_zero() {
+                return Self::ZERO.with_sign(self.negative && rhs.negative);
+            }
+            return self;
+        }
+        if self.mant.is_zero() {
+            return rhs;
+        }
+        let (a, b) = if let Ordering::Less = self.cmp_abs(&rhs) {
+            (rhs, self)
+        } else {
+            (self, rhs)
+        };
+        let diff = a.exp - b.exp;
+        let shift = if diff > Self::BITS as i64 * 2 {
+            Self::BITS * 2
+        } else {
+            diff as ExpType
+        };
+        // shift both mantissas right by one extra bit to leave room for a carry
+        let (a_hi, a_lo) = shr_wide(a.mant, BUint::ZERO, 1);
+        let (b_hi, b_lo) = shr_wide(b.mant, BUint::ZERO, shift + 1);
+        let (hi, lo) = if a.negative == b.negative {
+            let (lo, carry) = a_lo.overflowing_add(b_lo);
+            let hi = a_hi.add(b_hi);
+            (if carry { hi.add(BUint::ONE) } else { hi }, lo)
+        } else {
+            let (lo, borrow) = a_lo.overflowing_sub(b_lo);
+            let hi = a_hi.sub(b_hi);
+            (if borrow { hi.sub(BUint::ONE) } else { hi }, lo)
+        };
+        if hi.is_zero() && lo.is_zero() {
+            return Self::ZERO;
+        }
+        let lz = leading_zeros_wide(hi, lo);
+        let (hi, lo) = shl_wide(hi, lo, lz);
+        Self {
+            negative: a.negative,
+            exp: a.exp + 1 - lz as i64,
+            mant: jam(hi, lo),
+        }
+    }
+
+    #[inline]
+    pub const fn sub(self, rhs: Self) -> Self {
+        self.add(rhs.neg())
+    }
+
+    pub const fn mul(self, rhs: Self) -> Self {
+        let negative = self.negative ^ rhs.negative;
+        if self.mant.is_zero() || rhs.mant.is_zero() {
+            return Self::ZERO.with_sign(negative);
+        }
+        let (lo, hi) = self.mant.widening_mul(rhs.mant);
+        let exp = self.exp + rhs.exp + Self::BITS as i64;
+        // the product of two normalised mantissas has either `2 * BITS` or `2 * BITS - 1` bits
+        if hi.bit(Self::BITS - 1) {
+            Self {
+                negative,
+                exp,
+                mant: jam(hi, lo),
+            }
+        } else {
+            let (hi, lo) = shl_wide(hi, lo, 1);
+            Self {
+                negative,
+                exp: exp - 1,
+                mant: jam(hi, lo),
+            }
+        }
+    }
+
+    /// `rhs` must be non-zero.
+    pub const fn div(self, rhs: Self) -> Self {
+        debug_assert!(!rhs.mant.is_zero());
+        let negative = self.negative ^ rhs.negative;
+        if self.mant.is_zero() {
+            return Self::ZERO.with_sign(negative);
+        }
+        let (a, b) = (self.mant, rhs.mant);
+        // long division, one bit at a time. Since both mantissas are normalised, the quotient `a / b` lies in `(1/2, 2)`, so it has `BITS` significant bits after `BITS - 1` or `BITS` iterations
+        let (mut quotient, mut rem, mut steps, exp) = if let Ordering::Less = a.cmp(&b) {
+            (BUint::ZERO, a, Self::BITS, self.exp - rhs.exp - Self::BITS as i64)
+        } else {
+            (
+                BUint::ONE,
+                a.sub(b),
+                Self::BITS - 1,
+                self.exp - rhs.exp - (Self::BITS as i64 - 1),
+            )
+        };
+        while steps > 0 {
+            let carry = rem.bit(Self::BITS - 1);
+            rem = rem.shl(1);
+            quotient = quotient.shl(1);
+            if carry || !matches!(rem.cmp(&b), Ordering::Less) {
+                rem = rem.wrapping_sub(b);
+                quotient = quotient.bitor(BUint::ONE);
+            }
+            steps -= 1;
+        }
+        Self {
+            negative,
+            exp,
+            mant: jam(quotient, rem),
+        }
+    }
+
+    /// `self` must not be negative.
+    pub const fn sqrt(self) -> Self {
+        if self.mant.is_zero() {
+            return self;
+        }
+        debug_assert!(!self.negative);
+        // the radicand is `mant * 2^k` as a double width integer, where `k` is `BITS` or `BITS - 1`, chosen so that `exp - k` is even
+        let k = if (self.exp - Self::BITS as i64) % 2 == 0 {
+            Self::BITS
+        } else {
+            Self::BITS - 1
+        };
+        let (hi, lo) = shr_wide(self.mant, BUint::ZERO, Self::BITS - k);
+        // digit by digit calculation of the integer square root. The remainder needs two more bits than the root, which are stored in `rem_hi`
+        let mut root = BUint::<N>::ZERO;
+        let mut rem_hi = 0u8;
+        let mut rem = BUint::<N>::ZERO;
+        let mut i = Self::BITS;
+        while i > 0 {
+            i -= 1;
+            let two_bits = if i >= Self::BITS / 2 {
+                hi.shr((i - Self::BITS / 2) * 2).digits[0] & 0b11
+            } else {
+                lo.shr(i * 2).digits[0] & 0b11
+            };
+            rem_hi = (rem_hi << 2) | (rem.digits[N - 1] >> 62) as u8;
+            rem = rem.shl(2).bitor(BUint::from_digit(two_bits));
+            let trial_hi = (root.digits[N - 1] >> 62) as u8;
+            let trial = root.shl(2).bitor(BUint::ONE);
+            let ge = rem_hi > trial_hi
+                || (rem_hi == trial_hi && !matches!(rem.cmp(&trial), Ordering::Less));
+            root = root.shl(1);
+            if ge {
+                let (diff, borrow) = rem.overflowing_sub(trial);
+                rem_hi = rem_hi - trial_hi - borrow as u8;
+                rem = diff;
+                root = root.bitor(BUint::ONE);
+            }
+        }
+        let sticky = rem_hi != 0 || !rem.is_zero();
+        Self {
+            negative: false,
+            exp: (self.exp - k as i64) / 2,
+            mant: if sticky {
+                root.bitor(BUint::ONE)
+            } else {
+                root
+            },
+        }
+    }
+
+    /// Rounds `self` to the nearest integer, with ties rounding away from zero. `|self|` must be less than `2^62`.
+    pub const fn round_to_i64(self) -> i64 {
+        if self.mant.is_zero() || self.exp < -(Self::BITS as i64) {
+            return 0;
+        }
+        debug_assert!(self.ilog2() < 62);
+        let int = if self.exp >= 0 {
+            word_to_i64(self.mant) << self.exp
+        } else {
+            let shift = (-self.exp) as ExpType;
+            let truncated = if shift == Self::BITS {
+                0
+            } else {
+                word_to_i64(self.mant.shr(shift))
+            };
+            truncated + self.mant.bit(shift - 1) as i64
+        };
+        if self.negative {
+            -int
+        } else {
+            int
+        }
+    }
+
+    /// Returns `true` if `self` is small enough to be neglected when added to `reference`.
+    #[inline]
+    const fn is_negligible(&self, reference: &Self) -> bool {
+        self.mant.is_zero() || self.exp < reference.exp - Self::BITS as i64 - 2
+    }
+}
+
+/// An exact unsigned double width binary number `(hi * 2^BITS + lo) * 2^exp`, used to decide hard rounding cases exactly. Values are kept with their trailing zeros removed, so that numbers of similar magnitude can be aligned without losing bits.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Wide<const N: usize> {
+    hi: BUint<N>,
+    lo: BUint<N>,
+    exp: i64,
+}
+
+impl<const N: usize> Wide<N> {
+    const fn new(hi: BUint<N>, lo: BUint<N>, exp: i64) -> Self {
+        if hi.is_zero() && lo.is_zero() {
+            return Self { hi, lo, exp: 0 };
+        }
+        let tz = if lo.is_zero() {
+            Extended::<N>::BITS + hi.trailing_zeros()
+        } else {
+            lo.trailing_zeros()
+        };
+        let (hi, lo) = shr_wide(hi, lo, tz);
+        Self {
+            hi,
+            lo,
+            exp: exp + tz as i64,
+        }
+    }
+
+    /// Returns `|x|`. Since `x` must be exact, its sticky bit must be clear.
+    #[inline]
+    pub const fn from_extended(x: Extended<N>) -> Self {
+        Self::new(BUint::ZERO, x.mant, x.exp)
+    }
+
+    /// Returns `|a * b|`, computed exactly.
+    #[inline]
+    pub const fn mul(a: Extended<N>, b: Extended<N>) -> Self {
+        let (lo, hi) = a.mant.widening_mul(b.mant);
+        Self::new(hi, lo, a.exp + b.exp)
+    }
+
+    #[inline]
+    const fn is_zero(&self) -> bool {
+        self.hi.is_zero() && self.lo.is_zero()
+    }
+
+    /// The exponent of the most significant bit. `self` must be non-zero.
+    #[inline]
+    const fn top(&self) -> i64 {
+        self.exp + (Extended::<N>::BITS * 2 - leading_zeros_wide(self.hi, self.lo)) as i64
+    }
+
+    /// Returns `(self, other)` shifted to have the same exponent. The aligned values must fit in `2 * BITS` bits.
+    const fn align(self, other: Self) -> (Self, Self, i64) {
+        if self.exp >= other.exp {
+            let (hi, lo) = shl_wide(self.hi, self.lo, (self.exp - other.exp) as ExpType);
+            (Self { hi, lo, exp: other.exp }, other, other.exp)
+        } else {
+            let (o, s, exp) = other.align(self);
+            (s, o, exp)
+        }
+    }
+
+    /// Returns `self + other`, which must fit in `2 * BITS` bits when aligned.
+    pub const fn add(self, other: Self) -> Self {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let (a, b, exp) = self.align(other);
+        let (lo, carry) = a.lo.overflowing_add(b.lo);
+        let hi = a.hi.add(b.hi);
+        let hi = if carry { hi.add(BUint::ONE) } else { hi };
+        Self::new(hi, lo, exp)
+    }
+
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            _ => {}
+        }
+        let (top_a, top_b) = (self.top(), other.top());
+        if top_a != top_b {
+            return if top_a < top_b {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        // both numbers have the same most significant bit and at most `2 * BITS` significant bits, so they can be aligned without overflow
+        let (a, b, _) = self.align(*other);
+        match a.hi.cmp(&b.hi) {
+            Ordering::Equal => a.lo.cmp(&b.lo),
+            ord => ord,
+        }
+    }
+}
+
+impl<const N: usize> Extended<N> {
+    /// Rounds `self` to the nearest `Float<W, MB>` (with ties rounding to even), where `self` approximates some real number `t` to within a relative error that is much smaller than `2^-(MB + 2)`, but `self` may be on the other side of a rounding boundary to `t`.
+    ///
+    /// The rounding is decided exactly by `cmp_midpoint`, which is called with the only rounding boundary `m` which could lie between `self` and `t`, and must return the ordering of `|t|` relative to `m`. `m` is positive, and exactly halfway between two adjacent values of `Float<W, MB>`. `8 * N` must be at least `W`.
+    pub fn to_float_exact<const W: usize, const MB: usize, F>(self, cmp_midpoint: F) -> Float<W, MB>
+    where
+        Width<W>: Storage,
+        F: FnOnce(Self) -> Ordering,
+    {
+        let negative = self.negative;
+        let approx = self.abs();
+        let rounded = approx.to_float::<W, MB>();
+        // the adjacent float on the same side of `rounded` as `approx`, and the midpoint between the two
+        let (lower, upper) = if rounded.is_infinite() {
+            (Float::MAX, rounded)
+        } else if let Ordering::Less = approx.cmp_abs(&Self::from_float(rounded)) {
+            (rounded.next_down(), rounded)
+        } else {
+            (rounded, rounded.next_up())
+        };
+        let lower_ext = Self::from_float(lower);
+        let midpoint = if upper.is_infinite() {
+            // the midpoint between `MAX` and `2^(MAX_EXP + 1)`
+            let max_ulp = lower_ext.sub(Self::from_float(lower.next_down()));
+            lower_ext.add(max_ulp.mul_pow2(-1))
+        } else {
+            lower_ext.add(Self::from_float(upper)).mul_pow2(-1)
+        };
+        let out = match cmp_midpoint(midpoint) {
+            Ordering::Less => lower,
+            Ordering::Greater => upper,
+            Ordering::Equal => {
+                if lower.to_bits().bit(0) {
+                    upper
+                } else {
+                    lower
+                }
+            }
+        };
+        if negative {
+            out.neg()
+        } else {
+            out
+        }
+    }
+}
+
+/// Elementary transcendental functions, computed to a precision of about `64 * N - 8` bits.
+impl<const N: usize> Extended<N> {
+    pub const PI: Self = Self::from_table(&tables::PI);
+    pub const FRAC_PI_2: Self = Self::PI.mul_pow2(-1);
+    pub const FRAC_PI_4: Self = Self::PI.mul_pow2(-2);
+    pub const LN_2: Self = Self::from_table(&tables::LN_2);
+    pub const LOG2_E: Self = Self::from_table(&tables::LOG2_E);
+    pub const LOG10_E: Self = Self::from_table(&tables::LOG10_E);
+    pub const SQRT_2: Self = Self::from_table(&tables::SQRT_2);
+
+    /// Returns `k` and `r` such that `self = k * π/2 + r` (modulo `2π`), where `0 <= k < 4` and `|r| <= π/4`.
+    ///
+    /// For `|self| > π/4`, the reduction uses the Payne-Hanek method: `self` is multiplied by the bits of `2/π` which affect the fractional part and the last two bits of the integer part of the product, so the reduction is accurate no matter how large the argument is, as long as those bits are stored in [`tables::TWO_OVER_PI`]. This needs the bits of `2/π` up to position `q - 3 + 2 * BITS`, where `2^q` is the largest power of two dividing `self`; bits past the end of the table are read as zero, which gives an inaccurate result. [`Float::TWO_OVER_PI_FITS`] checks that this holds for every finite value of a float type.
+    pub const fn rem_pio2(self) -> (u8, Self) {
+        if !matches!(self.cmp_abs(&Self::FRAC_PI_4), Ordering::Greater) {
+            return (0, self);
+        }
+        let bits = Self::BITS as i64;
+        // |self| = m * 2^q for an odd integer m
+        let tz = self.mant.trailing_zeros();
+        let m = self.mant.shr(tz);
+        let q = self.exp + tz as i64;
+
+        // the bits b_i of 2/π = sum b_i 2^-i with i < q - 1 contribute multiples of 4 to m * 2^q * 2/π, so are not needed
+        let start = if q >= 2 { q - 1 } else { 1 };
+        let window_hi = table_window::<N>(&tables::TWO_OVER_PI, (start - 1) as usize);
+        let window_lo = table_window::<N>(&tables::TWO_OVER_PI, (start - 1 + bits) as usize);
+
+        // product = m * (window_hi * 2^BITS + window_lo), a triple width integer. m * 2^q * 2/π = product * 2^-frac_bits plus a negligible error
+        let (p0, p1) = m.widening_mul(window_lo);
+        let (h0, h1) = m.widening_mul(window_hi);
+        let (w1, carry) = p1.overflowing_add(h0);
+        let w2 = if carry { h1.add(BUint::ONE) } else { h1 };
+        let mut product = [p0, w1, w2];
+        let frac_bits = start + 2 * bits - 1 - q;
+
+        let mut k = triple_bit(&product, frac_bits) as u8 + 2 * triple_bit(&product, frac_bits + 1) as u8;
+        // clear the integer bits
+        triple_truncate(&mut product, frac_bits);
+        // if the fractional part f is at least 1/2, replace it with f - 1 (which is negative) and increment k
+        let negative = triple_bit(&product, frac_bits - 1);
+        if negative {
+            k = (k + 1) % 4;
+            let mut borrow = false;
+            let mut i = 0;
+            while i < 3 {
+                let (d, b1) = BUint::ZERO.overflowing_sub(product[i]);
+                let (d, b2) = d.overflowing_sub(if borrow { BUint::ONE } else { BUint::ZERO });
+                product[i] = d;
+                borrow = b1 || b2;
+                i += 1;
+            }
+            // the negation is modulo 2^(3 * BITS), so clear the bits from position frac_bits onwards again
+            triple_truncate(&mut product, frac_bits);
+        }
+        let top = if !product[2].is_zero() {
+            2 * bits + product[2].bits() as i64 - 1
+        } else if !product[1].is_zero() {
+            bits + product[1].bits() as i64 - 1
+        } else if !product[0].is_zero() {
+            product[0].bits() as i64 - 1
+        } else {
+            return (k, Self::ZERO);
+        };
+        let f = Self {
+            negative,
+            exp: top - bits + 1 - frac_bits,
+            mant: triple_window(&product, top - bits + 1),
+        };
+        let r = f.mul(Self::FRAC_PI_2);
+        if self.negative {
+            ((4 - k) % 4, r.neg())
+        } else {
+            (k, r)
+        }
+    }
+
+    /// Returns `(sin(self), cos(self))` for `|self| <= π/4`.
+    pub const fn sin_cos_reduced(self) -> (Self, Self) {
+        let r2 = self.mul(self);
+        let mut sin_sum = Self::ONE;
+        let mut cos_sum = Self::ONE;
+        // term = r^(2k) / (2k)!
+        let mut term = Self::ONE;
+        let mut k = 1u64;
+        loop {
+            term = term.mul(r2).div(Self::from_u64((2 * k - 1) * (2 * k)));
+            let sin_term = term.div(Self::from_u64(2 * k + 1));
+            if k % 2 == 1 {
+                cos_sum = cos_sum.sub(term);
+                sin_sum = sin_sum.sub(sin_term);
+            } else {
+                cos_sum = cos_sum.add(term);
+                sin_sum = sin_sum.add(sin_term);
+            }
+            if term.is_negligible(&Self::ONE) {
+                break;
+            }
+            k += 1;
+        }
+        (self.mul(sin_sum), cos_sum)
+    }
+
+    /// Returns `(sin(self), cos(self))`.
+    pub const fn sin_cos(self) -> (Self, Self) {
+        let (k, r) = self.rem_pio2();
+        let (sin, cos) = r.sin_cos_reduced();
+        match k {
+            0 => (sin, cos),
+            1 => (cos, sin.neg()),
+            2 => (sin.neg(), cos.neg()),
+            _ => (cos.neg(), sin),
+        }
+    }
+
+    pub const fn atan(self) -> Self {
+        if self.mant.is_zero() {
+            return self;
+        }
+        let mut t = self.abs();
+        // atan(t) = π/2 - atan(1/t) for t > 0
+        let invert = matches!(t.cmp_abs(&Self::ONE), Ordering::Greater);
+        if invert {
+            t = Self::ONE.div(t);
+        }
+        // atan(t) = 2 atan(t / (1 + sqrt(1 + t^2))), apply this until t < 1/16 so that the series converges quickly
+        let mut doublings = 0;
+        while t.ilog2() >= -4 {
+            let s = Self::ONE.add(t.mul(t)).sqrt();
+            t = t.div(Self::ONE.add(s));
+            doublings += 1;
+        }
+        // atan(t) = t * sum_{k >= 0} (-1)^k t^(2k) / (2k + 1)
+        let t2 = t.mul(t);
+        let mut sum = Self::ONE;
+        let mut power = Self::ONE;
+        let mut k = 1u64;
+        loop {
+            power = power.mul(t2);
+            let term = power.div(Self::from_u64(2 * k + 1));
+            if k % 2 == 1 {
+                sum = sum.sub(term);
+            } else {
+                sum = sum.add(term);
+            }
+            if term.is_negligible(&Self::ONE) {
+                break;
+            }
+            k += 1;
+        }
+        let mut out = t.mul(sum).mul_pow2(doublings);
+        if invert {
+            out = Self::FRAC_PI_2.sub(out);
+        }
+        out.with_sign(self.negative)
+    }
+
+    /// Returns `atan2(self, x)`, the angle of the point `(x, self)` in `[-π, π]`. `self` and `x` must not both be zero.
+    pub const fn atan2(self, x: Self) -> Self {
+        if x.mant.is_zero() {
+            return Self::FRAC_PI_2.with_sign(self.negative);
+        }
+        let angle = self.abs().div(x.abs()).atan();
+        let angle = if x.negative {
+            Self::PI.sub(angle)
+        } else {
+            angle
+        };
+        angle.with_sign(self.negative)
+    }
+
+    /// Returns `e^self - 1` for `|self| < 1/2`.
+    const fn exp_m1_small(self) -> Self {
+        if self.mant.is_zero() {
+            return self;
+        }
+        // e^(2y) - 1 = (e^y - 1)(e^y - 1 + 2), so halve the argument until it is less than 2^-8 and then double the result back up
+        let halvings = if self.ilog2() >= -8 {
+            self.ilog2() + 9
+        } else {
+            0
+        };
+        let x = self.mul_pow2(-halvings);
+        let mut sum = x;
+        let mut term = x;
+        let mut k = 2u64;
+        loop {
+            term = term.mul(x).div(Self::from_u64(k));
+            sum = sum.add(term);
+            if term.is_negligible(&x) {
+                break;
+            }
+            k += 1;
+        }
+        let mut i = 0;
+        while i < halvings {
+            sum = sum.mul(sum.add(Self::TWO));
+            i += 1;
+        }
+        sum
+    }
+
+    /// Returns `e^self`. `|self|` must be less than `2^61`.
+    pub const fn exp(self) -> Self {
+        if self.mant.is_zero() {
+            return Self::ONE;
+        }
+        // e^x = 2^k e^r where r = x - k ln(2) and |r| <= ln(2)/2
+        let k = self.mul(Self::LOG2_E).round_to_i64();
+        let r = self.sub(Self::from_i64(k).mul(Self::LN_2));
+        Self::ONE.add(r.exp_m1_small()).mul_pow2(k)
+    }
+
+    /// Returns `e^self - 1`. `|self|` must be less than `2^61`.
+    pub const fn exp_m1(self) -> Self {
+        if self.mant.is_zero() || self.ilog2() < -1 {
+            self.exp_m1_small()
+        } else {
+            self.exp().sub(Self::ONE)
+        }
+    }
+
+    /// Returns `ln(1 + self)` for `|self| <= 1/2`.
+    const fn ln_1p_small(self) -> Self {
+        if self.mant.is_zero() {
+            return self;
+        }
+        // ln(1 + y) = 2 atanh(z) where z = y / (2 + y), and atanh(z) = z * sum_{k >= 0} z^(2k) / (2k + 1)
+        let z = self.div(Self::TWO.add(self));
+        let z2 = z.mul(z);
+        let mut sum = Self::ONE;
+        let mut power = Self::ONE;
+        let mut k = 1u64;
+        loop {
+            power = power.mul(z2);
+            let term = power.div(Self::from_u64(2 * k + 1));
+            sum = sum.add(term);
+            if term.is_negligible(&Self::ONE) {
+                break;
+            }
+            k += 1;
+        }
+        z.mul(sum).mul_pow2(1)
+    }
+
+    /// Returns `ln(self)`. `self` must be positive.
+    pub const fn ln(self) -> Self {
+        debug_assert!(!self.negative && !self.mant.is_zero());
+        // self = v * 2^e where 1/sqrt(2) <= v < sqrt(2), so ln(self) = e ln(2) + ln(v)
+        let mut e = self.exp + Self::BITS as i64;
+        let mut v = Self {
+            negative: false,
+            exp: -(Self::BITS as i64),
+            mant: self.mant,
+        };
+        if let Ordering::Less = v.mul_pow2(1).cmp_abs(&Self::SQRT_2) {
+            v = v.mul_pow2(1);
+            e -= 1;
+        }
+        let ln_v = v.sub(Self::ONE).ln_1p_small();
+        Self::from_i64(e).mul(Self::LN_2).add(ln_v)
+    }
+
+    /// Returns the cube root of `self`.
+    pub const fn cbrt(self) -> Self {
+        if self.mant.is_zero() {
+            return self;
+        }
+        self.abs()
+            .ln()
+            .div(Self::from_u64(3))
+            .exp()
+            .with_sign(self.negative)
+    }
+
+    /// Returns `ln(1 + self)`. `self` must be greater than `-1`.
+    pub const fn ln_1p(self) -> Self {
+        if self.mant.is_zero() || self.ilog2() < -1 {
+            self.ln_1p_small()
+        } else {
+            Self::ONE.add(self).ln()
+        }
+    }
+}
@@ -0,0 +1,647 @@
+use super::bignum::Big;
+use super::extended::Extended;
+use crate::words::{buint_to_i64, bytes_to_words};
+use super::Float;
+use super::storage::{Storage, Width};
+use crate::errors::{FloatErrorKind, ParseFloatError};
+use crate::int::radix::assert_range;
+use crate::nightly::option_try;
+use crate::{BUint, ExpType};
+use core::cmp::Ordering;
+use core::str::FromStr;
+
+const INVALID: ParseFloatError = ParseFloatError {
+    kind: FloatErrorKind::Invalid,
+};
+
+const EMPTY: ParseFloatError = ParseFloatError {
+    kind: FloatErrorKind::Empty,
+};
+
+const UNDECIDABLE: &str = crate::errors::err_msg!(
+    "float literal is too close to halfway between two floats to be parsed in a `const` context, use `from_str_radix` instead"
+);
+
+/// Exponents are clamped to this magnitude while parsing, which is far beyond the point at which any float overflows or underflows.
+const EXP_LIMIT: i64 = 1 << 40;
+
+/// Parses the digits of a decimal exponent, saturating at `EXP_LIMIT`.
+fn parse_exponent(src: &[u8]) -> Result<i64, ParseFloatError> {
+    let (negative, digits) = match src.first() {
+        Some(b'+') => (false, &src[1..]),
+        Some(b'-') => (true, &src[1..]),
+        _ => (false, src),
+    };
+    if digits.is_empty() {
+        return Err(INVALID);
+    }
+    let mut exp = 0i64;
+    for &byte in digits {
+        let digit = (byte as char).to_digit(10).ok_or(INVALID)?;
+        exp = (exp * 10 + digit as i64).min(EXP_LIMIT);
+    }
+    Ok(if negative { -exp } else { exp })
+}
+
+/// Returns whether the bytes of `a` from index `start` onwards are equal to `b`, ignoring ASCII case.
+const fn eq_ignore_ascii_case(a: &[u8], start: usize, b: &[u8]) -> bool {
+    if a.len() - start != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < b.len() {
+        if !a[start + i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `const` equivalent of `char::to_digit` for ASCII bytes.
+const fn byte_to_digit(byte: u8, radix: u32) -> Option<u32> {
+    let digit = match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'z' => byte - b'a' + 10,
+        b'A'..=b'Z' => byte - b'A' + 10,
+        _ => return None,
+    };
+    if (digit as u32) < radix {
+        Some(digit as u32)
+    } else {
+        None
+    }
+}
+
+/// `const` equivalent of `parse_exponent`, which parses the exponent starting at index `start` of `src` and panics if it is invalid.
+const fn parse_exponent_const(src: &[u8], start: usize) -> i64 {
+    let mut i = start;
+    let negative = i < src.len() && src[i] == b'-';
+    if i < src.len() && (src[i] == b'+' || src[i] == b'-') {
+        i += 1;
+    }
+    if i == src.len() {
+        panic!("{}", INVALID.description());
+    }
+    let mut exp = 0i64;
+    while i < src.len() {
+        let digit = match byte_to_digit(src[i], 10) {
+            Some(digit) => digit,
+            None => panic!("{}", INVALID.description()),
+        };
+        exp = exp * 10 + digit as i64;
+        if exp > EXP_LIMIT {
+            exp = EXP_LIMIT;
+        }
+        i += 1;
+    }
+    if negative {
+        -exp
+    } else {
+        exp
+    }
+}
+
+/// Returns `u << rhs`, or `None` if any set bits would be shifted out.
+const fn shl_exact<const N: usize>(u: BUint<N>, rhs: u64) -> Option<BUint<N>> {
+    if u.is_zero() {
+        return Some(u);
+    }
+    if rhs > u.leading_zeros() as u64 {
+        return None;
+    }
+    Some(u.shl(rhs as ExpType))
+}
+
+/// Compares `mant * radix^radix_exp` with `mid * 2^mid_exp` exactly, or returns `None` if either side is too large to be computed in `64 * N` bits after clearing the negative exponents.
+const fn cmp_exact<const N: usize>(
+    mant: BUint<N>,
+    radix: u32,
+    radix_exp: i64,
+    mid: BUint<N>,
+    mid_exp: i64,
+) -> Option<Ordering> {
+    let pow_exp = radix_exp.unsigned_abs();
+    if pow_exp > ExpType::MAX as u64 {
+        return None;
+    }
+    let pow = option_try!(BUint::<N>::from_digit(radix as u64).checked_pow(pow_exp as ExpType));
+    let (mut lhs, mut rhs) = if radix_exp >= 0 {
+        (option_try!(mant.checked_mul(pow)), mid)
+    } else {
+        (mant, option_try!(mid.checked_mul(pow)))
+    };
+    if mid_exp >= 0 {
+        rhs = option_try!(shl_exact(rhs, mid_exp as u64));
+    } else {
+        lhs = option_try!(shl_exact(lhs, mid_exp.unsigned_abs()));
+    }
+    Some(lhs.cmp(&rhs))
+}
+
+/// Computes `radix^exp`, returning the result along with a bound on its relative error, in units of `2^-(64 * N - 1)`.
+const fn pow_approx<const N: usize>(radix: u32, mut exp: u64) -> (Extended<N>, u64) {
+    let mut base = Extended::<N>::from_u64(radix as u64);
+    let mut base_err = 0;
+    let mut out = Extended::<N>::ONE;
+    let mut out_err = 0;
+    loop {
+        if exp & 1 == 1 {
+            out = out.mul(base);
+            out_err += base_err + 1;
+        }
+        exp >>= 1;
+        if exp == 0 {
+            return (out, out_err);
+        }
+        base = base.mul(base);
+        base_err = 2 * base_err + 1;
+    }
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Converts a string slice in the given base to a float, correctly rounded to the nearest representable value with ties rounding to even.
+    ///
+    /// The string consists of an optional `+` or `-` sign, followed either by one of the case-insensitive special values `inf`, `infinity` or `nan`, or by digits in the given base with an optional `.` separating the integer and fractional parts. At least one digit must be present. If `radix` is 10, the digits may be followed by an exponent consisting of `e` or `E`, an optional sign and decimal digits, which multiplies the number by that power of ten. If `radix` is 16, the digits may be followed by an exponent consisting of `p` or `P`, an optional sign and decimal digits, which multiplies the number by that power of two.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 inclusive.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFloatError> {
+        assert_range!(radix, 36);
+        if src.is_empty() {
+            return Err(ParseFloatError {
+                kind: FloatErrorKind::Empty,
+            });
+        }
+        let (negative, src) = match src.as_bytes()[0] {
+            b'+' => (false, &src[1..]),
+            b'-' => (true, &src[1..]),
+            _ => (false, src),
+        };
+        let signed = |f: Self| if negative { f.neg() } else { f };
+        if src.eq_ignore_ascii_case("inf") || src.eq_ignore_ascii_case("infinity") {
+            return Ok(signed(Self::INFINITY));
+        }
+        if src.eq_ignore_ascii_case("nan") {
+            return Ok(signed(Self::NAN));
+        }
+
+        let bytes = src.as_bytes();
+        let exp_markers: &[u8] = match radix {
+            10 => b"eE",
+            16 => b"pP",
+            _ => b"",
+        };
+        let (digits, exp) = match bytes.iter().position(|b| exp_markers.contains(b)) {
+            Some(i) => (&bytes[..i], parse_exponent(&bytes[i + 1..])?),
+            None => (bytes, 0),
+        };
+
+        // the largest power of the radix which fits in a `u32`, so that digits can be accumulated in chunks
+        let (chunk_base, chunk_len) = {
+            let mut base = radix;
+            let mut len = 1;
+            while let Some(next) = base.checked_mul(radix) {
+                base = next;
+                len += 1;
+            }
+            (base, len)
+        };
+        let mut mant = Big::zero();
+        let mut chunk = 0u32;
+        let mut chunk_digits = 0;
+        let mut digit_count = 0u64;
+        let mut frac_digits = 0i64;
+        let mut seen_point = false;
+        for &byte in digits {
+            if byte == b'.' && !seen_point {
+                seen_point = true;
+                continue;
+            }
+            let digit = (byte as char).to_digit(radix).ok_or(INVALID)?;
+            digit_count += 1;
+            if seen_point {
+                frac_digits = (frac_digits + 1).min(EXP_LIMIT);
+            }
+            chunk = chunk * radix + digit;
+            chunk_digits += 1;
+            if chunk_digits == chunk_len {
+                mant.mul_small(chunk_base).add_small(chunk);
+                chunk = 0;
+                chunk_digits = 0;
+            }
+        }
+        if digit_count == 0 {
+            return Err(INVALID);
+        }
+        if chunk_digits > 0 {
+            mant.mul_pow(radix, chunk_digits).add_small(chunk);
+        }
+        if mant.is_zero() {
+            return Ok(signed(Self::ZERO));
+        }
+
+        // the value is `mant * radix^radix_exp * 2^bin_exp`
+        let (mut radix_exp, mut bin_exp) = if radix == 16 {
+            (-frac_digits, exp)
+        } else {
+            (exp - frac_digits, 0)
+        };
+        // floor of the binary logarithm of the radix
+        let radix_log2 = u32::BITS - 1 - radix.leading_zeros();
+        if radix.is_power_of_two() {
+            bin_exp += radix_exp * radix_log2 as i64;
+            radix_exp = 0;
+        }
+
+        // bound the binary logarithm of the value, to avoid computing huge powers of the radix when the result certainly overflows or underflows
+        let (log_lo, log_hi) = if radix.is_power_of_two() {
+            (radix_log2 as i128, radix_log2 as i128)
+        } else {
+            (radix_log2 as i128, radix_log2 as i128 + 1)
+        };
+        let bits = mant.bits() as i128;
+        let (lower, upper) = if radix_exp >= 0 {
+            (radix_exp as i128 * log_lo, radix_exp as i128 * log_hi)
+        } else {
+            (radix_exp as i128 * log_hi, radix_exp as i128 * log_lo)
+        };
+        let lower = lower + bits - 1 + bin_exp as i128;
+        let upper = upper + bits + bin_exp as i128;
+        let bias = buint_to_i64(Self::EXP_BIAS.to_bits()) as i128;
+        if lower > bias {
+            return Ok(signed(Self::INFINITY));
+        }
+        // values less than half of the smallest positive subnormal round to zero
+        if upper < -bias - MB as i128 {
+            return Ok(signed(Self::ZERO));
+        }
+
+        let prec = Extended::<W>::BITS as u64;
+        let mut den = Big::from_u64(1);
+        if radix_exp >= 0 {
+            mant.mul_pow(radix, radix_exp as u64);
+        } else {
+            den.mul_pow(radix, radix_exp.unsigned_abs());
+        }
+        let (quotient, mut sticky) = if den == Big::from_u64(1) {
+            (mant, false)
+        } else {
+            // scale the numerator so that the quotient has at least `prec` bits
+            let shift = (den.bits() + prec).saturating_sub(mant.bits());
+            mant.shl(shift);
+            bin_exp -= shift as i64;
+            let quotient = mant.div_rem(&den);
+            (quotient, !mant.is_zero())
+        };
+        let discard = quotient.bits().saturating_sub(prec);
+        sticky |= !quotient.low_bits_zero(discard);
+        let mut window = quotient.window::<W>(discard);
+        if sticky {
+            // the discarded bits are all below the rounding bit, so they only need to be recorded as a sticky bit
+            window = window.bitor(BUint::ONE);
+        }
+        Ok(Extended::<W>::normalised(negative, bin_exp + discard as i64, window).to_float())
+    }
+
+    /// Converts a string slice in the given base to a float, correctly rounded to the nearest representable value with ties rounding to even. This is a `const` equivalent of [`from_str_radix`](Self::from_str_radix), which accepts the same format and returns the same result, but panics instead of returning an error.
+    ///
+    /// As heap allocation is not available in a `const` context, the digits are parsed into a fixed size buffer, and the result is computed to a precision of several times the precision of `Self`. Very rarely, this is not enough to decide which way the value should be rounded: this only happens when the value is extremely close to halfway between two adjacent floats, and the digits of the string or the exponent of the value are very large.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 inclusive, if `src` is not a valid float literal, or if the correctly rounded result could not be determined as described above. In this last case, [`from_str_radix`](Self::from_str_radix) should be used instead.
+    pub const fn parse_str_radix(src: &str, radix: u32) -> Self {
+        assert_range!(radix, 36);
+        let bytes = src.as_bytes();
+        if bytes.is_empty() {
+            panic!("{}", EMPTY.description());
+        }
+        let (negative, start) = match bytes[0] {
+            b'+' => (false, 1),
+            b'-' => (true, 1),
+            _ => (false, 0),
+        };
+        if eq_ignore_ascii_case(bytes, start, b"inf")
+            || eq_ignore_ascii_case(bytes, start, b"infinity")
+        {
+            return Self::INFINITY.with_sign(negative);
+        }
+        if eq_ignore_ascii_case(bytes, start, b"nan") {
+            return Self::NAN.with_sign(negative);
+        }
+
+        let exp_marker = match radix {
+            10 => b'e',
+            16 => b'p',
+            _ => 0,
+        };
+        // digits are accumulated into `mant` exactly until it is full, after which they are discarded, only recording whether any of them were non-zero
+        let mut mant = BUint::<W>::ZERO;
+        let mut full = false;
+        let mut sticky = false;
+        let mut discarded = 0i64;
+        let mut digit_count = 0u64;
+        let mut frac_digits = 0i64;
+        let mut seen_point = false;
+        let mut exp = 0;
+        let mut i = start;
+        while i < bytes.len() {
+            let byte = bytes[i];
+            if exp_marker != 0 && byte.to_ascii_lowercase() == exp_marker {
+                exp = parse_exponent_const(bytes, i + 1);
+                break;
+            }
+            i += 1;
+            if byte == b'.' && !seen_point {
+                seen_point = true;
+                continue;
+            }
+            let digit = match byte_to_digit(byte, radix) {
+                Some(digit) => digit,
+                None => panic!("{}", INVALID.description()),
+            };
+            digit_count += 1;
+            if seen_point && frac_digits < EXP_LIMIT {
+                frac_digits += 1;
+            }
+            if !full {
+                let next = match mant.checked_mul(BUint::from_digit(radix as u64)) {
+                    Some(m) => m.checked_add(BUint::from_digit(digit as u64)),
+                    None => None,
+                };
+                match next {
+                    Some(m) => {
+                        mant = m;
+                        continue;
+                    }
+                    None => full = true,
+                }
+            }
+            if discarded < EXP_LIMIT {
+                discarded += 1;
+            }
+            sticky |= digit != 0;
+        }
+        if digit_count == 0 {
+            panic!("{}", INVALID.description());
+        }
+        if mant.is_zero() {
+            return Self::ZERO.with_sign(negative);
+        }
+
+        // the value is `(mant + s) * radix^radix_exp * 2^bin_exp`, where `s` is zero if `sticky` is not set, and strictly between zero and one otherwise
+        let (mut radix_exp, mut bin_exp) = if radix == 16 {
+            (discarded - frac_digits, exp)
+        } else {
+            (exp + discarded - frac_digits, 0)
+        };
+        let radix_log2 = u32::BITS - 1 - radix.leading_zeros();
+        if radix.is_power_of_two() {
+            bin_exp += radix_exp * radix_log2 as i64;
+            radix_exp = 0;
+        }
+
+        let (log_lo, log_hi) = if radix.is_power_of_two() {
+            (radix_log2 as i64, radix_log2 as i64)
+        } else {
+            (radix_log2 as i64, radix_log2 as i64 + 1)
+        };
+        let bits = mant.bits() as i64;
+        let (lower, upper) = if radix_exp >= 0 {
+            (radix_exp * log_lo, radix_exp * log_hi)
+        } else {
+            (radix_exp * log_hi, radix_exp * log_lo)
+        };
+        let bias = buint_to_i64(Self::EXP_BIAS.to_bits());
+        if lower + bits - 1 + bin_exp > bias {
+            return Self::INFINITY.with_sign(negative);
+        }
+        if upper + bits + bin_exp < -bias - MB as i64 {
+            return Self::ZERO.with_sign(negative);
+        }
+
+        if radix_exp == 0 {
+            // the sticky bit is below the rounding bit, since `mant` is much wider than the mantissa of `Self`
+            let mant = if sticky { mant.bitor(BUint::ONE) } else { mant };
+            return Extended::<W>::normalised(negative, bin_exp, mant).to_float();
+        }
+
+        // compute an approximation to the value along with a bound on its relative error, in units of `2^-(64 * W - 1)`
+        let (pow, mut err) = pow_approx::<W>(radix, radix_exp.unsigned_abs());
+        let mant_ext = Extended::<W>::normalised(false, 0, mant);
+        let approx = if radix_exp > 0 {
+            mant_ext.mul(pow)
+        } else {
+            mant_ext.div(pow)
+        };
+        err += 1;
+        if sticky {
+            // if digits were discarded, `mant` is at least `2^(64 * W) / 36`
+            err += 64;
+        }
+        // the error bound is doubled (plus a constant) to account for second order terms
+        let units = 2 * err + 4;
+        let err_log2 = (u64::BITS - (units - 1).leading_zeros()) as i64;
+        let delta = approx.mul_pow2(err_log2 + 2 - Extended::<W>::BITS as i64);
+        let lo: Self = approx.sub(delta).to_float();
+        let hi: Self = approx.add(delta).to_float();
+        if lo.to_bits().eq(&hi.to_bits()) {
+            return lo.with_sign(negative);
+        }
+
+        // the value is within `delta` of a rounding boundary, so compare it with the boundary exactly
+        let rounded: Self = approx.to_float();
+        let (lower, upper) = if rounded.is_infinite() {
+            (Self::MAX, rounded)
+        } else if let Ordering::Less = approx.cmp_abs(&Extended::from_float(rounded)) {
+            (rounded.next_down(), rounded)
+        } else {
+            (rounded, rounded.next_up())
+        };
+        // the boundary is `mid * 2^mid_exp`, halfway between `lower` and `upper`
+        let (_, lower_exp, lower_mant) = lower.to_parts_biased();
+        let mid = BUint::<W>::from_digit(1).bitor(bytes_to_words::<W, W>(lower_mant).shl(1));
+        let mid_exp = buint_to_i64(lower_exp) - bias - MB as i64 - 1;
+        let out = match cmp_exact(mant, radix, radix_exp, mid, mid_exp) {
+            Some(Ordering::Less) => {
+                if !sticky {
+                    lower
+                } else {
+                    let cmp_next = match mant.checked_add(BUint::ONE) {
+                        Some(next) => cmp_exact(next, radix, radix_exp, mid, mid_exp),
+                        None => None,
+                    };
+                    match cmp_next {
+                        Some(Ordering::Less | Ordering::Equal) => lower,
+                        _ => panic!("{}", UNDECIDABLE),
+                    }
+                }
+            }
+            Some(Ordering::Greater) => upper,
+            Some(Ordering::Equal) => {
+                if sticky || lower.to_bits().bit(0) {
+                    upper
+                } else {
+                    lower
+                }
+            }
+            None => panic!("{}", UNDECIDABLE),
+        };
+        out.with_sign(negative)
+    }
+}
+
+impl<const W: usize, const MB: usize> FromStr for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    type Err = ParseFloatError;
+
+    /// Parses a decimal string to a float, in the same format as [`from_str_radix`](Self::from_str_radix) with a radix of 10.
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::types::{ftest, FTEST};
+    use crate::test::TestConvert;
+    use alloc::string::String;
+    use core::str::FromStr;
+
+    fn check(s: &str) {
+        let big = FTEST::from_str(s);
+        let primitive = ftest::from_str(s);
+        match (big, primitive) {
+            (Ok(big), Ok(primitive)) => assert_eq!(TestConvert::into(big), TestConvert::into(primitive), "{}", s),
+            (big, primitive) => assert_eq!(big.is_ok(), primitive.is_ok(), "{}", s),
+        }
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_from_str_round_trip(f: ftest) -> bool {
+            let s = format!("{:e}", f);
+            let big = FTEST::from_str(&s).unwrap();
+            if f.is_nan() {
+                big.is_nan()
+            } else {
+                TestConvert::into(big) == TestConvert::into(f)
+            }
+        }
+
+        fn quickcheck_from_str_decimal(mant: u64, exp: i16, point: u8) -> bool {
+            let mut s = format!("{}", mant);
+            let point = point as usize % (s.len() + 1);
+            s.insert(point, '.');
+            let s = format!("{}e{}", s, exp % 400);
+            check(&s);
+            true
+        }
+
+        fn quickcheck_parse_str_radix_decimal(mant: u64, exp: i16, point: u8) -> bool {
+            let mut s = format!("{}", mant);
+            let point = point as usize % (s.len() + 1);
+            s.insert(point, '.');
+            let s = format!("{}e{}", s, exp % 400);
+            FTEST::parse_str_radix(&s, 10).to_bits() == FTEST::from_str(&s).unwrap().to_bits()
+        }
+
+        fn quickcheck_parse_str_radix(digits: alloc::vec::Vec<u8>, radix: u8, point: u8) -> quickcheck::TestResult {
+            let radix = radix as u32 % 35 + 2;
+            let mut s: String = digits.iter().map(|d| char::from_digit(*d as u32 % radix, radix).unwrap()).collect();
+            if s.is_empty() {
+                return quickcheck::TestResult::discard();
+            }
+            let point = point as usize % (s.len() + 1);
+            s.insert(point, '.');
+            let big = FTEST::from_str_radix(&s, radix).unwrap();
+            quickcheck::TestResult::from_bool(FTEST::parse_str_radix(&s, radix).to_bits() == big.to_bits())
+        }
+
+        fn quickcheck_from_str_long(digits: alloc::vec::Vec<u8>, exp: i8) -> bool {
+            let mut s: String = digits.iter().map(|d| char::from(b'0' + d % 10)).collect();
+            s.insert(0, '1');
+            let s = format!("0.{}e{}", s, exp);
+            check(&s);
+            true
+        }
+    }
+
+    #[test]
+    fn from_str_cases() {
+        for s in [
+            "0", "-0", "1", "+1.5", "1.", ".5", "1.e1", "1E+5", "-nan", "NaN", "+inf", "-Infinity",
+            "infinit", "", ".", "+", "-", ".e1", "1e", "1e+", "0x1", " 1", "1_0", "1..0",
+            "1e1000", "-1e-1000", "1e99999999999999999999", "1e-99999999999999999999",
+            "0.00000000000000000000000000000000000000000000001401298464324817070923729583289916131280261941876515771757068283889791082685860601486638188362121582031249999",
+            "0.000000000000000000000000000000000000000000000014012984643248170709237295832899161312802619418765157717570682838897910826858606014866381883621215820312500001",
+            "340282356779733661637539395458142568448",
+            "340282356779733661637539395458142568447.99999",
+            "2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324",
+        ] {
+            check(s);
+        }
+    }
+
+    #[test]
+    fn parse_str_radix_cases() {
+        let mut halfway = String::from("16777217");
+        for s in [
+            "0", "-0", "1", "+1.5", "1.", ".5", "1.e1", "1E+5", "-inf", "NaN", "+Infinity",
+            "1e1000", "-1e-1000", "1e99999999999999999999", "1e-99999999999999999999",
+            "340282356779733661637539395458142568448", "340282356779733661637539395458142568447.99999",
+            "9007199254740993", "9007199254740993.0000000000000000000000000000000001", "0.1", "1.7976931348623157e308",
+            "1.7976931348623158e308", "2.2250738585072014e-308", "4.9406564584124654e-324", "123456789e-20",
+            halfway.as_str(),
+        ] {
+            assert_eq!(
+                FTEST::parse_str_radix(s, 10).to_bits(),
+                FTEST::from_str(s).unwrap().to_bits(),
+                "{}",
+                s
+            );
+        }
+        // enough digits to overflow the buffer, so that the digits after the halfway point are discarded
+        halfway.push('.');
+        for _ in 0..200 {
+            halfway.push('0');
+        }
+        assert_eq!(FTEST::parse_str_radix(&halfway, 10), FTEST::from_str(&halfway).unwrap());
+        halfway.push('1');
+        assert_eq!(FTEST::parse_str_radix(&halfway, 10), FTEST::from_str(&halfway).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid float literal")]
+    fn parse_str_radix_invalid() {
+        let _ = FTEST::parse_str_radix("1e", 10);
+    }
+
+    #[test]
+    fn from_str_radix() {
+        use crate::Float;
+        type F = Float<8, 52>;
+        assert_eq!(F::from_str_radix("1.8", 16).unwrap(), F::from(1.5f64));
+        assert_eq!(F::from_str_radix("-1.8p3", 16).unwrap(), F::from(-12.0f64));
+        assert_eq!(F::from_str_radix("1p-1074", 16).unwrap(), F::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(F::from_str_radix("0.1", 2).unwrap(), F::from(0.5f64));
+        assert_eq!(F::from_str_radix("z.i", 36).unwrap(), F::from(35.5f64));
+        assert_eq!(F::from_str_radix("0.1", 3).unwrap(), F::from(1.0f64 / 3.0));
+        assert_eq!(F::from_str_radix("-10", 7).unwrap(), F::from(-7.0f64));
+        assert!(F::from_str_radix("1e1", 16).unwrap() == F::from(481.0f64));
+        assert!(F::from_str_radix("2", 2).is_err());
+        assert!(F::from_str_radix("1e1", 8).is_err());
+
+        const THIRD: F = F::parse_str_radix("0.1", 3);
+        const HEX: F = F::parse_str_radix("-1.8p3", 16);
+        assert_eq!(THIRD, F::from(1.0f64 / 3.0));
+        assert_eq!(HEX, F::from(-12.0f64));
+    }
+}
@@ -0,0 +1,168 @@
+use super::extended::Extended;
+use super::Float;
+use super::storage::{Storage, Width};
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Computes the hyperbolic sine of `self`.
+    pub fn sinh(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_infinite() || self.is_zero() {
+            return self;
+        }
+        if self.exp_out_of_range() {
+            return Self::INFINITY.copysign(self);
+        }
+        // sinh(x) = (e^x - e^-x) / 2 = (E + E / (E + 1)) / 2, where E = e^|x| - 1
+        let e = Extended::<{ (W + 3) / 4 }>::from_float(self.abs()).exp_m1();
+        let sinh = e.add(e.div(e.add(Extended::ONE))).mul_pow2(-1);
+        sinh.with_sign(self.is_sign_negative()).to_float()
+    }
+
+    /// Computes the hyperbolic cosine of `self`.
+    pub fn cosh(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_infinite() {
+            return Self::INFINITY;
+        }
+        if self.exp_out_of_range() {
+            return Self::INFINITY;
+        }
+        // cosh(x) = (e^|x| + e^-|x|) / 2
+        let e = Extended::<{ (W + 3) / 4 }>::from_float(self.abs()).exp();
+        e.add(Extended::ONE.div(e)).mul_pow2(-1).to_float()
+    }
+
+    /// Computes the hyperbolic tangent of `self`.
+    pub fn tanh(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return self;
+        }
+        if self.is_infinite() || self.exp_out_of_range() {
+            return Self::ONE.copysign(self);
+        }
+        // tanh(x) = (e^(2x) - 1) / (e^(2x) + 1) = E / (E + 2), where E = e^(2|x|) - 1
+        let e = Extended::<{ (W + 3) / 4 }>::from_float(self.abs())
+            .mul_pow2(1)
+            .exp_m1();
+        let tanh = e.div(e.add(Extended::TWO));
+        tanh.with_sign(self.is_sign_negative()).to_float()
+    }
+
+    /// Computes the inverse hyperbolic sine of `self`.
+    pub fn asinh(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_infinite() || self.is_zero() {
+            return self;
+        }
+        // asinh(x) = ln(|x| + sqrt(x^2 + 1)) = ln_1p(|x| + x^2 / (1 + sqrt(x^2 + 1))), which avoids cancellation for small x
+        let x = Extended::<{ (W + 3) / 4 }>::from_float(self.abs());
+        let x2 = x.mul(x);
+        let s = Extended::ONE.add(x2).sqrt();
+        let asinh = x.add(x2.div(Extended::ONE.add(s))).ln_1p();
+        asinh.with_sign(self.is_sign_negative()).to_float()
+    }
+
+    /// Computes the inverse hyperbolic cosine of `self`. Returns NaN if `self` is less than one.
+    pub fn acosh(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self < Self::ONE {
+            return Self::NAN;
+        }
+        if self.is_infinite() {
+            return self;
+        }
+        // acosh(x) = ln(x + sqrt(x^2 - 1)) = ln_1p(t + sqrt(t (t + 2))), where t = x - 1
+        let t = Extended::<{ (W + 3) / 4 }>::from_float(self).sub(Extended::ONE);
+        let s = t.mul(t.add(Extended::TWO)).sqrt();
+        t.add(s).ln_1p().to_float()
+    }
+
+    /// Computes the inverse hyperbolic tangent of `self`. Returns NaN if `self` is outside the range `[-1, 1]`.
+    pub fn atanh(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        let abs = self.abs();
+        if abs > Self::ONE {
+            return Self::NAN;
+        }
+        if abs == Self::ONE {
+            return Self::INFINITY.copysign(self);
+        }
+        if self.is_zero() {
+            return self;
+        }
+        // atanh(x) = ln((1 + x) / (1 - x)) / 2 = ln_1p(2|x| / (1 - |x|)) / 2
+        let x = Extended::<{ (W + 3) / 4 }>::from_float(abs);
+        let atanh = x
+            .mul_pow2(1)
+            .div(Extended::ONE.sub(x))
+            .ln_1p()
+            .mul_pow2(-1);
+        atanh.with_sign(self.is_sign_negative()).to_float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::test_float_ulps;
+    use crate::test::types::{ftest, FTEST};
+
+    // the primitive float functions are not guaranteed to be correctly rounded, so allow an error of one ulp
+    test_float_ulps! {
+        function: <ftest>::sinh(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-1e-3), (1e-30), (88.5), (-89.5), (1e5), (-0.0)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::cosh(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-1e-3), (1e-30), (88.5), (-89.5), (1e5), (-0.0)]
+    }
+
+    // the primitive `tanh` can be out by two ulps
+    test_float_ulps! {
+        function: <ftest>::tanh(f: ftest),
+        ulps: 2,
+        cases: [(0.5), (-1e-3), (1e-30), (8.5), (-9.5), (1e5), (-0.0)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::asinh(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-1e-3), (1e-30), (1e30), (-0.0)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::acosh(f: ftest),
+        ulps: 1,
+        cases: [(1.0), (1.0001), (0.5), (1e30)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::atanh(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-1e-3), (1e-30), (0.99999), (-1.0), (1.0), (-0.0)]
+    }
+}
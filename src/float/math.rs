@@ -1,6 +1,10 @@
+use super::extended::{Extended, Wide};
+use crate::words::buint_to_i64;
 use super::Float;
+use super::storage::{Storage, Width};
 use crate::cast::As;
-use crate::{BIntD8, BUintD8};
+use crate::{BIntD8, BUintD8, ExpType};
+use core::cmp::Ordering;
 
 /*/// Returns tuple of division and whether u is less than v
 pub const fn div_float<const N: usize>(u: BUintD8<N>, v: BUintD8<N>) -> (BUintD8<N>, bool) {
@@ -196,7 +200,10 @@ All functions:
 mul_add, div_euclid, rem_euclid, powi, powf, exp, exp2, ln, log, log2, log10, cbrt, hypot, sin, cos, tan, asin, acos, atan, atan2, sin_cos, exp_m1, ln_1p, sinh, cosh, tanh, asinh, acosh, atanh, to_degrees, to_radians
 */
 
-impl<const W: usize, const MB: usize> Float<W, MB> {
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     #[inline]
     pub const fn abs(self) -> Self {
         if self.is_sign_negative() {
@@ -206,165 +213,130 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         }
     }
 
-    pub fn sqrt(self) -> Self {
+    /// Returns the unbiased exponent of `self`. This is only meaningful for normal numbers, but is small enough for zero and subnormal numbers, and large enough for infinity and NaN, to be handled correctly by the rounding methods below.
+    #[inline]
+    const fn unbiased_exp(self) -> i64 {
+        buint_to_i64(self.exp_mant().0) - buint_to_i64(Self::EXP_BIAS.to_bits())
+    }
+
+    /// Returns a mask of the bits of `self` which represent the fractional part of `self`, where `e` is the unbiased exponent of `self` and `0 <= e < MB`.
+    #[inline]
+    const fn fract_mask(e: i64) -> BUintD8<W> {
+        BUintD8::ONE
+            .shl((MB as i64 - e) as ExpType)
+            .sub(BUintD8::ONE)
+    }
+
+    /// Returns the square root of `self`, correctly rounded.
+    pub const fn sqrt(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
         handle_nan!(self; self);
-        if self.is_zero() {
+        if self.is_zero() || (self.is_infinite() && self.is_sign_positive()) {
             return self;
         }
-        let bits = self.to_bits();
-        if bits == Self::INFINITY.to_bits() {
-            return Self::INFINITY;
-        }
         if self.is_sign_negative() {
             return Self::NAN;
-            /*let u = BUintD8::MAX << (Self::MB - 1);
-            return Self::from_bits(u);*/
-        }
-
-        let tiny = Self::from_bits(BUintD8::from(0b11011u8) << Self::MB); // TODO: may not work for exponents stored with very few bits
-
-        let mut ix = BIntD8::from_bits(bits);
-        let mut i: BIntD8<W>;
-        let mut m = ix >> Self::MB;
-        if m.is_zero() {
-            /* subnormal x */
-            i = BIntD8::ZERO;
-            while (ix & (BIntD8::ONE << Self::MB)).is_zero() {
-                ix <<= 1;
-                i = i + BIntD8::ONE;
-            }
-            m -= i - BIntD8::ONE;
-        }
-        m -= Self::EXP_BIAS; /* unbias exponent */
-        ix = (ix & BIntD8::from_bits(BUintD8::MAX >> (Self::BITS - Self::MB)))
-            | (BIntD8::ONE << Self::MB);
-        if m & BIntD8::ONE == BIntD8::ONE {
-            /* odd m, double x to make it even */
-            ix += ix;
-        }
-        m >>= 1; /* m = [m/2] */
-
-        /* generate sqrt(x) bit by bit */
-        ix += ix;
-        let mut q = BIntD8::ZERO;
-        let mut s = BIntD8::ZERO;
-        let mut r = BUintD8::ONE << (Self::MB + 1); /* r = moving bit from right to left */
-
-        let mut t: BIntD8<W>;
-        while !r.is_zero() {
-            t = s + BIntD8::from_bits(r);
-            if t <= ix {
-                s = t + BIntD8::from_bits(r);
-                ix -= t;
-                q += BIntD8::from_bits(r);
-            }
-            ix += ix;
-            r = r >> 1u8;
-        }
-
-        /* use floating add to find out rounding direction */
-        let mut z: Self;
-        if !ix.is_zero() {
-            z = Self::ONE - tiny; /* raise inexact flag */
-            if z >= Self::ONE {
-                z = Self::ONE + tiny;
-                if z > Self::ONE {
-                    q += BIntD8::TWO;
-                } else {
-                    q += q & BIntD8::ONE;
-                }
-            }
         }
-
-        ix = (q >> 1u8) + BIntD8::from_bits((BUintD8::MAX << (Self::MB + 1 + 2)) >> 2u8);
-        ix += m << Self::MB;
-        Self::from_bits(ix.to_bits())
-    }
-
-    #[inline]
-    pub fn round(self) -> Self {
-        let a = Self::HALF - Self::QUARTER * Self::EPSILON;
-        (self + a.copysign(self)).trunc()
+        Extended::<{ (W + 3) / 4 }>::from_float(self).sqrt().to_float()
     }
 
-    #[inline]
-    pub fn ceil(self) -> Self {
-        let mut u = self.to_bits();
-        let e = self.exponent() - Self::EXP_BIAS;
-
-        if e >= BIntD8::from(MB) {
+    /// Returns the nearest integer to `self`, rounding half-way cases away from zero.
+    pub const fn round(self) -> Self {
+        let e = self.unbiased_exp();
+        if e >= MB as i64 {
             return self;
         }
-        if !e.is_negative() {
-            let m = (BUintD8::MAX >> (Self::BITS - Self::MB)) >> e;
-            if (u & m).is_zero() {
-                return self;
-            }
-            if self.is_sign_positive() {
-                u += m;
-            }
-            u &= !m;
-        } else if self.is_sign_negative() {
-            return Self::NEG_ZERO;
-        } else if !(u << 1u8).is_zero() {
-            return Self::ONE;
+        if e < -1 {
+            return Self::ZERO.copysign(self);
         }
-        Self::from_bits(u)
+        if e == -1 {
+            return Self::ONE.copysign(self);
+        }
+        // adding half of the unit in the last place of the integer part rounds the magnitude half-way away from zero, and any carry into the exponent is correct
+        let half = BUintD8::ONE.shl((MB as i64 - 1 - e) as ExpType);
+        let bits = self.to_bits().add(half);
+        Self::from_bits(bits.bitand(Self::fract_mask(e).not()))
     }
 
-    #[inline]
-    pub fn floor(self) -> Self {
+    /// Returns the smallest integer greater than or equal to `self`.
+    pub const fn ceil(self) -> Self {
+        let e = self.unbiased_exp();
+        if e >= MB as i64 {
+            return self;
+        }
+        if e < 0 {
+            return if self.is_zero() {
+                self
+            } else if self.is_sign_negative() {
+                Self::NEG_ZERO
+            } else {
+                Self::ONE
+            };
+        }
+        let mask = Self::fract_mask(e);
         let mut bits = self.to_bits();
-        let e = self.exponent() - Self::EXP_BIAS;
-
-        if e >= BIntD8::from(MB) {
+        if bits.bitand(mask).is_zero() {
             return self;
         }
-        if !e.is_negative() {
-            let m = (BUintD8::MAX >> (Self::BITS - Self::MB)) >> e;
-            if (bits & m).is_zero() {
-                return self;
-            }
-            if self.is_sign_negative() {
-                bits += m;
-            }
-            bits &= !m;
-        } else if self.is_sign_positive() {
-            return Self::ZERO;
-        } else if !(bits << 1u8).is_zero() {
-            return Self::NEG_ONE;
+        if self.is_sign_positive() {
+            bits = bits.add(mask);
         }
-        Self::from_bits(bits)
+        Self::from_bits(bits.bitand(mask.not()))
     }
 
-    #[inline]
-    pub fn trunc(self) -> Self {
-        //return self.fract_trunc().1;
-        let mut i = self.to_bits();
-        let exp_bits = BIntD8::from(Self::BITS - Self::MB);
-        let mut e = self.exponent() - Self::EXP_BIAS + exp_bits;
-
-        if e >= BIntD8::from(Self::BITS) {
+    /// Returns the largest integer less than or equal to `self`.
+    pub const fn floor(self) -> Self {
+        let e = self.unbiased_exp();
+        if e >= MB as i64 {
+            return self;
+        }
+        if e < 0 {
+            return if self.is_zero() {
+                self
+            } else if self.is_sign_positive() {
+                Self::ZERO
+            } else {
+                Self::NEG_ONE
+            };
+        }
+        let mask = Self::fract_mask(e);
+        let mut bits = self.to_bits();
+        if bits.bitand(mask).is_zero() {
             return self;
         }
-        if e < exp_bits {
-            e = BIntD8::ONE;
+        if self.is_sign_negative() {
+            bits = bits.add(mask);
         }
-        let m = BIntD8::NEG_ONE.to_bits() >> e;
-        if (i & m).is_zero() {
+        Self::from_bits(bits.bitand(mask.not()))
+    }
+
+    /// Returns the integer part of `self`, rounding toward zero.
+    pub const fn trunc(self) -> Self {
+        let e = self.unbiased_exp();
+        if e >= MB as i64 {
             return self;
         }
-        i &= !m;
-        Self::from_bits(i)
+        if e < 0 {
+            return Self::ZERO.copysign(self);
+        }
+        Self::from_bits(self.to_bits().bitand(Self::fract_mask(e).not()))
     }
 
     #[inline]
-    pub fn fract(self) -> Self {
+    pub fn fract(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
         self.fract_trunc().0
     }
 
     #[inline]
-    pub fn fract_trunc(self) -> (Self, Self) {
+    pub fn fract_trunc(self) -> (Self, Self)
+    where
+        [(); (W + 3) / 4]:,
+    {
         handle_nan!((self, self); self);
         if self.is_infinite() {
             return (Self::NAN, self);
@@ -404,7 +376,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     #[inline]
     pub fn recip2(self) -> Self
     where
-        [(); W * 2]:,
+        [(); (W + 3) / 4]:,
     {
         Self::ONE / self
     }
@@ -412,7 +384,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     #[inline]
     pub fn div_euclid(self, rhs: Self) -> Self
     where
-        [(); W * 2]:,
+        [(); (W + 3) / 4]:,
     {
         let div = (self / rhs).trunc();
         if self % rhs < Self::ZERO {
@@ -426,7 +398,10 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     }
 
     #[inline]
-    pub fn rem_euclid(self, rhs: Self) -> Self {
+    pub fn rem_euclid(self, rhs: Self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
         let rem = self % rhs;
         if rem < Self::NEG_ZERO {
             rem + rhs.abs()
@@ -435,133 +410,181 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         }
     }
 
-    #[inline]
-    pub fn powi(mut self, n: i32) -> Self
+    /// Raises `self` to the integer power `n`, by repeated squaring and multiplication.
+    pub const fn powi(mut self, n: i32) -> Self
     where
-        [(); W * 2]:,
+        [(); (W + 3) / 4]:,
     {
-        // println!("{:032b}, {}", self.to_bits(), n);
         if n == 0 {
-            return self;
+            return Self::ONE;
         }
         let mut n_abs = n.unsigned_abs(); // unsigned abs since otherwise overflow could occur (if n == i32::MIN)
         let mut y = Self::ONE;
         while n_abs > 1 {
             if n_abs & 1 == 1 {
-                // out = out * self;
-                y = y * self;
+                y = y.mul(self);
             }
-            self = self * self;
+            self = self.mul(self);
             n_abs >>= 1;
         }
         if n.is_negative() {
-            Self::ONE / (self * y)
+            Self::ONE.div(self.mul(y))
         } else {
-            self * y
+            self.mul(y)
         }
     }
 
-    /*pub fn remquof(mut self, mut y: Self) -> /*(Self, BIntD8<W>)*/(Self, Self) {
+    /// Computes `(self * a) + b` with only one rounding error, yielding a more accurate result than an unfused multiply-add.
+    pub fn mul_add(self, a: Self, b: Self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(Self::NAN; self, a, b);
+        let negative = self.is_sign_negative() ^ a.is_sign_negative();
+        if self.is_infinite() || a.is_infinite() {
+            if self.is_zero() || a.is_zero() {
+                return Self::NAN;
+            }
+            if b.is_infinite() && b.is_sign_negative() != negative {
+                return Self::NAN;
+            }
+            return if negative {
+                Self::NEG_INFINITY
+            } else {
+                Self::INFINITY
+            };
+        }
+        if b.is_infinite() {
+            return b;
+        }
+        // the product of the mantissas is computed exactly, so the only rounding occurs when the result is converted back to `Self`
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .mul(Extended::from_float(a))
+            .add(Extended::from_float(b))
+            .to_float()
+    }
+
+    /// Returns the cube root of `self`, correctly rounded.
+    pub fn cbrt(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
         handle_nan!(self; self);
-        handle_nan!(y; y);
-        if self.is_infinite() || y.is_infinite() {
-            return (Self::NAN, Self::NAN);
+        if self.is_infinite() || self.is_zero() {
+            return self;
         }
+        let x = Extended::<{ (W + 3) / 4 }>::from_float(self);
+        x.cbrt().to_float_exact(|m| {
+            let cube = Wide::mul(m.mul(m), m);
+            Wide::from_extended(x).cmp(&cube)
+        })
+    }
 
-        if y.is_zero() {
-            return (Self::QNAN, Self::QNAN);
+    /// Computes `sqrt(self^2 + other^2)`, correctly rounded and without intermediate overflow or underflow.
+    pub fn hypot(self, other: Self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if self.is_infinite() || other.is_infinite() {
+            return Self::INFINITY;
         }
-        if self.is_zero() {
-            return (self, self);
-        }
-        let ux = self.to_bits();
-        let mut uy = y.to_bits();
-        let mut ex = self.exponent();
-        let mut ey = y.exponent();
-        let sx = self.is_sign_negative();
-        let sy = y.is_sign_negative();
-        let mut uxi = ux;
-
-        /* normalize x and y */
-        let mut i;
-        if ex.is_zero() {
-            i = uxi << (Self::BITS - Self::MB);
-            while !BIntD8::from_bits(i).is_negative() {
-                ex -= BIntD8::ONE;
-                i <<= 1u8;
-            }
-            uxi <<= -ex + BIntD8::ONE;
-        } else {
-            uxi &= BUintD8::MAX >> (Self::BITS - Self::MB);
-            uxi |= BUintD8::ONE << Self::MB;
-        }
-        if ey.is_zero() {
-            i = uy << (Self::BITS - Self::MB);
-            while !BIntD8::from_bits(i).is_negative() {
-                ey -= BIntD8::ONE;
-                i <<= 1u8;
-            }
-            uy <<= -ey + BIntD8::ONE;
-        } else {
-            uy &= BUintD8::MAX >> (Self::BITS - Self::MB);
-            uy |= BUintD8::ONE << Self::MB;
+        handle_nan!(Self::NAN; self, other);
+        let (a, b) = (self.abs(), other.abs());
+        let (a, b) = if a < b { (b, a) } else { (a, b) };
+        if b.is_zero() {
+            return a;
+        }
+        let x = Extended::<{ (W + 3) / 4 }>::from_float(a);
+        let y = Extended::<{ (W + 3) / 4 }>::from_float(b);
+        // if b < a * 2^-(MB + 2), then b^2 is less than a quarter of an ulp of a^2 and hypot(a, b) rounds to a
+        if x.ilog2() - y.ilog2() > MB as i64 + 2 {
+            return a;
         }
+        let sum_squares = x.mul(x).add(y.mul(y));
+        sum_squares.sqrt().to_float_exact(|m| {
+            let sum_squares = Wide::mul(x, x).add(Wide::mul(y, y));
+            sum_squares.cmp(&Wide::mul(m, m))
+        })
+    }
 
-        let mut q = BUintD8::<W>::ZERO;
-        if ex + BIntD8::ONE != ey {
-            if ex < ey {
-                return (self, 0);
-            }
-            /* x mod y */
+    /// Computes the IEEE 754 remainder of `self` divided by `rhs`, which is `self - n * rhs` where `n` is the integer nearest to `self / rhs` (with ties rounding to even). The result is always exact.
+    #[inline]
+    pub fn remainder(self, rhs: Self) -> Self {
+        self.remquo(rhs).0
+    }
+
+    /// Computes the IEEE 754 remainder of `self` divided by `rhs` (see [`remainder`](Self::remainder)), together with an integer which has the same sign as `self / rhs` and whose magnitude is congruent modulo `2^31` to the magnitude of the integer quotient `n`.
+    pub fn remquo(self, rhs: Self) -> (Self, i32) {
+        handle_nan!((Self::NAN, 0); self, rhs);
+        if self.is_infinite() || rhs.is_zero() {
+            return (Self::NAN, 0);
+        }
+        if rhs.is_infinite() || self.is_zero() {
+            return (self, 0);
+        }
+        let negative = self.is_sign_negative();
+        let quotient_negative = negative ^ rhs.is_sign_negative();
+
+        // normalise the mantissas so that their most significant bit is bit `MB`
+        let normalise = |f: Self| {
+            let (_, exp, mant) = f.to_parts_biased();
+            let shift = mant.leading_zeros() - (Self::BITS - Self::MB - 1);
+            (buint_to_i64(exp) - shift as i64, mant << shift)
+        };
+        let (mut ex, mut mx) = normalise(self);
+        let (ey, my) = normalise(rhs);
+
+        let mut q = 0u32;
+        if ex + 1 < ey {
+            // |self| < |rhs| / 2
+            return (self, 0);
+        }
+        if ex >= ey {
+            // long division, computing the remainder of |self| divided by |rhs|, and the least significant bits of the quotient
             while ex > ey {
-                i = uxi.wrapping_sub(uy);
-                if !BIntD8::from_bits(i).is_negative() {
-                    uxi = i;
-                    q += BUintD8::ONE;
+                if mx >= my {
+                    mx -= my;
+                    q += 1;
                 }
-                uxi <<= 1u8;
-                q <<= 1u8;
-                ex -= BIntD8::ONE;
+                mx <<= 1;
+                q <<= 1;
+                ex -= 1;
             }
-            i = uxi.wrapping_sub(uy);
-            if !BIntD8::from_bits(i).is_negative() {
-                uxi = i;
-                q += BUintD8::ONE;
+            if mx >= my {
+                mx -= my;
+                q += 1;
             }
-            if uxi.is_zero() {
-                //ex = BIntD8::TWO - BIntD8::from(Self::BITS);
-                ex = BIntD8::from(-60i8);
-            } else {
-                while (uxi >> Self::MB).is_zero() {
-                    uxi <<= 1u8;
-                    ex -= BIntD8::ONE;
-                }
+            if mx.is_zero() {
+                let q = (q & (i32::MAX as u32)) as i32;
+                let zero = if negative { Self::NEG_ZERO } else { Self::ZERO };
+                return (zero, if quotient_negative { -q } else { q });
             }
-        }
-
-        /* scale result and decide between |x| and |x|-|y| */
-        if ex.is_positive() {
-            uxi -= BUintD8::ONE << Self::MB;
-            uxi |= ex.to_bits() << Self::MB;
-        } else {
-            uxi >>= -ex + BIntD8::ONE;
-        }
-        self = Self::from_bits(uxi);
-        if sy {
-            y = -y;
-        }
-        if ex == ey || (ex + BIntD8::ONE == ey && (Self::TWO * self > y || (Self::TWO * self == y && !(q % BUintD8::TWO).is_zero()))) {
-            self = self - y;
-            q += BUintD8::ONE;
-        }
-        q &= BUintD8::MAX >> 1u8;
-        let quo = if sx ^ sy { -BIntD8::from_bits(q) } else { BIntD8::from_bits(q) };
-        if sx {
-            (-self, quo)
-        } else {
-            (self, quo)
-        }
-    }*/
+            let shift = mx.leading_zeros() - (Self::BITS - Self::MB - 1);
+            mx <<= shift;
+            ex -= shift as i64;
+        }
+        // the remainder r = |self| mod |rhs| is now mx * 2^(ex - EXP_BIAS - MB). Decide between r and r - |rhs|
+        let bias = buint_to_i64(Self::EXP_BIAS.to_bits());
+        let mut r = Extended::<W>::from_parts(false, ex - bias - Self::MB as i64, mx);
+        let y = Extended::<W>::from_float(rhs.abs());
+        let round_up = ex == ey
+            || (ex + 1 == ey
+                && match r.mul_pow2(1).cmp_abs(&y) {
+                    Ordering::Greater => true,
+                    Ordering::Equal => q % 2 == 1,
+                    Ordering::Less => false,
+                });
+        if round_up {
+            r = r.sub(y);
+            q = q.wrapping_add(1);
+        }
+        let q = (q & (i32::MAX as u32)) as i32;
+        let r: Self = r.to_float();
+        (
+            if negative { r.neg() } else { r },
+            if quotient_negative { -q } else { q },
+        )
+    }
 
     #[cfg(test)]
     pub(crate) fn to_f64(self) -> f64 {
@@ -647,7 +670,6 @@ impl super::F32 {
                     m ^= BUintD8::ONE << Self::MB;
                 }
                 let unnormalised = Self::from_exp_mant(self.is_sign_negative(), inv_e, m);
-                println!("iters: {}", iters);
                 return unnormalised;
             }
             x_n = x_n_1;
@@ -658,7 +680,7 @@ impl super::F32 {
 
 #[cfg(test)]
 mod tests {
-    use crate::test::test_bignum;
+    use crate::test::{test_bignum, test_float_ulps};
     use crate::test::types::{ftest, FTEST};
 
     test_bignum! {
@@ -701,6 +723,89 @@ mod tests {
         function: <ftest>::powi(f: ftest, n: i32)
     }
 
+    test_float_ulps! {
+        function: <ftest>::mul_add(a: ftest, b: ftest, c: ftest),
+        ulps: 0,
+        cases: [
+            (0.1, 10.0, -1.0), (ftest::MAX, 2.0, -ftest::MAX), (ftest::INFINITY, 0.0, 1.0),
+            (ftest::INFINITY, 1.0, ftest::NEG_INFINITY), (-0.0, 1.0, 0.0), (-0.0, 1.0, -0.0),
+            (1.0, 1.0, -1.0), (ftest::MIN_POSITIVE, 0.5, -1e-45), (1.0 + ftest::EPSILON, 1.0 - ftest::EPSILON, -1.0)
+        ]
+    }
+
+    // the primitive `cbrt` and `hypot` are not guaranteed to be correctly rounded, so allow an error of one ulp
+    test_float_ulps! {
+        function: <ftest>::cbrt(f: ftest),
+        ulps: 1,
+        cases: [(27.0), (-8.0), (0.001), (1e-40), (ftest::MAX), (-0.0)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::hypot(a: ftest, b: ftest),
+        ulps: 1,
+        cases: [
+            (3.0, 4.0), (ftest::MAX, ftest::MAX), (1e-40, 1e-40), (ftest::NAN, ftest::INFINITY),
+            (1.0, 1e-30), (-5.0, 12.0), (0.0, -0.0)
+        ]
+    }
+
+    /// Computes the remainder and the three least significant bits of the quotient of `x` divided by `y` with the primitive `%` operator, which is exact.
+    fn remquo(x: ftest, y: ftest) -> (ftest, i32) {
+        if x.is_nan() || y.is_nan() || x.is_infinite() || y == 0.0 {
+            return (ftest::NAN, 0);
+        }
+        if y.is_infinite() {
+            return (x, 0);
+        }
+        let y_abs = y.abs();
+        let r8 = x.abs() % (y_abs * 8.0);
+        let mut rem = r8 % y_abs;
+        let mut k = (r8 / y_abs).trunc();
+        if k.mul_add(y_abs, rem) != r8 {
+            k -= 1.0;
+        }
+        let mut k = k as i32;
+        if rem * 2.0 > y_abs || (rem * 2.0 == y_abs && k % 2 == 1) {
+            rem -= y_abs;
+            k += 1;
+        }
+        let q = k % 8;
+        (
+            if x.is_sign_negative() { -rem } else { rem },
+            if x.is_sign_negative() ^ y.is_sign_negative() { -q } else { q },
+        )
+    }
+
+    fn remquo_matches(x: ftest, y: ftest) -> bool {
+        use crate::test::TestConvert;
+
+        let (rem, quo) = FTEST::from(x).remquo(FTEST::from(y));
+        let (expected_rem, expected_quo) = remquo(x, y);
+        let rem = ftest::from_bits(TestConvert::into(rem));
+        let remainder = ftest::from_bits(TestConvert::into(FTEST::from(x).remainder(FTEST::from(y))));
+        if expected_rem.is_nan() {
+            return rem.is_nan() && remainder.is_nan();
+        }
+        rem.to_bits() == expected_rem.to_bits() && quo % 8 == expected_quo && remainder.to_bits() == rem.to_bits()
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_ftest_remquo(x: ftest, y: ftest) -> bool {
+            remquo_matches(x, y)
+        }
+    }
+
+    #[test]
+    fn cases_ftest_remquo() {
+        let cases: [(ftest, ftest); 10] = [
+            (5.0, 2.0), (7.0, 2.0), (-7.0, 2.0), (7.0, -2.0), (1e30, 3.0), (1e-40, 3e-45),
+            (ftest::MAX, ftest::MIN_POSITIVE), (6.0, 4.0), (-0.0, 1.0), (1.5, ftest::INFINITY)
+        ];
+        for (x, y) in cases {
+            assert!(remquo_matches(x, y), "{} {}", x, y);
+        }
+    }
+
     /*#[test]
     fn fmod() {
         use super::super::F64;
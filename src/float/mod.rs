@@ -1,3 +1,7 @@
+//! Fixed size floating point numbers with arbitrary exponent and mantissa widths, and utilities for working with them.
+//!
+//! The `nightly` feature must be enabled to use items from this module.
+
 use crate::bint::BIntD8;
 use crate::cast::{As, CastFrom};
 use crate::digit::u8 as digit;
@@ -19,29 +23,161 @@ macro_rules! handle_nan {
     };
 }
 
+mod bignum;
 mod cast;
 mod classify;
 mod cmp;
 mod consts;
 mod convert;
 mod endian;
+mod exp;
+pub(crate) mod extended;
+mod from_str;
+mod hyperbolic;
 mod math;
+#[cfg(feature = "numtraits")]
+mod numtraits;
 mod ops;
+mod ordered;
+mod rounding;
+pub mod storage;
+mod tables;
 mod to_str;
+mod trig;
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+pub use ordered::Ordered;
+pub use crate::rounding::{RoundingMode, Status};
+use storage::{Storage, Width};
 
-#[derive(Clone, Copy, Debug)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "borsh")]
+use ::{
+    alloc::string::ToString,
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+};
+
+/// A binary floating point number with a width of `W` bytes and `MB` mantissa bits, laid out in the same way as the IEEE 754 binary interchange formats.
+///
+/// The bits are stored in the integer type chosen by [`Storage`] for `Width<W>`, which uses 64 bit digits when `W` is a multiple of 8. See the [`storage`] module for details. Generic code over `Float<W, MB>` needs the bound `Width<W>: Storage`.
+#[derive(Clone, Copy)]
 #[repr(transparent)]
-pub struct Float<const W: usize, const MB: usize> {
-    bits: BUintD8<W>,
+pub struct Float<const W: usize, const MB: usize>
+where
+    Width<W>: Storage,
+{
+    bits: storage::Bits<W>,
+}
+
+// the serialized form is that of the `BUintD8<W>` returned by `to_bits`, which does not depend on the storage
+#[cfg(feature = "borsh")]
+impl<const W: usize, const MB: usize> BorshSerialize for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn serialize<Wr: borsh::io::Write>(&self, writer: &mut Wr) -> borsh::io::Result<()> {
+        self.to_bits().serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const W: usize, const MB: usize> BorshDeserialize for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        BUintD8::<W>::deserialize_reader(reader).map(Self::from_bits)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const W: usize, const MB: usize> BorshSchema for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    fn add_definitions_recursively(
+        definitions: &mut alloc::collections::BTreeMap<borsh::schema::Declaration, borsh::schema::Definition>,
+    ) {
+        let fields = borsh::schema::Fields::NamedFields(vec![(
+            "bits".to_string(),
+            BUintD8::<W>::declaration(),
+        )]);
+        let no_recursion_flag = definitions.get(&Self::declaration()).is_none();
+        borsh::schema::add_definition(
+            Self::declaration(),
+            borsh::schema::Definition::Struct { fields },
+            definitions,
+        );
+        if no_recursion_flag {
+            BUintD8::<W>::add_definitions_recursively(definitions);
+        }
+    }
+
+    #[inline]
+    fn declaration() -> borsh::schema::Declaration {
+        "Float".to_string()
+    }
 }
 
-// TODO: implement rand traits
+#[cfg(feature = "arbitrary")]
+impl<'a, const W: usize, const MB: usize> arbitrary::Arbitrary<'a> for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        BUintD8::<W>::arbitrary(u).map(Self::from_bits)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        BUintD8::<W>::size_hint(depth)
+    }
+}
 
-impl<const W: usize, const MB: usize> Float<W, MB> {
+#[cfg(feature = "zeroize")]
+impl<const W: usize, const MB: usize> zeroize::DefaultIsZeroes for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+}
+
+#[cfg(any(test, feature = "quickcheck"))]
+impl<const W: usize, const MB: usize> quickcheck::Arbitrary for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        // special values are chosen often, as they are unlikely to be produced from random bits
+        if <u8 as quickcheck::Arbitrary>::arbitrary(g) % 4 == 0 {
+            return *g
+                .choose(&[
+                    Self::ZERO,
+                    Self::NEG_ZERO,
+                    Self::ONE,
+                    Self::NEG_ONE,
+                    Self::INFINITY,
+                    Self::NEG_INFINITY,
+                    Self::NAN,
+                    Self::MIN,
+                    Self::MAX,
+                    Self::MIN_POSITIVE,
+                    Self::MIN_POSITIVE_SUBNORMAL,
+                    Self::MAX_SUBNORMAL,
+                ])
+                .unwrap();
+        }
+        Self::from_bits(<BUintD8<W> as quickcheck::Arbitrary>::arbitrary(g))
+    }
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     const MB: ExpType = MB as _;
     const BITS: ExpType = BUintD8::<W>::BITS;
 
@@ -51,7 +187,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 
     const EXPONENT_MASK: BUintD8<W> = BUintD8::MAX.wrapping_shl(Self::MB) ^ BIntD8::MIN.to_bits();*/
 
-    pub fn parse(digits: &[u8], exp: i32) -> Self where [u8; W * 2]: {
+    pub fn parse(digits: &[u8], exp: i32) -> Self where [(); (W + 3) / 4]: {
         let one = Self::ONE;
         let two = Self::TWO;
         let three: Self = 3u8.as_();
@@ -75,32 +211,18 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     }
 }
 
-#[test]
-fn test_parse() {
-    use core::str::FromStr;
-
-    let digits = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8];
-    let parsed = F32::parse(&digits, 0);
-    let mut s = unsafe { String::from_utf8_unchecked(digits.into_iter().map(|d| d + 48).collect()) };
-    s.insert(1, '.');
-    s.push_str("");
-    println!("{}", s);
-    println!("{:032b}", parsed.to_bits());
-    println!("{:032b}", f32::from_str(&s).unwrap().to_bits());
-    println!("F: {}", parsed.to_f32());
-    println!("f: {}", f32::from_str(&s).unwrap());
-    println!("n: {}", <f32 as num_traits::Num>::from_str_radix(&s, 10).unwrap());
-}
-
-impl<const W: usize, const MB: usize> Float<W, MB> {
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     #[inline(always)]
     const fn from_words(words: [Digit; W]) -> Self {
         Self::from_bits(BUintD8::from_digits(words))
     }
 
     #[inline(always)]
-    const fn words(&self) -> &[Digit; W] {
-        &self.bits.digits
+    const fn words(&self) -> [Digit; W] {
+        self.to_bits().digits
     }
 
     #[inline]
@@ -132,7 +254,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 
     #[inline]
     pub const fn copysign(self, sign: Self) -> Self {
-        let mut self_words = *self.words();
+        let mut self_words = self.words();
         if sign.is_sign_negative() {
             self_words[W - 1] |= 1 << (digit::BITS - 1);
         } else {
@@ -196,20 +318,27 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     }
 }
 
-impl<const W: usize, const MB: usize> Default for Float<W, MB> {
+impl<const W: usize, const MB: usize> Default for Float<W, MB>
+where
+    Width<W>: Storage,
+{
     #[inline]
     fn default() -> Self {
         Self::ZERO
     }
 }
 
-impl<const W: usize, const MB: usize> Float<W, MB> {
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
     // split into sign, exponent and mantissa
     #[inline]
     const fn to_raw_parts(self) -> (bool, BUintD8<W>, BUintD8<W>) {
         let sign = self.is_sign_negative();
-        let exp = self.bits.bitand(BIntD8::<W>::MAX.to_bits()).shr(Self::MB);
-        let mant = self.bits.bitand(Self::MANTISSA_MASK);
+        let bits = self.to_bits();
+        let exp = bits.bitand(BIntD8::<W>::MAX.to_bits()).shr(Self::MB);
+        let mant = bits.bitand(Self::MANTISSA_MASK);
 
         (sign, exp, mant)
     }
@@ -232,6 +361,11 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         (sign, BIntD8::from_bits(exp).sub(Self::EXP_BIAS), mant)
     }*/
 
+    #[inline]
+    const fn is_signalling_nan(self) -> bool {
+        self.is_nan() && !self.to_bits().bit(Self::MB - 1)
+    }
+
     // construct float from sign, exponent and mantissa
     #[inline]
     const fn from_raw_parts(sign: bool, exp: BUintD8<W>, mant: BUintD8<W>) -> Self {
@@ -255,7 +389,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 
     #[inline]
     const fn exp_mant(&self) -> (BUintD8<W>, BUintD8<W>) {
-        let bits = self.bits;
+        let bits = self.to_bits();
         let exp = (bits.shl(1)).shr(Self::MB + 1);
         let mant = bits.bitand(Self::MANTISSA_MASK);
 
@@ -281,6 +415,13 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         (mant, exp)
     }*/
 
+    /// Returns `mant * 2^exp`, rounded to nearest with ties to even.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub(crate) const fn from_scaled_int(mant: BUintD8<W>, exp: i64) -> Self {
+        extended::Extended::<W>::from_parts(false, exp, mant).to_float()
+    }
+
     #[inline]
     const fn from_exp_mant(negative: bool, exp: BUintD8<W>, mant: BUintD8<W>) -> Self {
         let mut bits = (exp.shl(Self::MB)).bitor(mant);
@@ -292,22 +433,6 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     }
 }
 
-#[cfg(test)]
-impl From<f64> for F64 {
-    #[inline]
-    fn from(f: f64) -> Self {
-        Self::from_bits(f.to_bits().into())
-    }
-}
-
-#[cfg(test)]
-impl From<f32> for F32 {
-    #[inline]
-    fn from(f: f32) -> Self {
-        Self::from_bits(f.to_bits().into())
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::test::test_bignum;
@@ -322,11 +447,13 @@ mod tests {
     }
 
     test_bignum! {
-        function: <ftest>::next_up(f: ftest)
+        function: <ftest>::next_up(f: ftest),
+        cases: [(0.0 as ftest), (-0.0 as ftest), (ftest::MAX), (ftest::NEG_INFINITY), (-ftest::MIN_POSITIVE), (-ftest::from_bits(1))]
     }
 
     test_bignum! {
-        function: <ftest>::next_down(f: ftest)
+        function: <ftest>::next_down(f: ftest),
+        cases: [(0.0 as ftest), (-0.0 as ftest), (ftest::MIN), (ftest::INFINITY), (ftest::MIN_POSITIVE), (ftest::from_bits(1))]
     }
 }
 
@@ -0,0 +1,447 @@
+use super::Float;
+use super::storage::{Storage, Width};
+use crate::cast::CastFrom;
+use crate::errors::ParseFloatError;
+use crate::{BInt, BUint};
+use core::num::FpCategory;
+use num_traits::{
+    Bounded, FloatConst, FromPrimitive, MulAdd, MulAddAssign, Num, NumCast, One, Pow, Signed,
+    ToPrimitive, Zero,
+};
+
+impl<const W: usize, const MB: usize> Bounded for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const W: usize, const MB: usize> Zero for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self)
+    }
+}
+
+impl<const W: usize, const MB: usize> One for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<const W: usize, const MB: usize> Num for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    type FromStrRadixErr = ParseFloatError;
+
+    #[inline]
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFloatError> {
+        Self::from_str_radix(src, radix)
+    }
+}
+
+/// Returns `true` if `f` is a finite integer.
+#[inline]
+fn is_integral(f: f64) -> bool {
+    const LIMIT: f64 = 170141183460469231731687303715884105728.0; // 2^127
+    if !f.is_finite() {
+        return false;
+    }
+    // all floats with magnitude at least 2^127 are integers, and all others can be cast to `i128` without saturating
+    f >= LIMIT || f <= -LIMIT || (f as i128) as f64 == f
+}
+
+impl<const W: usize, const MB: usize> NumCast for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Converts `n` to a float via [`ToPrimitive::to_f64`]. If `n` is an integer and fits in a 128 bit integer, it is converted exactly via [`ToPrimitive::to_i128`] or [`ToPrimitive::to_u128`] instead, so that no precision is lost if the float type has more than 53 bits of precision.
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let f = n.to_f64()?;
+        // zero is excluded so that the sign of `-0.0` is preserved
+        if f != 0.0 && is_integral(f) {
+            let int = if f < 0.0 {
+                n.to_i128().map(Self::cast_from)
+            } else {
+                n.to_u128().map(Self::cast_from)
+            };
+            if int.is_some() {
+                return int;
+            }
+        }
+        Some(Self::cast_from(f))
+    }
+}
+
+macro_rules! from_primitive {
+    ($($method: ident ($primitive: ty)), *) => {
+        $(
+            #[inline]
+            fn $method(n: $primitive) -> Option<Self> {
+                Some(Self::cast_from(n))
+            }
+        )*
+    };
+}
+
+impl<const W: usize, const MB: usize> FromPrimitive for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    from_primitive!(
+        from_i8(i8), from_i16(i16), from_i32(i32), from_i64(i64), from_i128(i128), from_isize(isize),
+        from_u8(u8), from_u16(u16), from_u32(u32), from_u64(u64), from_u128(u128), from_usize(usize),
+        from_f32(f32), from_f64(f64)
+    );
+}
+
+macro_rules! to_int {
+    ($via: ident, $wide: ty; $($method: ident -> $int: ty), *) => {
+        $(
+            /// Truncates `self` toward zero and converts it to an integer, returning `None` if `self` is NaN, infinite or out of range of the integer type.
+            #[inline]
+            fn $method(&self) -> Option<$int> {
+                let int = $via::<2>::try_from(self.trunc()).ok()?;
+                <$int>::try_from(<$wide>::cast_from(int)).ok()
+            }
+        )*
+    };
+}
+
+impl<const W: usize, const MB: usize> ToPrimitive for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    to_int!(BInt, i128; to_i8 -> i8, to_i16 -> i16, to_i32 -> i32, to_i64 -> i64, to_i128 -> i128, to_isize -> isize);
+    to_int!(BUint, u128; to_u8 -> u8, to_u16 -> u16, to_u32 -> u32, to_u64 -> u64, to_u128 -> u128, to_usize -> usize);
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::cast_from(*self))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::cast_from(*self))
+    }
+}
+
+impl<const W: usize, const MB: usize> Signed for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    #[inline]
+    fn abs(&self) -> Self {
+        Self::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        Self::signum(*self)
+    }
+
+    /// Returns `true` if the sign bit of `self` is not set, including for `+0.0`, `INFINITY` and positive NaNs.
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.is_sign_positive()
+    }
+
+    /// Returns `true` if the sign bit of `self` is set, including for `-0.0`, `NEG_INFINITY` and negative NaNs.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.is_sign_negative()
+    }
+}
+
+macro_rules! pow_int {
+    ($($int: ty), *) => {
+        $(
+            impl<const W: usize, const MB: usize> Pow<$int> for Float<W, MB>
+            where
+                Width<W>: Storage,
+                [(); (W + 3) / 4]:,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn pow(self, n: $int) -> Self {
+                    self.powi(n as i32)
+                }
+            }
+        )*
+    };
+}
+
+pow_int!(i8, u8, i16, u16, i32);
+
+impl<const W: usize, const MB: usize> Pow<Self> for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, n: Self) -> Self {
+        self.powf(n)
+    }
+}
+
+impl<const W: usize, const MB: usize> MulAdd for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+}
+
+impl<const W: usize, const MB: usize> MulAddAssign for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    #[inline]
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        *self = Self::mul_add(*self, a, b);
+    }
+}
+
+macro_rules! float_consts {
+    ($($name: ident), *) => {
+        $(
+            #[inline]
+            fn $name() -> Self {
+                Self::$name
+            }
+        )*
+    };
+}
+
+impl<const W: usize, const MB: usize> FloatConst for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    float_consts!(
+        E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4,
+        FRAC_PI_6, FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG2_E, PI, SQRT_2, TAU, LOG10_2, LOG2_10
+    );
+}
+
+macro_rules! delegate {
+    ($($method: ident ($($arg: ident : $ty: ty), *) -> $ret: ty), *) => {
+        $(
+            #[inline]
+            fn $method(self $(, $arg: $ty)*) -> $ret {
+                Self::$method(self $(, $arg)*)
+            }
+        )*
+    };
+}
+
+impl<const W: usize, const MB: usize> num_traits::Float for Float<W, MB>
+where
+    Width<W>: Storage,
+    [(); (W + 3) / 4]:,
+{
+    #[inline]
+    fn nan() -> Self {
+        Self::NAN
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        Self::INFINITY
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        Self::NEG_ZERO
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        Self::MIN_POSITIVE
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    delegate!(
+        is_nan() -> bool, is_infinite() -> bool, is_finite() -> bool, is_normal() -> bool,
+        is_subnormal() -> bool, classify() -> FpCategory, is_sign_positive() -> bool,
+        is_sign_negative() -> bool,
+        floor() -> Self, ceil() -> Self, round() -> Self, trunc() -> Self, fract() -> Self,
+        abs() -> Self, signum() -> Self, copysign(sign: Self) -> Self,
+        mul_add(a: Self, b: Self) -> Self, powi(n: i32) -> Self, powf(n: Self) -> Self,
+        sqrt() -> Self, cbrt() -> Self, hypot(other: Self) -> Self,
+        exp() -> Self, exp2() -> Self, exp_m1() -> Self,
+        ln() -> Self, log(base: Self) -> Self, log2() -> Self, log10() -> Self, ln_1p() -> Self,
+        to_degrees() -> Self, to_radians() -> Self,
+        max(other: Self) -> Self, min(other: Self) -> Self, clamp(min: Self, max: Self) -> Self,
+        sin() -> Self, cos() -> Self, tan() -> Self, sin_cos() -> (Self, Self),
+        asin() -> Self, acos() -> Self, atan() -> Self, atan2(other: Self) -> Self,
+        sinh() -> Self, cosh() -> Self, tanh() -> Self, asinh() -> Self, acosh() -> Self, atanh() -> Self
+    );
+
+    #[inline]
+    fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Self) -> Self {
+        <Self as Signed>::abs_sub(&self, &other)
+    }
+
+    /// Returns the mantissa, base 2 exponent and sign of `self`, such that `self = sign * mantissa * 2^exponent`, in the same way as for the primitive float types.
+    ///
+    /// If the mantissa needs more than 64 bits, its least significant bits are discarded, and if the exponent does not fit in an `i16`, it saturates. So for types with more than 63 mantissa bits or more than 15 exponent bits, the decoded value may not be exactly equal to `self`.
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let sign = if self.is_sign_negative() { -1 } else { 1 };
+        let (_, exp, mant) = self.to_raw_parts();
+        let bias = crate::words::buint_to_i64(Self::EXP_BIAS.to_bits());
+        let (mant, exp) = if exp.is_zero() {
+            (mant.shl(1), -bias - MB as i64)
+        } else {
+            (
+                mant.bitor(crate::BUintD8::ONE.shl(Self::MB)),
+                crate::words::buint_to_i64(exp) - bias - MB as i64,
+            )
+        };
+        let discard = (crate::BUintD8::<W>::BITS - mant.leading_zeros()).saturating_sub(64);
+        let exp = (exp + discard as i64).clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+        (u64::cast_from(mant.shr(discard)), exp, sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::types::{ftest, FTEST};
+    use crate::test::{test_bignum, TestConvert};
+    use num_traits::{Float as NumFloat, FromPrimitive, NumCast, Pow, Signed, ToPrimitive};
+
+    test_bignum! {
+        function: <ftest as ToPrimitive>::to_i8(f: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as ToPrimitive>::to_i64(f: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as ToPrimitive>::to_i128(f: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as ToPrimitive>::to_u16(f: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as ToPrimitive>::to_u128(f: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as ToPrimitive>::to_f32(f: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as ToPrimitive>::to_f64(f: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as FromPrimitive>::from_i128(i: i128)
+    }
+    test_bignum! {
+        function: <ftest as FromPrimitive>::from_f64(f: f64)
+    }
+    test_bignum! {
+        function: <ftest as Signed>::abs_sub(a: ref &ftest, b: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as Signed>::is_positive(a: ref &ftest)
+    }
+    test_bignum! {
+        function: <ftest as Pow<i16> >::pow(a: ftest, n: i16)
+    }
+    test_bignum! {
+        function: <ftest as NumFloat>::recip(a: ftest)
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_integer_decode(f: ftest) -> bool {
+            NumFloat::integer_decode(<FTEST as From<ftest>>::from(f)) == NumFloat::integer_decode(f)
+        }
+
+        fn quickcheck_num_cast_u128(u: u128) -> bool {
+            let big: FTEST = NumCast::from(u).unwrap();
+            let primitive: ftest = NumCast::from(u).unwrap();
+            TestConvert::into(big) == TestConvert::into(primitive)
+        }
+
+        fn quickcheck_num_cast_f64(f: f64) -> bool {
+            let big: FTEST = NumCast::from(f).unwrap();
+            let primitive: ftest = NumCast::from(f).unwrap();
+            TestConvert::into(big) == TestConvert::into(primitive)
+        }
+    }
+
+    #[test]
+    fn num_cast_exact() {
+        use crate::Float;
+
+        let big: Float<16, 112> = NumCast::from(u128::MAX).unwrap();
+        assert_eq!(big, Float::from_str_radix("340282366920938463463374607431768211455", 10).unwrap());
+        let int: Float<16, 112> = NumCast::from(-(1i128 << 100) - 1).unwrap();
+        assert_eq!(int.to_i128(), Some(-(1i128 << 100) - 1));
+    }
+}
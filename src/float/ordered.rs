@@ -0,0 +1,214 @@
+use super::Float;
+use super::storage::{Storage, Width};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter, LowerExp, UpperExp};
+use core::hash::{Hash, Hasher};
+
+/// Wrapper around a [`Float`] which is totally ordered, so that it can be used as a key in a `BTreeMap` or `HashMap`, or be sorted with `sort`.
+///
+/// Comparisons use the IEEE 754 `totalOrder` predicate (see [`Float::total_cmp`]), which orders values as follows:
+///
+/// - negative quiet NaNs
+/// - negative signalling NaNs
+/// - negative infinity
+/// - negative numbers
+/// - negative subnormal numbers
+/// - negative zero
+/// - positive zero
+/// - positive subnormal numbers
+/// - positive numbers
+/// - positive infinity
+/// - positive signalling NaNs
+/// - positive quiet NaNs
+///
+/// Under this order, two values are equal exactly when they have the same bit pattern. In particular, `-0.0` and `+0.0` are distinct, as are NaNs with different signs or payloads. [`Hash`] is implemented by hashing the bits of the wrapped float, so is consistent with equality.
+///
+/// The `min`, `max` and `clamp` methods of the [`Ord`] trait also use this order, so for example the maximum of a positive NaN and any other value is the NaN, but the maximum of a negative NaN and any other value is the other value. For the IEEE 754-2019 operations, use the methods of the wrapped float: [`Float::min`], [`Float::max`], [`Float::minimum`], [`Float::maximum`] and [`Float::clamp`].
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Ordered<T>(pub T);
+
+impl<T> Ordered<T> {
+    /// Wraps `value`.
+    #[must_use]
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<const W: usize, const MB: usize> Ordered<Float<W, MB>>
+where
+    Width<W>: Storage,
+{
+    /// Returns the wrapped float.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> Float<W, MB> {
+        self.0
+    }
+}
+
+impl<const W: usize, const MB: usize> From<Float<W, MB>> for Ordered<Float<W, MB>>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn from(value: Float<W, MB>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const W: usize, const MB: usize> From<Ordered<Float<W, MB>>> for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn from(value: Ordered<Float<W, MB>>) -> Self {
+        value.0
+    }
+}
+
+impl<const W: usize, const MB: usize> PartialEq for Ordered<Float<W, MB>>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl<const W: usize, const MB: usize> Eq for Ordered<Float<W, MB>>
+where
+    Width<W>: Storage,
+{
+}
+
+impl<const W: usize, const MB: usize> PartialOrd for Ordered<Float<W, MB>>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const W: usize, const MB: usize> Ord for Ordered<Float<W, MB>>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<const W: usize, const MB: usize> Hash for Ordered<Float<W, MB>>
+where
+    Width<W>: Storage,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+macro_rules! fmt_trait {
+    ($($Trait: ident), *) => {
+        $(
+            impl<const W: usize, const MB: usize> $Trait for Ordered<Float<W, MB>>
+            where
+                Width<W>: Storage,
+            {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    $Trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+fmt_trait!(Display, LowerExp, UpperExp);
+
+#[cfg(test)]
+mod tests {
+    use super::Ordered;
+    use crate::test::types::{ftest, FTEST};
+    use alloc::collections::BTreeSet;
+    use alloc::vec::Vec;
+    use core::hash::{Hash, Hasher};
+
+    /// Records the bytes written to it, so that hashes can be compared exactly.
+    #[derive(Default)]
+    struct RecordingHasher(Vec<u8>);
+
+    impl Hasher for RecordingHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+
+    fn hash(f: Ordered<FTEST>) -> Vec<u8> {
+        let mut hasher = RecordingHasher::default();
+        f.hash(&mut hasher);
+        hasher.0
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_ordered_cmp(a: ftest, b: ftest) -> bool {
+            let (big_a, big_b) = (Ordered(FTEST::from(a)), Ordered(FTEST::from(b)));
+            big_a.cmp(&big_b) == a.total_cmp(&b) && (big_a == big_b) == (a.to_bits() == b.to_bits())
+        }
+
+        fn quickcheck_ordered_hash(a: ftest, b: ftest) -> bool {
+            let (big_a, big_b) = (Ordered(FTEST::from(a)), Ordered(FTEST::from(b)));
+            (big_a == big_b) == (hash(big_a) == hash(big_b))
+        }
+    }
+
+    #[test]
+    fn ordered_btree_set() {
+        let values = [
+            FTEST::NAN,
+            FTEST::NEG_ZERO,
+            FTEST::ONE,
+            FTEST::ZERO,
+            FTEST::NEG_NAN,
+            FTEST::NEG_INFINITY,
+            FTEST::ZERO,
+            FTEST::NAN,
+        ];
+        let set: BTreeSet<_> = values.into_iter().map(Ordered).collect();
+        let sorted: Vec<_> = set.into_iter().map(Ordered::get).collect();
+        let expected = [
+            FTEST::NEG_NAN,
+            FTEST::NEG_INFINITY,
+            FTEST::NEG_ZERO,
+            FTEST::ZERO,
+            FTEST::ONE,
+            FTEST::NAN,
+        ];
+        assert_eq!(sorted.len(), expected.len());
+        for (a, b) in sorted.into_iter().zip(expected) {
+            assert_eq!(a.to_bits(), b.to_bits());
+        }
+    }
+
+    #[test]
+    fn ordered_min_max() {
+        assert_eq!(Ordered(FTEST::NEG_ZERO).max(Ordered(FTEST::ZERO)), Ordered(FTEST::ZERO));
+        assert_eq!(Ordered(FTEST::NAN).max(Ordered(FTEST::INFINITY)), Ordered(FTEST::NAN));
+        assert_eq!(Ordered(FTEST::NEG_NAN).max(Ordered(FTEST::ONE)), Ordered(FTEST::ONE));
+        assert_eq!(
+            Ordered(FTEST::NEG_ZERO).clamp(Ordered(FTEST::ZERO), Ordered(FTEST::ONE)),
+            Ordered(FTEST::ZERO)
+        );
+    }
+}
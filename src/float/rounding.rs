@@ -0,0 +1,444 @@
+use super::extended::Extended;
+use super::Float;
+use super::storage::{Storage, Width};
+use crate::rounding::{RoundingMode, Status};
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Returns NaN, along with the invalid operation flag if either operand is a signalling NaN.
+    #[inline]
+    const fn nan_status(self, rhs: Self) -> (Self, Status) {
+        if self.is_signalling_nan() || rhs.is_signalling_nan() {
+            (Self::NAN, Status::INVALID_OP)
+        } else {
+            (Self::NAN, Status::OK)
+        }
+    }
+
+    /// Computes `self + rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    pub const fn add_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status)
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if self.is_nan() || rhs.is_nan() {
+            return self.nan_status(rhs);
+        }
+        match (self.is_infinite(), rhs.is_infinite()) {
+            (true, true) => {
+                if self.is_sign_negative() == rhs.is_sign_negative() {
+                    return (self, Status::OK);
+                }
+                return (Self::NAN, Status::INVALID_OP);
+            }
+            (true, false) => return (self, Status::OK),
+            (false, true) => return (rhs, Status::OK),
+            (false, false) => {}
+        }
+        let sum = Extended::<{ (W + 3) / 4 }>::from_float(self).add(Extended::from_float(rhs));
+        if sum.is_zero() {
+            // an exact zero sum is positive unless both operands are negative, or the rounding mode is toward negative infinity
+            let negative = if self.is_zero()
+                && rhs.is_zero()
+                && self.is_sign_negative() == rhs.is_sign_negative()
+            {
+                self.is_sign_negative()
+            } else {
+                matches!(mode, RoundingMode::TowardNegative)
+            };
+            let zero = if negative { Self::NEG_ZERO } else { Self::ZERO };
+            return (zero, Status::OK);
+        }
+        sum.to_float_round(mode)
+    }
+
+    /// Computes `self - rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    #[inline]
+    pub const fn sub_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status)
+    where
+        [(); (W + 3) / 4]:,
+    {
+        self.add_round(rhs.neg(), mode)
+    }
+
+    /// Computes `self * rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    pub const fn mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status)
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if self.is_nan() || rhs.is_nan() {
+            return self.nan_status(rhs);
+        }
+        let negative = self.is_sign_negative() ^ rhs.is_sign_negative();
+        if self.is_infinite() || rhs.is_infinite() {
+            if self.is_zero() || rhs.is_zero() {
+                return (Self::NAN, Status::INVALID_OP);
+            }
+            return (Self::INFINITY.with_sign(negative), Status::OK);
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .mul(Extended::from_float(rhs))
+            .to_float_round(mode)
+    }
+
+    /// Computes `self / rhs`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    pub const fn div_round(self, rhs: Self, mode: RoundingMode) -> (Self, Status)
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if self.is_nan() || rhs.is_nan() {
+            return self.nan_status(rhs);
+        }
+        let negative = self.is_sign_negative() ^ rhs.is_sign_negative();
+        match (self.is_infinite(), rhs.is_infinite()) {
+            (true, true) => return (Self::NAN, Status::INVALID_OP),
+            (true, false) => return (Self::INFINITY.with_sign(negative), Status::OK),
+            (false, true) => return (Self::ZERO.with_sign(negative), Status::OK),
+            (false, false) => {}
+        }
+        if rhs.is_zero() {
+            if self.is_zero() {
+                return (Self::NAN, Status::INVALID_OP);
+            }
+            return (Self::INFINITY.with_sign(negative), Status::DIV_BY_ZERO);
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .div(Extended::from_float(rhs))
+            .to_float_round(mode)
+    }
+
+    /// Computes the square root of `self`, rounding the result according to `mode`. Returns the result along with the exception flags raised by the operation.
+    pub const fn sqrt_round(self, mode: RoundingMode) -> (Self, Status)
+    where
+        [(); (W + 3) / 4]:,
+    {
+        if self.is_nan() {
+            return self.nan_status(self);
+        }
+        if self.is_zero() || (self.is_infinite() && self.is_sign_positive()) {
+            return (self, Status::OK);
+        }
+        if self.is_sign_negative() {
+            return (Self::NAN, Status::INVALID_OP);
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .sqrt()
+            .to_float_round(mode)
+    }
+
+    #[inline]
+    pub(super) const fn with_sign(self, negative: bool) -> Self {
+        if negative {
+            self.neg()
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RoundingMode, Status};
+    use crate::test::types::{ftest, FTEST};
+    use crate::test::TestConvert;
+    use quickcheck::{Arbitrary, Gen};
+
+    impl Arbitrary for RoundingMode {
+        fn arbitrary(g: &mut Gen) -> Self {
+            *g.choose(&[
+                Self::NearestTiesToEven,
+                Self::NearestTiesToAway,
+                Self::TowardZero,
+                Self::TowardPositive,
+                Self::TowardNegative,
+            ])
+            .unwrap()
+        }
+    }
+
+    /// Rounds the exact value `nearest + err` according to `mode`, where `nearest` is the exact value rounded to nearest, ties to even.
+    fn round(nearest: ftest, err: ftest, mode: RoundingMode) -> ftest {
+        if err == 0.0 {
+            return nearest;
+        }
+        let neighbour = if err > 0.0 {
+            nearest.next_up()
+        } else {
+            nearest.next_down()
+        };
+        let away = nearest.is_sign_negative() == (err < 0.0);
+        match mode {
+            RoundingMode::NearestTiesToEven => nearest,
+            RoundingMode::NearestTiesToAway => {
+                if away && neighbour - nearest == err * 2.0 {
+                    neighbour
+                } else {
+                    nearest
+                }
+            }
+            RoundingMode::TowardZero => {
+                if away {
+                    nearest
+                } else {
+                    neighbour
+                }
+            }
+            RoundingMode::TowardPositive => {
+                if err > 0.0 {
+                    neighbour
+                } else {
+                    nearest
+                }
+            }
+            RoundingMode::TowardNegative => {
+                if err < 0.0 {
+                    neighbour
+                } else {
+                    nearest
+                }
+            }
+        }
+    }
+
+    /// Checks the result of a rounded operation against `nearest + err`, which is the exact result of the operation, where `nearest` is the result of the primitive operation.
+    fn check(
+        result: (FTEST, Status),
+        nearest: ftest,
+        err: ftest,
+        mode: RoundingMode,
+    ) -> quickcheck::TestResult {
+        // the error terms are only exact if the result is not too close to the subnormal range, and no intermediate calculation overflows
+        if !nearest.is_finite()
+            || !err.is_finite()
+            || nearest.abs() < ftest::MIN_POSITIVE / (ftest::EPSILON * ftest::EPSILON)
+        {
+            return quickcheck::TestResult::discard();
+        }
+        let (value, status) = result;
+        let value = ftest::from_bits(TestConvert::into(value));
+        let expected = round(nearest, err, mode);
+        quickcheck::TestResult::from_bool(
+            value.to_bits() == expected.to_bits()
+                && status.contains(Status::INEXACT) == (err != 0.0)
+                && !status.contains(Status::UNDERFLOW),
+        )
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_ftest_add_round(a: ftest, b: ftest, mode: RoundingMode) -> quickcheck::TestResult {
+            let s = a + b;
+            // the two-sum algorithm computes the rounding error of `a + b` exactly
+            let bb = s - a;
+            let err = (a - (s - bb)) + (b - bb);
+            check(FTEST::from(a).add_round(FTEST::from(b), mode), s, err, mode)
+        }
+
+        fn quickcheck_ftest_sub_round(a: ftest, b: ftest, mode: RoundingMode) -> quickcheck::TestResult {
+            let s = a - b;
+            let bb = s - a;
+            let err = (a - (s - bb)) + (-b - bb);
+            check(FTEST::from(a).sub_round(FTEST::from(b), mode), s, err, mode)
+        }
+
+        fn quickcheck_ftest_mul_round(a: ftest, b: ftest, mode: RoundingMode) -> quickcheck::TestResult {
+            let p = a * b;
+            let err = a.mul_add(b, -p);
+            check(FTEST::from(a).mul_round(FTEST::from(b), mode), p, err, mode)
+        }
+
+        fn quickcheck_ftest_div_round(a: ftest, b: ftest, mode: RoundingMode) -> quickcheck::TestResult {
+            if a.abs() < ftest::MIN_POSITIVE / (ftest::EPSILON * ftest::EPSILON) {
+                return quickcheck::TestResult::discard();
+            }
+            let q = a / b;
+            // `a - q * b` is exact, and has the same sign as `a / b - q` if `b` is positive
+            let rem = (-q).mul_add(b, a);
+            let err = if b.is_sign_negative() { -rem } else { rem };
+            check(FTEST::from(a).div_round(FTEST::from(b), mode), q, err, mode)
+        }
+
+        fn quickcheck_ftest_sqrt_round(a: ftest, mode: RoundingMode) -> quickcheck::TestResult {
+            if a.abs() < ftest::MIN_POSITIVE / (ftest::EPSILON * ftest::EPSILON) {
+                return quickcheck::TestResult::discard();
+            }
+            let s = a.sqrt();
+            let err = (-s).mul_add(s, a);
+            check(FTEST::from(a).sqrt_round(mode), s, err, mode)
+        }
+    }
+
+    fn to_primitive(result: (FTEST, Status)) -> (ftest, Status) {
+        (ftest::from_bits(TestConvert::into(result.0)), result.1)
+    }
+
+    fn assert_result(result: (FTEST, Status), expected: ftest, status: Status) {
+        let (value, s) = to_primitive(result);
+        assert!(
+            value.to_bits() == expected.to_bits() || (value.is_nan() && expected.is_nan()),
+            "{} != {}",
+            value,
+            expected
+        );
+        assert_eq!(s, status);
+    }
+
+    #[test]
+    fn special_cases() {
+        use RoundingMode::*;
+
+        let f = FTEST::from;
+        let inf = ftest::INFINITY;
+        let inexact = Status::INEXACT;
+        let overflow = Status::OVERFLOW | Status::INEXACT;
+        let underflow = Status::UNDERFLOW | Status::INEXACT;
+
+        // signs of exact zero results
+        assert_result(
+            f(1.0).add_round(f(-1.0), NearestTiesToEven),
+            0.0,
+            Status::OK,
+        );
+        assert_result(f(1.0).add_round(f(-1.0), TowardNegative), -0.0, Status::OK);
+        assert_result(f(-0.0).add_round(f(-0.0), TowardPositive), -0.0, Status::OK);
+        assert_result(f(0.0).sub_round(f(0.0), TowardNegative), -0.0, Status::OK);
+        assert_result(f(-0.0).sqrt_round(TowardNegative), -0.0, Status::OK);
+
+        // overflow
+        assert_result(
+            f(ftest::MAX).add_round(f(ftest::MAX), NearestTiesToEven),
+            inf,
+            overflow,
+        );
+        assert_result(
+            f(ftest::MAX).add_round(f(ftest::MAX), TowardZero),
+            ftest::MAX,
+            overflow,
+        );
+        assert_result(
+            f(ftest::MAX).mul_round(f(-2.0), TowardPositive),
+            ftest::MIN,
+            overflow,
+        );
+        assert_result(
+            f(ftest::MAX).mul_round(f(-2.0), TowardNegative),
+            -inf,
+            overflow,
+        );
+        let half_ulp = (ftest::MAX - ftest::MAX.next_down()) / 2.0;
+        assert_result(
+            f(ftest::MAX).add_round(f(half_ulp), NearestTiesToAway),
+            inf,
+            overflow,
+        );
+        assert_result(
+            f(ftest::MAX).add_round(f(half_ulp / 2.0), NearestTiesToAway),
+            ftest::MAX,
+            inexact,
+        );
+
+        // underflow
+        let tiny = ftest::from_bits(1);
+        assert_result(f(tiny).mul_round(f(0.5), NearestTiesToEven), 0.0, underflow);
+        assert_result(
+            f(tiny).mul_round(f(0.5), NearestTiesToAway),
+            tiny,
+            underflow,
+        );
+        assert_result(
+            f(-tiny).mul_round(f(0.25), TowardNegative),
+            -tiny,
+            underflow,
+        );
+        assert_result(f(-tiny).mul_round(f(0.25), TowardZero), -0.0, underflow);
+        assert_result(
+            f(tiny).mul_round(f(2.0), TowardZero),
+            tiny * 2.0,
+            Status::OK,
+        );
+        let halfway = ftest::MIN_POSITIVE.next_up();
+        assert_result(
+            f(halfway).mul_round(f(0.5), TowardPositive),
+            (halfway * 0.5).next_up(),
+            underflow,
+        );
+
+        // invalid operations and division by zero
+        assert_result(
+            f(inf).add_round(f(-inf), NearestTiesToEven),
+            ftest::NAN,
+            Status::INVALID_OP,
+        );
+        assert_result(
+            f(inf).mul_round(f(0.0), NearestTiesToEven),
+            ftest::NAN,
+            Status::INVALID_OP,
+        );
+        assert_result(
+            f(0.0).div_round(f(-0.0), NearestTiesToEven),
+            ftest::NAN,
+            Status::INVALID_OP,
+        );
+        assert_result(
+            f(inf).div_round(f(inf), NearestTiesToEven),
+            ftest::NAN,
+            Status::INVALID_OP,
+        );
+        assert_result(
+            f(-1.0).sqrt_round(NearestTiesToEven),
+            ftest::NAN,
+            Status::INVALID_OP,
+        );
+        assert_result(
+            f(-1.0).div_round(f(0.0), TowardZero),
+            -inf,
+            Status::DIV_BY_ZERO,
+        );
+        assert_result(f(1.0).div_round(f(-inf), TowardZero), -0.0, Status::OK);
+        assert_result(
+            f(ftest::NAN).add_round(f(1.0), TowardZero),
+            ftest::NAN,
+            Status::OK,
+        );
+
+        let snan = FTEST::from_bits(FTEST::INFINITY.to_bits() | crate::BUintD8::ONE);
+        assert_eq!(snan.add_round(FTEST::ONE, TowardZero).1, Status::INVALID_OP);
+        assert_eq!(FTEST::ONE.mul_round(snan, TowardZero).1, Status::INVALID_OP);
+        assert_eq!(snan.sqrt_round(TowardZero).1, Status::INVALID_OP);
+
+        // directed rounding of inexact results
+        assert_result(
+            f(1.0).add_round(f(ftest::EPSILON / 4.0), TowardPositive),
+            1.0 + ftest::EPSILON,
+            inexact,
+        );
+        assert_result(
+            f(-1.0).sub_round(f(ftest::EPSILON / 4.0), TowardZero),
+            -1.0,
+            inexact,
+        );
+        assert_result(
+            f(1.0).add_round(f(ftest::EPSILON / 2.0), NearestTiesToAway),
+            1.0 + ftest::EPSILON,
+            inexact,
+        );
+        assert_result(
+            f(1.0).add_round(f(ftest::EPSILON / 2.0), NearestTiesToEven),
+            1.0,
+            inexact,
+        );
+    }
+
+    #[test]
+    fn status_flags() {
+        let status = Status::INEXACT | Status::OVERFLOW;
+        assert!(status.contains(Status::INEXACT));
+        assert!(status.contains(Status::OVERFLOW | Status::INEXACT));
+        assert!(!status.contains(Status::UNDERFLOW));
+        assert!(status.contains(Status::OK));
+        assert!(!status.is_ok());
+        assert!(Status::default().is_ok());
+        assert_eq!(status & Status::OVERFLOW, Status::OVERFLOW);
+        assert_eq!(RoundingMode::default(), RoundingMode::NearestTiesToEven);
+    }
+}
@@ -0,0 +1,78 @@
+use super::Float;
+use super::storage::{Storage, Width};
+use crate::serde::le_bytes;
+use crate::BUintD8;
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+/// Human-readable formats serialize the float as its shortest round-trip decimal string (the same as its `Display` output), and other formats serialize its raw little-endian bytes.
+impl<const W: usize, const MB: usize> Serialize for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            le_bytes::serialize(&self.to_bits(), serializer)
+        }
+    }
+}
+
+struct FloatVisitor<const W: usize, const MB: usize>(PhantomData<Float<W, MB>>)
+where
+    Width<W>: Storage;
+
+impl<'de, const W: usize, const MB: usize> Visitor<'de> for FloatVisitor<W, MB>
+where
+    Width<W>: Storage,
+{
+    type Value = Float<W, MB>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a string containing a float")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Float::from_str(v).map_err(E::custom)
+    }
+}
+
+/// Human-readable formats deserialize the float from a decimal string, in the format accepted by [`FromStr`], and other formats deserialize it from its raw little-endian bytes.
+impl<'de, const W: usize, const MB: usize> Deserialize<'de> for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FloatVisitor(PhantomData))
+        } else {
+            let bits: BUintD8<W> = le_bytes::deserialize(deserializer)?;
+            Ok(Self::from_bits(bits))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::types::{ftest, FTEST};
+    use alloc::vec::Vec;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_serde_round_trip(a: ftest) -> bool {
+            let f = FTEST::from(a);
+            let mut cbor = Vec::new();
+            ciborium::into_writer(&f, &mut cbor).unwrap();
+            let from_cbor: FTEST = ciborium::from_reader(&cbor[..]).unwrap();
+
+            // the binary representation is a tuple of the little-endian bytes
+            let mut bytes = Vec::new();
+            ciborium::into_writer(&f.to_bits(), &mut bytes).unwrap();
+
+            from_cbor.to_bits() == f.to_bits() && cbor == bytes
+        }
+    }
+}
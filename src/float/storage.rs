@@ -0,0 +1,205 @@
+//! The integer types which store the bits of a [`Float`](super::Float).
+//!
+//! A `Float<W, MB>` stores its bits in the widest of `BUint`, `BUintD32`, `BUintD16` and `BUintD8` whose digits divide its width of `W` bytes. For example, `Float<8, 52>` is stored as a `BUint<1>`, and `Float<2, 10>` as a `BUintD16<1>`. The digits of each of these types are stored in little-endian order, so all of them have the same bit layout as `BUintD8<W>`, which is the type used by [`to_bits`](super::Float::to_bits) and [`from_bits`](super::Float::from_bits). Only the alignment of the float depends on the choice of digit.
+//!
+//! Generic code over `Float<W, MB>` needs the bound `Width<W>: Storage`, which holds for every `W`.
+
+use crate::{BUint, BUintD16, BUintD32, BUintD8};
+
+/// Returns the number of bytes in each digit of the integer which stores a `Float` with a width of `w` bytes.
+#[inline]
+pub const fn digit_bytes(w: usize) -> usize {
+    if w % 8 == 0 {
+        8
+    } else if w % 4 == 0 {
+        4
+    } else if w % 2 == 0 {
+        2
+    } else {
+        1
+    }
+}
+
+/// Marker type for the width of a `Float` in bytes.
+pub struct Width<const W: usize>;
+
+/// Marker type for the number of bytes in each digit of an integer.
+pub struct DigitBytes<const B: usize>;
+
+/// Chooses the integer type which stores the bits of a `Float` with a width of `W` bytes. This is implemented for [`Width<W>`] for every `W`.
+pub trait Storage {
+    /// The integer which stores the bits. This has the same size and bit layout as `BUintD8<W>`.
+    type Bits: Copy;
+}
+
+/// The integer type with `W` bytes whose digits have `Self` bytes.
+pub trait Digits<const W: usize> {
+    /// The integer with `W` bytes whose digits have `Self` bytes.
+    type Bits: Copy;
+}
+
+impl<const W: usize> Digits<W> for DigitBytes<8>
+where
+    [(); W / 8]:,
+{
+    type Bits = BUint<{ W / 8 }>;
+}
+
+impl<const W: usize> Digits<W> for DigitBytes<4>
+where
+    [(); W / 4]:,
+{
+    type Bits = BUintD32<{ W / 4 }>;
+}
+
+impl<const W: usize> Digits<W> for DigitBytes<2>
+where
+    [(); W / 2]:,
+{
+    type Bits = BUintD16<{ W / 2 }>;
+}
+
+impl<const W: usize> Digits<W> for DigitBytes<1> {
+    type Bits = BUintD8<W>;
+}
+
+impl<const W: usize> Storage for Width<W>
+where
+    DigitBytes<{ digit_bytes(W) }>: Digits<W>,
+{
+    type Bits = <DigitBytes<{ digit_bytes(W) }> as Digits<W>>::Bits;
+}
+
+/// The bits of a `Float<W, MB>`, as stored in the struct.
+pub(crate) type Bits<const W: usize> = <Width<W> as Storage>::Bits;
+
+/// Reinterprets the bytes of a value as a value of a different type. Both fields start at the same address.
+union Transmute<T: Copy, U: Copy> {
+    from: T,
+    to: U,
+}
+
+/// Reverses the order of the bytes in each digit of `bytes` on big-endian targets, converting between the in-memory layout of the stored integer and that of `BUintD8<W>`. On little-endian targets, the two layouts are the same and `bytes` is returned unchanged.
+#[inline]
+const fn swap_digit_bytes<const W: usize>(mut bytes: BUintD8<W>) -> BUintD8<W> {
+    if cfg!(target_endian = "big") {
+        let b = digit_bytes(W);
+        let mut i = 0;
+        while i < W {
+            let mut j = 0;
+            while j < b / 2 {
+                let tmp = bytes.digits[i + j];
+                bytes.digits[i + j] = bytes.digits[i + b - 1 - j];
+                bytes.digits[i + b - 1 - j] = tmp;
+                j += 1;
+            }
+            i += b;
+        }
+    }
+    bytes
+}
+
+/// Converts the stored bits of a `Float<W, MB>` to a `BUintD8<W>` with the same value.
+#[inline]
+pub(crate) const fn to_bytes<const W: usize>(bits: Bits<W>) -> BUintD8<W>
+where
+    Width<W>: Storage,
+{
+    // SAFETY: `Bits<W>` and `BUintD8<W>` are both `W` bytes long, and every bit pattern is valid for both
+    swap_digit_bytes(unsafe { Transmute { from: bits }.to })
+}
+
+/// Converts a `BUintD8<W>` to the stored bits of a `Float<W, MB>` with the same value.
+#[inline]
+pub(crate) const fn from_bytes<const W: usize>(bytes: BUintD8<W>) -> Bits<W>
+where
+    Width<W>: Storage,
+{
+    // SAFETY: `Bits<W>` and `BUintD8<W>` are both `W` bytes long, and every bit pattern is valid for both
+    unsafe {
+        Transmute {
+            to: swap_digit_bytes(bytes),
+        }
+        .from
+    }
+}
+
+/// Whether the in-memory layout of the stored bits is the same as that of 64 bit words in little-endian order, which holds if the bits are stored in 64 bit digits or the target is little-endian.
+#[inline]
+const fn words_layout(w: usize) -> bool {
+    digit_bytes(w) == 8 || cfg!(target_endian = "little")
+}
+
+/// Packs the stored bits of a `Float<W, MB>` into 64 bit words, truncating to the least significant `8 * M` bytes. This is a copy if the stored bits already have the layout of 64 bit words.
+#[inline]
+pub(crate) const fn to_words<const W: usize, const M: usize>(bits: Bits<W>) -> BUint<M>
+where
+    Width<W>: Storage,
+{
+    if !words_layout(W) {
+        return crate::words::bytes_to_words(to_bytes::<W>(bits));
+    }
+    let mut t = Transmute { to: BUint::<M>::ZERO };
+    t.from = bits;
+    // SAFETY: the union is initialised to zero, then its first `W` bytes are overwritten by `bits`, so every byte of `t.to` is initialised. Any bytes past the end of `bits` are zero, which pads the value
+    unsafe { t.to }
+}
+
+/// Unpacks 64 bit words into the stored bits of a `Float<W, MB>`, truncating to the least significant `W` bytes. This is a copy if the stored bits have the layout of 64 bit words.
+#[inline]
+pub(crate) const fn from_words<const W: usize, const M: usize>(words: BUint<M>) -> Bits<W>
+where
+    Width<W>: Storage,
+{
+    if !words_layout(W) {
+        return from_bytes::<W>(crate::words::words_to_bytes(words));
+    }
+    let mut t = Transmute { from: from_bytes::<W>(BUintD8::ZERO) };
+    t.to = words;
+    // SAFETY: as in `to_words`, with the roles of the fields swapped
+    unsafe { t.from }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::float::Float;
+
+    fn round_trip<const W: usize, const MB: usize>(bytes: [u8; W]) -> bool
+    where
+        Width<W>: Storage,
+    {
+        let bits = BUintD8::from_digits(bytes);
+        let f = Float::<W, MB>::from_bits(bits);
+        f.to_bits() == bits
+            && to_words::<W, 4>(from_bytes::<W>(bits)) == crate::words::bytes_to_words(bits)
+            && to_bytes::<W>(from_words::<W, 4>(crate::words::bytes_to_words(bits))) == bits
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_storage_round_trip(a: u64, b: u64) -> bool {
+            let mut bytes = [0; 16];
+            bytes[..8].copy_from_slice(&a.to_le_bytes());
+            bytes[8..].copy_from_slice(&b.to_le_bytes());
+            round_trip::<16, 112>(bytes)
+                && round_trip::<8, 52>(bytes[..8].try_into().unwrap())
+                && round_trip::<4, 23>(bytes[..4].try_into().unwrap())
+                && round_trip::<2, 10>(bytes[..2].try_into().unwrap())
+                && round_trip::<3, 15>(bytes[..3].try_into().unwrap())
+                && round_trip::<12, 64>(bytes[..12].try_into().unwrap())
+        }
+    }
+
+    #[test]
+    fn layout() {
+        use core::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<Float<4, 23>>(), 4);
+        assert_eq!(size_of::<Float<3, 15>>(), 3);
+        assert_eq!(size_of::<Float<32, 236>>(), 32);
+        assert_eq!(align_of::<Float<8, 52>>(), align_of::<u64>());
+        assert_eq!(align_of::<Float<4, 23>>(), align_of::<u32>());
+        assert_eq!(align_of::<Float<2, 10>>(), align_of::<u16>());
+        assert_eq!(align_of::<Float<3, 15>>(), 1);
+    }
+}
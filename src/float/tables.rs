@@ -0,0 +1,1566 @@
+// Binary expansions of the mathematical constants used by `Float`. These are used both to compute correctly rounded constants for every precision, and as extended precision operands when evaluating transcendental functions.
+//
+// The tables were generated with mpmath, using a working precision at least 256 bits greater than the number of bits stored. Each constant is stored to `CONST_WORDS * 64 = 8192` bits, except for `TWO_OVER_PI`, which is stored to 270336 bits. Bits past the end of a table are not known, so code which reads the tables must not need more bits than they store: `Extended::from_table` and the `Float` constants assert this at compile time.
+
+/// The number of 64 bit words stored for each constant other than [`TWO_OVER_PI`].
+pub(crate) const CONST_WORDS: usize = 128;
+
+/// The leading bits of the binary expansion of a positive real number `x`, normalised so that `x = 0.b_1 b_2 b_3 ... * 2^exp` with `b_1 = 1`. `bits` stores `b_1, b_2, ...`, most significant bit first.
+pub(crate) struct BitTable {
+    pub exp: i64,
+    pub bits: &'static [u64],
+}
+
+impl BitTable {
+    /// The number of bits stored in the table.
+    #[inline]
+    pub const fn bit_len(&self) -> usize {
+        self.bits.len() * 64
+    }
+
+    /// Returns the 64 bits `b_{start + 1}, ..., b_{start + 64}` as a word, most significant bit first. Bits past the end of the table are returned as zero, so callers must check that they only read bits within [`bit_len`](Self::bit_len).
+    #[inline]
+    pub const fn word(&self, start: usize) -> u64 {
+        let index = start / 64;
+        let offset = start % 64;
+        if index >= self.bits.len() {
+            return 0;
+        }
+        let mut word = self.bits[index] << offset;
+        if offset > 0 && index + 1 < self.bits.len() {
+            word |= self.bits[index + 1] >> (64 - offset);
+        }
+        word
+    }
+}
+
+/// The binary expansion of π.
+pub(crate) const PI: BitTable = BitTable {
+    exp: 2,
+    bits: &[
+        0xc90fdaa22168c234, 0xc4c6628b80dc1cd1, 0x29024e088a67cc74, 0x020bbea63b139b22,
+        0x514a08798e3404dd, 0xef9519b3cd3a431b, 0x302b0a6df25f1437, 0x4fe1356d6d51c245,
+        0xe485b576625e7ec6, 0xf44c42e9a637ed6b, 0x0bff5cb6f406b7ed, 0xee386bfb5a899fa5,
+        0xae9f24117c4b1fe6, 0x49286651ece45b3d, 0xc2007cb8a163bf05, 0x98da48361c55d39a,
+        0x69163fa8fd24cf5f, 0x83655d23dca3ad96, 0x1c62f356208552bb, 0x9ed529077096966d,
+        0x670c354e4abc9804, 0xf1746c08ca18217c, 0x32905e462e36ce3b, 0xe39e772c180e8603,
+        0x9b2783a2ec07a28f, 0xb5c55df06f4c52c9, 0xde2bcbf695581718, 0x3995497cea956ae5,
+        0x15d2261898fa0510, 0x15728e5a8aaac42d, 0xad33170d04507a33, 0xa85521abdf1cba64,
+        0xecfb850458dbef0a, 0x8aea71575d060c7d, 0xb3970f85a6e1e4c7, 0xabf5ae8cdb0933d7,
+        0x1e8c94e04a25619d, 0xcee3d2261ad2ee6b, 0xf12ffa06d98a0864, 0xd87602733ec86a64,
+        0x521f2b18177b200c, 0xbbe117577a615d6c, 0x770988c0bad946e2, 0x08e24fa074e5ab31,
+        0x43db5bfce0fd108e, 0x4b82d120a9210801, 0x1a723c12a787e6d7, 0x88719a10bdba5b26,
+        0x99c327186af4e23c, 0x1a946834b6150bda, 0x2583e9ca2ad44ce8, 0xdbbbc2db04de8ef9,
+        0x2e8efc141fbecaa6, 0x287c59474e6bc05d, 0x99b2964fa090c3a2, 0x233ba186515be7ed,
+        0x1f612970cee2d7af, 0xb81bdd762170481c, 0xd0069127d5b05aa9, 0x93b4ea988d8fddc1,
+        0x86ffb7dc90a6c08f, 0x4df435c934028492, 0x36c3fab4d27c7026, 0xc1d4dcb2602646de,
+        0xc9751e763dba37bd, 0xf8ff9406ad9e530e, 0xe5db382f413001ae, 0xb06a53ed9027d831,
+        0x179727b0865a8918, 0xda3edbebcf9b14ed, 0x44ce6cbaced4bb1b, 0xdb7f1447e6cc254b,
+        0x332051512bd7af42, 0x6fb8f401378cd2bf, 0x5983ca01c64b92ec, 0xf032ea15d1721d03,
+        0xf482d7ce6e74fef6, 0xd55e702f46980c82, 0xb5a84031900b1c9e, 0x59e7c97fbec7e8f3,
+        0x23a97a7e36cc88be, 0x0f1d45b7ff585ac5, 0x4bd407b22b4154aa, 0xcc8f6d7ebf48e1d8,
+        0x14cc5ed20f8037e0, 0xa79715eef29be328, 0x06a1d58bb7c5da76, 0xf550aa3d8a1fbff0,
+        0xeb19ccb1a313d55c, 0xda56c9ec2ef29632, 0x387fe8d76e3c0468, 0x043e8f663f4860ee,
+        0x12bf2d5b0b7474d6, 0xe694f91e6dbe1159, 0x74a3926f12fee5e4, 0x38777cb6a932df8c,
+        0xd8bec4d073b931ba, 0x3bc832b68d9dd300, 0x741fa7bf8afc47ed, 0x2576f6936ba42466,
+        0x3aab639c5ae4f568, 0x3423b4742bf1c978, 0x238f16cbe39d652d, 0xe3fdb8befc848ad9,
+        0x22222e04a4037c07, 0x13eb57a81a23f0c7, 0x3473fc646cea306b, 0x4bcbc8862f8385dd,
+        0xfa9d4b7fa2c087e8, 0x79683303ed5bdd3a, 0x062b3cf5b3a278a6, 0x6d2a13f83f44f82d,
+        0xdf310ee074ab6a36, 0x4597e899a0255dc1, 0x64f31cc50846851d, 0xf9ab48195ded7ea1,
+        0xb1d510bd7ee74d73, 0xfaf36bc31ecfa268, 0x359046f4eb879f92, 0x4009438b481c6cd7,
+        0x889a002ed5ee382b, 0xc9190da6fc026e47, 0x9558e4475677e9aa, 0x9e3050e2765694df,
+        0xc81f56e880b96e71, 0x60c980dd98a573ea, 0x4472065a139cd290, 0x6cd1cb729ec52a52,
+    ],
+};
+
+/// The binary expansion of e.
+pub(crate) const E: BitTable = BitTable {
+    exp: 2,
+    bits: &[
+        0xadf85458a2bb4a9a, 0xafdc5620273d3cf1, 0xd8b9c583ce2d3695, 0xa9e13641146433fb,
+        0xcc939dce249b3ef9, 0x7d2fe363630c75d8, 0xf681b202aec4617a, 0xd3df1ed5d5fd6561,
+        0x2433f51f5f066ed0, 0x856365553ded1af3, 0xb557135e7f57c935, 0x984f0c70e0e68b77,
+        0xe2a689daf3efe872, 0x1df158a136ade735, 0x30acca4f483a797a, 0xbc0ab182b324fb61,
+        0xd108a94bb2c8e3fb, 0xb96adab760d7f468, 0x1d4f42a3de394df4, 0xae56ede76372bb19,
+        0x0b07a7c8ee0a6d70, 0x9e02fce1cdf7e2ec, 0xc03404cd28342f61, 0x9172fe9ce98583ff,
+        0x8e4f1232eef28183, 0xc3fe3b1b4c6fad73, 0x3bb5fcbc2ec22005, 0xc58ef1837d1683b2,
+        0xc6f34a26c1b2effa, 0x886b4238611fcfdc, 0xde355b3b6519035b, 0xbc34f4def99c0238,
+        0x61b46fc9d6e6c907, 0x7ad91d2691f7f7ee, 0x598cb0fac186d91c, 0xaefe130985139270,
+        0xb4130c93bc437944, 0xf4fd4452e2d74dd3, 0x64f2e21e71f54bff, 0x5cae82ab9c9df69e,
+        0xe86d2bc522363a0d, 0xabc521979b0deada, 0x1dbf9a42d5c4484e, 0x0abcd06bfa53ddef,
+        0x3c1b20ee3fd59d7c, 0x25e41d2b669e1ef1, 0x6e6f52c3164df4fb, 0x7930e9e4e58857b6,
+        0xac7d5f42d69f6d18, 0x7763cf1d55034004, 0x87f55ba57e31cc7a, 0x7135c886efb4318a,
+        0xed6a1e012d9e6832, 0xa907600a918130c4, 0x6dc778f971ad0038, 0x092999a333cb8b7a,
+        0x1a1db93d7140003c, 0x2a4ecea9f98d0acc, 0x0a8291cdcec97dcf, 0x8ec9b55a7f88a46b,
+        0x4db5a851f44182e1, 0xc68a007e5e0dd902, 0x0bfd64b645036c7a, 0x4e677d2c38532a3a,
+        0x23ba4442caf53ea6, 0x3bb454329b7624c8, 0x917bdd64b1c0fd4c, 0xb38e8c334c701c3a,
+        0xcdad0657fccfec71, 0x9b1f5c3e4e46041f, 0x388147fb4cfdb477, 0xa52471f7a9a96910,
+        0xb855322edb6340d8, 0xa00ef092350511e3, 0x0abec1fff9e3a26e, 0x7fb29f8c183023c3,
+        0x587e38da0077d9b4, 0x763e4e4b94b2bbc1, 0x94c6651e77caf992, 0xeeaac0232a281bf6,
+        0xb3a739c122611682, 0x0ae8db5847a67cbe, 0xf9c9091b462d538c, 0xd72b03746ae77f5e,
+        0x62292c311562a846, 0x505dc82db854338a, 0xe49f5235c95b9117, 0x8ccf2dd5cacef403,
+        0xec9d1810c6272b04, 0x5b3b71f9dc6b80d6, 0x3fdd4a8e9adb1e69, 0x62a69526d43161c1,
+        0xa41d570d7938dad4, 0xa40e329ccff46aaa, 0x36ad004cf600c838, 0x1e425a31d951ae64,
+        0xfdb23fcec9509d43, 0x687feb69edd1cc5e, 0x0b8cc3bdf64b10ef, 0x86b63142a3ab8829,
+        0x555b2f747c932665, 0xcb2c0f1cc01bd702, 0x29388839d2af05e4, 0x54504ac78b758282,
+        0x2846c0ba35c35f5c, 0x59160cc046fd8251, 0x541fc68c9c86b022, 0xbb7099876a460e74,
+        0x51a8a93109703fee, 0x1c217e6c3826e52c, 0x51aa691e0e423cfc, 0x99e9e31650c1217b,
+        0x624816cdad9a95f9, 0xd5b8019488d9c0a0, 0xa1fe3075a577e231, 0x83f81d4a3f2fa457,
+        0x1efc8ce0ba8a4fe8, 0xb6855dfe72b0a66e, 0xded2fbabfbe58a30, 0xfafabe1c5d71a87e,
+        0x2f741ef8c1fe86fe, 0xa6bbfde530677f0d, 0x97d11d49f7a8443d, 0x0822e506a9f4614e,
+        0x011e2a94838ff88c, 0xd68c8bb7c51eef6d, 0x49ea8ab4f2c3df5b, 0xb4e0735ab0d68749,
+    ],
+};
+
+/// The binary expansion of ln(2).
+pub(crate) const LN_2: BitTable = BitTable {
+    exp: 0,
+    bits: &[
+        0xb17217f7d1cf79ab, 0xc9e3b39803f2f6af, 0x40f343267298b62d, 0x8a0d175b8baafa2b,
+        0xe7b876206debac98, 0x559552fb4afa1b10, 0xed2eae35c1382144, 0x27573b291169b825,
+        0x3e96ca16224ae8c5, 0x1acbda11317c387e, 0xb9ea9bc3b136603b, 0x256fa0ec7657f74b,
+        0x72ce87b19d6548ca, 0xf5dfa6bd38303248, 0x655fa1872f20e3a2, 0xda2d97c50f3fd5c6,
+        0x07f4ca11fb5bfb90, 0x610d30f88fe551a2, 0xee569d6dfc1efa15, 0x7d2e23de1400b396,
+        0x17460775db8990e5, 0xc943e732b479cd33, 0xcccc4e659393514c, 0x4c1a1e0bd1d6095d,
+        0x25669b333564a337, 0x6a9c7f8a5e148e82, 0x074db6015cfe7aa3, 0x0c480a5417350d2c,
+        0x955d5179b1e17b9d, 0xae313cdb6c606cb1, 0x078f735d1b2db31b, 0x5f50b5185064c18b,
+        0x4d162db3b365853d, 0x7598a1951ae273ee, 0x5570b6c68f969834, 0x96d4e6d330af889b,
+        0x44a02554731cdc8e, 0xa17293d1228a4ef9, 0x8d6f5177fbcf0755, 0x268a5c1f9538b982,
+        0x61affd446b1ca3cf, 0x5e9222b88c66d3c5, 0x422183edc9942109, 0x0bbb16faf3d949f2,
+        0x36e02b20cee886b9, 0x05c128d53d0bd2f9, 0x621363196af50302, 0x0060e49908391a0c,
+        0x57339ba2beba7d05, 0x2ac5b61cc4e9207c, 0xef2f0ce2d7373958, 0xd7622658901e646a,
+        0x95184460dc4e7487, 0x156e0c292413d5e3, 0x61c1696dd24aaebd, 0x473826fda0c238b9,
+        0x0ab111bbbd67c724, 0x972cd18bfbbd9d42, 0x6c472096e76115c0, 0x5f6f7cebac9f45ae,
+        0xcecb72f19c38339d, 0x8f6826250dea891e, 0xf07afff3a892374e, 0x175eb4afc8daadd8,
+        0x85db6ab03a49bd0d, 0xc0b1b31d8a0e23fa, 0xc5e5767df95884e0, 0x6425a41526fac51c,
+        0x3ea8449fe8f70edd, 0x062b1a63a6c4c60c, 0x52ab33161e238438, 0x897a39ce78b63c9f,
+        0x364f5b8aef22ec2f, 0xee6e0850eca42d06, 0xfb0c75df5497e00c, 0x554b03d7d2874a00,
+        0x0ca8f58d94f0341c, 0xbe2ec92156c9f949, 0xdb4a9316f281501e, 0x53daec3f64f1b783,
+        0x154c60320e2ff793, 0x33ce3573facc5fdc, 0xf11785903155bbd9, 0x0f023b220224fcd8,
+        0x471bf4f445f0a88a, 0x14f0cd976ea354bb, 0x20cdb5ccb3db2392, 0x88d586554e2a0e8a,
+        0x6fe51a8cfaa72ef2, 0xad8a43dc4212b210, 0xb779dfe49d7307cc, 0x846532e4b9694eda,
+        0xd162af053b1751f3, 0xa3d091f656658154, 0x12b5e8c202461069, 0xac14b958784934b8,
+        0xd6cce1daa5053701, 0x1aa4fb42b9a3def4, 0x1bda1f85ef6fdbf2, 0xf2d89d2a4b183527,
+        0x8fd9405789f45681, 0x2b552879a6168695, 0xc12963b0ff01eaab, 0x73e5b5c1585318e7,
+        0x624f14a51a4a026b, 0x6808292057fd99b6, 0x6dc085a98ac8d8ca, 0xf9eeeea98a2400ca,
+        0xc95f260fd10036f9, 0xf91096ac3195220a, 0x1a356b2a73b7eaad, 0xaf6d605871ef7afb,
+        0x80bc423433562e94, 0xb12dfab414451579, 0xdf59eae051707062, 0x4012a82962c59cab,
+        0x347f8304d889659e, 0x5a9139db14efcc30, 0x852be3e8fc99f14d, 0x1d822dd6e2f76797,
+        0xe30219c8aa9ce884, 0x8a886eb3c87b7295, 0x988012e8314186ed, 0xbaf86856ccd3c3b6,
+        0xee94e62f110a6783, 0xd2aae89ccc3b76fc, 0x435a0ce134c2838f, 0xd571ec6c1366a992,
+    ],
+};
+
+/// The binary expansion of ln(10).
+pub(crate) const LN_10: BitTable = BitTable {
+    exp: 2,
+    bits: &[
+        0x935d8dddaaa8ac16, 0xea56d62b82d30a28, 0xe28fecf9da5df90e, 0x83c61e8201f02d72,
+        0x962f02d7b1a8105c, 0xcc70cbc02c5f0d68, 0x2c622418410be2da, 0xfb8f788402e516d6,
+        0x782cf8a28a8c911e, 0x765aa6c3b0d831fb, 0xef66ceb04ab3c6fa, 0x5161bb49d219c7bb,
+        0xca67b35b23605085, 0x8e93368d44789c4f, 0x5b08b057d5ede20f, 0x469ea58e9305e981,
+        0xe2478fcaad3aee98, 0x9cd5b42e6a271619, 0xa47ecb26978c5d4f, 0xdb1d28ea57d4fdc0,
+        0xe40bf3cc1e14126a, 0x45765cde268339db, 0xf47fa96deb271060, 0xaf88486ea9b7401e,
+        0x3dfd3c51748e6d6e, 0x3848c8d25faf1bca, 0xe88047f17b0d9b50, 0xa949eaaadf69e8a5,
+        0xf77e37604e943960, 0xe38a5700ffde2db1, 0xad6bfbffd821ba0a, 0x4cb0466d61ba648e,
+        0xef99c8e5f6974f36, 0x3982a78ca45ddfc8, 0x0942617819127a6e, 0x3b70fcda2d732d47,
+        0xb5e4b1c8c0e5a10a, 0xaa6604a5324ec3dc, 0xbc64ea806e198566, 0x1f1d366c20663834,
+        0x4d5e843f20642b97, 0x0a62d18e478f7bd5, 0x8fcd08324a7b32a6, 0xdef85a05eb56323a,
+        0x421ef5e0b00410a0, 0xa0d9c260794a976f, 0xf6ff363db00b6b33, 0xf42c58def8a3c52d,
+        0xed69b13dc1a03730, 0xb6524dc18c167e86, 0x99d6d20ea2defd2b, 0xd006f8b4be145a2a,
+        0xdf3ccbb3189da49d, 0xbc1261c8b3e4daad, 0x6a36ceccb2d5ae5b, 0x89bf752fb5dfb353,
+        0xff3065c40cfceec8, 0x1be5a9a967fddc57, 0xc4b83301006bf062, 0x4b40ed7a56c6cdcd,
+        0xa2d6fe91388e9e3e, 0x48a93f5f5e3b6eb4, 0xb81c4a5b53d49ea6, 0x8e668aeaba83c7f8,
+        0xfb5f06c358ac8f70, 0xfa9d8c598c574502, 0xbaf54c96c84911f0, 0x0482d0951a0af022,
+        0xabbab080ec97efd3, 0x671e4e0e52f166b6, 0xcd5cd2260dc67795, 0x2e1e34a3f799677f,
+        0x2c1d48f12944b6c5, 0x2ba1307e704d67f9, 0x1c1035e44e927c63, 0x03cf12bfe2cd2e31,
+        0xf8ee4843344d51b0, 0xf37da42b9f0b0fd9, 0x134fb2d9f815e490, 0xd966283f23962766,
+        0xeceab1e4f3b5fc86, 0x468127e2b606d10d, 0x3a45f4b6b776102d, 0x2fdbb42080c8fa84,
+        0xd0ff9f45c58aef38, 0xdb2410fd1f1cebad, 0x733b228152ca5f36, 0xddf29daa544334b8,
+        0xdeeaf6594e462713, 0x1ed485b46a0822e1, 0x28db471ca53938a8, 0x44c3bef7f35215c8,
+        0xb382bc4e3e4c6f15, 0x6285f54c17ab408e, 0xccbf7f5ed16ab3f6, 0xced2846df457e14f,
+        0xbb45d9c5646ad497, 0xac697494145de32e, 0x93907128d263d521, 0x79efb424d64651d6,
+        0xebc0c9f0bb583a44, 0xc6412c8485bb29a6, 0x4d31a2cd92954469, 0xa32b1abdf7f5202c,
+        0xa4aa6c932e9b53cf, 0x385ab1362741f356, 0x5de9c0656009901c, 0x88abbdd874efcf73,
+        0x3f761ad435f3c083, 0xfd6b8ee00bef11c7, 0xc552a89d58ce4a21, 0xd71e54f24157f6c7,
+        0xd4622316e98956d7, 0x450027decbd398d8, 0x4b98b36a0724c25c, 0xdb237760e9324b68,
+        0x7523e5068edad933, 0x92197f00b853a326, 0xb330c44465129296, 0x34bc0670e177806d,
+        0xe338dac45537492a, 0xe19add83cf45000f, 0x5b423bce6497d209, 0xe30e18a13cbf0687,
+        0x67973103d9485366, 0x81506bba2e93a9a4, 0x7dd59d3ff17cd746, 0x8c2075be552a4347,
+    ],
+};
+
+/// The binary expansion of √2.
+pub(crate) const SQRT_2: BitTable = BitTable {
+    exp: 1,
+    bits: &[
+        0xb504f333f9de6484, 0x597d89b3754abe9f, 0x1d6f60ba893ba84c, 0xed17ac8583339915,
+        0x4afc83043ab8a2c3, 0xa8b1fe6fdc83db39, 0x0f74a85e439c7b4a, 0x780487363dfa2768,
+        0xd2202e8742af1f4e, 0x53059c6011bc337b, 0xcab1bc911688458a, 0x460abc722f7c4e33,
+        0xc6d5a8a38bb7e9dc, 0xcb2a634331f3c84d, 0xf52f120f836e582e, 0xeaa4a0899040ca4a,
+        0x81394ab6d8fd0efd, 0xf4d3a02cebc93e0c, 0x4264dabcd528b651, 0xb8cf341b6f8236c7,
+        0x0104dc01fe32352f, 0x332a5e9f7bda1ebf, 0xf6a1be3fca221307, 0xdea06241f7aa81c2,
+        0xc1fcbddea2f7dc33, 0x18838a2eaff5f3b2, 0xd24f4a763facb882, 0xfdfe170fd3b1f780,
+        0xf9acce41797f2805, 0xc246785e92957023, 0x5fcf8f7bca3ea33b, 0x4d7c60a5e633e3e1,
+        0x485f3b494d82bc60, 0x85ac27da43e4927a, 0xdb8fc16e69481b04, 0xef744894c1ea7556,
+        0x8775190fba44fa35, 0x3f48185f107dbb4a, 0x77dac64cc266eb85, 0x0ed4822e1e899d03,
+        0x4211eb71c181ec80, 0xdd4ed1a3b3423cb6, 0x2e6acb96e07f9aa0, 0x61a094a16b203080,
+        0xf7b7e36f488a515a, 0x79246344e3005da0, 0x545ab5820feaef37, 0x06e86336a418ff3f,
+        0xffababf23884c066, 0xdeae134242ed2f48, 0xd9f17902db9392dc, 0xb8eb050fc4478450,
+        0x5370806676e1672d, 0xecc57738f2171346, 0x9bd3039791011a30, 0x9ffe11229a1cf54b,
+        0xd4ccdb64f1e738fc, 0xa6b04956709055c7, 0x2a8706aa88b44318, 0xbbc67b01a86817f4,
+        0x2f94f645f2e395c0, 0x3d7abb8dc12d9850, 0x73c1bb548e046353, 0xf87c7991d9b140e9,
+        0x12b44e05ad41023e, 0xdcc4fb1d45327428, 0xcde06860f1114024, 0x26ca7a7cdd9ea598,
+        0xea44eba918dae319, 0xe24064b5f2a4dfae, 0xcb33c5a69626ed43, 0x3dec724014ff5464,
+        0x0b9ab3e15d1e9e74, 0xeff05e8da6ebb88b, 0xc02bdb4adbf578d8, 0x2e11646aff75e83b,
+        0xff64b6dc7bbc7e0e, 0x15dde70da4f5fad7, 0xa2304414ac56e80e, 0x53f8db5e05bf60de,
+        0x3705376de33fc2d9, 0x3a70430d9d09bab8, 0xd8b2a4c39e908e35, 0x5734ec00f2bca22d,
+        0xe3051f0527ec4b47, 0x5bca5ee3816b4a2e, 0xd4a5825220655e4a, 0x1c3e1e937e879df4,
+        0x57b182d29d0bb944, 0x56509fda0364c148, 0xaec1dd069aac6c0e, 0xe88acf4b21f5513f,
+        0x5bbabd10294badb7, 0xa5444f1e8495d8e3, 0x42a406e174cca3d9, 0xb96f6d02f10c97ca,
+        0xd8dc932895a02619, 0x8c0eb251acd44db7, 0xc43279eaba98c9ac, 0xd51c312bd49d2bd2,
+        0xcbbaa3fd07b03661, 0x97754277b881e969, 0x6abdb60a9d2f9be1, 0x63bff14946990d94,
+        0xa3875720ac59e76e, 0x3256f0f218d09c5d, 0xbdf3982da37bff05, 0xe3aa7f9f60215ee4,
+        0x2d992ccb291543dc, 0x321f1e8bc387c612, 0xdacbc0306aadc17e, 0xa769f9f0ac495ada,
+        0x91b0c1659418fa26, 0x513d68d337eab7ee, 0x14d3e3d49f213cd5, 0x096a30506a4a4adb,
+        0x5f26e39eefcb7740, 0x54efe116c69c2f51, 0x766400eba060cc86, 0x3df9422c240293dd,
+        0xc5d4084e93b861ff, 0xef090d3df21a7c1e, 0x8555fdef298fba5d, 0x7ede16d7df670173,
+        0x2ddbbdc7ddc66da5, 0x6c725cf780f16487, 0xc034e91f67d86471, 0x713eb23ed521bb15,
+    ],
+};
+
+/// The binary expansion of log₂(e).
+pub(crate) const LOG2_E: BitTable = BitTable {
+    exp: 1,
+    bits: &[
+        0xb8aa3b295c17f0bb, 0xbe87fed0691d3e88, 0xeb577aa8dd695a58, 0x8b25166cd1a13247,
+        0xde1c43f755176cd6, 0x24d92f75c16be0b3, 0xea90b9e60c4a909f, 0xc4bfaf0353df39b3,
+        0x2fe294932617d9d5, 0xb21b43d579d5a206, 0x0b5ebbbf3a828546, 0x8d1cf457ab63253c,
+        0x199a94836f5b4967, 0x278ccf084679c940, 0xce7e20358cd5db8f, 0x612f08fbae30a173,
+        0x2650b6d1058eba50, 0x9638c84c5a02065f, 0x411a8dec5ea11213, 0x918fcf712de86238,
+        0x7f12325c49412609, 0x079683eda1a245b5, 0xee9ac0b2f8d159ec, 0x33afcf70ec68ce71,
+        0x17670ec70e7976ca, 0x812e39d05b047658, 0x564e5c21d0e29d81, 0x0b4c6075d10bb3bf,
+        0xbe85ce25b7002652, 0x8b4d76ead87dfbc9, 0x103aaf0413d7210d, 0x79c61a86dfe9007a,
+        0xa1bd6c442a50c2dd, 0xe25ddafc918bcbef, 0x13e0cc170ae37c3d, 0x185a3461cd952c37,
+        0x28fdc908d28dfe8f, 0x083af2a2f7f936b5, 0xfe05a8d9edc06de5, 0x9212a4bbb99c326d,
+        0x6e5b0b6ba4f20af5, 0xb065ed2179efaea5, 0x293ebb17dcbf7ed9, 0x43f0b6306472e5eb,
+        0xfe2dcba22fa52853, 0x147c1365334b25c8, 0x1e7d2ed04ca303c8, 0x604838e0f11e5520,
+        0xf6806c3e69f50831, 0xcec4b58a7f8cd79a, 0x92ce29baf817b287, 0x2720aa507856a0a8,
+        0x64f91e15620d0a9e, 0x0aa609a102aad0ee, 0x91acda1ec30fd513, 0x8107c49206670b94,
+        0x5e20d8360ce408ea, 0xbd2217994b23aa5f, 0xa9c9e347e73e08a1, 0xdf668175edceceb6,
+        0x6b6703b93b2d204d, 0x996a717faf23cf54, 0xe7b0379c458a5f88, 0x207ebd61e2b97d4b,
+        0x3ccd7d446cdc8477, 0x19e60a26ebd9d481, 0xa5f7e8bc36ffe8d9, 0xba61d582be3c1841,
+        0xc151765a54574f5f, 0xf12006dbf21758d7, 0xd7b186f0f746c2c0, 0x40b00496bdfd93b7,
+        0x41c9c8796636ec00, 0xeec683c8eda54f30, 0x161b8bcea6bc9fc2, 0xf35bef20be4ec963,
+        0x66f686f61d0aab45, 0xec620d9ae8b59317, 0xce4dc37c77d8a36b, 0xac0bd2d106ecf906,
+        0x459b324fe3389f5c, 0xd2cb5bdb1e485c17, 0xa77d659d7d1beded, 0x25e912cf602df823,
+        0x2639e4c0949a4d0b, 0xbe0df563811b186b, 0x34ff8ccfaddbdc4c, 0x62fa102b9306ca91,
+        0x91305c0af30458a5, 0x6e9442c610dd7e5b, 0x830d1a2edc075dec, 0x460d466beaac61f2,
+        0x4fef3f33f30da3ba, 0x96628d52cd3d4484, 0xd3c44515298ac59a, 0xf243fce67c48a670,
+        0x963a1549f4e5b6b9, 0xb052f27d972566eb, 0x879d3e2d7db919ec, 0xaa39bc6decd07c77,
+        0x740600a235932e01, 0x85de85dd4d5122e0, 0xd1a8d5206474d360, 0xd17c92cefc6bc019,
+        0xdc72d8fdda3637f8, 0x28a65378be3924c7, 0xfc9bee3f78938752, 0xcd9b6a5b7a0fb699,
+        0x3f1b1f0223193cf2, 0xd449ec42b370b8dc, 0x96057885cf5980ad, 0x0c4f0bf5c09440a1,
+        0xf2e75044d22e545a, 0xe34d75a19f26e210, 0x2c48c8d3b3fc2f2d, 0x50a884562c6f2187,
+        0x926ee4c378d0c1bb, 0xb1cee640cdbff5c9, 0x020d7591c640956f, 0x94223a7c0eb94ecb,
+        0x10a4fb4f1d510005, 0x28d66f09fbec604b, 0xe6ef145c95b991f7, 0x4afb359e08ddc50a,
+        0x613ed683b42fc75f, 0x20ca910778c3789a, 0x9b242eea69fb68d0, 0x0d3faee318a2fdab,
+    ],
+};
+
+/// The binary expansion of log₂(10).
+pub(crate) const LOG2_10: BitTable = BitTable {
+    exp: 2,
+    bits: &[
+        0xd49a784bcd1b8afe, 0x492bf6ff4dafdb4c, 0xd96c55fe37b3ad4e, 0x91b6ac8082e7859d,
+        0x06650fde9dd51f3a, 0x3e24beab63ad0bd6, 0x1435ee480565ca54, 0xcff162cd4f603381,
+        0xf64f96c17d9b5d4d, 0xfee6c26b8f8c9bd0, 0x385e5f901cc2d1ac, 0x8e8f75775109530b,
+        0x8658c6dfea3eed71, 0x304f88b43eb2b86f, 0xaa9363cf9c1988c4, 0xd69c4bc2089e1f61,
+        0x6bbb72c3e9a54fdd, 0x1a5e3f119211b15d, 0xefa3fa978c421f86, 0x631fc105a48d30ec,
+        0xb11bb454f2afc692, 0x0316d7eceea2f19d, 0x9302a515a26beecf, 0x28d043701752b1be,
+        0x45a72e9a5cbd3538, 0x5100e14f4cb3c6f1, 0x71adfaf978c96688, 0xf1721f8f78de6e07,
+        0x570b3806a8f95259, 0x17e0ec22349fe285, 0x1c70c438006dd6c9, 0x34927c5400b04edb,
+        0xfd697d0087dd3520, 0x4becb5851425e145, 0x1796c0dd437a66c9, 0xc347e1a39dccd400,
+        0x4b06503d4706c266, 0x84b0c4ccccf6077c, 0xbebdf0570df542a8, 0x4e6d5f96cbfa8472,
+        0x8dcef996a499d28f, 0x1cd7206f4e05f23a, 0x02bcee0d72aec8ab, 0x73268140ac8644e0,
+        0x7dec175ac16de00e, 0x98ea1c65a6ac1df1, 0x11bba405f90c1fd0, 0xacff990714925323,
+        0x0236329beb346d35, 0xed0f9bfbd90c40a7, 0x620ed1e1b77093d1, 0x7590bf767ca87246,
+        0x2ea23ede5b6c16b3, 0x80a18d4cd55999ff, 0xfcb5cf26d9c1a02e, 0xe76a09d2529f4533,
+        0x18e19934c0518cb6, 0xcbcf414e570eea0e, 0x38ebbad55a8d1586, 0xfae8ade2f4a6bb8b,
+        0x72aed240c2c05605, 0xb5272c455d55eac6, 0xf91663aa3aeb6320, 0x4c95f1ee0fd6c2e7,
+        0xd3d3f6128afde8b5, 0xef7c9b6b4762b166, 0x90b2a5ec33c2ac28, 0x17a58bfc1c5515cd,
+        0x98f12d33761419fe, 0x9d01ac26a724364f, 0xde133793ff95d909, 0xb4fce6cca9965366,
+        0xdb14c639c284e917, 0x50761ae788b4c27f, 0x3ef3b275e503242f, 0xa61353f87d73b7d7,
+        0x9a8a195cae77c497, 0x3517ed3fab6baa76, 0x61615483b2d8ad09, 0x5a0935ee78aa761e,
+        0x3a6f267658ce9597, 0x2fd703013b553870, 0x1befbffde2b2e7d0, 0x685b247f3d6fb238,
+        0xfe0bc3ba65578454, 0x584b49486e40bf2b, 0x6ca117be9970d886, 0x9b99705da2a147ee,
+        0x4eee5d45f91a4c8a, 0x1163f07cbe21c754, 0xae67dbd6c7c031d2, 0xb161369c6b72a93e,
+        0x05ad085838e9f961, 0x2b289eb45a6f9c82, 0xbf497eafe2837629, 0x221d417893c140c1,
+        0xfd221e872454b670, 0xa8b904a782f54d58, 0x583ae01d131fe6f3, 0x23c5ad1d3874e7dd,
+        0x56df98ffa2d82dd2, 0x5c6613500ddb22e2, 0x6184c04be32da0cd, 0xcd02652639324aa9,
+        0xea73b89874223061, 0x393c996f67ff8747, 0x76dda7f2b66bf22a, 0x70bb9754948fdf47,
+        0x986ae3e05e0b7df9, 0x45b4c2fd8ca7579c, 0x4a50011387e02497, 0x287fc06a3806452f,
+        0xd6c118fe081ee2c6, 0xf721f92c36699bde, 0x5316c6b5427cfb61, 0x1d1bed6ad4019a5b,
+        0x48f853c8be0f588c, 0x44f9b0379fec78c2, 0xcf9374dd26556416, 0xa721e7e2b40f7f59,
+        0xab133d7b7d32d1cb, 0x5244bd4c57f323d3, 0xc8a189ddac63c89a, 0xc2a4c68a8568b6d3,
+        0x4543637f76d12213, 0x539286cbb7375563, 0xb5f6dbca19962203, 0xd63327d18200acd5,
+    ],
+};
+
+/// The binary expansion of log₁₀(2).
+pub(crate) const LOG10_2: BitTable = BitTable {
+    exp: -1,
+    bits: &[
+        0x9a209a84fbcff798, 0x8f8959ac0b7c9178, 0x26ad30c543d1f349, 0x8a5e6f26b7cc63cb,
+        0x286a2d81919fabd0, 0x9e5cbc733471bd12, 0x91473495b18b921e, 0x58b527f52552d2cc,
+        0x5f837dee02555cbc, 0x9cf1d190c40602e5, 0xd26c272fde7102f4, 0xeb91129b1592d9dc,
+        0x048d7ea59eb153d8, 0x0b0833c5941ab821, 0x6a367b813cf4c8fb, 0xdeea3177c8f88caa,
+        0xbff800a15ad8675d, 0x51a50bf743565adc, 0x51192bed5e5549a2, 0x86a551e143b9f8e0,
+        0xca00cd20e15e3c9f, 0x13b4ca8a479b62f5, 0x65ea7c58e959bdce, 0x3b29673427b875ff,
+        0x7edfafad98f394c0, 0x9f4ed35e2eac8644, 0x0f50cb2dce50c2e9, 0x5be3d29eeb01afed,
+        0x1fa74de0932267a0, 0x79e75ee2bc9d5cda, 0x35a1bacd4fb6144a, 0xf8bf31b36a257b2d,
+        0xdda66c6847c1d97f, 0x64e24a7e3df2314c, 0xd819d58c9eaf4826, 0xdd49764eee39ef0e,
+        0x6ec753f543eda30a, 0x1282f11333996f2c, 0xbc2c03d5a83f26ef, 0xb996dd56d49281b6,
+        0x56c287b7fd00a160, 0x75052ab21f9eb630, 0x1d9c73d77197365a, 0x7319e8fc4f6da305,
+        0x827b909691748c3f, 0xe8d54946343d2fa1, 0x2536763ed799233f, 0x64792933048c641f,
+        0x227b2185a0d19be2, 0xec071998bf0c37a0, 0xbaf99a9ba68b038d, 0x4b7d8a106444a2c9,
+        0x147862d5a642a259, 0x0609145e3dafc141, 0xa8b9d01826ce42c4, 0xef31efafa9f1b21d,
+        0x5550060cc890300a, 0x0cd65d996f22a857, 0x0206a92e50ffc348, 0xc2cc32ec8c36318b,
+        0x804eaea57ec178b9, 0x57a0ab9ca39e86d6, 0x96c7edaad327cb1c, 0xef0db91c4670716a,
+        0xd089c44c81005eb0, 0xe20845f7d9e1dbc2, 0xb419748f47f7d828, 0xf25209f05c32f1c6,
+        0x9212949d980aeb64, 0xf4277f1fa0014e0d, 0x6ba8fd28cce43e3c, 0x52684b5aa94efaf8,
+        0x91b1449f3b07788a, 0x62291b9d786ae266, 0xe1b92c4b385bc439, 0x8ac695ef8c3443d8,
+        0xbf213c2e814d66a4, 0xc454c5444899bc55, 0x2c4ff9589c162d10, 0x28842927cbad2504,
+        0xd8dd76c6135b30bd, 0xe4f85fbe059569c9, 0xf26ffdeeac45c3c3, 0x3005daf3681f047c,
+        0x8e377ebd06e51171, 0xdd58b77c6c11fb7f, 0xc0e59211c9a68abb, 0x4aa5589be57eab7c,
+        0xc7e8780bbb9149f1, 0xbc3189147d9f5a67, 0x138d2846658871bf, 0xf4363afdcaf3d27e,
+        0x77c3c23fbc0993b2, 0x15a3d15c0800c4c2, 0xcfab8f32fc71c510, 0x4966658cc661b016,
+        0x480634f7ba650227, 0x42d10196cc22d01e, 0xc8f6565d943904f7, 0x503159e269ce13cb,
+        0xeef7df3e2f4828e0, 0x0219f484b63d1041, 0xbb4ee987108711c4, 0xc42ee1b560fbd009,
+        0x3bd6afd2174a306b, 0x701d95e11759df28, 0xb39758e525d74ba2, 0xccbeeefbfc7eb915,
+        0x0f647860e464479b, 0x3346ad54fd07dd11, 0x6669cf0bfb91408f, 0x990769f8adc895b5,
+        0x3a0aa337bd053203, 0x9e26c0248ca497fa, 0xe5b32aea32a0cbe5, 0xac7fd5bcc8456684,
+        0xebd1423663eb648f, 0x55e0f1948c8b15ea, 0xe6891cedc32989d7, 0x0cd7d9182dcefdd5,
+        0xa62b710e0a9601a7, 0xc42c9669767dfeb4, 0x14a8e831f5d7fcec, 0xb0f66b3137d4fe25,
+        0x9185a8a1f5b38ece, 0xe898c022d68f2317, 0x11196d8b23e7e694, 0x05d71a69fa102003,
+    ],
+};
+
+/// The binary expansion of log₁₀(e).
+pub(crate) const LOG10_E: BitTable = BitTable {
+    exp: -1,
+    bits: &[
+        0xde5bd8a937287195, 0x355baaafad33dc32, 0x3ee3460245c9a202, 0x3a3f2d44f78ea53c,
+        0x75424efa1402f3f2, 0x92235592c6464a15, 0x18ce3bd9fd38dcbc, 0x6fa2b8d2c8cda7b3,
+        0x4356bd1948d06ff9, 0x400720058c1dc4da, 0x658b61ea42c84d6a, 0x50b36ded2f3739d5,
+        0x5430212ae68c04d4, 0x648ecc89cc51f934, 0xd794d64f27c968eb, 0xb3fe40c2ecdb1f6c,
+        0xd121adc650c65e94, 0x7dd6c0871e7f62c8, 0x2b1385466fcdc598, 0xd7125df1214e565e,
+        0x2ba50bd8edbc0a89, 0xbb924a9bb37a8c02, 0xc7275516fc3a7d1c, 0x17cc4ce204c9f608,
+        0x6769428d78d56936, 0x2614b10dcbc530d3, 0x26677bf52be16736, 0x1faeed1f336d6141,
+        0x884953ba9f17de56, 0x3fd1b1c6ce62f695, 0xdc53fb59ba287641, 0xaed3c14af47fbb41,
+        0x5de6086410e138be, 0x4508cf37f0e65908, 0xd4d8661c047cb2dd, 0x2ab0eb939c816b6b,
+        0xeebe6e594ba6b791, 0xf8093a749d74c55c, 0x604381a1fd27b774, 0x630d724438f27b7d,
+        0x215cf5228e74a33d, 0xb40acaecc93c5302, 0x1b9df24d98e5e7bb, 0xa432074eb67de837,
+        0x4501aa902cbef501, 0xd06d8f805e0b8469, 0x425c2c34001d3334, 0x84cd13694acd9eca,
+        0x4e51bbd07628320d, 0x23004a225aec6a90, 0x8820aba2e0476c49, 0xa00657300c2224b8,
+        0x8495aad4361acefd, 0x0a92d7d795b9bfc9, 0x62cd18cf3da818e9, 0x2ed765f1a22b43bf,
+        0x30100cd0ec062167, 0x1f16a8b708ec898b, 0xcf85811cc3941307, 0xc485249a6fd159d4,
+        0x21260248ad311434, 0x379ac73ebb6c1fb9, 0xed037c1ce0525a32, 0x65348f73d99a823f,
+        0xb08eb978d195c5a5, 0x595b65e2f93ca2fb, 0xe3af7f3a87ba59fb, 0x27036e8d27cfdb15,
+        0x8f8615e7be5074b6, 0xb1ea564da7baa01f, 0xe40daf8c80a8ad16, 0x31dbfae0a5734ed4,
+        0x2b98e11f20eccd1f, 0x76c1d03339b1fe64, 0x1e4601d14cc114be, 0xb65ddc30414a8cb4,
+        0xa3df0cb6c9453c84, 0x1475a27f34525f8e, 0x4a30c3f52a9c6fc1, 0x07ed1da22b87d8eb,
+        0xb01944651952291c, 0x3643e5becefd22e4, 0xf28d50d1105c3d9e, 0x8a5b8ff2073a604b,
+        0xb1e97e2eccb67ff6, 0x2599f4f349771046, 0x4294ca043f4a80ba, 0xa369a82dfc85859a,
+        0x6d08747dc9ec6fa9, 0xeff3a213266c7d9a, 0x73c7506f9152b9a7, 0x5152c654c48faeea,
+        0x69e60fc3f079774f, 0x92471ab7bb46eccd, 0x101644365f18e966, 0xe8f9ba1afaa73416,
+        0x9a6de161967ac8b1, 0x1d9d2b09e07d1a45, 0xc546b5555438af7c, 0x7805a6e42a1dcafe,
+        0xb706cde35b079402, 0x72944777dab80766, 0x7e00dce9b23b1b40, 0xf353f5f6732117e5,
+        0xb51a041d0c424497, 0x346d19b6c0472c58, 0x4a94d21aedc90a62, 0xa2148a8312c436c3,
+        0x28f82bdb431ff291, 0xe1e04cb4c31c0c63, 0x6688afd002b39688, 0x13035e9cea289d76,
+        0x84eb769f6261cc25, 0xf154a17cc56f1bdc, 0xd4ad752ae77da765, 0xef7ad70be58d0fae,
+        0x59b49b2c24e07061, 0x7f257708f9158d50, 0x2af83cb851396b9d, 0x6003fccfe4b91bbd,
+        0x93be2566ef78ea7c, 0x4a33ada43dd6dec2, 0xa75296d9b06dc97c, 0x3d2e0ba12fb7db28,
+        0xb9c0eb06c5557098, 0xbb8be9bfd676e42b, 0x6f3c8d250e658827, 0x9f13d3e5bdef572a,
+    ],
+};
+
+/// The binary expansion of 2/√π.
+pub(crate) const FRAC_2_SQRT_PI: BitTable = BitTable {
+    exp: 1,
+    bits: &[
+        0x906eba8214db688d, 0x71d48a7f6bfec344, 0x1409a0ebac3e7517, 0x39a15830cce620b0,
+        0xc0759cf859270f11, 0x40c036096cc79aeb, 0xbd1f4eee48e1ca78, 0x74f76f877ffec251,
+        0x52561dcc244dc65e, 0x9c22f47f7b7fb57c, 0x9522f2f93e16b2a3, 0xd27a3282dada7316,
+        0xeb9feb2436f2f272, 0xac2c88bbba81b1c7, 0x50754b409e94d32d, 0x18d3e91adcff6c03,
+        0x9ad39cf866dd0962, 0x8cd2681847e75924, 0x7bb68d0db3217d69, 0x28d2c52986a1ef82,
+        0xfd6bde19d4863cd8, 0x1de6bb94f13db14d, 0x3ac25186f4f23712, 0x538d461ea97b001e,
+        0x73157a8e5fd0894d, 0xfd79bea110cd0c88, 0x6c16e0cc2d71f233, 0x1782fe3af6af38d9,
+        0x5ebaf4bdcaf407f1, 0x27fcee21139e560f, 0x41988fba498490a2, 0xf046bd5d92806083,
+        0x3f81efeffc9b1f47, 0x6e77a5c560d16d31, 0xaff355b18658c49d, 0xfffbf2b24ebd8557,
+        0x0ea4e3551e6045d0, 0xa36dcb1e46fe9121, 0x958e5e0085a461d2, 0xe69efd1284fb5f44,
+        0x5a03e882c983dc91, 0xec8ffd8edb30c57e, 0x8ad4952dd036641d, 0x7d5223048f5567aa,
+        0x5a9178ca2e8c8f26, 0xd66d0899c3cba579, 0xd1a1fbe71b4cabf7, 0xee1c212c2987e7c8,
+        0x1ba09dd83fd1b82d, 0xc9a5d640a2bb4106, 0x903a05532629934b, 0x6622adbce3256ae6,
+        0x72fffa96cd508a76, 0x4ff883a76e3184d1, 0x3014db85491972cf, 0x555280d505652513,
+        0xb94139e1397635d9, 0xa38584adbe3a3d17, 0x7cf1e310732f87b2, 0x4661689d25a18170,
+        0xcbd38f4766de0088, 0xc2c050e08d1a0e3c, 0x9a6988eb9f9a25c2, 0xe839623d90f4226b,
+        0x5c965b39c56a5777, 0xa8a4e07a5f76e5da, 0xc5d468d209c0346e, 0xfe257ef980d1deaf,
+        0x815ccd5e0661a456, 0xebeaf04e66aad8ed, 0x6462a7117e127986, 0x462672d4372a902f,
+        0x7db4341aeac82533, 0xaf16409add2cb38c, 0xfdd50cdd3c49336d, 0xbd8d5a6d9b58e5a0,
+        0x95a6af709feef8bb, 0x46de1a3e9536a629, 0xb5d6487ffff1ab63, 0xbaf70322c7216ba2,
+        0x6b50b76a8d62074f, 0x1019a114b1ebbd2a, 0x2fc5ad771751d9be, 0x11eda7a826a3dcc3,
+        0x6516aa1d5bf8ac79, 0x36a37a25d41c7167, 0x53bba34924d7fb95, 0x7eb8baad78e7560f,
+        0xd3d6864629b76a79, 0x59212a2c2c19e83e, 0xfb12a9f58ffffe0e, 0x879efbb8a0c05671,
+        0x30c689128e7f54f3, 0x53052ff8436c6696, 0xbd07a1748582a575, 0x6e413401db988220,
+        0xbc7b3b589f914a6c, 0xbdccf698c70552af, 0xd06a795b55b0ce2b, 0xb849e6704291c36f,
+        0x27aa171cee7eae5a, 0x646b9b49e5a8fe66, 0x8b1fbe87c2a9c3a0, 0x9bebd4e81c43620d,
+        0xbd1a3d4418f0d6ea, 0x7c30ea4749dbded9, 0xa534c38ea7457b0a, 0x410276136368d0aa,
+        0x59998f7ef31f0914, 0x94d36e75bbc31316, 0xa0b4a715d23ed230, 0x545370985cc1222f,
+        0x50722da007537535, 0x38227128b1aa8de1, 0x3ca2840e9a229209, 0x879f8f4687e0510d,
+        0x2dad16e543125828, 0x0d050039bf530219, 0x4770c6354c1c43f6, 0x1d1192f579f9440c,
+        0xa53f792c8bbffc33, 0x132f201844b7e8da, 0xfbab89aeda56c8b2, 0x3b471de02baaebba,
+        0xe4a33608e0d4e6ce, 0x9c02fe99164d5ffa, 0xa134ff9246ae00f4, 0xcc6d6560cad52a48,
+    ],
+};
+
+/// The binary expansion of π/3.
+pub(crate) const FRAC_PI_3: BitTable = BitTable {
+    exp: 1,
+    bits: &[
+        0x860a91c16b9b2c23, 0x2dd99707ab3d688b, 0x70ac3405b19a884d, 0x56b27f197cb7bcc1,
+        0x8b86b0510978033e, 0x9fb8bbcd337c2cbc, 0xcac75c494c3f62cf, 0x8a96239e48e12c2e,
+        0x985923a441945484, 0xa2dd81f1197a9e47, 0x5d54e879f8047a9e, 0x9ed047fce7066a6e,
+        0x746a180ba8321544, 0x30c5998bf342e77e, 0x8155a87b16427f59, 0x10918579683937bc,
+        0x460ed51b536ddf95, 0x0243936d3dc273b9, 0x6841f78ec058e1d2, 0x69e370afa0646448,
+        0xef5d78dedc7dbaad, 0xf64d9d5b31656ba8, 0x21b5942ec979ded2, 0x97befa1d655f0402,
+        0x676fad174805170a, 0x792e3ea04a32e1db, 0xe97287f9b8e564ba, 0xd10e30fdf1b8f1ee,
+        0x0e8c1965bb5158b5, 0x63a1b43c5c71d81e, 0x737764b35835a6cd, 0x1ae36bc7ea1326ed,
+        0xf35258ad90929f5c, 0x5c9c4b8f93595da9, 0x2264b503c4969885, 0x1d4e745de75b77e4,
+        0xbf086340316e4113, 0xdf428c196737499d, 0x4b755159e65c0598, 0x904eac4cd4859c42,
+        0xe16a1cbaba52155d, 0xd2960f8fa6eb939d, 0xa4b105d5d1e62f41, 0x5b418a6af8991ccb,
+        0x82923d5340a8b5b4, 0x32573615c6160556, 0x11a17d61c505448f, 0xb04bbc0b2926e76f,
+        0x112cc4baf1f896d2, 0xbc62f023240e07e6, 0xc3ad4686c7383345, 0xe7d281e758945f50,
+        0xc9b4a80d6a7f31c4, 0x1afd90da3447d593, 0xbbcc643515b5d7c1, 0x6cd26baee0e7eff3,
+        0x6a40c64b34973a75, 0x2567e8f96ba03013, 0x3559b61a8e7591c6, 0x62789c65b3b53e81,
+        0x04aa7a930b19d5b4, 0xdea2ce8622ac5861, 0x79d7fc788c52f56f, 0x2be33dcc40198494,
+        0x864e144ed3d17a7e, 0xa5ffb80473bee209, 0xee922574d620011f, 0x2046e29e601a9020,
+        0xba64c5205991b0bb, 0x3c29e7f28a676348, 0xd8899dd1df387cbd, 0x3cff62da99dd6e32,
+        0x22158b8b728fca2c, 0x4a7b4d5625088c7f, 0x910286abd987b748, 0xa021f163e0f6be02,
+        0xa301e53449a354a4, 0x8e3ef574d9bab301, 0xce702acbb55cbdbe, 0xe69a865529da9b4c,
+        0xc270fc5424885b29, 0x5f68d92554e591d8, 0xdd380521722b8dc7, 0x330a48ff2a309690,
+        0x0ddd948c0a557a95, 0xc50f63f4a1bd421a, 0xaf168e5d252e91a4, 0xa38b1c2906bfd54b,
+        0x476688766cb7e393, 0x3c39dbf2c9f70ecc, 0x25aa9b3a497d5845, 0x5829b4eed4daeb49,
+        0x61d4c8e75cf84de4, 0x99b8a614492960e6, 0x4dc2619f61ff43ed, 0x7afa532470cc9508,
+        0x907f2de04d262126, 0xd28577245e693755, 0xa2bfc52a5ca82ff3, 0x6e4f4f0cf26d6d99,
+        0x7c7242683c98a39a, 0xcd6d22f81d4bdba5, 0x6d0a0f3297be4373, 0xed53d07f5303073b,
+        0x6c16c9586d57a804, 0xb7f23a70116d4b2f, 0x784d52ed9df1759c, 0xdd3285aeca57ae93,
+        0xfc68dcffc1d5aff0, 0x50f022029e3d3e26, 0xaec77df9226c506e, 0xf37162a57f83501e,
+        0x94cb5f404dc79c24, 0x2e6545bbc018e92b, 0x98a2132e058458be, 0xa6723010e948ff16,
+        0x768e0b28ff44de4d, 0x51f79d2cbf3516f0, 0x23b584a347afbfb6, 0xd55b825cdabd9de5,
+        0x05bc001f39497ac7, 0xdb660919fd56f42f, 0xb8e5ed84e44ff11c, 0x69758b41a439b895,
+        0x3014e49b007b9ef6, 0x408655e9106e4d46, 0xd84c043c0d13370a, 0xf33687a1bf2e1c37,
+    ],
+};
+
+/// The binary expansion of π/6.
+pub(crate) const FRAC_PI_6: BitTable = BitTable {
+    exp: 0,
+    bits: &[
+        0x860a91c16b9b2c23, 0x2dd99707ab3d688b, 0x70ac3405b19a884d, 0x56b27f197cb7bcc1,
+        0x8b86b0510978033e, 0x9fb8bbcd337c2cbc, 0xcac75c494c3f62cf, 0x8a96239e48e12c2e,
+        0x985923a441945484, 0xa2dd81f1197a9e47, 0x5d54e879f8047a9e, 0x9ed047fce7066a6e,
+        0x746a180ba8321544, 0x30c5998bf342e77e, 0x8155a87b16427f59, 0x10918579683937bc,
+        0x460ed51b536ddf95, 0x0243936d3dc273b9, 0x6841f78ec058e1d2, 0x69e370afa0646448,
+        0xef5d78dedc7dbaad, 0xf64d9d5b31656ba8, 0x21b5942ec979ded2, 0x97befa1d655f0402,
+        0x676fad174805170a, 0x792e3ea04a32e1db, 0xe97287f9b8e564ba, 0xd10e30fdf1b8f1ee,
+        0x0e8c1965bb5158b5, 0x63a1b43c5c71d81e, 0x737764b35835a6cd, 0x1ae36bc7ea1326ed,
+        0xf35258ad90929f5c, 0x5c9c4b8f93595da9, 0x2264b503c4969885, 0x1d4e745de75b77e4,
+        0xbf086340316e4113, 0xdf428c196737499d, 0x4b755159e65c0598, 0x904eac4cd4859c42,
+        0xe16a1cbaba52155d, 0xd2960f8fa6eb939d, 0xa4b105d5d1e62f41, 0x5b418a6af8991ccb,
+        0x82923d5340a8b5b4, 0x32573615c6160556, 0x11a17d61c505448f, 0xb04bbc0b2926e76f,
+        0x112cc4baf1f896d2, 0xbc62f023240e07e6, 0xc3ad4686c7383345, 0xe7d281e758945f50,
+        0xc9b4a80d6a7f31c4, 0x1afd90da3447d593, 0xbbcc643515b5d7c1, 0x6cd26baee0e7eff3,
+        0x6a40c64b34973a75, 0x2567e8f96ba03013, 0x3559b61a8e7591c6, 0x62789c65b3b53e81,
+        0x04aa7a930b19d5b4, 0xdea2ce8622ac5861, 0x79d7fc788c52f56f, 0x2be33dcc40198494,
+        0x864e144ed3d17a7e, 0xa5ffb80473bee209, 0xee922574d620011f, 0x2046e29e601a9020,
+        0xba64c5205991b0bb, 0x3c29e7f28a676348, 0xd8899dd1df387cbd, 0x3cff62da99dd6e32,
+        0x22158b8b728fca2c, 0x4a7b4d5625088c7f, 0x910286abd987b748, 0xa021f163e0f6be02,
+        0xa301e53449a354a4, 0x8e3ef574d9bab301, 0xce702acbb55cbdbe, 0xe69a865529da9b4c,
+        0xc270fc5424885b29, 0x5f68d92554e591d8, 0xdd380521722b8dc7, 0x330a48ff2a309690,
+        0x0ddd948c0a557a95, 0xc50f63f4a1bd421a, 0xaf168e5d252e91a4, 0xa38b1c2906bfd54b,
+        0x476688766cb7e393, 0x3c39dbf2c9f70ecc, 0x25aa9b3a497d5845, 0x5829b4eed4daeb49,
+        0x61d4c8e75cf84de4, 0x99b8a614492960e6, 0x4dc2619f61ff43ed, 0x7afa532470cc9508,
+        0x907f2de04d262126, 0xd28577245e693755, 0xa2bfc52a5ca82ff3, 0x6e4f4f0cf26d6d99,
+        0x7c7242683c98a39a, 0xcd6d22f81d4bdba5, 0x6d0a0f3297be4373, 0xed53d07f5303073b,
+        0x6c16c9586d57a804, 0xb7f23a70116d4b2f, 0x784d52ed9df1759c, 0xdd3285aeca57ae93,
+        0xfc68dcffc1d5aff0, 0x50f022029e3d3e26, 0xaec77df9226c506e, 0xf37162a57f83501e,
+        0x94cb5f404dc79c24, 0x2e6545bbc018e92b, 0x98a2132e058458be, 0xa6723010e948ff16,
+        0x768e0b28ff44de4d, 0x51f79d2cbf3516f0, 0x23b584a347afbfb6, 0xd55b825cdabd9de5,
+        0x05bc001f39497ac7, 0xdb660919fd56f42f, 0xb8e5ed84e44ff11c, 0x69758b41a439b895,
+        0x3014e49b007b9ef6, 0x408655e9106e4d46, 0xd84c043c0d13370a, 0xf33687a1bf2e1c37,
+    ],
+};
+
+/// The binary expansion of 2/π. This table is much longer than the others, since reducing an argument `x` modulo `π/2` needs the bits of `2/π` from position `log2(x)` onwards; it stores `2^18 + 2^13 = 270336` bits, which is enough to reduce any finite argument of formats with at most 19 exponent bits (the exponent width of IEEE 754 binary256) and a total width of at most 2048 bits, or with fewer exponent bits and a larger width.
+pub(crate) const TWO_OVER_PI: BitTable = BitTable {
+    exp: 0,
+    bits: &[
+        0xa2f9836e4e441529, 0xfc2757d1f534ddc0, 0xdb6295993c439041, 0xfe5163abdebbc561,
+        0xb7246e3a424dd2e0, 0x06492eea09d1921c, 0xfe1deb1cb129a73e, 0xe88235f52ebb4484,
+        0xe99c7026b45f7e41, 0x3991d639835339f4, 0x9c845f8bbdf9283b, 0x1ff897ffde05980f,
+        0xef2f118b5a0a6d1f, 0x6d367ecf27cb09b7, 0x4f463f669e5fea2d, 0x7527bac7ebe5f17b,
+        0x3d0739f78a5292ea, 0x6bfb5fb11f8d5d08, 0x56033046fc7b6bab, 0xf0cfbc209af4361d,
+        0xa9e391615ee61b08, 0x6599855f14a06840, 0x8dffd8804d732731, 0x06061556ca73a8c9,
+        0x60e27bc08c6b47c4, 0x19c367cddce8092a, 0x8359c4768b961ca6, 0xddaf44d15719053e,
+        0xa5ff07053f7e33e8, 0x32c2de4f98327dbb, 0xc33d26ef6b1e5ef8, 0x9f3a1f35caf27f1d,
+        0x87f121907c7c246a, 0xfa6ed5772d30433b, 0x15c614b59d19c3c2, 0xc4ad414d2c5d000c,
+        0x467d862d71e39ac6, 0x9b0062337cd2b497, 0xa7b4d55537f63ed7, 0x1810a3fc764d2a9d,
+        0x64abd770f87c6357, 0xb07ae715175649c0, 0xd9d63b3884a7cb23, 0x24778ad623545ab9,
+        0x1f001b0af1dfce19, 0xff319f6a1e666157, 0x9947fbacd87f7eb7, 0x652289e83260bfe6,
+        0xcdc4ef09366cd43f, 0x5dd7de16de3b5892, 0x9bde2822d2e88628, 0x4d58e232cac616e3,
+        0x08cb7de050c017a7, 0x1df35be01834132e, 0x6212830148835b8e, 0xf57fb0adf2e91e43,
+        0x4a48d36710d8ddaa, 0x425faece616aa428, 0x0ab499d3f2a6067f, 0x775c83c2a3883c61,
+        0x78738a5a8cafbdd7, 0x6f63a62dcbbff4ef, 0x818d67c12645ca55, 0x36d9cad2a8288d61,
+        0xc277c9121426049b, 0x4612c459c444c5c8, 0x91b24df31700ad43, 0xd4e5492910d5fdfc,
+        0xbe00cc941eeece70, 0xf53e1380f1ecc3e7, 0xb328f8c79405933e, 0x71c1b3092ef3450b,
+        0x9c12887b20ab9fb5, 0x2ec292472f327b6d, 0x550c90a7721fe76b, 0x96cb314a1679e279,
+        0x4189dff49794e884, 0xe6e29731996bed88, 0x365f5f0efdbbb49a, 0x486ca46742727132,
+        0x5d8db8159f09e5bc, 0x25318d3974f71c05, 0x30010c0d68084b58, 0xee2c90aa4702e774,
+        0x24d6bda67df77248, 0x6eef169fa6948ef6, 0x91b45153d1f20acf, 0x3398207e4bf56863,
+        0xb25f3edd035d407f, 0x8985295255c06437, 0x10d86d324832754c, 0x5bd4714e6e5445c1,
+        0x090b69f52ad56614, 0x9d072750045ddb3b, 0xb4c576ea17f9877d, 0x6b49ba271d296996,
+        0xacccc65414ad6ae2, 0x9089d98850722cbe, 0xa4049407777030f3, 0x27fc00a871ea49c2,
+        0x663de06483dd9797, 0x3fa3fd94438c860d, 0xde41319d39928c70, 0xdde7b7173bdf082b,
+        0x3715a0805c93805a, 0x921110d8e80faf80, 0x6c4bffdb0f903876, 0x185915a562bbcb61,
+        0xb989c7bd401004f2, 0xd2277549f6b6ebbb, 0x22dbaa140a2f2689, 0x768364333b091a94,
+        0x0eaa3a51c2a31dae, 0xedaf12265c4dc26d, 0x9c7a2d9756c0833f, 0x03f6f0098c402b99,
+        0x316d07b43915200c, 0x5bc3d8c492f54bad, 0xc6a5ca4ecd37a736, 0xa9e69492ab6842dd,
+        0xde6319ef8c76528b, 0x6837dbfcaba1ae31, 0x15dfa1ae00dafb0c, 0x664d64b705ed3065,
+        0x29bf56573aff47b9, 0xf96af3be75df9328, 0x3080abf68c6615cb, 0x040622fa1de4d9a4,
+        0xb33d8f1b5709cd36, 0xe9424ea4be13b523, 0x331aaaf0a8654fa5, 0xc1d20f3f0bcd785b,
+        0x76f923048b7b7217, 0x8953a6c6e26e6f00, 0xebef584a9bb7dac4, 0xba66aacfcf761d02,
+        0xd12df1b1c1998c77, 0xadc3da4886a05df7, 0xf480c62ff0ac9aec, 0xddbc5c3f6dded01f,
+        0xc790b6db2a3a25a3, 0x9aaf009353ad0457, 0xb6b42d297e804ba7, 0x07da0eaa76a1597b,
+        0x2a12162db7dcfde5, 0xfafedb89fdbe896c, 0x76e4fca90670803e, 0x156e85ff87fd073e,
+        0x2833676186182aea, 0xbd4dafe7b36e6d8f, 0x3967955bbf3148d7, 0x8416df30432dc735,
+        0x6125ce70c9b8cb30, 0xfd6cbfa200a4e46c, 0x05a0dd5a476f21d2, 0x1262845cb9496170,
+        0xe0566b0152993755, 0x50b7d51ec4f1335f, 0x6e13e4305da92e85, 0xc3b21d3632a1a4b7,
+        0x08d4b1ea21f716e4, 0x698f77ff2780030c, 0x2d408da0cd4f99a5, 0x20d3a2b30a5d2f42,
+        0xf9b4cbda11d0be7d, 0xc1db9bbd17ab81a2, 0xca5c6a0817552e55, 0x0027f0147f8607e1,
+        0x640b148d4196debe, 0x872afddab6256b34, 0x897bfef3059ebfb9, 0x4f6a68a82a4a5ac4,
+        0x4fbcf82d985ad795, 0xc7f48d4d0da63a20, 0x5f57a4b13f149538, 0x800120cc86dd71b6,
+        0xdec9f560bf11654d, 0x6b0701acb08cd0c0, 0xb24855510efb1ec3, 0x72953b06a33540c0,
+        0x7bdc06cc45e0fa29, 0x4ec8cad641f3e8de, 0x647cd8649b31bed9, 0xc397a4d45877c5e3,
+        0x6913daf03c3aba46, 0x18465f7555f5bdd2, 0xc6926e5d2eaced44, 0x0e423e1c87c461e9,
+        0xfd29f3d6e7ca7c22, 0x35916fc5e0088dd7, 0xffe26a6ec6fdb0c1, 0x0893745d7cb2ad6b,
+        0x9d6ecd7b723e6a11, 0xc6a9cff7df7329ba, 0xc9b55100b70db2e2, 0x24ba74607de58ad8,
+        0x742c150d0c188194, 0x667e162901767a9f, 0xbefdfdef4556367e, 0xd913d9ecb9ba8bfc,
+        0x97c427a831c36ef1, 0x36c59456a8d8b5a8, 0xb40ecccf2d891234, 0x576f89562ce3ce99,
+        0xb920d6aa5e6b9c2a, 0x3ecc5f114a0bfdfb, 0xf4e16d3b8e2c86e2, 0x84d4e9a9b4fcd1ee,
+        0xefc9352e61392f44, 0x2138c8d91b0afc81, 0x6a4afbd81c2f84b4, 0x538c994ecc2254dc,
+        0x552ad6c6c096190b, 0xb8701a649569605a, 0x26ee523f0f117f11, 0xb5f4f5cbfc2dbc34,
+        0xeebc34cc5de8605e, 0xdd9b8e67ef3392b8, 0x17c99b5861bc57e1, 0xc68351103ed84871,
+        0xdddd1c2da118af46, 0x2c21d7f359987ad9, 0xc0549efa864ffc06, 0x56ae79e536228922,
+        0xad38dc9367aae855, 0x3826829be7caa40d, 0x51b133990ed7a948, 0x0569f0b265a7887f,
+        0x974c8836d1f9b392, 0x214a827b21cf98dc, 0x9f405547dc3a74e1, 0x42eb67df9dfe5fd4,
+        0x5ea4677b7aacbaa2, 0xf65523882b55ba41, 0x086e59862a218347, 0x39e6e389d49ee540,
+        0xfb49e956ffca0f1c, 0x8a59c52bfa94c5c1, 0xd3cfc50fae5adb86, 0xc5476243853b8621,
+        0x94792c8761107b4c, 0x2a1a2c8012bf4390, 0x2688893c78e4c4a8, 0x7bdbe5c23ac4eaf4,
+        0x268a67f7bf920d2b, 0xa365b1933d0b7cbd, 0xdc51a463dd27dde1, 0x6919949a9529a828,
+        0xce68b4ed09209f44, 0xca984e638270237c, 0x7e32b90f8ef5a7e7, 0x561408f1212a9db5,
+        0x4d7e6f5119a5abf9, 0xb5d6df8261dd9602, 0x36169f3ac4a1a283, 0x6ded727a8d39a9b8,
+        0x825c326b5b2746ed, 0x34007700d255f4fc, 0x4d59018071e0e13f, 0x89b295f364a8f1ae,
+        0xa74b38fc4ceab2bb, 0x47270babc3a734ba, 0x6052dd34f8563aeb, 0x7e8a31bb365895b7,
+        0x47f7a994c3aad392, 0x251e7f3ed8974ebb, 0xa94fd8ae01e661b4, 0x393d8ea523aa3306,
+        0x8e1633b53bb1881d, 0x3a9d4013d0cc1be5, 0xf862e73bf28f39b5, 0xbf0bc23522747ea2,
+        0x47c0d52d1f19add3, 0x9094df9311d0b42b, 0x25496db2e264b25e, 0xf1353bc6a41a4ad0,
+        0xaac92e64e8865730, 0x91982cfb311b1a08, 0x728bbdcee160e142, 0xeb641dd0bba3e559,
+        0xd4597b8c2a4483f3, 0x32baf848672c8d1b, 0x2fa9b050f3ddf9f5, 0x73db61b4fe233e6c,
+        0x41a6eea318775a26, 0xbc5e5ccea70894dc, 0x57e20196f1e839be, 0x48515d2d2f4e9555,
+        0xd96ec2e7d7556304, 0xe0c02e0efc40a0bb, 0xf9b37125a7222dfb, 0xf619d8838c1c6619,
+        0xe6b20d55bb513779, 0xe809af91490d73de, 0x0b0da5ce7f58ac19, 0x347246677a1a139e,
+        0x26bc4555e7585cb5, 0x711d14486991480d, 0x6056adabd62f6496, 0xee0c212ff35d6d88,
+        0xa6768495651eab9e, 0x0a4ddefe57101083, 0x6a39f8ea319e381d, 0xeac8b1cac96b37f2,
+        0x1ed505e99847439f, 0xc56c0331b73b8bf8, 0x86e56a8dc3436230, 0xe793cfd56a8f2d73,
+        0x30051af021a09fcb, 0x7415a1d56b236ff7, 0x252f4bc7b8a5917f, 0xac595c55de212c38,
+        0xb132965cff503662, 0x62fa7b16f4d9a62a, 0xcfe7f07403d4d604, 0x6fd91631b1bfcbb4,
+        0x505bd7c80ce1946b, 0xd6434fd91cdf4543, 0x5f3453e2b5aac9ae, 0xc8131485f9d2bfba,
+        0xdb9e76f5b9af15cf, 0xca318214b56de9fe, 0x4d50fc35f5aed5a2, 0xd0c1c96057192eb6,
+        0xe91d9207d144aea3, 0xc634356626d5b431, 0x61e237f1a2209eff, 0x958e2349379835f4,
+        0xa64bdc02c2be13be, 0x80a00b72a3115c5f, 0x1e1bd10db4d3869e, 0x8596976b2ac91f8a,
+        0x26c23070f0041412, 0xfc9fa5f72a389c68, 0x78e2aa7650cfe155, 0x9274934e380a92f7,
+        0x5533f0a63db43999, 0x71e2b755a98a7c00, 0x8f19ac54d22ea0b4, 0xf5f3e060c849ffd2,
+        0x69ae52ce7a5fdde9, 0xce06fb0ae8a50cce, 0xea9d3e3766ddb834, 0xf50da090846f884a,
+        0xe3d5099a032eae2d, 0xfcb40afb9b33e281, 0xdd1b16bad8c0afd9, 0x6b97b52dc99c277f,
+        0x5951d521ccd6b649, 0x6b584562b3baf2a1, 0xa5c47ca2cfa9b93d, 0x7b7b89483d3896b0,
+        0x3cc79cb1d0825d88, 0xedb7d383390c6e66, 0xe912dc1120340de7, 0x82a0fee6667881dc,
+        0x0ae80abfcd28c1b7, 0xacde18c4f8237666, 0xb9959db42c07faab, 0xd6038a557056aeeb,
+        0xf5b0bd95e94be00b, 0x9eb3a61e23f24ad6, 0x2c9ac4f1ad75c412, 0xf93b6e0b4c2ed46c,
+        0x0ed2e55e16558786, 0x80fbf676b41e698e, 0xc3ae285d4977c92a, 0x16374e193906f911,
+        0xc91aaa27467f0da5, 0x933f69d6a2578ec2, 0x9f9faa8b4add9bcf, 0xbb5c1d0be88dd533,
+        0xfede36f7ef60b308, 0xb1cc01829ebb5915, 0x17b1cc96b7eef0cd, 0xe267ca73d1a01943,
+        0xb8a3749628ea6a37, 0x26262e928185ea61, 0x5fdeeb61d7e2cfa7, 0xaeb7948d28b48c7b,
+        0x1c4c02f15f9d850b, 0x8a2db016cdd5638e, 0x33f4b3b45c292f51, 0x2b04b90d6fd83995,
+        0xdeadc51d8262ac03, 0x09a2a916025aaf0a, 0xd3e4275f590b067e, 0x774b63e8ab012afe,
+        0xfc81441b67bfb19f, 0x6d4d79557ffa4d3b, 0x0b29c0264dd64734, 0xa1ad8633e708725b,
+        0xdf8a0fbefc685251, 0xe13b665d171871a8, 0x41a28f4e2e14db2f, 0xa42505c02c474738,
+        0x60373048512ddf2c, 0xab5aa559238ba27b, 0xc2460006db6951b1, 0xe0f1341979ab9bc2,
+        0x00cbe2ca690fa693, 0x4f31f325f2fef910, 0xb32d6fcc0215d378, 0x6cffaee95d2616fe,
+        0x23843f2c828acdf4, 0xd2499ee3ceb09f06, 0xfb3942e0d6c4e24c, 0x163a6888e07fd00c,
+        0xb22dd531bc9b63c5, 0x3c94eb2d8ffa1e1a, 0x7fba45e57b7c2624, 0xe78a92ac2996341d,
+        0x1352f7d5a42191d1, 0x231a2c3909576f11, 0x161fffc69032a3fe, 0xd8e43e97df96da4d,
+        0xf9a472a237db66be, 0xab208b01bdba8f4a, 0xa0b4c1e8e4038614, 0x695a01d9d1914e00,
+        0xe8de742238f4d580, 0x42160ff0da4af8a0, 0xf7527e5ecb981d04, 0xbedfa47a1f4045dc,
+        0x68477f528c7ef7f0, 0x58b5b4a4bbae0a35, 0x51267e265db7c59b, 0xbc2a68e9d37e1ad6,
+        0x1d19fb56b25c68ed, 0xa71aefc9be937af4, 0x599520f5daeb0755, 0x08c71f46bde8fac7,
+        0x829495c93aaafbed, 0xbbf1be263e4f0dde, 0xfdafb99eeeb0762b, 0xac49bdcf73b8d068,
+        0x92510aed848c2176, 0x7f3a59d42cde0fea, 0x912a6c2fb60a4920, 0x9fe95da2b596bb00,
+        0x566245199c8b2fea, 0xf0c42cfc3e6c8afd, 0xf40ff29c022d8021, 0x4a8cd63ead13ba94,
+        0x9befec3707a4d18a, 0x0a4325445aba761f, 0x06cca16d4d1536ab, 0x5e0ce333d4c2913f,
+        0x281bf7464239e78b, 0x12aff80175f844f9, 0x832ce5b78d6da4ea, 0x4f0aed6c1bd83f1d,
+        0x2efc76318bd928fd, 0xdc3bf172e2bcb977, 0x44afe05b819cd975, 0x1f7254b9e4e059ab,
+        0x67652e1b7dfbacd9, 0x04e72e0b2a99948b, 0x16b9a380eaf9d1f3, 0x5399cb58de762c86,
+        0xa36b0214b7d9f208, 0x34e2aa7c39917735, 0xebaf80537add36c8, 0xa562cd07d485e85e,
+        0x0a57d5ad78298160, 0x2ac3e45b1305f3a3, 0xef26db0d0910214a, 0x27790cd1e3c2182d,
+        0xf51c3c2001c11d39, 0x2986c73c43c14264, 0x74927ce6ce03dba3, 0xb72ade5ad28afb5d,
+        0xf8886f4060ad413e, 0x90e0083bb550001e, 0x7da01b409a139aad, 0x4a378687c6c56a3d,
+        0xf68cc38b4ab7ae39, 0xc9dad17e6178851b, 0xa345d3ab0da7ed4f, 0x9f4038caabc87d9a,
+        0x6a007a584c63e151, 0x4d4dde063f368501, 0xef295fa25965ca67, 0x31bc830f846b554e,
+        0x0bd445020f5605da, 0x490db5e33fa7404a, 0x2717c4e17059ca13, 0xfb742c2b7a1c2dd7,
+        0xe754a9545d040274, 0x654a19dadfc79923, 0xe89dc953edd05cea, 0xc48360a7d19a1800,
+        0xa909cd3765585975, 0x5e196dcac7e07141, 0xf2a86b8df49dde9d, 0xd9a4b034bc48a10c,
+        0x19a4b52a2a47c599, 0x32de0df67f67d7ae, 0x3393c90f913f7754, 0x971ec1c6297725aa,
+        0x0d0aa6fa0a70e816, 0xf4f7caf4cf7711ef, 0x5e960a929895960f, 0xba2e9b3c5c6382a0,
+        0xcb52513bb0fc856f, 0xf7afa8dd087b7451, 0xd9279c56c9173c0a, 0x80996aafdbdbb81f,
+        0xeba9f6c2e1491602, 0xa9769411ce869a53, 0x955c863526c3446b, 0x9fbe0ab79cfa9cc8,
+        0xb4f49457bc07ceaf, 0x01dfb6b9b36dfda0, 0x437fafbff66dfac8, 0x82d11f4ed74fd9ee,
+        0xc335b12cc9c58468, 0xe0af4ace0d5539f5, 0x91d18d78c5f2328f, 0x8a9e29078d4d40b8,
+        0x1831877373cb9567, 0x5bc8d0de6e60996b, 0x5b5113c577aaab80, 0xb387ddc92a7c6a13,
+        0x291dcd9967044bae, 0xd431b3cf7ee28772, 0x8ef5212310bcf765, 0x1c112c7d0c5dc625,
+        0xce02299b0c65b3ab, 0xd63e7e13a0e4b378, 0x7662d7ec9f2859c0, 0x58d8fa0b8bd66872,
+        0xc98e2d3aabe08cb0, 0x38ee5afae0a69c94, 0x71813c0d290f115b, 0x4d5448006f08b20a,
+        0x02c29155e70276e9, 0xa12220b959a61a47, 0xff278141b892ddd0, 0xc29c0eb39fe63ee0,
+        0xd06504dfdefa62dc, 0x44ef837842e941ce, 0x8fe00dddf0a9b0e4, 0xdafea2048ae861c4,
+        0xc8e81b61e81d087c, 0x1b97db9b381750fb, 0x63b5c309b178f2a6, 0x9e9357928c6ae15f,
+        0x4096e6739a5ae3d7, 0x1d7f78255bd41424, 0x8c0b8c6a1c704468, 0x19f54a03562ebfb5,
+        0x5a26349dbf9c18b6, 0xb4fd16615fbaa6f2, 0xa67015f9056a4aaa, 0xa1b621f24a82594c,
+        0x0ded1cd0cf12b832, 0x25c0a48b606c84de, 0xcc16eef409cfbbe4, 0xbb68bfe02a158db4,
+        0x9e8280d137ec11cc, 0x67e18f263f374050, 0x7ecd1438ebcefea2, 0x2bd40ae85dbaf302,
+        0xdf0cb72940b91252, 0xf6cf17d5e815f0a6, 0xec7ebc244c11f412, 0xe32c7ba844579966,
+        0x188a0f984002ee63, 0x4b861eb8a5600233, 0x335a491a48d9937c, 0x3b66d1732ce8b5a3,
+        0x1d5eb73fa018a355, 0x9f59272eccb718c9, 0xcf48c4886a9f133d, 0x9623750baa9396eb,
+        0x7bfba3de4d2b6b66, 0x6153da73521ff187, 0xdbce424432b790f1, 0x03423764e6c80f01,
+        0x5e1a2fb668f9f0e6, 0xcdfdc09d4cbe9234, 0x75ccecc741a79ee4, 0x2a254539f4d32db4,
+        0x8a265f13b43c4699, 0x5fb00be081f4e89c, 0x1265f5c8b4f61544, 0x1fbfaa99fd22bda1,
+        0xf32fdefce7a64382, 0x51c1e2b46e82ad8b, 0x82f8a691a9487b6a, 0x02050c453f6f3167,
+        0xd367f91a1945f973, 0x9db3d39d41ebc5e2, 0x48299ae27a82fe99, 0xddd3f274b8f982ae,
+        0x429012ffa72607b5, 0x34cf467c0c25353b, 0x7490f2511166c314, 0x9fbaaf8cbbf2e077,
+        0xd915eea5d16aa52d, 0x229a5a52bab7297a, 0x21764f0a4c436e58, 0x89bdef81306997a8,
+        0xd9f9886acdf7b529, 0xe48569445bcc78b4, 0x76bcc79842aad92c, 0x30a8a996462e338c,
+        0x7e711bceec61f51d, 0x1abcca05caffb606, 0x77cf7c8db728e1f7, 0xef93e570e66a6aaf,
+        0x7f1dad75c3cb9e00, 0xfbbac147b5199b14, 0x74c6925d4af903dc, 0xcc92514c77bbf59a,
+        0x9c3581c81549d6cd, 0x570e1468e5123a56, 0x66fa597dc908cf32, 0x2f6041fe8dd2e30b,
+        0xc53f3b8e42fa1370, 0xf957369e2d2738dc, 0xf6defd86fe8fefa9, 0xa6eb61d1747dd947,
+        0x8b9ec181fe19cc7e, 0x2a7e019465ebf9a0, 0x82758fd7210010ff, 0xa3f36146b61c8273,
+        0x24a4c418a7a55046, 0x03e3bbba606c0912, 0xf3d1bc886358a8a2, 0x47c1433e8348e84d,
+        0x25c8b0e083b47c9d, 0xe804e305067d611c, 0x42f22b4990cfe863, 0x4242aa10f4d41b09,
+        0x548a2e5aa0fe0b34, 0x9614f1e6b6558e02, 0x756495702f7620de, 0x09579d370788dfb8,
+        0xa07413bb11c31f04, 0xd9368aa63589fa22, 0xdf9095e6f2d69516, 0xb05d17738d7c5066,
+        0xe35b4a04463a6ff6, 0x8991e5372ac5fdc7, 0xba151d9e123ad423, 0x9b205188b3d3c431,
+        0xf40d491a375ddcee, 0xffdbecc87bf7a375, 0x42f36f1588e314a5, 0xb29a99bf407f26b6,
+        0xde778643da7fdba6, 0x436224291959ed1c, 0xe7972e3271cbadaf, 0x66766346e9bf11bc,
+        0xcaae96f37ac00896, 0xb4b4b3f2b210c15e, 0x44d78199752c0864, 0x7d157e6f032cc614,
+        0x89bbbcc7a87364f3, 0x0bd5037b5d2e9c05, 0x300f6ebf9af82b38, 0x73bbe297fbcfbdc2,
+        0x5b39a28320eeb945, 0x96c932dd142da1d4, 0xd2c96bfa91fbadd0, 0xbd86adc50bb2bddd,
+        0x9e77ea90ba0297b1, 0x06ed5b1cd405563c, 0xf25ed6cf0aff0fea, 0xb6c2b4452c3becf1,
+        0x8c5a570fb0df21c2, 0xfae15eeda6f29e2a, 0x5a6e44f75ea55e65, 0xf5733fa4e17637e6,
+        0xc4121e0fa9db7dca, 0x9dde894d3d14e00b, 0x7642980cced6610c, 0xa0c49ebdcf873d94,
+        0x35d83ff14c3adfae, 0x376b61a12bd24251, 0x08157c7fd6deef6a, 0x134e3c20490b6c99,
+        0x9eeb87b72d113266, 0xfec137e8d6257ad7, 0x276e71c084b042c1, 0xfbf06a70f327606f,
+        0x1138da2717ee4097, 0x96272392286c41b7, 0xdc1047dfdf55c37c, 0x2d3bcf93a4544623,
+        0x4b0fb68c180624c1, 0xec66fc6d40650020, 0x14d03369abbf0e08, 0xa72a54092e54d93a,
+        0x5f18d4b16cdaea86, 0x4f63d0352aabc8db, 0x20328c16748753f4, 0x32ddc59ef64a0862,
+        0xbe99a364d62e5259, 0xc55c9f25699960b3, 0x884b727ce9d24d36, 0x996b761d86ed115a,
+        0x2cc538f9c43f8959, 0xe974e751b3b55060, 0x4d94a922015f190e, 0x1ba55884bc3a9574,
+        0x5d009a73f29ca7bb, 0x4d719e27014c42f3, 0xd41228b28d922070, 0x15b9904f5cc2bb94,
+        0xea6916fdd9c40ed0, 0x81b586619a9fb813, 0x33b7b2aa6e2a70fa, 0xcfd7bc4a2579943f,
+        0x22aa87607e9c99e8, 0xf12435b267412f6b, 0x96dca1536ef06051, 0x35dee9c640125bdf,
+        0x7e30f9d416c5487e, 0x1a047431c77e38a0, 0x361b0f5c2b1134f0, 0xcf5b57cb5324a4e7,
+        0x30f5d11c9c250e77, 0xbdf692ff13668416, 0xe0198abd358d8d8e, 0x957ba0bf81a1dffe,
+        0xb68aec18142d5e9b, 0xcb3e14d20ecbe46b, 0x516138d04bf547fa, 0xba86e737e026f0c2,
+        0x19e7a8845465d136, 0x677b2ac12a8933c9, 0x704944565da9844f, 0x016b59c2bd989db4,
+        0x2617be05941ae303, 0xf40f976a36db46c0, 0x9dd99532d65ab49e, 0x310bf6e1bb834689,
+        0x23415595e93aedb9, 0x4b1638fe2b4aa3f6, 0xda0d685c136d83be, 0x50c1188ae8ce261b,
+        0x2c276d8dd95709d0, 0xcf752e59825d8c91, 0xa2d63ea23b478aa6, 0x052362cbef3eb435,
+        0x8680533b586dfe51, 0x0b71bb6d796d0d51, 0x650cec1c0675550c, 0x2140eee62590bccf,
+        0xef71f65bb2febf99, 0xda2a2aed4f6a44d3, 0x71a7434cbc2ed852, 0x1d2cd6a7944d148d,
+        0xe71bb2c67430ab2e, 0x92b0604c76eafccb, 0xcd0f511efd46c9a3, 0xe0c073f274309b0b,
+        0xaefd49552a88688a, 0xb4258baa7840bd5a, 0x60f7aac651227491, 0xf73cfdfb0d6608c4,
+        0x474ec5792f7ff898, 0xd83e0abe86c1660b, 0x5152fd9c6fe62d16, 0x45ee1baaf0022dab,
+        0xde83404127cfa9e8, 0x8484e3f21f26d3f7, 0x9ab66094e0aee633, 0xaf4b366a8f9e42b2,
+        0xe19e73547add580a, 0x4cd42cd886699e44, 0x38127365bbfe2a12, 0x887b307917042101,
+        0x2acd6528d985c22f, 0x008b439147ef7bae, 0x5e00464051e5c20d, 0xed523d98bad6ed39,
+        0x59f7649050469716, 0xbebf6531d27c3899, 0xc6ead65b67735256, 0xd1677a7e2ab9477a,
+        0x98f33f5a9ec84fe1, 0xf14a726e396c4a74, 0x8dbf0cc011073690, 0x90edf43334f658ff,
+        0xd146d413e8381497, 0x169db6cd8a000d3f, 0xc134c78fe24dddd0, 0x7f21646b214f1264,
+        0xf69d7af3e283d149, 0xc3a6f11038fa0be6, 0x7fe813d84a1540fe, 0x746a191f3260fed6,
+        0x0f59f922ac396cbe, 0x3addadec40fd464c, 0x26266c0b6df8082b, 0xac3480ace12d3558,
+        0x608e6f0078d39bf2, 0xce2b316fa206ee1d, 0x4c2738752a6a7568, 0x2e330a42f2f3e1ac,
+        0x677e8d822d628712, 0x137d81551a139f7d, 0x98d663e835607752, 0xd11f27d84de7da3e,
+        0x316c5206d0e31071, 0xd23f4175ad2277c5, 0x22d20bf9fc1edc16, 0xec9198b1cf0962e4,
+        0x4a64da17e3ad3bcb, 0x19dc7cafb6a3b52d, 0x892f4bb2f9187459, 0xe0f84061704e8636,
+        0x61a19ebed50ee67a, 0x060f9aef0d321133, 0x5c5042b7f773b397, 0xe3871d5466f258cf,
+        0xa605278088034391, 0x49ee583ebbe97443, 0xea268fff0c3208fd, 0x1ab04181ff468823,
+        0x82e2fd5906be5fd3, 0xd556b16e60b8b6aa, 0x1fa7f1dc54fbfd8e, 0xeb2638cea53097f1,
+        0xcbdfe15ce8747544, 0xb045e8f7ce4470b9, 0x1c30f3b890343bca, 0xcd38006233f55726,
+        0x366c7e9102fc2bdd, 0xb5361018e391c36a, 0x9a0e5274c52c2171, 0xd6f4c21efaa6c2fd,
+        0xfef20b811f1d2752, 0x893a10aa4422e0fb, 0x92a1def62679bd13, 0xdb1bfe323118c789,
+        0x8ce498a8465fb8df, 0x394f585257462cc6, 0x2193c03d618d0fbb, 0x95e71d877af0d6e3,
+        0x241fece19ee9bdea, 0x1e7f3b12a547ef89, 0x3339cde4a167b06a, 0xe3673f6256bd9813,
+        0x5ea0ddb30d7517c9, 0x6a397c5287e29ae6, 0x1d88d51adaec4bec, 0xd33d4dc7ec7ab8d8,
+        0x5df2161c259093fa, 0xd6b5f8366c325815, 0x724eab484dec0a5f, 0x37de0b124409e378,
+        0xb2dc58276e631501, 0xbfcc5b4a2a9d3c11, 0x6d66a1420839198d, 0x5e64bb9e93c92cd3,
+        0x28f7b63078cae18f, 0xb4472415330d41bb, 0x19ca15bc75307e7f, 0x43a3d40b25615af6,
+        0x6714e241e71faee1, 0x2ac58cfdf1474062, 0x3469161f5b26587a, 0xe92810cf1a28a8ec,
+        0xfb8833b29b03d0ba, 0xacd3aeb09c17e50c, 0xf5db34bc2690cfd4, 0xfb29454238f18675,
+        0xcc238199dcf0758b, 0x0d7bbd62d7580581, 0x1b92d9020d621d06, 0xe7b0a1421c6341cb,
+        0x3b723c07e661062c, 0xa94d305191c1aafb, 0x089ead397b657531, 0x82799308c047b21f,
+        0x0a29bf21a291b3ee, 0x7868227ad8e4a56b, 0x3198f89e1d26c82e, 0xc6b81366812178bc,
+        0x77c1198a2b8dcd66, 0x26e5b6fb93720289, 0xecf27783c285a49c, 0xcb519d2518b65413,
+        0x5514783bfed03633, 0xaaf70eac51b4a7ca, 0xa14d83495385f170, 0x15e6f44a48c59515,
+        0x46ae89b214370e62, 0x7f0c2bac13dffbaf, 0xd6e57defb2cfdd83, 0x57b53c7db4da3c11,
+        0x466ddc60bffe51c5, 0x45d6525276030753, 0xd8cfceed1f9fc188, 0x74682f4962f5b540,
+        0x4542a6ddba5afbb6, 0x207420d3382a0479, 0x3c4ba9650a0f36a1, 0x48c2a42b60c4106f,
+        0xf03fd65c86c93e8a, 0x2b5775c59918f026, 0x6a1978ee871e7b36, 0xbf1ead6cd0fda000,
+        0x9efa374b1a7a01af, 0x0ad21a1cda9af546, 0x40a31373158a3f55, 0x0f346d48cbf9ce83,
+        0x423fb7df7a1f9151, 0xdb743e072b34f772, 0x576dd27924ea4cdf, 0xa15ececbed6ea2ba,
+        0x610f473a98770ebb, 0xfa5ddab5020da757, 0xacdb999a307b566a, 0x44fbdb67e87ff940,
+        0x83abe3fc026ac074, 0x77082a03870313d6, 0x7fb7fc39b5c19919, 0xdb13b33d37bbb3f3,
+        0x60a525281587d310, 0xad94f4e9adbb2f66, 0x54887e657838d0ce, 0x5448048e239a6009,
+        0x7a3d4d7a2cea296d, 0x068d4cac54a9947a, 0xdf33b36663a8638e, 0xfbfdc2aad38bac56,
+        0xa2915cfe3269cd18, 0x185b483012416f53, 0x8ea928f5a214accc, 0xe2c1c0fe5e5769a4,
+        0x7d4d2ec04b1cbe82, 0x7b4f28befd82ccda, 0x295dff2ce0b80f8e, 0x0b6b5e1bc9ebff17,
+        0xee0ca059f55a4e09, 0xb2f6756e3931ac13, 0x392b36d2a73a6dec, 0x74b39ec993520951,
+        0xab2388995843b949, 0x59e6ac8cef2438df, 0x2cb58c09e29e9ba7, 0xb20c0f6e4d32abca,
+        0xef087783622b0722, 0x6c5c988cb84d4b25, 0x22c43798934565a0, 0x0992676560f84145,
+        0x0d66d2afe544bc10, 0xfb144f77b019b9b2, 0xf13b83c8fb52cd08, 0x15ffa6b30dc34997,
+        0x57ba07171db47952, 0x5055d9ab6ccdf356, 0xeb2357a4dd0da73e, 0x4bbbcf7a0b7f7338,
+        0x253f977c30125b00, 0x8c55dd9a464d3514, 0x55e6c111dae5315f, 0x1df03d99c3565094,
+        0xad29bd7afc926265, 0x5422b4cda9e220ae, 0x5a86496e220f5aed, 0xe6ce591d774a64ce,
+        0x3a74e212d9a67245, 0x6b5e0fd54d33fdb9, 0xd686885d856748d1, 0xf9a2e0125139bcaf,
+        0x33c4c8adbc3f27ae, 0x2467759b50b0d0c7, 0xf3d7bc0a48c9b669, 0xabc12bdf65609406,
+        0x13abf6b925bf9444, 0x8e73f58629708ff6, 0x7ac694db7f549d41, 0x8e7a81a247b5b24b,
+        0xb9070c45d687035e, 0xe16fa12ddf21ff21, 0x1d9aaa709efb4542, 0x48372fd40ef83e88,
+        0x438d6011f0c80c48, 0x75811e2235367e7b, 0x8f35db96cb46b870, 0xede2b871bfd4f3b9,
+        0x4906e14abb00d0b6, 0x713947bb600a0aec, 0x8325941e7ff3d12c, 0x60080fde47838d54,
+        0x7f69fe176e22ffff, 0x45c3b80765f30056, 0xc3b544503ba90ae1, 0x02f60f63a65368f8,
+        0xa6040f3879b9e203, 0xba66fdb9177a12f0, 0x9994658c646470e2, 0xe40de99ce20074df,
+        0xb50b905a1ea30b3f, 0x5cebed7cd0fe1af4, 0x726b70a3a7de2c1e, 0x0e3c2eea79461961,
+        0xd4495fad0f911c3d, 0x301e691fcf38cacb, 0x6f17c07281a391b8, 0x24149b89c5edf399,
+        0x7a48ca6873fa4687, 0x87b8cb33bf8e12b4, 0x6d401bc6203349ed, 0x39017861c7350bd6,
+        0xf05636260d2efbd1, 0x51409672858ee74b, 0xa0998aaba4132253, 0xe6fc0ce5684f94ae,
+        0x6f3b986483c8ddb5, 0x3945c27a0586c2c6, 0xfd7464df930b03e3, 0x6c6f748b80ea4447,
+        0xb556778d9b42e986, 0x60942547e8e3009f, 0x041c7dde183b3426, 0xa8b84fea6a40d766,
+        0xb8b9d660348870ee, 0xad08f578718a2a7d, 0xb724f30fcef13f01, 0x5506578a35b72393,
+        0xdfcaf49b76dd67b9, 0xb888c579cde35a51, 0xfbc975ec0a38ee5a, 0xc32dbd9a881e2122,
+        0xc326749f3affec54, 0x86ae4295bf959ebb, 0x5e82a74eee99e69f, 0xb49040b4173f5446,
+        0x9a25d959db7e7b98, 0xe4ae6d8b2e96e58c, 0x0645d48624a11cb1, 0x7234dcb43c745f42,
+        0x913df72cbd413d01, 0x99c914c6fea8d488, 0xef84d726e7405c96, 0xae2c7415a4667c84,
+        0xf874f2127b5dfa3c, 0x28ac4af972b26f1b, 0x126dba4b68c86a56, 0xdc13184e7e16a9c6,
+        0x95e4fb9581c0353c, 0x162b6cea1cad60b0, 0x51210e2d998ffa7c, 0x96ad1bf72aceb61d,
+        0x519c8f7802160b5d, 0x3dd4d25ed0ae7813, 0xe32a057a7100b0e4, 0x132781c06574b1b0,
+        0x2fe3634d204eebe2, 0x1462d03062134e82, 0x8d5ff1ee5c74fd4c, 0xd4891bf3a57cc1d2,
+        0xb996f6c17f91cadd, 0xd00010f695c7e457, 0xe0139cc6c61084c6, 0x38b828190deac6e1,
+        0xf7581a3c9dca68df, 0x15c7a3aa1113c4ec, 0x879f1694db38fa11, 0xe384e250b1241cd3,
+        0x9f7434d564893621, 0xea76005e3da2fc13, 0x2c8e787f23860957, 0xfde0f8e01dcd287a,
+        0xaf98ff616263e3ba, 0x26774182c4581a1e, 0x812a4d045a9da93a, 0xe254ce9507c7230c,
+        0x6e92f2011c17a9a9, 0xa8d92c746596cb5c, 0x172a234778161165, 0x1d45d6d8fb0218d7,
+        0xda8810a0865b5077, 0x0bd524a8ba089692, 0x2207aba45484d616, 0x28af340f1636acce,
+        0xc878c19fb94cf601, 0x33cb83fd75166851, 0xe1560cd305cc5a00, 0x953693d910995bed,
+        0xde874d4303c64e77, 0x42972567d482762e, 0xc64276853f89f147, 0xcfa46dd0cb2be393,
+        0x9d039b5be6f299c5, 0xc56e095b4890dec9, 0x319d94e351d1db1c, 0xb89517bf979eb6fd,
+        0x9419891eca10b034, 0x2350a42d331475d3, 0xe2b03f6398e95dcb, 0x94fa7776580c677f,
+        0x818335b3bc48edb1, 0xdd8f8d83d7186e5e, 0xe06fb1513afbc902, 0xcd5766d39b726484,
+        0x7474286697b921fa, 0xe1c192ba01f0ae72, 0x552c87cc3e1d6545, 0x3d8d83ec2493feaf,
+        0x974f275cb7e8909b, 0x188606731b4027eb, 0x0ea66c23b140bee5, 0x421c81edf6eeeefc,
+        0x0e51ea2cb7448adf, 0x6e50f64d15192572, 0xb05f8188def3b92c, 0x981d62807ea25d89,
+        0x4d0ad3195d98a08a, 0xf2b79696ffa3674e, 0x8b4d137cbee01c79, 0x560f9aeb11bdb079,
+        0x31d26e24f993a20f, 0xb6a34ce8ae310eff, 0x8309d16811e30a3d, 0x6a98232a9a932bcb,
+        0x7f2187de4d8e854f, 0x17833df7c51930a8, 0xe7a928c1876c2e7e, 0xc155d05aac4d47a7,
+        0x5df0851f543bc4a6, 0x7418844d83d63765, 0xa2776de86db946b7, 0xcbfd4b7e5858321d,
+        0xbe2b954532250bd9, 0x2df94ebb1178846e, 0xee0eb22a433390c2, 0x969400d9af6594d6,
+        0xe4ac4357e1391b45, 0x1b2cacc8ed2f06bd, 0x2bcd5421496c1e97, 0xb173e49a238861ee,
+        0x67fd1be30e53ad45, 0x7d408bba51844a66, 0x1d97a8d36577f6c5, 0xe0f650b998b8594d,
+        0x2c5cd122f1ecad16, 0x14c3d05dd9413853, 0xfaa0842dffff7aaf, 0x0530006d7a672b64,
+        0x1076ef91e6de3371, 0x14137c21f501b761, 0x43db1cb68e626aa5, 0x022d8298665a2052,
+        0x08d99ae630924aa8, 0xfd32819f44f58369, 0x3a14e2af11b92d52, 0x2283aa95616a7ef9,
+        0x053ea35224de67f7, 0x1e25222ba2f7a622, 0xf3f3833aa700f413, 0xfc8d6f9258278794,
+        0xc783701fa546213a, 0x7297a933fd35a757, 0x6b98dd30bc045ae1, 0xbbbeacaa99b416da,
+        0x8162a94b1f3be9bd, 0x49a105ff3bccd303, 0x4769d26b810b04c4, 0xf7cfd20864bd83f1,
+        0x1c90809d06d4ae6d, 0xf3fc3253ea18f64e, 0x6978500c5c917359, 0x8f1b1b6ad6a40b33,
+        0x9ca60a3ca76c8315, 0xbb4ecffe99bed0af, 0xe5567d5846fe7d04, 0x119311d3e8579e70,
+        0x8343a4d533baf6cb, 0x4bc875599498ddb8, 0x6f362780fe074f7e, 0x68bc520373a32e09,
+        0x99c118fe1fd16660, 0x345f0a98278705ff, 0xa85e60ca38456331, 0x49cfe2e970c7824a,
+        0xa205b9088cbfd2c6, 0xefd954be25670775, 0xfb8e897e75310a73, 0xe77196ea18a3f97e,
+        0x7d8e8ad783728454, 0x98e14ed90fbe5377, 0xd6153520037c54e0, 0x88e4bac235c05b50,
+        0x9eda80c8826874fc, 0x5913a18baec5a03f, 0xc3572af048bb9f26, 0x0b283c7dcc39098f,
+        0xa602efd78df97edc, 0x9a3a0a04b391684b, 0x243698d95ce75f48, 0x7b0b6f8c72ead11c,
+        0x44c14edf42d41442, 0x2a3b5febd1714c9a, 0x92a334e090bec836, 0xcd1daefb7e8f5582,
+        0xba9243f505ab7817, 0x5eb5dff64ff823bc, 0x3baf98d92f1b65e4, 0xd24210bba550a482,
+        0x366755f4a76b8709, 0x642805eef82732b2, 0x4f8b8a58a3867065, 0x573d2cd20e35d815,
+        0x23f1496343ec3ee0, 0x18c3b11ae61d499a, 0x534c7213759fad76, 0x58c454fadd647aa8,
+        0xbb2f20e78928b156, 0x1a84a6ef7d993fba, 0x4753a756e80e5a51, 0xbdf4ff3b0a241f3b,
+        0x33412cebf8ab5c00, 0xe768e2acd24e32e8, 0x80d7a4de78193a7a, 0xeae7bfbc90daba45,
+        0xf83f16d00eea7290, 0xfe347b81d9ec075a, 0xa93b7fd05fecec83, 0x698a7fcaebec1d65,
+        0xc893de8ab8077445, 0xbddd44a403f5a188, 0x220bd8f7378b341a, 0x5ef1d03e9cd051d5,
+        0x2fa991a2babf2ad0, 0x1c9cc8a05c89656d, 0xdba3c420e5c96b8c, 0x7f95a6cb6b8c5580,
+        0x5ccf5a84e878a004, 0xb81b5e6481f2fff7, 0x5cb473a17322d148, 0x1ce97be7bf52fea7,
+        0x82972126ce9524d6, 0xb761e65e874f4a0d, 0xd555f09f20e88b81, 0x8209f467fc51b467,
+        0x38a0eac3e6585c6a, 0x094bf35027bf8ee8, 0x11093a33780f72dd, 0x51113dcde4376413,
+        0x3861bd3ef02b98a6, 0x5c15e3690504b312, 0x682b94635b808f75, 0x1d45d4e90e25e8b1,
+        0x6aefb0d0f59e1b3d, 0xe080255bcbac0e23, 0xaecd038e89ef2642, 0xc17b21a7805c3856,
+        0x9c830bd0870c9540, 0x770d4b4b4c3d49a5, 0x06d85fc1042c9ba4, 0x87131bf22c8c314d,
+        0xc487f115af4e18c7, 0xb501d141466af075, 0x23ac8d761c77862c, 0x433bcbbfd6cdaf06,
+        0x00a6b5ef7f55d628, 0x2573590d9a75f6cb, 0xedeb0a0b25463c26, 0x8916f28f8c58ef7f,
+        0x42325d674162324e, 0xa8c8afb3d3d8d444, 0xce1ce5b87c94f664, 0x8ff05ce02c0ff630,
+        0x46c7a2a497ef98f8, 0x8bbcd23d21d75093, 0xd14a887ea8d0afde, 0x2fec4836a1596c04,
+        0x39845339f2569975, 0x1ba2e2d899d2d0e0, 0x5e4a7e023372fe49, 0x7a7160058d4ed39b,
+        0x1c017b1ec1c14e84, 0x18ee1a85ae9b9bd6, 0x94922e9e50f8a93c, 0x863aa98d2104dfbd,
+        0x7540cc44d2f024fa, 0x9a2fb43f3bac4333, 0x48899653369fa701, 0x5c6d698944b9b03d,
+        0x3601989a9a9b61e1, 0x6372bfc3ae554a42, 0xf80909f432c45e32, 0xa8d42abcff502513,
+        0x2665557590f25292, 0xd287c1f643b5e9d0, 0xabd169f37cec8874, 0x41972172e95871c9,
+        0x56315fc194055a86, 0xa13910a86e364ebe, 0xfb3f07b39d7b84c9, 0x55e9fd8e13a28196,
+        0x6ae56daaf1065510, 0x982e10319a112584, 0xedf23910ac90e50a, 0x2eee1336a3f16cdc,
+        0x7506d5c9cf99e170, 0xf424274daadb13fc, 0x8204b122a7a5295d, 0xff6847ecade26fe0,
+        0xead7c4776488a80e, 0xc95a6768f87d3555, 0xe38003b1f29226c7, 0x19822828b54daaa2,
+        0xf3d2390996b6c906, 0x1e045ddc34cb9496, 0x66757dd7575f5899, 0x585b0ed01810b161,
+        0x46c3cbb15b52d872, 0xdd0f7d8b62d69343, 0x934ad472eb4e7df6, 0x9ac657396a700628,
+        0x309a25a57e4fd609, 0xc9a830b2109dcc92, 0xe7e2861ce6e3efd8, 0x364f1511db5f3839,
+        0x213c918af60585ea, 0x713efea0b44b27e3, 0x2107ef57b5205394, 0xdffdcc67670372c9,
+        0x4eb124bf62db6d3a, 0x667f5697e98f9468, 0x3f784878801143e4, 0x6c6cf19685ed60b2,
+        0xb63e71ef508b53e1, 0x179d71f8c4017ce8, 0x18a0efc7fdc30e0d, 0xc106262e7e82f251,
+        0x61272d47222e1e58, 0x771d42c0b7094d15, 0x41d507424aaeb431, 0x98c7b908ac4f6038,
+        0x1237ed1fd25a0d31, 0x095fdffa869771ed, 0x2c2b7a3c4fd28540, 0x4a1d18815a4e55f4,
+        0xdb06cc687afa77c1, 0x1da780c944fd568b, 0xb3e5aef342167dee, 0x943f4a18c99840f7,
+        0x22e034da8f77b64d, 0x804cf03d05e9cc3d, 0x98eeec4a54d28ba6, 0x2023dd67742f2f32,
+        0x33f0362515d07124, 0xc289e64b4a590631, 0x64d9865dfeb509ae, 0xbd12b46bb64e8de8,
+        0x39fcba3ca50ddfdc, 0xaa20b603a55dc9cc, 0xf7da7e73ac5e895b, 0x72051361c46b939e,
+        0xd6daa831bb40106b, 0xec730ef593c33b0d, 0xb4a0301dd8807f21, 0xdead4a32e8a6207c,
+        0x806e28b41b05be54, 0x22332aa69bb8d717, 0xbfdff8d7dcf0dd3c, 0xe076971abafa332a,
+        0xd6f81355b4a684b5, 0xac1be7125c41e708, 0xe0ed3b05205510f0, 0x56b3268ebc35ae64,
+        0x47e6223313f24267, 0x65c45f9e07a064a7, 0x7d7d40917b156cc6, 0x73998ec3b959840b,
+        0xcc2522d41a964460, 0x71a0a240fae353f9, 0xadfaf026bbfcbfe1, 0x83f42933d928ff8f,
+        0xa23cdefc161e6212, 0xb4b12619166744ee, 0x64efa089bfc93cec, 0x178c69d9f0099c82,
+        0x695f122693537982, 0xa7b2ef95038fdeca, 0xc883165c5025c2e8, 0x665a5e193cf1e038,
+        0x712f56aff70170a1, 0xda74c1a1673e8c24, 0x4a68ada663da708c, 0x42fd9fc0ebfdf93b,
+        0x91fd87c1ec4561ea, 0x233931598063ea5e, 0xe17d1ddbfc47eb0c, 0x0f67f07a13478c69,
+        0x4a8f1be9d6ccbe1d, 0x8b1374c65ac99885, 0x140773f22dc72312, 0x19be407396c27f7a,
+        0xcb78bbdf70274205, 0x6665bcd40961f6f4, 0xac93ef9e5bf373a2, 0xdf5af550eeca77c1,
+        0x436e11c61bfe6c9a, 0x020b13df601447ad, 0x520250bd80b7226b, 0xda61519bc2cd2ca4,
+        0x0249866441748ce5, 0xbab9eb1358788a80, 0xe3737822b53c743b, 0xe39e9b6191af2fa8,
+        0xbb1d6226e63135d2, 0xe62d5cc43305f0da, 0xcc84cc94fe083adc, 0xdd0cc2997c9e9b5d,
+        0x8f93356c1ef1350b, 0xe20a979ccdd5c016, 0x1bee10c85e2555a4, 0x991cc2a21fcba923,
+        0x9ab11a9568c5956c, 0x0bf0990e369b50ae, 0x84cdd5efe0360cda, 0x219511563b5b22db,
+        0x7750de0f6d0853c5, 0xada51526bb7a2dba, 0x2bf6b40729bb54a4, 0xfd8cab0f7187df9b,
+        0x2166932b80bff61e, 0xfc2f96fc9f5ffddd, 0x1bc262c63bcd6d24, 0x07532cb7406cfaa9,
+        0xe913a4acc7d312e6, 0xd75a4aa6eb6eb1f0, 0x39e69db5279de6ce, 0x8a1c5536064a8819,
+        0x9f986e85b0f19279, 0x95aac6a53b80b674, 0x75843414676a0e08, 0x774772e159a9ee3b,
+        0xfa0126479293c382, 0xc3cb2a5402de350f, 0x5a05ba197996ad3c, 0xb030929e0e9018a6,
+        0x01f52b93f06e88c9, 0x39a220f521fa2230, 0x8b9bcb0042fd1994, 0x082a76a4255e7896,
+        0x14df5b7adbbd1d67, 0xae0c239b35bd1821, 0x5c792d6cc4b501e9, 0x658ef2a0164d5a80,
+        0xc62e50a00ddf26fb, 0x02ca068f950b9d3e, 0xfc13a189958ef13e, 0xd76d81a061aa273b,
+        0x68c031ba3157c21c, 0x86c570096ad975c4, 0x67ec0ab9dda5a4e4, 0x8771022d8cbdc153,
+        0x6789bc4d5e2d21df, 0x3499611344102f53, 0x519170aa32a30b1e, 0x69fab3ab7331579a,
+        0xb0e2fc793c516f62, 0xd6a06e2f6b08a5ad, 0x07004cb37b0335ca, 0xc8367e23e8f211fc,
+        0x79891f93f86ed6e7, 0x89bbed008b7b0aea, 0xe82f7b23d1e6fc89, 0x67e036cc730fd0a5,
+        0x705004fce3500bcc, 0x45add587fc533a0d, 0xf9f4f0658bfe5659, 0x3d6229caf84a5267,
+        0x1f56852383a39efd, 0xce1597819d2c5322, 0x61c00701be69ada0, 0x1b97a0c04c82ad1a,
+        0xaec36423c949b396, 0x8b1581065372bbe9, 0xd8db29ef072b6059, 0x3e450759b9612fdc,
+        0x36a44e8ecc0dd6b8, 0x99a31698eeff32a6, 0x7ddab8a3e8cc22d4, 0x5e7f6162ea278099,
+        0x7737aa33c0bf1882, 0xc686b27fcb88bc2f, 0x2cd0d8d644546479, 0x89d380c0e5812456,
+        0xeadb2960013121cf, 0x35971e091695df50, 0x22e65b5df5f79a2f, 0x2dc9f2973e0bf8f2,
+        0xe6c4b6309cfee056, 0x8f2c6f487e721db6, 0x388de382b62c276f, 0x137eb95bc11ff348,
+        0x51a98e1293e537d1, 0xa7268e53ee6aca3c, 0x1850a0d342c1a79f, 0x0cfb6892642e7d20,
+        0xfd059afc88fba686, 0xe897ab9310a237d1, 0xf571d507432a17e6, 0x0ef1e6415a6cf5cb,
+        0xff2f02a7fd92750b, 0xe0a95073eb202df1, 0xe47ba5fd1c63fb52, 0xa164dfd3f4821275,
+        0x26e27825fad83da0, 0xf14d3c4542742661, 0xfbd42dce4df9fa3e, 0x61e8cf4ec0db2069,
+        0x390041ff7bc6949b, 0x738b0bb461fe826b, 0x76b87cfa92511e52, 0x8ec473ce15462edb,
+        0x7f64a4b6f4908479, 0xdf4d14135f84b4b5, 0x2adc399efb19718a, 0xf7ce5d7f8257ec41,
+        0x1913d6c06ac7bb67, 0xfd01a1e2419c5ebe, 0x4f60a8defa913a0e, 0x8f95a8d82f97ae0f,
+        0x0494538424a067f3, 0x12f4a31af340ab7b, 0x71d9e92381426bd0, 0x546a53c16a2f8a0c,
+        0x74f126c69d7a6ede, 0x01aac1c67c536e17, 0x63f7be588975b7c6, 0x64c984f4d48d8bf6,
+        0xf774cf308089e79d, 0x3499fe18f1129822, 0x0cf04afe76f6c185, 0x484a9ca319e9a2d5,
+        0xe72634380bf92838, 0x1a8bfb7238e0134e, 0x7657b98cd946ef18, 0x4a43fafb5282a079,
+        0x2100b45e34a1e199, 0x62469decbac35857, 0xb96f3c674448ba19, 0xab9a8ab16b0b48bd,
+        0x4613c413180834a6, 0xbfce84ff7783f11b, 0x6b158fb426477aa1, 0xa7508940906d0a44,
+        0xc7a48e5149c46078, 0xa06b919435de8973, 0xdf386c2f00a7561d, 0xf53d3627fd2e26e6,
+        0x9c078d7c146a8de7, 0xf98d23164a372bdd, 0x5d4ab37e9447f948, 0x3e32499083cc7a87,
+        0xbdfae65a465ff330, 0x8b2339f9737292c2, 0x5ba71e2a4291130e, 0xa2e5c58a7538a3a5,
+        0x6e829ddb83840115, 0x620236d39ceb74f8, 0xf3a54183cbd3c379, 0xaa53781a7a73670a,
+        0xfb57268371834480, 0xca2110bf1733f320, 0x590f05a7ff9a8933, 0x3fc2591dc6fc0f50,
+        0xd3a4e6f07f8e9c12, 0x2a076c8ae183e038, 0xc438c1dfdccfce23, 0xd0bce80bf3e0e0dc,
+        0xd3ba6ab0f8fdd597, 0x900ce8755511c207, 0x8de35daff9b8a0ae, 0x4a144b1a2058ac3f,
+        0xbecf8e2b8e861fd7, 0xf3262063eb4eac20, 0x7e297b9a3af971eb, 0x1d4a537f6868aaf6,
+        0x0188c464c79c737a, 0x6772726617c71164, 0xd8d16ae8681cdc63, 0x6725408a9e7ab1c2,
+        0x79029eff8a639553, 0x2a887d644a0f0987, 0x24f6fe4813d1bf7e, 0x43697000c1ed8431,
+        0x2e56d866308b8879, 0xb3d9bfdb4b7359f8, 0x55b87658e84b46f5, 0x3ba74af566c0781c,
+        0xe381a37e7c0fc321, 0x3284028e6a4b983b, 0xee6090b684399d67, 0x72432f3cc68a4bbf,
+        0xa4bf2a382b3028fd, 0xb4d1115fada82552, 0xa5f8c0511d8b076b, 0x0a8aab2005afc2fb,
+        0x51b11f4679d9f645, 0x41e8d9312352db69, 0xbcf5ed19fcbbda51, 0x15e26ce405752305,
+        0xaa8e6fa764f91f3c, 0xce4cf4494f971ff5, 0x28cb68a73b5ca29e, 0x3d1f252b046a9e53,
+        0xd4efc9f427ba3fc7, 0x88940be2a79ae6b8, 0x5b210bffd39b4b3b, 0xea904c4e201f146c,
+        0xc030cb4d639878fe, 0x46ddbd3be0be1326, 0x4448f97e1199ead5, 0xae47e9dd0b1c6d6a,
+        0x58f3a7b564ec26fa, 0x5d29fe0678a8cd3a, 0x36deba19e069c5c6, 0x196415bac7bb9655,
+        0x3762bfd998909dbe, 0x351ce6ecdd14f411, 0x2157dafa16ecc975, 0x244bb36d52225305,
+        0x4079c625b0bae8a1, 0x23cf87c38a63188e, 0x0822e060112ecfe9, 0x58a245ccca420a3b,
+        0xe14388ad35ff73ab, 0xaf733155d8bb13aa, 0x7e86c596f9c13d8b, 0xeaf114c6af6b8f07,
+        0x16101cb9f0f01f6e, 0x139d0c2a23a04d03, 0x2bea283207491fb5, 0xee9e46c01797f409,
+        0x760b0d7a2f6faf20, 0x7cdbc4fd88794c38, 0xbaec551f61fcf0fb, 0xeccf885a5cd0eab9,
+        0x9da34f91efde69fa, 0x38a728bef417a437, 0x7567fdb65849b99d, 0x85047ad3628a411d,
+        0x2801619027181125, 0x21da5b9f91afa3c8, 0x395a9e9bf1b8a0b9, 0xb60a860d8e323d03,
+        0x46e735a5c7961195, 0xa7a855e65c290059, 0x0211a5bccb7c91be, 0xf91a05387d833b0a,
+        0x5167d4b733206086, 0x4bcacacebecbb8ed, 0x3ccd5c9c5c107db1, 0x7eaaf962340b5f60,
+        0xa7d3bbc3293d0dd2, 0xda7fe81df3a423c2, 0x60850476535792ed, 0x362984dd381a254e,
+        0x9c61242a7c4b4bae, 0x5b4c5198105205e5, 0x6ac8aa2201439c4c, 0x4e3151779e064419,
+        0x32694a0959b0f37a, 0x879879eaba00249b, 0x8c3525846f0dd5cc, 0x1acf2dfd99b79688,
+        0x8b0f4badd816aedb, 0x6dbfc2887f0979b3, 0x6092b76df425667c, 0xe77edaf612d1ecd3,
+        0xf94126a069bb8e27, 0x2f45c828eb35c369, 0x97e3a45d4c8b0505, 0xa011a1aa1f0f83f0,
+        0x073d7614ceb379d9, 0xa9f2a310d7e77b42, 0xac275842eb25b38c, 0xb75e957155489c7d,
+        0x9531931b576ea4bc, 0xcf6bd5433da00b08, 0xaf6f24afbb99ce6e, 0x29b2512f0ffe5e15,
+        0xeeaef232c0fec25b, 0x2cd26c2c6a6765bc, 0x2f6343990f4cab86, 0xd746ba08bd799224,
+        0x42dbddae40d2ae75, 0x9585c9a91e29834f, 0xd849d374bbf50837, 0xf6dd4a35abe73199,
+        0xc76c76f23f793b45, 0xa4c738e942692d04, 0x6bf80c4e5ceea1ac, 0xbe4db13fa61a57cf,
+        0xbc97c869709a573c, 0xffa9f0cd05252ac4, 0xf4185c425f70942f, 0xd7493b5832273ac1,
+        0x6e34539f1ef8add0, 0xaf1495adef267271, 0x93776a6dfc29e715, 0xa31a84300bc752ff,
+        0x4f5904428e65b408, 0xb4182aabbd0bad9a, 0x8327241c65ee1ac5, 0x2ae61f037d182b44,
+        0x4ed73f65b1808e0b, 0x8b797362d12ca82d, 0xe1fafd60807c1f68, 0x24cc931425f9dbcb,
+        0xfaf83b5615c16e8e, 0x20bf347486b861ac, 0x9fa29416b0ec4f70, 0x9a6927d0df232cc2,
+        0x77e1a962d6308497, 0x7ca01539887ce2d9, 0xdebe3204f07656f8, 0xffb110d5c9a8e506,
+        0xc10b801d69521393, 0xc16ca3b24a0a6062, 0x44f456780107e5ff, 0x53a73d01da8dfe8c,
+        0x1841643853edbf88, 0x25a69d8d4559c48c, 0x0f086ba329cbb42a, 0xf11c778869f3d355,
+        0x7f0f2fb89729c252, 0xeea946898522a4d9, 0x7f5abde2f689b3d3, 0x341d215314c6109e,
+        0x5f6518c64897dd3f, 0x6cb3ae28bb807a1a, 0xf6fcbd4ee2c6fe46, 0x8a3cc39f95700c7e,
+        0x2a07e10daf28b648, 0xabedd96c864d18ae, 0x32bf2b3d2c160ef9, 0xfd79c042210fcbf8,
+        0x9b8a16fc42503b41, 0xb105916b573d5324, 0x4fbf8206bbbc94c9, 0x5d64ba00a4b1e174,
+        0x6f1463ef49600db4, 0xd3b3461574653574, 0xe5c8936eaea3e60f, 0x6aa8e6b35140a728,
+        0x21175af6525b0868, 0xc916a8f4c3648072, 0xb81772a15994e938, 0x9adab6b42324c9e6,
+        0xe29ed1dcbd298fc5, 0x4d30d444be36861c, 0x64c3e6b5ec928181, 0x56fc774e116b505b,
+        0x726a21df9c34c8c2, 0x8df604fce4227005, 0xe81a29fb91a66553, 0xe0a1de4afd1925f7,
+        0x5e335ad2a99b96b0, 0xa5911b2b22f0621a, 0x900334b865d9676b, 0x02c1ecba73c231a8,
+        0x8ff7603e90514376, 0x8d5d3703ebdc61e7, 0x0b028112a682854a, 0x5fed613adb20fd6f,
+        0x230c75c9e3d5090f, 0xfbf0b5cb3b5a0ba6, 0x645aa573dd56e319, 0x7a647f49cf27be32,
+        0x70b82ec844d1cdfd, 0xde51fc6a3593ec1e, 0x5096d3c963f6e2e4, 0x1d9d465d88cd6db8,
+        0xb65d65d5a7fc3185, 0x79316f5c5eaea47e, 0xe73cf49d6a33d0a7, 0xe640a1839e572b52,
+        0x75d7e876c63b7000, 0x68d9f68acd97d8cb, 0xfd92e263cfd1874d, 0x2e1311959677d44e,
+        0xbcd15ab4f552f164, 0xc4fa3d5a39e10d95, 0xad90bfd6d17c1596, 0x6429cf9a2b9fcdc8,
+        0xa1b92b6b7ba2caaf, 0x10b9875aa8a97597, 0xae6695d119485b65, 0xad5f203af909128e,
+        0x891ce21185571da7, 0x2439ef4d6bac6d09, 0x288ec9fbf7ca8512, 0xa14ff01a4e842b91,
+        0xfb1b3cf507aff6c2, 0xd55611085091fc7c, 0x7291aa2da13f7afa, 0x3203aa93079b1289,
+        0xb6a3105f7a484059, 0x4f8ee1740686957b, 0x2e1726f413d09f76, 0x0ef228e4d3c685c6,
+        0x2c0d5829abcb0517, 0x283b6161e59ae8b1, 0x20b278663235f5cd, 0x10bc4548f813a731,
+        0xdb98d37bc0d0c3f9, 0x1aa7288f41172ba9, 0xedf01fc57be76fb8, 0x2674c179e49c0eb5,
+        0xa7c11086915697ed, 0x644ecb048c4d3cdf, 0x57b939011a331c15, 0x3b72a3d67baed6fc,
+        0x472ce601897ee8db, 0xac3ba7d51f921bac, 0x70769c16941d550c, 0xfa0d576dc0939fad,
+        0x80b84c367d55be4d, 0xe85ed3b2632fd1fb, 0x9877a2eafc2b55a7, 0xc5d53876be464e8a,
+        0x7487de0ed0f94b2c, 0x149ca4b41061968f, 0x52d7bb6c42374cdc, 0x6d4805c087c968cd,
+        0x8ec68d602711a124, 0xe573cf838f79a78d, 0xd3baaccedc14a2d6, 0x79ae74a94c3c1429,
+        0x4f4f52559af73eb8, 0x800dd3dd0a2a83bb, 0x68c05b56ac5335a4, 0x6482cce7449ea02e,
+        0xb5a9a315e8c0ac93, 0xea5bf389fac53fee, 0xa1cd898a7c72b177, 0x8ba5d517175f847b,
+        0xaf66d79e13e1ecbc, 0x890e9055c904cc2c, 0x96c844b2c4ca36fa, 0xd6c7e61b1eeecc04,
+        0xcf89972046909f38, 0x625072be2cfc9a78, 0xb38c12ac23bfb204, 0xa0851612f5a00dc3,
+        0x69b490f098a20958, 0xb50059b0fffee590, 0x6c4ea8f5bcab5c83, 0xe0ef1d6cac2011dd,
+        0x194114d3487a661d, 0x42b663194c093cfb, 0xf655d767e76c3b04, 0xab8ab6258abf4ca1,
+        0x1544b8306acb52f7, 0x28905b7ef8972b22, 0x0345888889dfc434, 0xc416895ef56839dc,
+        0xaa72a981ce3b0a32, 0x72e68552b811a993, 0xb3b2b79043240905, 0xfa8e678dc070ed93,
+        0xc9994b1da72bb4a2, 0x21e9993c9acedb85, 0x869a86d05e14e405, 0x50ce1caae5896d09,
+        0xf5568abc982b53af, 0x07852fe309fbff96, 0x97c551ba61403c4f, 0xb62679efed79efd9,
+        0x286421ecb41acdfb, 0xaab7e662f8adc71f, 0xc5fa40d37a2eee02, 0x1a55c1eada0c2c2f,
+        0x414c749739859a18, 0xc07b87f0b768b34c, 0x18ba0d2871da004e, 0x869fa4fa595b9407,
+        0x387a746f8827d661, 0x4786a32f47f8e657, 0x9457ea6c951404c5, 0xdc901909243eadbf,
+        0x32331c4a570123c9, 0x5c5b89776476d450, 0x68ea1380ae9856e3, 0xe82ed92456449eb2,
+        0x90d1c8d1eb70fb7d, 0x31daf1838c3b2a7e, 0xaa63310e3dd17507, 0x35eb6082458e167b,
+        0xef9a3a592be7c139, 0x5c48438765da15cb, 0xb501ae97624cf724, 0x9db58034d00cb438,
+        0x5429fc8120045ea3, 0xcf19f8d296fdb0e3, 0x8dc4e7a2ccf81619, 0xae1e42d55dd3d699,
+        0x010a41d835dcefbf, 0x0876f03ee43a857f, 0xcec2ac903800c9dc, 0x173abf51e83995de,
+        0xefd538b182a964f5, 0x4fe93bfb41cec2f1, 0x69a58d53d7d0f126, 0xea5b152934b5c03e,
+        0xc33fcac28f23ab88, 0xa5a8ec0c17d6b409, 0x2bab2f98967e1ef7, 0x718545e4f0d5bd8c,
+        0xef830e889ec8745b, 0xf8a7c29e8cf51920, 0x7e79714ed18a4d84, 0x6b999e48118bcdd2,
+        0x90bd7e45bdb6d4b5, 0x076c55a5af601bdf, 0x7bb12a7592b55875, 0x8d0fcab72c413249,
+        0x046c45bf4ee38564, 0xb14a902f6f1ec707, 0x7f02dffd6c24aa82, 0xbd063e70dd00d921,
+        0x4e741cd05297979d, 0xbd67d46c31cf4b92, 0x1834e0218fd65402, 0xa86ecaaf197b03ca,
+        0xd50061ee1d0a7c7c, 0x6f0f588120034f31, 0xcadcf70426e76fa1, 0xd083d5c6ec0e4af9,
+        0x90f9141fe5267900, 0xd3b2f791d60a2f2d, 0xb0d899133f128ef5, 0x69dfd81edf539432,
+        0x5aa54387a7dcb534, 0xf24adf5367789ef6, 0x7f115a6dd3b84bd5, 0x7ba19a98ed18fc9b,
+        0xdf7b1e738451c27e, 0x5c8d436d2fcf1ebe, 0x47dd90ec240695a5, 0xb704d2f5b93232ed,
+        0xfc6213997f1ee06b, 0xfe7358b91a27e4e8, 0x405eff5739dbb8be, 0x2bc2cb6e45ee2e2e,
+        0x41c54efba21f11c2, 0x4f78c91b5bc189c0, 0xc8043c11e73a21f5, 0xd447d50a8af9e307,
+        0x579b2f5ff12cacf9, 0x6ca8c8f88b7f8a6b, 0x7552cc4ef049fc2d, 0xe38a42ae94fef886,
+        0x318731ee067ff61b, 0x739f3ec2b808735a, 0x2565b64ec85e1cbe, 0xaca1c2d42fa165e8,
+        0x388b31253d374a28, 0x0140bb0939147dcb, 0x554022519bcff2dc, 0xcf1aed8bc8106b21,
+        0xa77f6ee90f1fd0b4, 0xc31ae57ed4ef3878, 0xf0dbe164f71de08a, 0x69e82cf63ab8b908,
+        0x1018897da9732650, 0x089801e8b76038e6, 0xd2bc9af88039ef24, 0xcd5572e1aa541a25,
+        0x1bde80792a596060, 0x9a3d0da89b6f4a34, 0x118e3a481264c048, 0xe3bf718504b448c2,
+        0x3c8696931325d552, 0xeeb462a8a5a0ed6d, 0x9822279b402dcb89, 0x70c20982a069e12a,
+        0x752bba020c9c2801, 0xb9b860a3cfb7817c, 0xea243d20c38a5d98, 0xe85f4be7f7d7c7e3,
+        0xd07aa9c09b62f8ea, 0xd706e3099957de79, 0xd91c696b15cfe040, 0x8271641a98ba4078,
+        0x59bbe181cc6dc96e, 0x7d4068e34c9c7a1a, 0x7f296e4ee50b1659, 0x233cb45c6c01c2c9,
+        0xc25895d3d80f4cbd, 0x0c03890e176b5f85, 0xc5161063af83dd00, 0xfe3ee97b2ffe6992,
+        0xe0c85618ac42d78a, 0x23acfb1ff453f51f, 0xfb47bf5ebd539443, 0x2540b6a0432b97dc,
+        0x537313bf8c046ad1, 0x39197ec5d25a58fd, 0xa34cd4dd89d8beaa, 0x6bad2b97e88a1616,
+        0x22f10a6167b1fcef, 0xd08c45a0e5dcd269, 0x3a14bf87f97199bc, 0xfa6f8f38e03eca49,
+        0x1995018cf3f3fd35, 0xd05477910c79da5a, 0x40a64cf5ea5e98fe, 0x974e208bba4afc24,
+        0x393ed2531086c1bc, 0xab4992862592bdef, 0xffeca9b316474925, 0x9b6c678f7858af98,
+        0x8646e934d82491c7, 0x1ec9ee587236b826, 0xe2a6f4ef0fca2e20, 0xc23d70e2d492a478,
+        0xd3663d46cbedc7e1, 0x551c26fd75d42a06, 0x449c0f2256da3ad0, 0x5c4efdf09f6d3155,
+        0x44ce926c383d77e2, 0x56aad7bcf60425d5, 0xa959c6590c4558fe, 0x75b21ba0f03fef93,
+        0xec0a90ea37073550, 0x5a11729a739a0345, 0x812b57caedc7ad13, 0xdf71cc66b49c8d13,
+        0x491787dbee39bc2d, 0x89fb9eec3b6369ea, 0xb5b1485b5ba2ca64, 0xde7559e1190e4e5c,
+        0xe28d41eca1b243c4, 0x2961e6e66d6c5743, 0x00184ccc05571c93, 0x67bebde0bd2c7095,
+        0x033031dae867a94f, 0x02af102c10b6559a, 0xf6422d325768af97, 0xde4395544ccedfa8,
+        0x2564e3f1280c865b, 0x908360ca09b006ab, 0xcc49f268bda9078c, 0x0bcc48c3d958e457,
+        0x7c28d1f26ab2c6f5, 0x5b4a332febd1759c, 0xb9f68f0d84d2f3fb, 0xc73e0d25840dd44c,
+        0x5c88c7029ef99a77, 0x808caf3141d50be4, 0x05db7ea5fb6f62e3, 0x1e88a25d9c8563e7,
+        0xf0abd56df2dcd879, 0x0416bb257efdae86, 0x0d7c90f1e388e487, 0x52ce9ac83c661fe5,
+        0x24c600f1a3f0a9d4, 0x3b3bed0fc6d70314, 0xd783b046dea8109a, 0xf6f738c492a1f0eb,
+        0x3325105894762f28, 0x5565de7f558d8eb6, 0xb587ec1c1059b10f, 0x66d8c7ba321c79b0,
+        0x198cbf2241505161, 0xad87a559d8273482, 0x4a8f0505507d7ff7, 0xbc55888010b9ce09,
+        0x37926e39950d04b7, 0x692b7cd0915f0397, 0xa2d108c1b3ebc824, 0x691863a883f27f88,
+        0x66f4dd09511afa84, 0xc36c7ed0f983fb7a, 0x5e7fb12bcb29b2e7, 0xc504cf341997241b,
+        0xcb7c5441382b4805, 0x93b8087cd1c2154b, 0x5d31ef864118d450, 0x9b9f66dec6d984aa,
+        0x79da1fc5af0728eb, 0x71af82e7b0e2c34d, 0xd310ce95b0fcab8e, 0x968010ea1367a661,
+        0x23311e1fe68d6176, 0x6d82b9fb7c80b3b7, 0x7a507c54699a7ef1, 0x442fc604902bb061,
+        0x07f591d440db69fb, 0x6e3da730e61a26a4, 0xd0914ca42ed91e38, 0xefd4a51a7ed45fac,
+        0x85c40d7141c06189, 0xaa84aaacca8d7832, 0x3314bf39e99ead44, 0xc94ed4846c38fa86,
+        0xaf925b63d3b679e9, 0xe9b6b9882e59e9e9, 0xe97c98fe8f59c648, 0xc7ffc9c14ac4059e,
+        0x39f9b6ff03083694, 0xec7963444864379c, 0x15ec228076c2073f, 0x7b2aa990d64d5c2b,
+        0x0707de18a474dfa8, 0x0a094658f6d67eb3, 0xc45066644342ccd4, 0xb216e8aeead8e84b,
+        0x89e50e3bfcfe033f, 0x386c80bc02fba793, 0xad9eb48fd7e43c7e, 0xfa78074f74d3039f,
+        0xcb2ac323a8975b86, 0x6f2f4cc1962b0f78, 0x1f23dee15172bb29, 0x1b3d8b354135c4e3,
+        0x4860824dfac63589, 0xb20fb9ecf978ce26, 0x9f832a6cb734288e, 0xea9d5fd98616c559,
+        0x38cbfafacf7b7159, 0x8e3f0f08a9b4cb85, 0x1b2be4dc2bffad33, 0x5b0b873cc434a735,
+        0x490cba7b17671883, 0x7b0ac387cf2b7ec9, 0x3d7e99e3951b48bd, 0x47843530ccf47793,
+        0xf7626255654ff936, 0xd76fe1aa1f8a985e, 0x5b8ffcf989c497eb, 0x2fe470b75b0ddb17,
+        0x5f44fc4220d61216, 0x786a9bec61296c2f, 0x65c22b86a6dc5bea, 0x9013db0554d412f7,
+        0x803a0d481b03f0db, 0x9083880860e334e1, 0x77a0021e6e33fb14, 0x42ae66a0206243be,
+        0x88d5dae1437f5dbf, 0x88707f3a05fb177a, 0xa537eb042595ddcb, 0xcb9abf0ea11c7b13,
+        0xb8e4ab06ff004bc9, 0x2903c16aa2fe23ac, 0x2c5b3899431a5c21, 0x536b3999d385dc21,
+        0x2e0435ec710ceff6, 0x2172b068b3ca6fa1, 0x0a9b8f0e8011b4a7, 0x4a9776de76f8ed30,
+        0x1feff8a04fbd7765, 0x98d93db79169e5b0, 0x66f767fdb727e84c, 0x6b0f102c1bc6cc82,
+        0x255b76528e591c21, 0x2551800e11754981, 0x2d454c90bf33d00e, 0x55614b1d0e10464d,
+        0xe8b33f141598e171, 0xcc4a0f2fe4d35897, 0xa046cc4e6a78aadb, 0xa3c4e91bb8e9eb6b,
+        0xb2cd034d4e6a8656, 0xf6732bdcc3003b9e, 0xb98495ba8b9728cd, 0x25efe4b93839d564,
+        0xb86ae451785da288, 0x5ade4d23437509ff, 0xc13f52e9e59537c1, 0xa05f26ed167622b3,
+        0xbe1634de7f11b760, 0x414da3cd0e8f5339, 0x9d5427181a89420f, 0x6fb6001d786482a3,
+        0x033a3fddc440b04a, 0x5af87329d36a6830, 0x0b2691b582ff40ab, 0xe4b16836f41e524e,
+        0xb8842dc7438b846c, 0x01c315a48a56439b, 0xc46bb7172d4186f7, 0xbbd1b084a6659502,
+        0x4f68ff004cee839b, 0x5ea9856602fe241e, 0xa987b550a14aaf7d, 0xcbb383807269360d,
+        0x0d1b736ad601e270, 0x31e1c2aaa1f30ef9, 0x5e4e644ff1993077, 0x6a6476a3eba7e63b,
+        0x0d88f5c35974dc5f, 0x7e91e0d48d9890db, 0xdff7f133c8794abf, 0xdd6ca63c61de538b,
+        0xbcec7e4cc497a0c2, 0xf7f3665c74ef453b, 0x4a863b4500f92d37, 0x2821c85406c99c64,
+        0x890768592c837ec8, 0xc462de0460ae98b5, 0xc36ab1d63ee9918a, 0xa356b6d0977b0bfa,
+        0x41cca8148cb3202f, 0x1db9f5422d508cb6, 0xfaa7bf4cf5492ce7, 0x63d58ebcf3e0b6f0,
+        0x5ff693496d4d00d6, 0xc2f84f6ef67cb640, 0x6909cfd78eba4b7c, 0xd30598e82ac8e0b3,
+        0x0fea6e105f1780c3, 0x660a6e1988b69340, 0xdd6d3d2eccc5e1d7, 0x630c2f11c0c38256,
+        0x22aaed5f88ff84e8, 0x0b8d8f4c1422f0e4, 0xc4f08723b537f4f4, 0x3a76fcccfcc40bdd,
+        0x2f211995ca01ba80, 0xcb4f07e1254e3fd8, 0xad3dbab054a45a4d, 0x0d490c4f8a343f05,
+        0x9b31e6511fb79b89, 0x6b3e272088285fbe, 0xff810bc59a40edbb, 0xdc2d4012551b46d2,
+        0xce69920eb1ed5a68, 0x4b48704d397727f6, 0x8c26c6ba20c95271, 0xb1ef682c66ae07cb,
+        0x638af592f3496ce9, 0x435d27a28bba667a, 0xcea16d16acb51f2a, 0x9ef8387e7689a1a0,
+        0x3503b8a82e792a0d, 0xc95e3cd5bd356922, 0x434aa43f33de9f2c, 0xef85173daef53496,
+        0x38c244841ee389e5, 0xcbbef43f566b68dd, 0x973015f3037cf98a, 0x5dd9f3179a2cb27b,
+        0x03169b52aeee2ac6, 0xd22e458dab9c7235, 0xd9b48c82e870f7c7, 0x340922dedcb65bf9,
+        0xf0d532f4925d67e7, 0xbdbc9d8cf72b8b06, 0x8dc2c50a6ff2bf75, 0xc2aaa065661bfbed,
+        0x9fda073280952899, 0x46bc32bf790bb79d, 0xdecf794c9f8c037d, 0x816b8ef65a36a6d9,
+        0x3d4b93392caf35a0, 0xc3445849d9615532, 0x6b48e0dd3637c389, 0x5e055a4626ca29e9,
+        0x817013fc6e36caca, 0xdf316684b6d8ddd8, 0x4b59ab03eea0b673, 0xe5d4e280bebb9e9e,
+        0xf5a141fa15327385, 0x73617e409ff83f09, 0x78f037655c1943ef, 0x0660adbd0554bbca,
+        0x954291c37163a247, 0x7b504b847ce8a9f8, 0xaa9b906338b3cc8a, 0xd99d204827e3db04,
+        0x1f506b356eb4f1b2, 0xc451da8facac95e8, 0x092d926a43b18fe9, 0x6718f876396fb66c,
+        0xc6f3cb138c09c89b, 0xe0a1ca65894612dd, 0x873272635726cc90, 0x9b2d98959697ae00,
+        0x9f4f5b762029bb1e, 0xc6460e56575a18db, 0xe2768d8fe6d64839, 0x38442fddf7617c4e,
+        0x0e0c5ef618a41c60, 0xdb9af8b10cedfea2, 0xbb2e7e8bd7f04930, 0x98085707d88130fe,
+        0x9b819e8ffe4f4398, 0x12f1f338416eb039, 0x83770ee27a9b78a2, 0x08ebd462a1ef5ff6,
+        0x960a2d4fd5d609f7, 0x2fa7f46b19a6cb6c, 0x84780a4e0837d011, 0x871dcb1ba737b9c7,
+        0xb4826ebe3de4eb49, 0x984f685799f70950, 0x770e2d446396777f, 0x5dd42fdb52826e67,
+        0x6b5e94e28a0ebeb8, 0xda8282889d5f4a94, 0x64a2acf16d98ea6a, 0x449c5cb6c3705fa9,
+        0x6f5761fc928c686f, 0x5dab8bcbbda92c78, 0xe358b41cf9c448df, 0x655e200258e4d2ad,
+        0x85499cddaccc9bd7, 0xbc5e5e1d27da6e59, 0xb1131c31ca4e0f7d, 0xbac074a8d76afb39,
+        0x8cfd6100810d1381, 0xc7f0eb620e704dbc, 0xd8dddcb4383aab02, 0xa63af122ea47e064,
+        0xb58bcc111ceeefd0, 0x9c11e8eac321b34d, 0xfff53df6c03fbe50, 0xe5dc497fe32cd0bc,
+        0x9f4abde31ec6d473, 0x4081628614d83bbe, 0xfdcc700554f13f72, 0x6423b5d9be4400bd,
+        0x74d963b4627c3269, 0x0b9065f408ae3547, 0x5f99527360988f92, 0x9ecdc297b8bcdf72,
+        0x384ae04a6b31f3fa, 0x4119e31e5c28dc23, 0x486ea22412a7c49c, 0x766cf1f4a5540cd6,
+        0x192b0344ed07454c, 0x059659891c6f3838, 0x6c6dd5f009ad857c, 0x409a6f9864f1fe04,
+        0xc4f6b39968973069, 0x89d56789620d7d42, 0x4e2b2bf08a15988b, 0x2e59da2574c9787f,
+        0x9cec299c37826fbf, 0x8e75c83845d7326e, 0x0e65ffbda7ce5026, 0x39234e2d9d9b2464,
+        0x613f435bca7d52a3, 0x97deb1c66a43e64f, 0x6a2a8ad9ed402c65, 0x0ac6033511316c03,
+        0x5e519425811895ef, 0xf38405b4244847ed, 0x5bae73bb8c5674bb, 0xa07edda58a338c1a,
+        0xa3f05fe7d88792d6, 0xa714582c8e4c59e9, 0xb0456bb520c99ba3, 0xa07165d700d414f5,
+        0x2c55d989945162a7, 0xe351b917582b7ddf, 0x2034e095e3d0d119, 0xef9b347bd074df02,
+        0xcf633a74a549c556, 0x4d3a3aa204bc051c, 0xe8838535b96477dc, 0x37c702235dbf5e06,
+        0x3aff42273d2de475, 0x13bfe1d3448c2424, 0x4c0f55e7ad39a639, 0x2e95c1522dfac4fb,
+        0x24109302b9308354, 0xae6f68bff477a3d3, 0x632191e61a7179ee, 0xdba84f27295283fd,
+        0x255276b85b5f6ad1, 0x9804742b73007c7b, 0x6c1bfc759927369f, 0xd8d8b519a16ac6e0,
+        0x169d3d2c8201cf97, 0x20a764a2e7b85705, 0x03b73cb13b0f5eab, 0x90adf4094f75de71,
+        0x34a44ecba1a0e9b4, 0xe0c096fb3a4b9c4a, 0x328b0cb47a54adfa, 0x3c61b0d59c8acc86,
+        0x7f9f33afb9fb192f, 0x667e06e6f9c6ac22, 0x7363edbff8762f09, 0x017811a9ed49f982,
+        0xa6b99d763bfeed20, 0xc92fbdae87c5562a, 0x9da181cb695c4bbf, 0x3f2c7e7b7319e908,
+        0x6c1421003861bc9f, 0x780248172bba3aec, 0xcac28d4c9d046904, 0xa71d709983cf5839,
+        0x2616c3cb18e32288, 0x8b5a1d55d7448482, 0x63f8cdf51421f310, 0xc39d81b976c3fdb6,
+        0x35dc5e92564eb722, 0x40a7c1afb4112917, 0xea6e63934d88b07d, 0x314a848399d7057c,
+        0x64f0a7efd234a752, 0xc2545b152196fe80, 0xaa722ee0f88f2519, 0xbdfaff5ce9816c32,
+        0x7242b60f2f8f839c, 0x6c667bc3251213c0, 0x69f74c019e26aee9, 0x04603fc894cd3385,
+        0x922ca7536c7c9b1c, 0x0529420ac0728d98, 0xe73f0f0ff2c0ee40, 0x1c01a9d7d31eb940,
+        0xb90f0993dd38d0a9, 0xabff95a797cb2e82, 0x8ec4d4f037844995, 0x069a110a41ce082b,
+        0xab9410920ade58e9, 0x8cb7843931d646ef, 0x83043db68885dc8e, 0x6aaa4bc1f49c42b5,
+        0xf3ed808c53c388bc, 0xf436d9db02aa25d2, 0xd959e4aae57ff218, 0x993801cb03c227b6,
+        0xb7bb38cffe2c2902, 0x93f67a814050b96b, 0xa178aa03b0621077, 0xae961262565404b5,
+        0x2a226d76f03d921c, 0x9c95bacc594c7a3d, 0x006d72d804a81629, 0x78d09e9d5c52e834,
+        0xf412c70eddde8c8d, 0x807c51f1913d5da5, 0x109722012aa3b97a, 0xed7cc815c23327d7,
+        0xbdfdaca7d83a6ca0, 0x0b3cb85ab1126700, 0x8643467320b8a2e4, 0x9951d80d9f326b65,
+        0x1804b81a42aa169e, 0x5367ee56322f04cf, 0x62c7ed7f3a149cda, 0x90f65096f30a69c5,
+        0xd7b63aab409c4e15, 0xe38f24f305037ff4, 0xc13a2c5dbe0f7c6f, 0x29a7617f864d5cef,
+        0x8ee094c09ac20f07, 0x14235baba2757168, 0x9e19980b415fadce, 0xef27b29e41cd8e9f,
+        0x04fe8949eeca8028, 0x2359b4af4addbf3f, 0x4f6c689e423c0f14, 0x1a0d35c9777ad185,
+        0xf3b2217c42107d5f, 0xc1ce3bfc2a2cec3a, 0xa79f71ebb2b281c0, 0x14586c9e234831d3,
+        0x0fdde54e55a9df9f, 0x1babccf084e093a8, 0xf84d9e186783e898, 0x6a731f1dcf15ef40,
+        0xd5cab65e68190177, 0x19a95088bd883cbb, 0xb22405372a4bc940, 0x059dcd96ebddc9a7,
+        0x4c6a284a0efaf5b4, 0xb1ab2ed0b5e80023, 0x418ee8d3bf075769, 0xa97766fb9145359e,
+        0x3e049df47e1798fd, 0x74e8e744b0813735, 0xa5aabec71033e822, 0x3f4cf84795845dd7,
+        0x331fe82db4c1b2d7, 0x573c14f4bef1c73a, 0xfee58fcd04dadccf, 0x62ec8254a3396bfe,
+        0xf44a39413c8e25be, 0xd87262af24ddef14, 0x09ce5075ee707566, 0x971fedfa2441f0b6,
+        0x21ac843aaff4df91, 0x408937dc1bf1cb45, 0x2428420cc6229dba, 0x1f0f022323b21bd1,
+        0x61c7b44ab4ef1160, 0xcfd4aee13702072c, 0xecc2a4e6760704ee, 0x55d94497fbf05a24,
+        0x8e0ca7670b9ac778, 0x94d3c8a89eab431a, 0xf44357078060f37b, 0x8d89293ef5679fba,
+        0xb87a6f2fbf3359d2, 0xeb7f2a0e14ad7612, 0x18ad3b5a0f907d94, 0xbc63a192f0761b02,
+        0xdce45452f4542ce1, 0xcc78b97366dae511, 0xc96c0167e31157eb, 0x2f695e07a4a92f05,
+        0x9dd35ea5ece5ea96, 0xe9f6c7e73aaca203, 0x83408a170b78a67c, 0xeb154c38d2346332,
+        0xcc20823d7c65304a, 0xdac6e88fcd5eb0ed, 0xe8fe6624fc4707b3, 0x3a12c1caeb294acd,
+        0xd2e402bfd03dde91, 0x3a5a5d91b4ef6fd8, 0x86bc1c64e7a89716, 0x8f30688ecde219ef,
+        0x41fc27cbfcfca48c, 0xafdda2e1df3bf697, 0x3ea3576846b906a4, 0x30d371221cee9ae1,
+        0xe028a550d615cc01, 0xaa2cc6e4550998b8, 0x068c77b5bf2038d7, 0xb58e96531844737f,
+        0xbc0a4b50b96df665, 0x808acd79d7776eb1, 0xc680b6543e52affb, 0x0da0fda0226d6a41,
+        0x16198e78a666a9dd, 0xd65a22c99f7c6b0f, 0xfc87964c29f3cca6, 0xc33de40563eec130,
+        0x84ee8d6cb1615fb6, 0x115c11767a8ac94f, 0x18d9d39941cd11e2, 0x1e0d6246532f322e,
+        0xf587cdff97efbe9c, 0x939a8a4307c9e0c8, 0xf935f078c6bf2440, 0x3603b0efa8066290,
+        0x5487cb2da6e7fc33, 0x3685c58ab29a962c, 0x6a62caf725d63eb3, 0xb938d7862493949b,
+        0x1b6893976cd00b51, 0x9aa0385cf34b6fc7, 0xb25eb84be898f61f, 0x0d46835fcc881d44,
+        0x15f0cbd861848a99, 0x6890f9b551aae053, 0x0cf5beb766a25cb2, 0x1bb6885799c7dcdd,
+        0x57fedc8fbe063b21, 0xb521ef5be74cd075, 0x19f6969accd8206c, 0x402068eefa66c426,
+        0x68e6fcc24878d987, 0xa2b6bd926f4023fe, 0x8ceac42f24d35ee6, 0x11721a0e27aa09a8,
+        0x594e669a1104c8bf, 0x718d6c4f76bf3513, 0x3a673e77ffe5868f, 0x72bdac5e871b25c9,
+        0xeb9f48e1755e81a2, 0x6b6cfbb7f21ac2b0, 0xda577c615ba37e88, 0x69477231d207f5ad,
+        0xbdf3c48711ecbc31, 0x6c3341b7d9b377ce, 0xdb92ff5824366568, 0xe1d2af4f8d040613,
+        0x6b662a4bd31dd367, 0x7e34b85abe78dcaa, 0xe80108850c231e22, 0x69c2c2bf753e0c6f,
+        0x41189b13e320b87e, 0x17f9eb5284b79fe6, 0x22bd020e20c73d1f, 0x06141e4a40de8a8d,
+        0x665dc0bcd5d06643, 0xd7e3769f5591b14d, 0x8fa3d8e7223586cc, 0x0788fb566276afe8,
+        0xb7f120f9d92bdf4e, 0xdd10a3b6f941619f, 0x7c23399a066aa57b, 0x2b6531e29fd19612,
+        0x17543b4ca9d6a4a9, 0x33c90c8dbe8bc026, 0x40e7d00ec3be2554, 0xd353e83b3eda4045,
+        0x68c5d6d0c9f750af, 0x297cffe71281712a, 0x2e2f01eed1482dcc, 0x9cb5f3d7baedcc53,
+        0x8ff68b60f5d791e4, 0xd855283d6c113ce2, 0x7a3532d60f5ebfd2, 0x0297afa0beff58b6,
+        0x202558f312409d91, 0xb9cc80f6b443d00c, 0x17e0e55468709a5f, 0x8d5c6b68094b1e14,
+        0x9da6982e9ae092c5, 0x252b4df664e1621b, 0xd04f5fdd7f962c70, 0xf9031d88d997d3ea,
+        0xc9bb68f2981c920c, 0x179b46c4fe75bdb6, 0x96edda95cfcc9652, 0x402f8ee1327bbc76,
+        0x46a5847c9837fdf2, 0x85a72549915ce5e5, 0x3d56961d61b4d18d, 0x75da8e1f0394b649,
+        0x53a672c5d466ed59, 0x5a58f2030e1cc033, 0x0dbd2d2448472078, 0x7f3181ae7dbd8076,
+        0x3176a72b8bf22fea, 0xecad00e1124d2917, 0xfafd47bb2c2c860d, 0xf82832c8719f2abf,
+        0x5ab369d7535c73de, 0x66d71c29cd1a9f28, 0xa76bb1b2864854c9, 0xa0500889d6e89012,
+        0xc867e2885a5be993, 0x7aa1e6d2db6b339c, 0xac1d927eca2d78f5, 0xd6178d118f1e7857,
+        0x7c26e686ba75bf2d, 0x71048495efe8c077, 0xa6c070c94b2fadb0, 0x831ca7391027149c,
+        0x4f0ac0c0885eb295, 0x1d6441911cd8f48a, 0xec4ce21215e61208, 0x8b56e4b13bcb3e45,
+        0xdeee9f9d7508ad7b, 0x4211a25d16dd0424, 0x84bf38bc3601e003, 0xfbbf686470509ca9,
+        0x0f618d5e6b5ef262, 0x6562b7c9c3ebeef4, 0x20f893dce332ea51, 0xf81ac171655b8b3b,
+        0x07d7190c5ef50f50, 0x40ccfe4aa5c9df5d, 0xf567a32d8b259941, 0x94dd6ccea2f21da2,
+        0x570df3b80bd69b73, 0x8b7edd58479fc8e5, 0xb32e657b1e97a1cf, 0x5ca9731fd4aaaeb7,
+        0x05ca93db135a15f8, 0x8886c6c99e910009, 0x2bbfe1ee19c86fa9, 0x7f2a8b0b098a4689,
+        0x5ea48fa323268c53, 0xb55d28f7c0c00df6, 0xfc3c89ebc2d0ac34, 0x527c63e43369e91a,
+        0x1705dd935103988a, 0x792b6ea70d69dd0f, 0xb5c77ed3fccdb1cc, 0x3656e6bbf1d80851,
+        0x1faa0988309386c6, 0x122c3b218814816f, 0xd9af2625cb094888, 0x263ad6e8a4ab346f,
+        0x19091e728f20ad7b, 0x91735c09e5a80f97, 0x5db359a39dbe85bc, 0x927b801adb8ad2c1,
+        0x5355403bea3eff44, 0x94ae550506fdd362, 0xfe56dac76f138d89, 0x7ac1921e06311ef8,
+        0x8eed6dfc596fe59f, 0x295c0c72ecae4271, 0x80dd00cd18b93825, 0x803c5c5963db43bf,
+        0x62feba1a3fae0763, 0x50dce22ac05c08dc, 0x3d7e7f516a65e241, 0x405ddced4d4ab022,
+        0x42f3ac9178d0e989, 0xd5655c6d3304e937, 0x9b5242460cb914f4, 0x7d59fabc01e8ebd9,
+        0xab33216453318a39, 0x3500f1a5e6633eb1, 0xdeb67a4a72ab53c3, 0x87ba4d5dbfe5d5c2,
+        0x36e36f62ef313f25, 0x4fa22ebbbd0c9795, 0x0de371286ddf6c40, 0x3b1acb4ea1f7417a,
+        0x297ae39fbafd57e7, 0xffbc12a4309043cc, 0xeb9b7748d5e5791c, 0xda527a3a2d6a906b,
+        0x0564c0c19cc05054, 0xb6f0bfd637233ad2, 0xf9adc3b9e1fba21d, 0xe483c064fb35a0c5,
+        0xe80336934c65aede, 0x0a804d97e997662d, 0x612723464ab79e4f, 0xf33523cf36d6015b,
+        0x8abd85ca9c28ab07, 0xa8fa686828d7de9c, 0x9ffb4710c234c967, 0x98bfdff87d82b4ea,
+        0x635aef235cd23ad3, 0x42b714a282803d97, 0xc166d10960a0a9a1, 0x3450681042408659,
+        0xa086f224f3cb381a, 0x45120dee824f3194, 0x1fe004a958a4f20f, 0xe32329b6cd2664a8,
+        0x20ec8ab9797520b5, 0x52c88f4e299493c8, 0x0f68aebbee82dce9, 0x039b4b417a2e054f,
+        0x007873b5f753ef2d, 0x35bfa3cdcfd1729d, 0xc3e2b5f844f77b65, 0x4f2a2b2f9d3d65c0,
+        0xefb1d56aa00be4b7, 0x27f39532b6562c0e, 0x5b65210c7f21e566, 0x83407927a2e4994a,
+        0xbdc70ad19c4a3fc6, 0xfdb861eec28dff42, 0x860fc47cce7e3a5b, 0xe4c5da6a966a7d6a,
+        0x2c939c9ee1deb098, 0x61816cc255cdaf37, 0x2daf1d1bbf6df345, 0xe1333772f824addc,
+        0x08c3a7e67d9f3ded, 0x5a108ae98edae9f6, 0x437c8bbf3e7bdd17, 0x4963912765da3215,
+        0xe69303a0055b6be6, 0x129199d06c2fa298, 0x19c106af1a4d507b, 0x8aa612c0e5f4d77a,
+        0xa4aaf420fca761d6, 0x9e2814359f69d658, 0xe88e023c9191bbdd, 0x33fb368a2366b217,
+        0x4e4cf3f01ee97ac8, 0x7a51ecff301f3163, 0xabb9a2eb1fb3857a, 0x49d1f1e22827ea8e,
+        0xa802840fbe830331, 0x471bb7fb21ab2e93, 0x72823a17375b1796, 0xf9a1ba5fa21c2fdb,
+        0x3ff74a2f8b07f873, 0xa6e6a188624b736e, 0xeaa59a30094ed69d, 0x47d064709c9dd359,
+        0x915941599630ae88, 0x40032fd5fac3bb3a, 0x189565ac3936833d, 0x56af1b441ce1488d,
+        0x7488f122ea76b1c4, 0x44b3ffb2fd00bf8c, 0xe08aaa7520a31ed7, 0x80a7d2394c3f0790,
+        0xa4c0372058b6cb2e, 0x9739f1799144b3e4, 0x252a4bc15c287845, 0xf380dbfad21b051c,
+        0x96768657a10c8b16, 0x9b596283f6316227, 0x312d474413ed2772, 0x9a15476dc2b601e4,
+        0x7e7a04a504066a38, 0xc4a7229df10192bf, 0x5317d123c25a1d84, 0xf958a6a0ea182fc5,
+        0x25b54d4fc0c6a087, 0x2ab02395c2725879, 0xda9bfd0d9cfeb7f3, 0x79a3677c92f7f8b7,
+        0xa7e5a1fbe316ad2c, 0x21a40c5fda3c22ae, 0xe2e3a6984cfe9636, 0xfd3a80425787370e,
+        0x6441b66f6ced8ab8, 0x7447563655ccbc1c, 0x0d1715998a398005, 0xdd020ec9328597d0,
+        0x87f9a32f67a78387, 0xc1576ca854bc32f1, 0x174866448266a177, 0x5a38e3391315298d,
+        0xa79a6773157e3580, 0x6865f68e490a581d, 0xdc9bc87a03566c1c, 0xc6d377258f039b43,
+        0xd64f9dab03fb69b3, 0x8ffdd377ff7b58d4, 0xbb9d2d9186b6f665, 0x1031b7f1048e4366,
+        0x60797b7933ad8a09, 0xdffd1a332540f1d4, 0x98b7ab508602dd55, 0x4413826bb6c9836d,
+        0xeaa2dbeaec2ae499, 0xf634156cdf8de48f, 0x4eee03ae3616a55b, 0x81810586985d65a8,
+        0xe1df79a74275fb95, 0x264ae473878376a1, 0x15da638de74e0f37, 0x4d51db2c60b7cb38,
+        0x54cf0ecbdd47338e, 0xe6a6ab1e9aab38e3, 0xd0200a144c4dcb10, 0x15a2a51c0a213d9e,
+        0xa442e236c8a39fca, 0xc925049c06774f0b, 0x5597021585a3d5c8, 0xb4a8f34fc4ca310d,
+        0xbfcbda1d61a02cfd, 0x4d364fb7270947b9, 0x5c90aed62074d6c8, 0x3491dc5cf1b2f90b,
+        0x7ed35bf4278fc359, 0x4437059259a3beab, 0x05302a2220738be2, 0x07c782f65e503d72,
+        0x0951911c835e5108, 0xab68879fc5745c2c, 0x0f7eb479ce965fa8, 0x69debe6714aa7c64,
+        0x92b90de460c2b0e0, 0x1937fad2f4366d15, 0x5535cc1d9be95b6d, 0xc3e190e3dc72b159,
+        0x548dec59a5183499, 0x18cf9e8e5f728a86, 0xd2005d0e27f14c6b, 0x5416095b3dc83e50,
+        0x69d1fa1256df598e, 0x8bbda67d6bd26dfc, 0xd76c2ce884168c80, 0x244ea6e82810e13a,
+        0xa1d88f92b5155046, 0xe4c11a395a2df9f4, 0x8665a612b9b7f4f0, 0x9a5b73dfa61244d8,
+        0xd6a537aa80f1464a, 0xa354389cc40a8649, 0x2f4c13067ea62745, 0x3bc3da29c1d69c48,
+        0xc8fc680466d394d6, 0x87fbffb59e570d02, 0x68d3a2795d4a3c83, 0x98b1cc27da0a33b7,
+        0x85d047bef92c2a7b, 0x966c541677ac3775, 0x757460a8ae5a416c, 0xa710d7da7aa27162,
+        0x1cb2fe1db865ec82, 0x948dbe4253b64d93, 0xd7d162f5c342da04, 0xe183102076e1973e,
+        0x5d9323a114c33468, 0xda0efe79a8b53a70, 0x8bcf51166609bc4c, 0xe750fbe8be7855cc,
+        0xbb18ab8fbe576031, 0x5c8a950772bda9eb, 0xb4969758970e8e77, 0x98740aac7c616e4b,
+        0xb25bd360e223243a, 0xecc7d5722da1d9cb, 0xfa221b5c00d2c4cd, 0x2a61939949acfc9f,
+        0x036fe7cf3b4ff1f1, 0xee18febcd42a9366, 0x387f4b5fd4f366b2, 0x2491d54c1ad51bd1,
+        0x5d6bbcb7b0637a1d, 0x5049c830a8f786dd, 0x8406ae63a46245ec, 0xe340e596508bbbc7,
+        0x5485726065cac3ab, 0xf50a9af59c4ec014, 0xa926049b993060ec, 0xb7326735232e3bcc,
+        0x5fd23f71c7b49a47, 0xb5c11de4ed3312d1, 0xc0b09b557fda9029, 0xb48658edeba40dad,
+        0x6b88e1c8a088f4a4, 0xe3a0a6347c99d063, 0xfdb5473632af2624, 0x5a4e5b13f56251e3,
+        0x750808e5cf5bbce0, 0x12282752d91c8e15, 0x6be41aac4569477c, 0x7e860e5aa0faa804,
+        0xe861cd9b33aa2e76, 0xad10767da3a685b1, 0x67850e99911572cd, 0x4de24c05e60fd337,
+        0x0fe2619539440e0f, 0xbd9b86bf91ceb0d4, 0xe1218091f41a9ac9, 0xbca1dec389260269,
+        0xaa6457d308d8600d, 0x373ce669a8d607c2, 0x7a497d911d96cdbe, 0xe129304cf54b9663,
+        0x2accb565fb99af85, 0x4ec3f2783356d439, 0xd0651cf080f1c989, 0xf8b53bec75ca7223,
+        0x4aa7551b9100972a, 0xfdab363546c8944e, 0x77c3595fe09b2c7f, 0xb9a7ada0c21115e1,
+        0x3ef2d00c09cdedb4, 0xfc1dee4cfc0d5969, 0xdb9fe3672e9a4b42, 0xb195a920ccf935de,
+        0x0a263324c04386b8, 0x786e7b921eb721a0, 0x3b6f27ed8e51ca31, 0xd7b3adc550f438b4,
+        0x310397984835842e, 0x62cad349c8ad91ee, 0x8add4607a325dc57, 0x1d8c3442eb12f7e3,
+        0xb4bb031e3b7a4ef9, 0x088fc1b633b03aa1, 0xd10a7c7eb6eca531, 0xe99dc35bb81d1c61,
+        0x124e315f3c993719, 0x6d3632b11d018a83, 0x48d5ee496dc7f708, 0x4285b370bc05d9eb,
+        0x3f5f55a14afe7912, 0x0d48c73b9cc9f9a4, 0x06b5c822c1005068, 0x5f27ca91526d3345,
+        0x1fbd6ddcb9f0dc7d, 0x4822df3298a45be4, 0x3c9de882d7c3ec1a, 0x6c050a2740712899,
+        0xc94d80e612202e43, 0x27e02a7b928aec48, 0xe95cbf3aa94e7ad3, 0x50c9e539f54e3f0e,
+        0xf7bb395351914fb1, 0x09a3fc94f7121cec, 0x5de4ca90666b4272, 0xa59268ae9cef95a9,
+        0x8ba75abc4688db1e, 0x64fa8deba2cef548, 0xf2043039d391d5c6, 0x4fc2a65cbc554571,
+        0x2954cf7ba79667cc, 0x918c452b125ea153, 0x978a6dffac9460d2, 0xa7416d7637c1e296,
+        0x6e5c918a932853f4, 0x6bc2055365eebe87, 0xbe59ee5f7fed8269, 0x2a4e0971d34b7914,
+        0x8fc3ab46a34c151c, 0x37e6f883a164bf86, 0xce35681f3dfb6f35, 0x09b01384ec21fda8,
+        0x0d0d4fe3994fbde4, 0x97dc1cc062381184, 0x26d355bdc0842e37, 0xd2426bf9560be379,
+        0xfbf7e3b78181d88f, 0x363034e5cdad9f28, 0xc759766410c15004, 0x58ee77ed1c9fb276,
+        0x4859527765960cc2, 0x274ebef7e8209b7c, 0x7062ffbc5346f688, 0x38208b8328760bfa,
+        0x1c9b8cf756501991, 0xc6b870e476696f35, 0x396b22284624a1fc, 0x449ea4a95656db83,
+        0x613c01ea585f20c3, 0xa6c840a7ffed54e0, 0x67e74fd1351fe3ef, 0x07abd1e0711f24c1,
+        0xadf2f384fa4bcc97, 0x690627a475e97fc2, 0xed07c00d558bd4ba, 0x18c395df4333a13e,
+        0xfafdb0f3dab30b81, 0xd7dacb2c4af2a3bd, 0x405efc69176ac0e8, 0x1092718e4307df73,
+        0xe9164308a4667132, 0xde027fca9b786e50, 0xca124c6fc19a1ed8, 0xd5378ec73dab9f1f,
+        0x03efcb8cd543f78b, 0xd3f28366c674da89, 0x0edcae18ef8f1a90, 0xdc19bf61a0b8fd05,
+        0x4759db22b2df3b2b, 0x528fc8b498ace20b, 0x0fed59fdaceb988e, 0xa3a2926171cc09f5,
+        0x7a5afc518de987b1, 0x0b8112b3f9ba67aa, 0x3d876c594bb861fd, 0x4e7b83803bbe6b90,
+        0xfa4e2d77386329a1, 0xbd76c76764e94788, 0x82afebeb841d63e2, 0x5da8f9c83faf097c,
+        0x5d35a25589c77bb3, 0x8d9fd2d389979223, 0x703a2962af0aebf0, 0xbb99d0c29a9eb7c0,
+        0x751a8927ea3ffd7a, 0x14f5e83803f65abe, 0xa6eb91343f4d6f55, 0x474e3ca4b00007d5,
+        0x2105350fff3fc99b, 0xc32a5ca87f55fceb, 0x7b989e1b3ceb678f, 0xc48f3f5c95863834,
+        0xd59b98609e99a101, 0xa9965d7e0c550606, 0x470c8cecb63fb557, 0x057d8badec7e4657,
+        0xaeea9026e7276660, 0xa9eebb0d80da8b19, 0x6f5a458e59dea2cd, 0xd7098d89d3451b3a,
+        0x54a3c2b673bef48e, 0xc5b9361aede2e6b6, 0xf1eb7150df01ce75, 0x6bf95831cee3c740,
+        0x385e12b8fa5fdb6a, 0xacf34a24dcd2bac2, 0x680a8c431b8e34ad, 0x17359898b8392be8,
+        0xc1018f789d3c6b12, 0x702bd6e4e0d8b50b, 0x64f7cb054115a73e, 0xbf7671362b61b376,
+        0x7e4549f5c8c7171a, 0x256bf9ea6c36ba32, 0xefa48cf56bfc7a43, 0x66da1cb352a27b6f,
+        0xcf015dea0d5230e7, 0x796f17b7d83e90e6, 0x4f1013056caba7c6, 0xa358c1ac9bd385c9,
+        0xfa335a108bc2aae2, 0xb911ca7806068c36, 0xad793a1a66960b7f, 0xeff417db65c4f138,
+        0x6c351f920a6695fd, 0xc470c84630b5ffb8, 0x8420016c89fc3b83, 0xa8660fba9f4951de,
+        0x5b61a3aeb904c0ce, 0x43d7dfb1414d69c5, 0x198baeef2b6e6849, 0xe4fb39a82c7640dd,
+        0x8c2ade8731387858, 0x369582e38f472976, 0xb7353dd51d8d5584, 0x7b221d899b295586,
+        0x8d8cc4805b25c05a, 0x1afb6d0a22fc8dfd, 0x1293dce69c650e32, 0xbdd80358c6162e56,
+        0x841ebe44367a96be, 0xf15812d05b3e61e0, 0x0d3e4fccb3c0471e, 0x8cd77f1242af505f,
+        0x1cdaecb01c993e04, 0x111c3ffefe9b1f78, 0x92cc7db12ca9467e, 0x17fb20f22d719877,
+        0x4cd6acd2ab254a24, 0x4f420e400a32732b, 0x77d483757c4615e6, 0x574a3f9d63b489b9,
+        0x52d5224b291c5629, 0xd45776647591a334, 0xd46f85999767faa3, 0xa28a7de24d853d92,
+        0x40f52a5e144321c5, 0xff067ef43be40417, 0x9a3376666a2319bc, 0x85d0f8c0ad758936,
+        0x2e9e95128cdfdc57, 0x04a2213e1a7d23ad, 0xf71e8c95f0d396a6, 0x86a40a4d5f62aa3d,
+        0x0d1e39617a8c730b, 0xe42e52690865f6ec, 0xec660100995edc71, 0xe47ce5ef3c8e27c0,
+        0xa7f6f68a9bac3687, 0x7b00b64933d3bbd1, 0x8d7af1f5f68f7d89, 0x1eb2cc56f3ee49e9,
+        0x744fe91b5a3c5e84, 0xece672707f17dc7c, 0xae0cda0493e20765, 0xeb27fed19308920b,
+        0x84fe262fafa160ca, 0x5a3587e81b029fad, 0xfdd74b92c9a9ce00, 0x19f601e3fa37ee39,
+        0x079edf5fb636c2aa, 0x6e8581b16b113e88, 0xe85f20731e17b81b, 0xed1dfb486cef2bb5,
+        0x9f8b6560b7d2b1eb, 0x9d0665366d45bb33, 0x736ec259cf1185a8, 0x9657d0e9d3405400,
+        0x41fe2ad876b34f3a, 0xc86708ac858da698, 0xa979ff069f91d401, 0xe0f0c7ea0524a607,
+        0x1cfa3e7e8d21aeb9, 0x9872d38a4f3861d1, 0x49b5b7325a989e77, 0x1c07f8aa9a47d0ca,
+        0xa3cb37c902756a4a, 0xd19e3914999fb53f, 0x1213fbfb0d6bfb35, 0x44f5326bb4ffd640,
+        0xae4776a89988f4f3, 0x84600b51b07cf7f6, 0xd94697bb4ed906c0, 0x8aae1c8ca64fda20,
+        0xda846f0f03c294ad, 0x8323b17ecb83d11e, 0x7b33788329339133, 0x7c6cc75c52957708,
+        0x7a64a158edac49f8, 0xe78db99f7a5faebd, 0x864ac7b38525c270, 0x18d8fb79a1b7ba3e,
+        0x8f5d25af6b3c471f, 0x2e469a394e927a77, 0x4bab6c175cbc708a, 0x1d2e30630814a127,
+        0x44a6736a2f637d4d, 0xe357393308623725, 0x52957db843dd4bc9, 0x0b1be8f793b5acdd,
+        0x75b44e7a73cdf366, 0xc23d767cec5e4eab, 0x22f29c2fcbf23fde, 0xcdf9229424230c02,
+        0x6ce11bb5e8b15c2f, 0x28b2f79dc33806bd, 0x31b7592a155b7aa7, 0x4dd5e4083007edcf,
+        0x60d267159d1c6a68, 0x1d6b0c22ca935262, 0xc2287e3665cab982, 0x1d29af357abdb726,
+        0xb17d151d784ab7c3, 0x193673a4cc0f66dc, 0x862e25af22d168d2, 0x8bf5f3c856bb84ab,
+        0x52e4e08acaa54638, 0xabd77fccc0d8e79e, 0xe1d15150afb76817, 0x10dfd4d85a1612cb,
+        0x0ba62e344e77ca3e, 0x6d9ab16269bcbf84, 0x4dd6387d2282dbb1, 0xaf4c8a9719f1ae6e,
+        0xb5eaf541a09654ad, 0x1fccaa4fdb4e35a4, 0x3efa36653d150b9b, 0x22485459f435090f,
+        0x4016043a2a68d2e1, 0xa78e8ee7d398f783, 0x4800dbf7571d9ab5, 0x900ea0473ac412ee,
+        0xe9880a5671747c09, 0x9c3b76fc6a90fd1d, 0x76876bbc669cc272, 0x4407e9649ae59d20,
+        0x7f36a883588ed7f8, 0xc1dd4948e3ccf815, 0xd17ebc0471bc8237, 0xf47ccb9251c46571,
+        0x221ea09d93348c0b, 0xf597369a5188646f, 0x626fcd0a8f54a955, 0x6b02b4a998fe4333,
+        0x7b2eac16b08b7a55, 0x347a48d59567dc84, 0x928b98847990e3d0, 0x0ac6b7d22752ab4a,
+        0xb9a4c100e81a0093, 0xa276de8fe7d296c8, 0x38548f78cd0e3667, 0x301f27a39bc31f27,
+        0x1e9d45a638849a71, 0x8da00175174c72b6, 0x9879053a3ebcca14, 0xa2a8b1eb20589607,
+        0xe578c30e1ce52ce9, 0x7070eed064deb923, 0xa51d97686fcbf94b, 0x089f422579ba7064,
+        0xe3c1a0e90b8e7f99, 0x9d037f269ff09574, 0x2bfc77c0e2e79663, 0xc1729181ffa7eb79,
+        0x2ddf39a9ad8ba070, 0x2ff4e29f108b8382, 0x827be0b2fe3656ee, 0x862aed649f3c6a64,
+        0x908c2d34d56d80cd, 0xb941e3447f6e6709, 0xa0cc39119b31cfa4, 0x22d2491230d2a982,
+        0x863a4fb2eaea1dc2, 0xa583669ed3204198, 0x4b1b53f40081ee5e, 0x652334789f4ed5ee,
+        0xfea6c7605365f68e, 0xd768b1c7df49edb2, 0x2f26bfdf2089ab3e, 0x64e1c7427d41482f,
+        0x9f10402f7786df09, 0x7f250b099ada7d37, 0x43679789e09c25b9, 0x6310a638f2c44214,
+        0x6b836e5ba0cf8e6c, 0xed69589e29dcf17a, 0x5942c49a9b724b3c, 0xbdd04db9eed3105c,
+        0x0b9380091067c9e3, 0x59243f7345f15791, 0x771408a6211d1e9e, 0x456c69e04a841a15,
+        0x0fd946d1f0200260, 0x302bb52353a9ffdb, 0x49ffecf195ae964e, 0x1be7d2be6d3ab22d,
+        0x4809dfd8fa021473, 0x4a9700d16768019f, 0x7ba19e13e4f9cc77, 0x55e5f587f728d026,
+        0x1fe037c39cef443e, 0x836934803b201a44, 0xc9de1f9b2a228a44, 0x4e79aeaf5e135751,
+        0xca0ca80e9f77a906, 0x7788215eeabc2efa, 0xd3886c6c942a5c02, 0xc5b65f256533da0f,
+        0x5b8294ad491c2022, 0x0d99c86f60b8efb0, 0x91e6bfeee341f6e1, 0x087f14c1f2382fee,
+        0x0537c6e0871575bb, 0x69fd4f64e037bfa6, 0xeea6c7448452b00b, 0x51bd0cf5e9b21d73,
+        0x6329bf4c78dd1051, 0xdae22b2d6dbdfd4b, 0x07a7f1c1a1a456cd, 0xe2223c7d55d50979,
+        0xd1d27ffcc49928ed, 0xce943c7ff7369305, 0x99b355962f1740c6, 0xe304df55728c4c46,
+        0x5957339867ac914c, 0x7a11bc0873786a18, 0x459a2d39aa25c257, 0x8d3df0eddf7053a7,
+        0xe48c7f6606a079c4, 0x7fad44e8fd4fa240, 0x86f25a720640967f, 0x620fd3c2b26c6fb8,
+        0x4c693c87f957efc9, 0xc98face71aa8c0a9, 0xe50b58896f3bd808, 0x2853dead3e38881d,
+        0x3e6e77cbb6e17d4f, 0x64ccd2e69a33e123, 0xc79a0eb108c6ff12, 0x1f0a83d2bad76f24,
+        0x48a979a46fd83e80, 0xeb426d76a3e781c3, 0x8191f8f07a6d13a4, 0x05dbd01174a76e84,
+        0x06832344d838f0f6, 0x3157c8b895098b5f, 0xcabd514914ee999f, 0x0f0fad9a8dec908f,
+        0xecd48ea7b949d520, 0xd5a0f6e41cc10083, 0x59ba1b31452be801, 0x5eca2000698d7b64,
+        0x3ec631fda375aea1, 0xbf40b40497a46df9, 0x2daa4412d0ea615a, 0xd9a097c0e9526048,
+        0x814b6aead23474ac, 0x7dcfd32ded9822fe, 0x54bb4278d5d972b7, 0xded7a2f162eebdfc,
+        0xb801581f1b1ac13b, 0xfdad8877b3b4d790, 0x00ef0ba68c6338cd, 0xb26cab55a7197c9f,
+        0xcfa9a4805ccf5302, 0xeeca980262ee5d36, 0x074102a87ba865e4, 0xd2d0c1fad7df9b58,
+        0x2913b621431364a8, 0xb5af71332433fda3, 0xccbde3fd5928d72f, 0x843f410b23c2cf68,
+        0x1311dd7e52cc6012, 0xc86f181276b9dcf0, 0x610e50fecf27d20e, 0xfd6fbdc79768cf52,
+        0x4f1f32a1ea9a22fc, 0xd0c38753c83b1bcf, 0x1c4b209b5fafc1eb, 0x6ca7d55a9b8d2a48,
+        0x6b6bed9211a2791e, 0xc61d0ed4341c8ef0, 0xae33f16cc3228ad2, 0x7620c79cc50d298b,
+        0x5ab8be624411c630, 0x04d0e52601861a56, 0xa590914dbbb8fbea, 0xa23733c66ff26006,
+        0xea76eaf37b301f73, 0xc8fc9d375135904d, 0x046e2810b768bd42, 0x0332ddbed8493aa9,
+        0xb4230af053571e78, 0x2abf3f96e3bae1f7, 0xf785059920e09ba8, 0xbc3df1d22d34b571,
+        0xf659a5b9e76ac545, 0x5de1c66f5817988c, 0x409a5d54233e4547, 0x0cb7fe06ca0931db,
+        0xa824a6ad7a753d05, 0xca293d1980d5f99d, 0x3f0580e830b3afa9, 0x3371ac202d4dcb4c,
+        0x38a2bbb390a42281, 0x3bfbf476dc13283c, 0x007a17ed7b1a9707, 0xa15dc9f740512bac,
+        0x455d1176637ffa07, 0xdc4718313a78d7f9, 0xd6067109c77aef6a, 0x4dcb8b8fee1402f2,
+        0x114ee4e86359edb5, 0x10e4838e0da4d3d3, 0x241d826c8acf41bb, 0x831af64b8a6840ca,
+        0x23c857ac869e6df6, 0x4c461fc26f8de74a, 0x9706bb336d655845, 0x5f198a6638ac594b,
+        0x9b3633d32be38920, 0xbf8bb2374098172e, 0x557d99fd0bd8e4c1, 0x9bf9ab9f68051e3f,
+        0x42a938a73eeaa569, 0xb176c895f4228f7f, 0x4791cdcd7c3bbba1, 0x319fab20b1b0f0f0,
+        0xd7f9829a6a91dfbb, 0xa80765ba376b1232, 0x7233110bd174dc8c, 0xf6c4d6a9a6e2215d,
+        0xd9ac868d0fb58e01, 0x295bfb42794bff53, 0x939eb3a72fa2a614, 0x390d8674c9d75885,
+        0x5947967b55586270, 0x5c9a7b549654c475, 0xa5fdb7fd8b6663fc, 0x5e6ad163f8616920,
+        0x1ecb0c511653ae88, 0xa01e87b59b303cd5, 0xd8f08fc73abaed2c, 0x3c7679263ce677c1,
+        0xedeec7267ac6b31d, 0x038660f5eb7bd987, 0xb60cf51cb7a20b10, 0x4e83ad84ac04b0fc,
+        0xa23477d97cecfe5d, 0x66bf1f5fbf2ce5f4, 0x6a0aa0125816751b, 0x383431a6186a6ba1,
+        0xacc07ce21a5adf6f, 0xa9dc00b2b0a7219a, 0x62ccd3aa124d1675, 0x00a8abcde23e9e57,
+        0xf344837a39c06d5a, 0xe2d065ec23f091a3, 0xacdafcc42fd746b8, 0xef4e46b991a46569,
+        0x5be4180ffefb0d19, 0x22f2e215d05487f5, 0xb9984a9ae5919cd7, 0x14dc000e010652a1,
+        0xd932609c9bd54c28, 0xbe228f42cb4bec23, 0x662555cbf706c5e8, 0x5232219f32039b01,
+        0xdf2e46192b817b9b, 0xe92cc585231bf675, 0x5814021d474d61cd, 0xde75c7c9a4d1ae18,
+        0xe595295b8e8e479e, 0x2ba14c9ef2cd6b0f, 0x4cbc5d2926883e65, 0x8a5be48ece582d00,
+        0x5558181f289753b3, 0x680fb5debf1da7d8, 0x10570acda49ec2a7, 0x2eabb523d42b0ab4,
+        0xbcf055705c770497, 0xb0389bdb6bbb43e1, 0x22e64d81525093b5, 0xae2a3f03efb69092,
+        0xaf07370ebb577f36, 0x2a9e43c972542bfb, 0x38eee2e0b96491e2, 0xdc9b26b460f08049,
+        0xba4431ff90312f8a, 0x2fc22329ae7c8fab, 0x499869c6452c0fce, 0x5d094cd2f92ff713,
+        0xd1d4319bc32a9a5d, 0x1b7dd78306d3658b, 0x9ce4caa005d59cae, 0x678f97d208b2441d,
+        0x5973e1ecf572002e, 0x15aef863f86a7286, 0x231f23da59bcfb9c, 0x515d8168c70123c8,
+        0xc3aa24fe77051005, 0x3db54ff932954617, 0x17fab32807bd60a5, 0x214db5dd4ca8f963,
+        0x948bdab5e191996c, 0xbe831976b45242c0, 0x973d152f116b7cb3, 0x2ae49cd0bee4ddd8,
+        0x8d10846940509001, 0x5928f48eea5de33f, 0x08e932c4dbf65754, 0xb7b1fd1994af65e9,
+        0xfb06a96da8b80c8f, 0x436e27544927f1c1, 0x519e29754311d46b, 0x75f42b803631fa65,
+        0xd73403f20a1f3d41, 0x1553f36b13136516, 0x85e6df5e5f6ad3e3, 0x19eabfe790b7ab4c,
+        0x11c867c251e59755, 0x9c250c437229306d, 0x7bb9a5b10e9f7757, 0xfad5c13d5c4285e7,
+        0x3ff7b19120ee0ef0, 0x3b76c7402e8c2db1, 0x28ef1246198ff3e6, 0x836d303991aef270,
+        0x4dc21ded6f4eef98, 0x4876da2b2b96df36, 0xa34137ccbc18cac5, 0x722594cc3c6a7c46,
+        0x7e73c6df00c87a11, 0x08a1e473674e694a, 0x9a946a2822e57ff1, 0x98e8d47c8d59470b,
+        0x13615fb23aa10ff0, 0x4fe603d7bd90d314, 0xe21ba2f9477ce746, 0x09e5c3705418e5bf,
+        0x588b636b4b758828, 0xa83416541bb29654, 0x5b7e0c0ffc7ff07a, 0xa10a0ecc3ec76937,
+        0x7c84aa630f85d7ba, 0xe207c5a07eeb45dd, 0xd1d40aa208627e80, 0x07d8e39bcc460a3e,
+        0xf830c8c125335b38, 0x77d7c1dd2273a71a, 0xa83d741f279c74b8, 0x20e66ef4bb173cbd,
+        0x14ea8c07e090c7dc, 0x9ac8ecd2181de0a8, 0xd2bba2663002e60c, 0x60de3ed1ad04397f,
+        0x0bd79ee75223c9ee, 0xc804b00400c56f2f, 0x04428e3a8ffeb0fe, 0x54ff68a1dc244dbc,
+        0x3df220f30054ddaa, 0x31207ada3d0e6e8f, 0xa190cfe02d52915a, 0x8d18e75262678cf6,
+        0x7e0cd1ed8e7cb42b, 0xad1c124e493692a8, 0x43a26d2263c9b0f9, 0xf1c496764e7f70d8,
+        0x6e314cc42453d899, 0xcbbc144c99dec3d1, 0xcdb43a3c2f73fc78, 0x16458e8a81143945,
+        0xf61f8caa9d3a839a, 0xdc64db0977cbc7d5, 0x130218e3338b4742, 0x48f86600000b2bee,
+        0x0ed2c2314f65e061, 0x6cae73726c6b9aa3, 0x4039127b47029754, 0x37002ea0df496aa8,
+        0x9b83c84dc51ba1b4, 0x71917f9e9c66a5f4, 0x6f26c131267339cd, 0x01ee579837f3d5df,
+        0xfa2b9eded86fb94b, 0x25810ca7b15f4879, 0x64599fae5b1ad96e, 0xf6c7166b4a3aafd8,
+        0x96c25bfd2da5fcd9, 0xf44fca17014c43bf, 0xd1e5fc9311377eda, 0x56ff8104ae712263,
+        0x3f793e1781602859, 0x26e882366d6bcefb, 0xc6bc3f9e78f810b4, 0x7a1470094c4250de,
+        0xaecdecbf87fdf0fe, 0x400193f7e10663ff, 0xec45c1088d420553, 0x9c33e59d004db40d,
+        0xee01175691041dc3, 0xfd5d5917ca29f602, 0x24e72211e4f85a1d, 0xcf8120c173431da0,
+        0x6021a0a3548b721b, 0x2121b90afbe9bb1e, 0x23daca42550f032c, 0x79af510cd59772f3,
+        0xcd41935d4a940b4b, 0x315da44e155c9923, 0x2a6792923763f703, 0x165637db71f420d8,
+        0x2ce4f4d267696800, 0xdb8ab73a363755de, 0xd3364d7561edb2a7, 0x69ae2857269446ec,
+        0x8938d0728e6bd4ec, 0xa1aa4f7d933c6145, 0x2667e37084fc14ed, 0x54749074c08049de,
+        0x2bf4c0012b9ae878, 0x9531c4cc380f285c, 0x8b4428fb430a136c, 0x9e8b72aeb698384c,
+        0xf64f97f12e815c91, 0x8813c9029d0f57c9, 0x9cd5cec36b5c4ad6, 0x109e7c9698149e5b,
+        0x48a480a1ea427c73, 0xb0f0e9c680553de9, 0x90710ca40574366e, 0x3228f6526febe75f,
+        0xba9fd94adfece736, 0xb3d90d3c5d783384, 0xd1098af7cc4ad0da, 0xa801eb7f24cb0244,
+        0x59bee30c77212328, 0x984726f09bdd3c3a, 0xe5ba0d8c005f59ee, 0x2015b94f7bba9b9b,
+        0xf3ecec78ecffd3a7, 0x0500b7ddc06f3226, 0x21445b6354badd21, 0x242900ebbe084b38,
+        0xada2af9df961cec5, 0x450b665d09cba5fe, 0x080e666d12f28bc5, 0x2a49bdf1b343aa1b,
+        0xf5ba169c1cca3de9, 0x109d63c9fe0babe0, 0xc2f83cb716608561, 0x1be732e750cf61c9,
+        0x74ebaac86d850904, 0x879eb2e44087be16, 0x237d66f28efe75ef, 0xe2b51ce24c18657d,
+        0x7061a67a9e71e849, 0x689f0095f2949e44, 0xfcdfc5b7452c935e, 0xaaf84c317180fc79,
+        0x04daaad7731cd628, 0x2f94ca067d63fa9e, 0xada1911d5fc4ec62, 0xcb18c5bd354a3daa,
+        0x508c763df78f7e0f, 0x7e13758373aab5d3, 0x63fb86063edd889f, 0xc2826d149c75d689,
+        0x560188d46bc45547, 0x6af76938010b7422, 0xbf3785d619f7446c, 0x770866f6dff655ac,
+        0x8ea9beedfec88c83, 0xef61e46dd7acd928, 0x39ca184ddbb9510b, 0xb90b53a50c62d9c6,
+        0xd936d5a9633a3ba0, 0x82ca2486312747fd, 0x80ddc19b716a5fe7, 0x8f5f8955fae2adef,
+        0x3a591ae6f347754b, 0x08a7f58438cd8600, 0x3a8757f703ab24cb, 0x2b3646f8be937ae8,
+        0xabcbb7f02e794a10, 0x19370ede0aa1ea24, 0x94ec064727702f7d, 0x9ae0f1503a7546ab,
+        0x8424d8711f180c46, 0x90766bc8c08bf845, 0xc96407929209cc43, 0xbc80049d84fef33e,
+        0xa156ebf89165ba55, 0x0b7d2fb563eddfd5, 0xf664e0ade7786631, 0xad55595f16dc0411,
+        0x0e2c07846cc4ade5, 0xe96c88d1324cc1f8, 0x605ac1370bad5015, 0xeb97b6a23726bf48,
+        0x45b31e90eb30fccd, 0xf9685e21fd655741, 0x6b8f130c076f2cc0, 0x296a834eea64c64c,
+        0x987096baad5ff24a, 0x3bc667e316ee7175, 0x1199583a6d235e78, 0x6978eaf2dd899bf9,
+        0xe213db4ff0bc404e, 0xba852bff28d3d349, 0x8b80341bb96ee7fa, 0x56bf37ab56c02079,
+        0xb245bd2f8fefc7e0, 0xacf03e826745a51b, 0x5e5b499c20153fed, 0x816512665f5c0cd0,
+        0x34a81da32391b8d5, 0x15ac8365e45c2e41, 0xe645d95928ac6df2, 0x771c5c7b8a56c571,
+        0x258044ea102aa0a5, 0xb387e89925ce62a9, 0x738f5580eff4acce, 0xeb8e69d8858d0efc,
+        0xf22286d1d576b7c9, 0x29d0546e9c603aa2, 0x48309b4f16fcaf92, 0xd786dbadf5cc3b1c,
+        0x18d12c1a0540b540, 0xe05593f048528378, 0x7db6d34e0a4ae044, 0x85270ad7b7595e2b,
+        0x2f2725652fc60004, 0x25ff6c8edb8bac0e, 0xa84e831ebc1c308c, 0x3805748e5a156c29,
+        0x82bb215d04a14d25, 0xab027c641e054127, 0x06386567dcdf2d75, 0xc4d834fff158bed0,
+        0x02abdea73602f501, 0xac84dbf5738da773, 0x9b6a827b46cc31ee, 0xbb3c6838a89d5395,
+        0x9162abbe2f8be49d, 0xd1c3acc8f0561011, 0x5162bd5134ef58c6, 0x9ab4de33420666ef,
+        0x9951388d052a0db7, 0xeae216382d5c0c1e, 0x4e1514d3abe53c1b, 0xf8c551f98baa5d25,
+        0x66254b038346893e, 0x8b9b362edac82b59, 0xd9a32a8861c91d14, 0x3a891066c5c4f9f9,
+        0xdae86d8ec7efef5f, 0x1259b24f152a01a2, 0x325e5fefe191c8b2, 0xb13b9fa7eb34a7de,
+        0x36c25ee076c5031b, 0x46a32227aa0a30a4, 0x483ca1d25505e894, 0xb9b4ffd750a0a125,
+        0x1223ebe10023e0fc, 0xe88ad08a7246f672, 0x5f4733d27e8aab77, 0x343dc2f916b06ba5,
+        0xc4d85fc427cd54e9, 0x843e5ed0ded6fe96, 0x5acd5077d7dd2fb1, 0xfba5bd4a58029c78,
+        0xc2ab8209ed65716a, 0x385cc43091b32783, 0x89aa1990e3ce5c7c, 0x9e840655931a2d5c,
+        0xa08fc0f2f050d30e, 0x93ea74cfd6cbd7cd, 0xabc8ffa819de81f2, 0x71378c4f6119e183,
+        0x0adc5302114736bf, 0x1dbf94e1e63d99ed, 0x2efb07aea1516d78, 0xd2daa07e2edd9190,
+        0x0c516deaf6f16b8c, 0x5f8f63e8ca1695d3, 0x5e392bdb2408031d, 0x9de2af825444d0d8,
+        0x6ea7478e39b178cf, 0x14ded56a21ea4391, 0x3029b2bdf466dec4, 0x2767e36c09180309,
+        0x2e02d371f7b536c2, 0x7b2d9fb1c3460fe4, 0xc7ba7080fc0580d7, 0x437cabc2309a4d5b,
+        0x14280f10a8cc30f0, 0x63960f592de60e3e, 0xd567a71a5acb6410, 0x129e3683094b211d,
+        0xe642af06878bd39b, 0xf60812d6dbf5832f, 0x0d30848ffa458bef, 0x1dbf69a0d2879d7d,
+        0xef37ddbef2e4961b, 0x924b56461520a4a3, 0xef4e0dbd88169c6a, 0x6870b1af877fe03d,
+        0xb0f8e565caa79427, 0xba1bc4f3c0298da8, 0xb56a40b49893f297, 0x891b498af75c8b43,
+        0x304f594b6f63da40, 0x20b43e95b5319b75, 0x4799775f6b8ec0ae, 0xce7d6bee4f1ae023,
+        0x9edf62e8b55d0c94, 0x274065c6fb3841ec, 0x010884cf1523021c, 0xfbd38e5273fb07aa,
+        0x0bb49bf49b6e6899, 0x0d9b341410fbc5c1, 0x98acb0a2ea5ee250, 0x0cb25e1b12396a9d,
+        0xae0cd549087cef67, 0xd435580aaaa147c0, 0xb7645ec5fc547b0b, 0x442227053b07791e,
+        0x96205f0b501add88, 0x6ecd92e0f2ff87fa, 0x4cc25ef49851918d, 0xcbba2ec0b2a0036e,
+        0x62a56f89a3f154c7, 0xadecff58fcd986fa, 0xcceef11bc64fa759, 0xa2b64ea73a224dc3,
+        0xa76ae74a2e983fe4, 0x54f0ecdb8301d38e, 0xdd9d46a1f3aa0f55, 0x92109c3c9538b2af,
+        0x6b090af394ee0152, 0x60ae983c1e2c7c61, 0x4cb527cc5cd7f0a2, 0xd025e28dcb8d9f60,
+        0x33f6b1e7fefa3e03, 0xf7fb24edadac07a8, 0x8ee0cff6d45dfafb, 0x29028db5b0df253d,
+        0x4097f77d500e3993, 0x80cd5f627770b44f, 0xd2a8b349d725c76e, 0x0240f5cd8744b929,
+        0x25c53640cc3e8b1f, 0x886057bb2f7e8df7, 0x3cc693088ef19ab8, 0xe06f4dac2291fcf2,
+        0xfa7508cc525cf01e, 0x7b902d6a0b0cc13e, 0x29a87d9b137a780b, 0xe94f1b3f3a560f0e,
+        0x545c5c793f5c5319, 0xe8994deef53ecdbf, 0xa7732a9138e4ba35, 0x2f615ddf8356ee4a,
+        0xc440754847d8d88d, 0xd1bdd24a01c1a01f, 0xc8ea57ac0d89d9cc, 0xc9ff54e47836a7a5,
+        0xb2858f3870f36a96, 0xaa60ca519a9a540c, 0xe637372beefc7096, 0x051cb9cec07efde9,
+        0x6ef523737f4115a1, 0x839cca3cd2b3a60d, 0x19c39b85f011e772, 0x6aaba5a1b05e1fd5,
+        0x4485add6b1054efa, 0x39a3f55b96121da9, 0xa434da17d6645375, 0xcaf7dfd528b3d39f,
+        0x34273488aa2e8152, 0x51bad9b14651e190, 0x8ec144bee5bc38fd, 0x8049ed930a3edec9,
+        0xba9bce6a1e53bfd7, 0xbd05ff30376818d6, 0x4b92511831213002, 0xf5e48259a9b632d1,
+        0x935f3c27e0d66157, 0x1a8818941006c835, 0x43105099ba431adc, 0x71f05306cff2c3ef,
+        0x1d8813ddd519724f, 0x5a5bd7bdd7847fb7, 0xfb5901207e23b39a, 0xdf08d16465a35710,
+        0x2274a14b7cc99c10, 0x1f946d06f14e2bcd, 0xfcb010f877a10ae5, 0xc6c844d50f337292,
+        0x6655d3df3ac08596, 0x186410baa0099c75, 0x6d6f650abd7060ec, 0x0ef9c5950914a113,
+        0xaef0453aa80150ea, 0x32a7fb6584e3a2d9, 0x55f974619ccca038, 0xb3e6f76831318884,
+        0x598a13f0fadb954e, 0x92041ce57f44ff46, 0x66f3c11ce0709469, 0xea7bbc6fb6c66936,
+        0x243eb6ea81c0dc91, 0x1964df462f210d66, 0x523c7fc6dad3ceac, 0x92fac56e93abf201,
+        0x5205b0b4d04413c4, 0xceb35e770971f780, 0xd2587902c90ee490, 0x6567ee243f53382c,
+        0x5e50acb1fa1f3efc, 0xa327cc18577e1edb, 0x7c5ff74ecc83d1dd, 0x903b50db2ab7bf06,
+        0x1efed0f620fd835d, 0x594de8f38dd241bb, 0xe8afd05dfd4260d0, 0x739bc6083ca72b9b,
+        0xb2d31e3e0c8c4b83, 0x2619f05aced0099a, 0xa2cb03c9562b8f73, 0xa886e9f3876c9d1a,
+        0xb3c15daf0b29e68e, 0xebc187bb4e39938f, 0x721eaa6f57d11ad1, 0x05ae7a1e9382152b,
+        0xebd08d38f48fe5ab, 0x814a5e01461d6600, 0x026bdf9cf1e2380e, 0xc965637424fdb5f9,
+        0x451472891becdb67, 0x2abcc9ad0563efc7, 0xdf0752cc5bb57ba4, 0xe0de30459b35ffd8,
+        0xf5b2ac361364407a, 0x474454e387d692b6, 0xdfd68f8a4dd0af3a, 0x4e59d588545e856c,
+        0x512f5ba83f8978f6, 0xe8c0cf6507755149, 0xc5d5992dfa91ac9b, 0x5a72f4bcdd6abcda,
+        0xa64b39e5061d352e, 0x2ff242b451394cca, 0xa1aac754106d19a9, 0x463b97393daeef2b,
+        0xf8c28c971c715477, 0xcb4185cb458c55b8, 0x7889075f0337454d, 0xf697d435da60f35d,
+        0x1a5c161cf8c54fc9, 0xb15154744c99e7cb, 0xc2dde313e5245ded, 0x3a8f1b3eec1c013c,
+        0xd43828cd2f93e037, 0xf7c0aedd18e620e1, 0xc64f5d686d883b0d, 0xf0d1c67b9791a453,
+        0xbaecc89a6ce5f9f6, 0xf952f9b11716735b, 0x229c4b4f79641889, 0x3054a778b0f989ff,
+        0xed70beb6c05245fc, 0x72bf13bf7e2e3540, 0x7cd519031a1e524d, 0x2fed4a779682a4af,
+        0xb372d483d26668f4, 0x3715148c4fc791ba, 0x373072fdfa914dad, 0x4b9226b9981101b3,
+        0x40ec1a865521f563, 0xee68d8183c9c5062, 0x6c12912aac211ca8, 0x73dbd05ff97f2f85,
+        0x3f1dac977ac86ef4, 0x7623a1c3c20b35e6, 0xdad77dee920e7c52, 0x68a599cf0fe4e9c1,
+        0xf76d7f794693e920, 0x4ae2f6808afe527e, 0x72baa41ad4c199e3, 0x228aa3995b890ac4,
+        0x94fbda2a5b32d2ff, 0x3996e1fbc75b91c1, 0x2a3de70822d39dd6, 0x7c3821b1a196636b,
+        0x0abfd18b6bc1d6b2, 0x057e66195d81ef37, 0x8ba0529ef2ae9ce5, 0x7dbf9fb831c42037,
+        0x325ae8562a888d83, 0xca72175aa1829fe7, 0xc67bbc146bd9fb8a, 0x73200a7ade6aea7d,
+        0x94c087b2ebb445ee, 0xd15af731e175b305, 0x1217e89f793ac89b, 0x13191786d3a5fd0e,
+        0x25a2cf728035fc9e, 0xf2fb41db9f0d61d5, 0x59f02fe87df43206, 0x64d97bda7b1196b1,
+        0x19b3a9d3f0641553, 0xc9381f73db53000e, 0x91d6ee0d03b4f85c, 0xe0e4162055b1ce9d,
+        0xdedaf7853953e567, 0x387a30879208c47e, 0xfcbad9e084903b13, 0xcae0797b0ad86359,
+        0x53697ca115f8d39e, 0x68d90dec504a6e9a, 0xd6c0207b64a14443, 0xd796f5b5177cd5d4,
+        0x25cfb9f8ca25ddc4, 0x8995d56099c9da00, 0x30af1765d49000f1, 0xa3133488da6970eb,
+        0xe6035a7fb25846bf, 0x8ebed43321c2de43, 0xa043030e2434b1b6, 0xcebce776fe48193b,
+        0xd9bba2e1b2477d39, 0xfb0a9ec5080d7193, 0xfa5d53748336cde7, 0x08e671208328c7ac,
+        0x9d67f2db052ccd88, 0xbb5a29aeddb5f37f, 0x16efc2e7b7947a62, 0xc431c9d1449ac01c,
+        0x09c0bc5720fadebc, 0x1c3facb2d610d904, 0x1e271b854e170652, 0x66955f3190a88914,
+        0x51098b502a2a1ac4, 0x82ec7dba1e0fc294, 0x366f9cf9cc6f5f5d, 0xef5df15a2e60cda7,
+        0xd0d9544f203ed32f, 0x4c60df09a3f1b5b2, 0x9bb41c4fc8c90a5a, 0xe24f54c0bf7e0a18,
+        0xc34d5c1f26d77a60, 0x0719e1707efe8e68, 0x62f3931a03396a0c, 0x1df1cc7db528dc9f,
+        0xa17f44d359028e48, 0xe3f6b9a7608069c4, 0x5d4f4261b20c8419, 0xd8bebda2f3bd10c4,
+        0xbd171f2252833f05, 0xdde71013de5da70c, 0x645f3f280411b346, 0x23b0b458f890646b,
+        0x804f5363aec7dec3, 0x488ca943428a5584, 0xd1a51496f410e8bf, 0xeb714005fbf778f8,
+        0xc7a6f80a272495a8, 0x707e3f02b32e83af, 0x93e5dd8df2951e62, 0x02ae189a1eb3a2fe,
+        0x4678115303a16cb0, 0xa48ad2ffa2ec4ec6, 0xc781759769e54de9, 0xfb4e5f8a03669296,
+        0xd3e2eaabe9206b8b, 0x071d375e06ee4717, 0x134b906a979d7360, 0x387324644dc6e2c0,
+        0x42299e455b8383bc, 0xd4150f9722d4544f, 0x0bd45eef6a429323, 0xda0aa5a30f3f55b2,
+        0x30ffe7ed94b2628c, 0xb43f3b217a83283a, 0x6fb3aa5fe46590b9, 0x7de45bbb3a7b90a0,
+        0x9a3d9f04166b2a71, 0xb700c67cf12d5f33, 0xc49c024430170251, 0xdcfa030b3e410f0f,
+        0x36540b467d841d3c, 0xdcd0748079ff417f, 0x1529549fae86f950, 0xb05cd5b2bf463eee,
+        0x4eda074ad868792c, 0xa7d933ff9184a5ad, 0x9471deb043f394cf, 0xf424ae3963e1f864,
+        0x23ec9162f3493d9d, 0xb8e68c94b831960e, 0xe2811b3eb6921a73, 0x1cc843cf99420440,
+        0x1d8ec675854732de, 0x68f8723dda845d7a, 0x530c40bae1d92f89, 0xa2edd2afa11e9561,
+        0x88c4003c7b4519c9, 0xdd2ed233401228a9, 0x34249821f97bd557, 0x80557f3ff6934ad8,
+        0x92111ad3b125aa72, 0xf33bcd38e1cc41c0, 0x802de5af301f61b3, 0x6230f7f5897c17a0,
+        0x0d82d99b3d25dd17, 0xf954cc59460c11f7, 0xce275d4f2b8498ce, 0xfd1c3b261d0bfa2c,
+        0xcdcf897dc5165e78, 0xcbc7073de70095ce, 0x3690398bd3ac36ff, 0x1dc34317b9fcdf9f,
+        0xcfcf5aafb012ceac, 0x24a5d3737ab55322, 0x73cce8f0e4045ae2, 0x1eaf33081d0337ad,
+        0x3d403412c03be6a3, 0x6ea06878d9bec062, 0x740cc6d14dec2f83, 0xdacb0a9c8fb360d3,
+        0x18c35ed355788f40, 0x4aa3732e6573c579, 0xe2292096f7478375, 0xfeb2e83ba274203e,
+        0x295413828e82385c, 0x3317155263d5c0ff, 0xab80355ad92b43a3, 0xc364053697defe56,
+        0x8f60cf2cd65743d8, 0xe2124ece086a9503, 0x8f440c99bdd85f26, 0xd8e4a9f464339e76,
+        0x58e27dbf68a9efa8, 0x8a450dae8356581e, 0xbf61c120b8f0ad22, 0x686c7cf8c1bafcce,
+        0x43649cc323afd74e, 0xc56c8e95b00afde5, 0x2ce2aa39aff5c2cc, 0x62d86e6493ce8d74,
+        0xb664dfbbb6220487, 0x44de1abf8047442b, 0x4116ec825737f3d1, 0x6dba0fc246d0ae95,
+        0xfc8f7c3294635ee9, 0x5f948099e0397983, 0x3a15f29a14555bd8, 0x147c8bfb43d34ab2,
+        0xdc8f3bc391be4325, 0x73d649c02800a284, 0x485e6fee18dfb49b, 0x4037092e205fafc8,
+        0x927a5ec648576a3e, 0xc75be814c2028aab, 0xf4c3aa2a7d27f1c3, 0x1dba1c9ec597c9f3,
+        0xb6b2c59f2cd9762d, 0xe16b568fe0c57ecd, 0x2fa12fef7f7bf8d4, 0x0d5886667a3274e1,
+        0xd9e6fabba85326b1, 0x69e3096546ad62af, 0x26eb40d1eae9db57, 0x78615f1d02f82200,
+        0x0fbd4f825b9a9d5f, 0xe23d15d8bf2c0c09, 0x018692a8c12d8db5, 0x5905cb4d1a35122a,
+        0xe199a47746b0a943, 0x25d64e1f97828a11, 0x39aee24fc41c027d, 0xffa7684061b63cb3,
+        0x2b8a5077ca601d68, 0xc78fb867069c0c18, 0x96279df8bdd6e58f, 0x36b37d6e482c02e9,
+        0x4bc4ed7110c49a4f, 0x2bd91054c55fd6b0, 0x3172d62e590aeece, 0x12824abf1973fd42,
+        0xbc5e9618ea5ff4b8, 0xb715e65a7bf457e5, 0xe737178876cf4132, 0xac9a7159960e1846,
+        0xdfadc2b376142a8e, 0xe7cc3884b3315c37, 0x877ee0fd2d055716, 0xecb0cbd2c076ac07,
+        0x2146c458435efd54, 0x49a920f47d1c2eec, 0x7afd993aae3d4726, 0x9dce916c467de52f,
+        0x4aa3d6d6a61277e5, 0xf8bf1471776f204b, 0x88106c1d1769562f, 0xfcb1e3f2d216120b,
+        0x738350097a5d1311, 0x0ffcc89fa44fdd1b, 0xa97550deeec22ba7, 0x164b1ef83cbf110e,
+        0x6a59c789d4fd5618, 0x93d346612319aae9, 0xdd03231a495930d2, 0xfeefca85ef93a482,
+        0xdb2b64baafc27147, 0x696cacf079b1adf0, 0x2753b26cd713c7ad, 0x9cadd8c730fefd8f,
+        0x37252f85ebb00b7a, 0xf54cc6cdd814eb22, 0x301148c77a07380b, 0x9d28b51a0f701c0b,
+        0xb4fb7e4794672d55, 0x2c9197387a1782cd, 0x7c0dc7f6c89a35a0, 0x44c16c5868746f18,
+        0x5520ff8b5288217f, 0x277b37b5fb3524fe, 0xd492e46f8b50a892, 0x2ea0c0cf07302650,
+        0x6de4b573f66224a7, 0x26c44bb9958aaa43, 0x8bae8f39a46e17cb, 0xad3f33ce70af7abe,
+        0x07d055e883cb3ff0, 0x7d78bc9a7b468407, 0xa55667bbfb554152, 0x17aa340cd11bdc4f,
+    ],
+};
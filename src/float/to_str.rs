@@ -1,5 +1,461 @@
+use super::bignum::Big;
+use crate::words::buint_to_i64;
 use super::Float;
+use super::storage::{Storage, Width};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Alignment, Debug, Display, Formatter, LowerExp, UpperExp, Write};
 
-impl<const W: usize, const MB: usize> Float<W, MB> {}
+// The digit generation algorithms are the same as the Dragon4 algorithms used by the standard library to format the primitive float types (see "How to Print Floating-Point Numbers Accurately" by Steele and White, and "Printing Floating-Point Numbers Quickly and Accurately" by Burger and Dybvig), so the output is identical to that of the primitive types for the same value. Digits are returned as ASCII bytes `d_1 ... d_n`, along with an exponent `k`, representing the number `0.d_1...d_n * 10^k`.
 
-// TODO: include copyright notice as described in dragon4 paper
+/// Returns an estimate of `floor(log10(2^n))`, which is never more than one greater or less than the true value.
+#[inline]
+fn estimate_log10(n: i64) -> i64 {
+    // 1292913986 / 2^32 is approximately log10(2)
+    ((n as i128 * 1292913986) >> 32) as i64
+}
+
+/// A finite, non-zero float with value `mant * 2^exp`.
+struct Decoded {
+    mant: Big,
+    exp: i64,
+    /// Whether the float's predecessor is closer to it than its successor, which is the case when its significand is a power of two.
+    lower_closer: bool,
+    /// Whether the float's rounding interval includes its endpoints.
+    inclusive: bool,
+}
+
+enum Limit {
+    /// Generate digits down to the given number of places after the decimal point.
+    Fixed(usize),
+    /// Generate the given number of significant digits.
+    Significant(usize),
+}
+
+/// Scales either `mant` or `scale` by `10^k` so that `mant / scale` is divided by `10^k`.
+fn scale_pow10(mant: &mut Big, scale: &mut Big, k: i64) {
+    if k >= 0 {
+        scale.mul_pow(10, k as u64);
+    } else {
+        mant.mul_pow(10, k.unsigned_abs());
+    }
+}
+
+/// Increments the decimal number stored in `digits`, returning `true` if it consisted of only nines and so was rounded up to a power of ten (which is stored as `10...0`, with the same number of digits).
+fn round_up(digits: &mut [u8]) -> bool {
+    match digits.iter().rposition(|&d| d != b'9') {
+        Some(i) => {
+            digits[i] += 1;
+            digits[i + 1..].fill(b'0');
+            false
+        }
+        None => {
+            digits[0] = b'1';
+            digits[1..].fill(b'0');
+            true
+        }
+    }
+}
+
+/// Returns the shortest string of digits which rounds to the decoded float.
+fn shortest(d: &Decoded) -> (Vec<u8>, i64) {
+    let (mut mant, mut minus, mut plus, exp) = if d.lower_closer {
+        let mut mant = d.mant.clone();
+        mant.shl(2);
+        (mant, Big::from_u64(1), Big::from_u64(2), d.exp - 2)
+    } else {
+        let mut mant = d.mant.clone();
+        mant.shl(1);
+        (mant, Big::from_u64(1), Big::from_u64(1), d.exp - 1)
+    };
+    let mut scale = Big::from_u64(1);
+    if exp < 0 {
+        scale.shl(exp.unsigned_abs());
+    } else {
+        mant.shl(exp as u64);
+        minus.shl(exp as u64);
+        plus.shl(exp as u64);
+    }
+    // whether `high` is greater than (or equal to, if the interval is inclusive) `scale`
+    let above = |scale: &Big, high: &Big| match scale.cmp(high) {
+        Ordering::Less => true,
+        Ordering::Equal => d.inclusive,
+        Ordering::Greater => false,
+    };
+    let high = |mant: &Big, plus: &Big| {
+        let mut high = mant.clone();
+        high.add(plus);
+        high
+    };
+
+    // find the smallest `k` such that the upper end of the rounding interval is below `10^k`
+    let mut k = estimate_log10(high(&mant, &plus).bits() as i64 + exp.min(0));
+    scale_pow10(&mut mant, &mut scale, k);
+    if k < 0 {
+        minus.mul_pow(10, k.unsigned_abs());
+        plus.mul_pow(10, k.unsigned_abs());
+    }
+    while above(&scale, &high(&mant, &plus)) {
+        scale.mul_small(10);
+        k += 1;
+    }
+    loop {
+        let mut high = high(&mant, &plus);
+        high.mul_small(10);
+        if above(&scale, &high) {
+            break;
+        }
+        mant.mul_small(10);
+        minus.mul_small(10);
+        plus.mul_small(10);
+        k -= 1;
+    }
+
+    let mut digits = Vec::new();
+    let (down, up) = loop {
+        mant.mul_small(10);
+        minus.mul_small(10);
+        plus.mul_small(10);
+        digits.push(b'0' + mant.div_rem_small(&scale) as u8);
+        let down = match mant.cmp(&minus) {
+            Ordering::Less => true,
+            Ordering::Equal => d.inclusive,
+            Ordering::Greater => false,
+        };
+        let up = above(&scale, &high(&mant, &plus));
+        if down || up {
+            break (down, up);
+        }
+    };
+    // if both the rounded down and rounded up digits are in the interval, choose the closer one, with ties rounding up
+    if up && (!down || *mant.mul_small(2) >= scale) && round_up(&mut digits) {
+        digits.push(b'0');
+        k += 1;
+    }
+    (digits, k)
+}
+
+/// Returns the digits of the decoded float, correctly rounded with ties rounding to even, with the number of digits given by `limit`.
+fn exact(d: &Decoded, limit: Limit) -> (Vec<u8>, i64) {
+    let mut mant = d.mant.clone();
+    let mut scale = Big::from_u64(1);
+    if d.exp < 0 {
+        scale.shl(d.exp.unsigned_abs());
+    } else {
+        mant.shl(d.exp as u64);
+    }
+    // find `k` such that `10^(k - 1) <= mant / scale < 10^k`
+    let mut k = estimate_log10(d.mant.bits() as i64 + d.exp);
+    scale_pow10(&mut mant, &mut scale, k);
+    while mant >= scale {
+        scale.mul_small(10);
+        k += 1;
+    }
+    loop {
+        let mut m = mant.clone();
+        m.mul_small(10);
+        if m >= scale {
+            break;
+        }
+        mant = m;
+        k -= 1;
+    }
+
+    let fixed = matches!(limit, Limit::Fixed(_));
+    let len = match limit {
+        Limit::Fixed(frac) => k + frac as i64,
+        Limit::Significant(n) => n as i64,
+    };
+    if len < 0 {
+        return (Vec::new(), k);
+    }
+    let mut digits = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        mant.mul_small(10);
+        digits.push(b'0' + mant.div_rem_small(&scale) as u8);
+    }
+    let round = match (*mant.mul_small(2)).cmp(&scale) {
+        Ordering::Greater => true,
+        Ordering::Equal => digits.last().map_or(false, |d| d & 1 == 1),
+        Ordering::Less => false,
+    };
+    if round {
+        if digits.is_empty() {
+            digits.push(b'1');
+            k += 1;
+        } else if round_up(&mut digits) {
+            k += 1;
+            if fixed {
+                digits.push(b'0');
+            }
+        }
+    }
+    (digits, k)
+}
+
+fn push_zeros(buf: &mut String, count: i64) {
+    for _ in 0..count {
+        buf.push('0');
+    }
+}
+
+fn push_digits(buf: &mut String, digits: &[u8]) {
+    buf.extend(digits.iter().map(|&d| d as char));
+}
+
+/// Writes `0.digits * 10^k` in positional notation, with at least `min_frac` digits after the decimal point.
+fn write_fixed(buf: &mut String, digits: &[u8], k: i64, min_frac: usize) {
+    let n = digits.len() as i64;
+    let min_frac = min_frac as i64;
+    if digits.is_empty() {
+        buf.push('0');
+        if min_frac > 0 {
+            buf.push('.');
+            push_zeros(buf, min_frac);
+        }
+    } else if k <= 0 {
+        buf.push_str("0.");
+        push_zeros(buf, -k);
+        push_digits(buf, digits);
+        push_zeros(buf, min_frac - (n - k));
+    } else if k < n {
+        push_digits(buf, &digits[..k as usize]);
+        buf.push('.');
+        push_digits(buf, &digits[k as usize..]);
+        push_zeros(buf, min_frac - (n - k));
+    } else {
+        push_digits(buf, digits);
+        push_zeros(buf, k - n);
+        if min_frac > 0 {
+            buf.push('.');
+            push_zeros(buf, min_frac);
+        }
+    }
+}
+
+/// Writes `0.digits * 10^k` in scientific notation, with at least `min_digits` significant digits.
+fn write_exp(buf: &mut String, digits: &[u8], k: i64, min_digits: usize, upper: bool) {
+    let (first, rest, exp) = match digits.split_first() {
+        Some((first, rest)) => (*first, rest, k - 1),
+        None => (b'0', &[][..], 0),
+    };
+    buf.push(first as char);
+    if !rest.is_empty() || min_digits > 1 {
+        buf.push('.');
+        push_digits(buf, rest);
+        push_zeros(buf, min_digits as i64 - 1 - rest.len() as i64);
+    }
+    buf.push(if upper { 'E' } else { 'e' });
+    write!(buf, "{}", exp).unwrap();
+}
+
+/// Returns the sign to write for a number, depending on whether it is negative and the formatter's flags.
+fn sign(f: &Formatter, negative: bool) -> &'static str {
+    if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    }
+}
+
+/// Writes `sign` followed by `body` to the formatter, applying the formatter's padding in the same way as for the primitive float types.
+fn pad(f: &mut Formatter, sign: &str, body: &str) -> fmt::Result {
+    let padding = f
+        .width()
+        .map_or(0, |width| width.saturating_sub(sign.len() + body.len()));
+    if padding == 0 {
+        f.write_str(sign)?;
+        return f.write_str(body);
+    }
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        return f.write_str(body);
+    }
+    let (pre, post) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        _ => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(body)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Display,
+    Debug,
+    LowerExp,
+    UpperExp,
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Decodes a finite, non-zero float.
+    fn decode(self) -> Decoded {
+        let (_, exp, mant) = self.to_parts_biased();
+        let bias = buint_to_i64(Self::EXP_BIAS.to_bits());
+        let lower_closer = self.is_normal() && mant.is_power_of_two();
+        Decoded {
+            inclusive: self.is_subnormal() || !mant.bit(0),
+            mant: Big::from_le_bytes(mant.digits()),
+            exp: buint_to_i64(exp) - bias - MB as i64,
+            lower_closer,
+        }
+    }
+
+    fn fmt_style(self, f: &mut Formatter, style: Style) -> fmt::Result {
+        let negative = self.is_sign_negative() && !self.is_nan();
+        if self.is_nan() {
+            // NaN is never written with a sign
+            return pad(f, "", "NaN");
+        }
+        if self.is_infinite() {
+            return pad(f, sign(f, negative), "inf");
+        }
+        let decoded = if self.is_zero() {
+            None
+        } else {
+            Some(self.decode())
+        };
+        let mut buf = String::new();
+        match (style, f.precision()) {
+            (Style::Display | Style::Debug, Some(precision)) => {
+                let (digits, k) = decoded.map_or((Vec::new(), 0), |d| {
+                    exact(&d, Limit::Fixed(precision))
+                });
+                write_fixed(&mut buf, &digits, k, precision);
+            }
+            (Style::LowerExp | Style::UpperExp, Some(precision)) => {
+                let (digits, k) = decoded.map_or((Vec::new(), 0), |d| {
+                    exact(&d, Limit::Significant(precision + 1))
+                });
+                write_exp(&mut buf, &digits, k, precision + 1, style == Style::UpperExp);
+            }
+            (_, None) => {
+                let (digits, k) = decoded.map_or((Vec::new(), 0), |d| shortest(&d));
+                match style {
+                    Style::Display => write_fixed(&mut buf, &digits, k, 0),
+                    // like the primitive floats, use scientific notation for values less than 1e-4 or at least 1e16
+                    Style::Debug if !digits.is_empty() && !(-4..16).contains(&(k - 1)) => {
+                        write_exp(&mut buf, &digits, k, 0, false)
+                    }
+                    Style::Debug => write_fixed(&mut buf, &digits, k, 1),
+                    _ => write_exp(&mut buf, &digits, k, 0, style == Style::UpperExp),
+                }
+            }
+        }
+        pad(f, sign(f, negative), &buf)
+    }
+}
+
+impl<const W: usize, const MB: usize> Display for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Formats the float in positional notation in the same way as the primitive float types: with the shortest string of digits which parses back to the same value, or if a precision is given, correctly rounded to that many decimal places with ties rounding to even.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_style(f, Style::Display)
+    }
+}
+
+impl<const W: usize, const MB: usize> Debug for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_style(f, Style::Debug)
+    }
+}
+
+impl<const W: usize, const MB: usize> LowerExp for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_style(f, Style::LowerExp)
+    }
+}
+
+impl<const W: usize, const MB: usize> UpperExp for Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_style(f, Style::UpperExp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::types::{ftest, FTEST};
+
+    macro_rules! test_format {
+        ($($name: ident: $fmt: literal), *) => {
+            quickcheck::quickcheck! {
+                $(
+                    fn $name(f: ftest) -> bool {
+                        format!($fmt, FTEST::from(f)) == format!($fmt, f)
+                    }
+                )*
+            }
+        };
+    }
+
+    test_format!(
+        quickcheck_display: "{}",
+        quickcheck_debug: "{:?}",
+        quickcheck_lower_exp: "{:e}",
+        quickcheck_upper_exp: "{:E}",
+        quickcheck_display_precision: "{:.3}",
+        quickcheck_display_zero_precision: "{:.0}",
+        quickcheck_lower_exp_precision: "{:.5e}",
+        quickcheck_debug_precision: "{:.2?}",
+        quickcheck_padding: "{:>+12.1}",
+        quickcheck_zero_padding: "{:015e}"
+    );
+
+    quickcheck::quickcheck! {
+        fn quickcheck_display_any_precision(f: ftest, precision: u8) -> bool {
+            format!("{:.*}", precision as usize, FTEST::from(f)) == format!("{:.*}", precision as usize, f)
+        }
+
+        fn quickcheck_exp_any_precision(f: ftest, precision: u8) -> bool {
+            format!("{:.*e}", precision as usize, FTEST::from(f)) == format!("{:.*e}", precision as usize, f)
+        }
+    }
+
+    #[test]
+    fn format_cases() {
+        for f in [
+            0.0, -0.0, 1.0, 0.5, 1.5, 2.5, -1e-7, 1e16, 1e15, 1e-4, 1.5e-5, ftest::MAX, ftest::MIN,
+            ftest::MIN_POSITIVE, ftest::from_bits(1), ftest::EPSILON, ftest::NAN, ftest::INFINITY,
+            ftest::NEG_INFINITY, 123456789.0, 0.3,
+        ] {
+            let big = FTEST::from(f);
+            assert_eq!(format!("{}", big), format!("{}", f));
+            assert_eq!(format!("{:?}", big), format!("{:?}", f));
+            assert_eq!(format!("{:e}", big), format!("{:e}", f));
+            assert_eq!(format!("{:.0}", big), format!("{:.0}", f));
+            assert_eq!(format!("{:.1e}", big), format!("{:.1e}", f));
+            assert_eq!(format!("{:^+9.1}", big), format!("{:^+9.1}", f));
+            assert_eq!(format!("{:*<9}", big), format!("{:*<9}", f));
+            assert_eq!(format!("{:09}", big), format!("{:09}", f));
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use super::extended::Extended;
+use super::tables;
+use super::Float;
+use super::storage::{Storage, Width};
+
+impl<const W: usize, const MB: usize> Float<W, MB>
+where
+    Width<W>: Storage,
+{
+    /// Asserts that the stored bits of `2/π` are enough to reduce every finite value of this type modulo `π/2` (see `Extended::rem_pio2`). This holds for formats with at most 19 exponent bits, like IEEE 754 binary256, which are at most 2048 bits wide.
+    pub(crate) const TWO_OVER_PI_FITS: () = {
+        let exp_bits = W * 8 - MB - 1;
+        assert!(
+            exp_bits < usize::BITS as usize
+                && (1 << (exp_bits - 1)) + 128 * ((W + 3) / 4) <= tables::TWO_OVER_PI.bit_len() + 3,
+            "the exponent range of this type is too large for the stored bits of 2/π"
+        );
+    };
+
+    /// Computes the sine of `self` (in radians).
+    ///
+    /// The argument is reduced modulo `π/2` using the Payne-Hanek method, so the result is accurate even for very large arguments. This fails to compile for types with more than 19 exponent bits or a width of more than 2048 bits, as not enough bits of `2/π` are stored to reduce all of their values.
+    pub fn sin(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        self.sin_cos().0
+    }
+
+    /// Computes the cosine of `self` (in radians).
+    ///
+    /// The argument is reduced modulo `π/2` using the Payne-Hanek method, so the result is accurate even for very large arguments. This fails to compile for types with more than 19 exponent bits or a width of more than 2048 bits, as not enough bits of `2/π` are stored to reduce all of their values.
+    pub fn cos(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        self.sin_cos().1
+    }
+
+    /// Computes the tangent of `self` (in radians).
+    ///
+    /// This fails to compile for types with more than 19 exponent bits or a width of more than 2048 bits, for the same reason as [`sin`](Self::sin).
+    pub fn tan(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::TWO_OVER_PI_FITS;
+        handle_nan!(self; self);
+        if !self.is_finite() {
+            return Self::NAN;
+        }
+        if self.is_zero() {
+            return self;
+        }
+        let (sin, cos) = Extended::<{ (W + 3) / 4 }>::from_float(self).sin_cos();
+        sin.div(cos).to_float()
+    }
+
+    /// Simultaneously computes the sine and cosine of `self` (in radians). Returns `(self.sin(), self.cos())`.
+    ///
+    /// This fails to compile for types with more than 19 exponent bits or a width of more than 2048 bits, for the same reason as [`sin`](Self::sin).
+    pub fn sin_cos(self) -> (Self, Self)
+    where
+        [(); (W + 3) / 4]:,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::TWO_OVER_PI_FITS;
+        handle_nan!((self, self); self);
+        if !self.is_finite() {
+            return (Self::NAN, Self::NAN);
+        }
+        if self.is_zero() {
+            return (self, Self::ONE);
+        }
+        let (sin, cos) = Extended::<{ (W + 3) / 4 }>::from_float(self).sin_cos();
+        (sin.to_float(), cos.to_float())
+    }
+
+    /// Computes the arcsine of `self`. The return value is in radians and in the range `[-π/2, π/2]`, or NaN if `self` is outside the range `[-1, 1]`.
+    pub fn asin(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.abs() > Self::ONE {
+            return Self::NAN;
+        }
+        if self.is_zero() {
+            return self;
+        }
+        let x = Extended::<{ (W + 3) / 4 }>::from_float(self);
+        // asin(x) = atan2(x, sqrt(1 - x^2)), where 1 - x^2 is computed as (1 - x)(1 + x) to avoid cancellation
+        let c = Extended::ONE.sub(x).mul(Extended::ONE.add(x)).sqrt();
+        x.atan2(c).to_float()
+    }
+
+    /// Computes the arccosine of `self`. The return value is in radians and in the range `[0, π]`, or NaN if `self` is outside the range `[-1, 1]`.
+    pub fn acos(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.abs() > Self::ONE {
+            return Self::NAN;
+        }
+        let x = Extended::<{ (W + 3) / 4 }>::from_float(self);
+        let s = Extended::ONE.sub(x).mul(Extended::ONE.add(x)).sqrt();
+        s.atan2(x).to_float()
+    }
+
+    /// Computes the arctangent of `self`. The return value is in radians and in the range `[-π/2, π/2]`.
+    pub fn atan(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if self.is_infinite() {
+            return Extended::<{ (W + 3) / 4 }>::FRAC_PI_2
+                .with_sign(self.is_sign_negative())
+                .to_float();
+        }
+        if self.is_zero() {
+            return self;
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self).atan().to_float()
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
+    ///
+    /// - `x = 0`, `y = 0`: `0`
+    /// - `x >= 0`: `arctan(y/x)` -> `[-π/2, π/2]`
+    /// - `y >= 0`: `arctan(y/x) + π` -> `(π/2, π]`
+    /// - `y < 0`: `arctan(y/x) - π` -> `(-π, -π/2)`
+    ///
+    /// Signed zeros and infinities are handled in the same way as the primitive float types.
+    pub fn atan2(self, other: Self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(Self::NAN; self, other);
+        let negative = self.is_sign_negative();
+        let angle = if self.is_zero() {
+            if other.is_sign_negative() {
+                Extended::<{ (W + 3) / 4 }>::PI
+            } else {
+                return self;
+            }
+        } else if self.is_infinite() {
+            if other.is_infinite() {
+                let quarter = Extended::<{ (W + 3) / 4 }>::FRAC_PI_4;
+                if other.is_sign_negative() {
+                    quarter.add(quarter.mul_pow2(1))
+                } else {
+                    quarter
+                }
+            } else {
+                Extended::FRAC_PI_2
+            }
+        } else if other.is_infinite() {
+            if other.is_sign_negative() {
+                Extended::PI
+            } else {
+                return Self::ZERO.copysign(self);
+            }
+        } else {
+            let y = Extended::<{ (W + 3) / 4 }>::from_float(self.abs());
+            let x = Extended::from_float(other);
+            y.atan2(x)
+        };
+        angle.with_sign(negative).to_float()
+    }
+
+    /// Converts radians to degrees.
+    pub fn to_degrees(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .mul(Extended::from_u64(180).div(Extended::PI))
+            .to_float()
+    }
+
+    /// Converts degrees to radians.
+    pub fn to_radians(self) -> Self
+    where
+        [(); (W + 3) / 4]:,
+    {
+        handle_nan!(self; self);
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        Extended::<{ (W + 3) / 4 }>::from_float(self)
+            .mul(Extended::PI)
+            .div(Extended::from_u64(180))
+            .to_float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::test_float_ulps;
+    use crate::test::types::{ftest, FTEST};
+
+    // the primitive float functions are not guaranteed to be correctly rounded, so allow an error of one ulp
+    test_float_ulps! {
+        function: <ftest>::sin(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-1e-3), (1e-30), (1e22), (-3.0e38), (core::f32::consts::PI as ftest)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::cos(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-1e-3), (1e-30), (1e22), (-3.0e38), (core::f32::consts::FRAC_PI_2 as ftest)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::tan(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-1e-3), (1e-30), (1e22), (-3.0e38), (core::f32::consts::FRAC_PI_2 as ftest)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::sin_cos(f: ftest),
+        ulps: 1
+    }
+
+    #[test]
+    fn binary256_large_arguments() {
+        use crate::float::Float;
+        use crate::BUintD8;
+
+        type F256 = Float<32, 236>;
+        let f = |hex: &str| F256::from_bits(BUintD8::parse_str_radix(hex, 16));
+
+        // `(x, sin(x), cos(x), tan(x))`, correctly rounded with mpmath
+        let cases = [
+            (
+                F256::MAX,
+                "3fffe8c2227bc0c62c0225d6ac3a9e51421945912aa79fbc3d8d8147dd67470e",
+                "3fffe4461409ea97cec20e9fb0504305289feb03f5720767a45fc5e48fe1dbd4",
+                "3ffff38a0b4179b10d403eb5f2a72c599f723e7d6fbd9df2397bcf1203db6387",
+            ),
+            (
+                f("586a080000000000000000000000000000000000000000000000000000000000"), // 3 * 2^100000
+                "bfffee1c098bbfd81276732a2599fa08167190d2133b6b047e3c6dcdd17defc6",
+                "bfffd5abfb4ed1ed81d36bad810ba79cfb336fa6afe60886ceb8b3eec7fe49a3",
+                "4000063abd6af05349487b8d66baf59d25b48c2ed516f9021bdb052c5d4067ca",
+            ),
+            (
+                f("4006293e5939a08ce9dbd4800000000000000000000000000000000000000000"), // 10^30
+                "bfffb711e6ba878b87f21c4544de200ef5dbf375206c7a039ba21c2b2b9ff5cb",
+                "bfffefdeab18a132523118d4772ad84449779c6487f35755555f5c1492815c3f",
+                "3fffb72a07881bb41a1edcb83763b835b09cbce1458e0060312c9109cf353d87",
+            ),
+        ];
+        for (x, sin, cos, tan) in cases {
+            assert_eq!(x.sin_cos(), (f(sin), f(cos)));
+            assert_eq!(x.tan(), f(tan));
+            assert_eq!((-x).sin(), -f(sin));
+        }
+    }
+
+    test_float_ulps! {
+        function: <ftest>::asin(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-0.99999), (1e-30), (1.0), (-1.0), (0.0), (-0.0)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::acos(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-0.99999), (1e-30), (1.0), (-1.0), (0.0), (-0.0)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::atan(f: ftest),
+        ulps: 1,
+        cases: [(0.5), (-0.99999), (1e-30), (1.0), (-1.0), (1e30)]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::atan2(f1: ftest, f2: ftest),
+        ulps: 1,
+        cases: [
+            (0.0, -0.0), (-0.0, -0.0), (0.0, 0.0), (-0.0, 1.0), (1.0, 0.0),
+            (ftest::INFINITY, ftest::NEG_INFINITY), (ftest::NEG_INFINITY, ftest::INFINITY),
+            (1.0, ftest::NEG_INFINITY), (-1.0, ftest::INFINITY),
+            (1e-30, -1e30), (0.5, -0.25)
+        ]
+    }
+
+    test_float_ulps! {
+        function: <ftest>::to_degrees(f: ftest),
+        ulps: 1
+    }
+
+    test_float_ulps! {
+        function: <ftest>::to_radians(f: ftest),
+        ulps: 1
+    }
+}
@@ -0,0 +1,219 @@
+use super::{BigInt, BigUint};
+use crate::errors::TryFromIntError;
+use crate::BTryFrom;
+use alloc::vec::Vec;
+
+macro_rules! from_uint {
+    ($($uint: ty),*) => {
+        $(
+            impl From<$uint> for BigUint {
+                #[inline]
+                fn from(int: $uint) -> Self {
+                    let int = int as u128;
+                    Self::from_limbs(vec![int as u64, (int >> 64) as u64])
+                }
+            }
+
+            impl From<$uint> for BigInt {
+                #[inline]
+                fn from(int: $uint) -> Self {
+                    Self::from_sign_magnitude(false, BigUint::from(int))
+                }
+            }
+        )*
+    };
+}
+
+from_uint!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! from_int {
+    ($($int: ty),*) => {
+        $(
+            impl From<$int> for BigInt {
+                #[inline]
+                fn from(int: $int) -> Self {
+                    Self::from_sign_magnitude(int < 0, BigUint::from(int.unsigned_abs()))
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize);
+
+impl From<BigUint> for BigInt {
+    #[inline]
+    fn from(magnitude: BigUint) -> Self {
+        Self::from_sign_magnitude(false, magnitude)
+    }
+}
+
+impl BTryFrom<BigInt> for BigUint {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(int: BigInt) -> Result<Self, Self::Error> {
+        if int.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(int.magnitude)
+        }
+    }
+}
+
+macro_rules! convert {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> From<$BUint<N>> for BigUint {
+            #[inline]
+            fn from(uint: $BUint<N>) -> Self {
+                const DIGIT_BITS: usize = $Digit::BITS as usize;
+                let mut limbs = vec![0u64; (N * DIGIT_BITS + 63) / 64];
+                for (i, &digit) in uint.digits().iter().enumerate() {
+                    let bit = i * DIGIT_BITS;
+                    limbs[bit / 64] |= (digit as u64) << (bit % 64);
+                }
+                Self::from_limbs(limbs)
+            }
+        }
+
+        impl<const N: usize> From<$BUint<N>> for BigInt {
+            #[inline]
+            fn from(uint: $BUint<N>) -> Self {
+                Self::from_sign_magnitude(false, BigUint::from(uint))
+            }
+        }
+
+        impl<const N: usize> From<$BInt<N>> for BigInt {
+            #[inline]
+            fn from(int: $BInt<N>) -> Self {
+                Self::from_sign_magnitude(int.is_negative(), BigUint::from(int.unsigned_abs()))
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigUint> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: BigUint) -> Result<Self, Self::Error> {
+                const DIGIT_BITS: usize = $Digit::BITS as usize;
+                if uint.bits() > Self::BITS {
+                    return Err(TryFromIntError(()));
+                }
+                let mut out = Self::ZERO;
+                for (i, digit) in out.digits_mut().iter_mut().enumerate() {
+                    let bit = i * DIGIT_BITS;
+                    let limb = uint.limbs.get(bit / 64).copied().unwrap_or(0);
+                    *digit = (limb >> (bit % 64)) as $Digit;
+                }
+                Ok(out)
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigInt> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: BigInt) -> Result<Self, Self::Error> {
+                <Self as BTryFrom<BigUint>>::try_from(<BigUint as BTryFrom<BigInt>>::try_from(int)?)
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigInt> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: BigInt) -> Result<Self, Self::Error> {
+                let negative = int.is_negative();
+                let magnitude = <$BUint<N> as BTryFrom<BigUint>>::try_from(int.magnitude)?;
+                let out = magnitude.cast_signed();
+                if negative {
+                    // `-magnitude` is in range if and only if `magnitude` is at most `2^(BITS - 1)`, which is the only magnitude for which `magnitude.wrapping_neg()` is negative or zero
+                    let neg = out.wrapping_neg();
+                    if neg.is_positive() {
+                        return Err(TryFromIntError(()));
+                    }
+                    Ok(neg)
+                } else if out.is_negative() {
+                    Err(TryFromIntError(()))
+                } else {
+                    Ok(out)
+                }
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigUint> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: BigUint) -> Result<Self, Self::Error> {
+                <Self as BTryFrom<BigInt>>::try_from(BigInt::from(uint))
+            }
+        }
+
+        impl<const N: usize> BTryFrom<$BInt<N>> for BigUint {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: $BInt<N>) -> Result<Self, Self::Error> {
+                <Self as BTryFrom<BigInt>>::try_from(BigInt::from(int))
+            }
+        }
+    };
+}
+
+crate::macro_impl!(convert);
+
+impl FromIterator<u64> for BigUint {
+    /// Creates an integer from an iterator over its little-endian limbs.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        Self::from_limbs(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigInt, BigUint};
+    use crate::{BInt, BIntD8, BTryFrom, BUint, BUintD16, BUintD8};
+
+    quickcheck::quickcheck! {
+        fn quickcheck_buint_round_trip(a: BUintD8<20>) -> bool {
+            let big = BigUint::from(a);
+            let wide: BUint<3> = BTryFrom::try_from(big.clone()).unwrap();
+            let narrow: Result<BUintD16<2>, _> = BTryFrom::try_from(big.clone());
+            <BUintD8<20> as BTryFrom<BigUint>>::try_from(big.clone()) == Ok(a)
+                && BigUint::from(wide) == big
+                && narrow.is_ok() == (a.bits() <= 32)
+        }
+
+        fn quickcheck_bint_round_trip(a: BIntD8<20>) -> bool {
+            let big = BigInt::from(a);
+            let narrow: Result<BInt<1>, _> = BTryFrom::try_from(big.clone());
+            let unsigned: Result<BUint<3>, _> = BTryFrom::try_from(big.clone());
+            <BIntD8<20> as BTryFrom<BigInt>>::try_from(big.clone()) == Ok(a)
+                && narrow.is_ok() == (a >= BIntD8::from(i64::MIN) && a <= BIntD8::from(i64::MAX))
+                && unsigned.is_ok() == !a.is_negative()
+        }
+
+        fn quickcheck_from_primitive(a: i128) -> bool {
+            BigInt::from(a) == BigInt::from(BInt::<2>::from(a))
+                && BigUint::from(a as u128) == BigUint::from(BUint::<2>::from(a as u128))
+        }
+    }
+
+    #[test]
+    fn bint_limits() {
+        let min = BigInt::from(BInt::<2>::MIN);
+        assert_eq!(
+            <BInt<2> as BTryFrom<BigInt>>::try_from(min.clone()),
+            Ok(BInt::MIN)
+        );
+        assert!(<BInt<2> as BTryFrom<BigInt>>::try_from(min - BigInt::from(1)).is_err());
+        let max = BigInt::from(BInt::<2>::MAX);
+        assert!(<BInt<2> as BTryFrom<BigInt>>::try_from(max + BigInt::from(1)).is_err());
+        assert_eq!(
+            <BInt<2> as BTryFrom<BigInt>>::try_from(BigInt::ZERO),
+            Ok(BInt::ZERO)
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use super::{limbs, BigInt, BigUint};
+use crate::errors::ParseIntError;
+use crate::errors::err_msg;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use core::num::IntErrorKind;
+use core::str::FromStr;
+
+#[inline]
+fn assert_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        err_msg!("Radix must be in range [2, 36]")
+    );
+}
+
+/// Returns the largest power of `radix` which fits in a limb, and its exponent.
+const fn radix_base(radix: u32) -> (u64, usize) {
+    let radix = radix as u64;
+    let mut base = radix;
+    let mut power = 1;
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        power += 1;
+    }
+    (base, power)
+}
+
+impl BigUint {
+    /// Returns the integer as a string in the given base, using lowercase letters for digits greater than 9.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 inclusive.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert_radix(radix);
+        if self.is_zero() {
+            return String::from("0");
+        }
+        let (base, power) = radix_base(radix);
+        let radix = radix as u64;
+        let mut out = Vec::with_capacity(self.bits() as usize);
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let (q, mut r) = limbs::div_rem_digit(&rest, base);
+            if q.is_empty() {
+                while r != 0 {
+                    out.push((r % radix) as u8);
+                    r /= radix;
+                }
+            } else {
+                for _ in 0..power {
+                    out.push((r % radix) as u8);
+                    r /= radix;
+                }
+            }
+            rest = q;
+        }
+        out.reverse();
+        for byte in out.iter_mut() {
+            if *byte < 10 {
+                *byte += b'0';
+            } else {
+                *byte += b'a' - 10;
+            }
+        }
+        unsafe { String::from_utf8_unchecked(out) }
+    }
+
+    /// Converts a string slice in a given base to an integer.
+    ///
+    /// The string is expected to be an optional `+` sign followed by digits, which are `0-9`, `a-z` or `A-Z` depending on `radix`. Leading and trailing whitespace represent an error.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 inclusive.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        assert_radix(radix);
+        let buf = src.as_bytes();
+        match buf.first() {
+            None => Err(ParseIntError {
+                kind: IntErrorKind::Empty,
+            }),
+            Some(b'+') => Self::from_digits_radix(&buf[1..], radix),
+            Some(_) => Self::from_digits_radix(buf, radix),
+        }
+    }
+
+    /// Parses a non-empty, unsigned string of digits.
+    fn from_digits_radix(buf: &[u8], radix: u32) -> Result<Self, ParseIntError> {
+        if buf.is_empty() {
+            return Err(ParseIntError {
+                kind: IntErrorKind::InvalidDigit,
+            });
+        }
+        let (_, power) = radix_base(radix);
+        let mut out = Vec::new();
+        for chunk in buf.chunks(power) {
+            let mut value = 0;
+            let mut base = 1;
+            for &byte in chunk {
+                let d = match byte {
+                    b'0'..=b'9' => byte - b'0',
+                    b'a'..=b'z' => byte - b'a' + 10,
+                    b'A'..=b'Z' => byte - b'A' + 10,
+                    _ => u8::MAX,
+                } as u64;
+                if d >= radix as u64 {
+                    return Err(ParseIntError {
+                        kind: IntErrorKind::InvalidDigit,
+                    });
+                }
+                value = value * radix as u64 + d;
+                base *= radix as u64;
+            }
+            out = limbs::mul_add_digit(&out, base, value);
+        }
+        Ok(Self { limbs: out })
+    }
+}
+
+impl BigInt {
+    /// Returns the integer as a string in the given base, with a leading `-` if it is negative, using lowercase letters for digits greater than 9.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 inclusive.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let magnitude = self.magnitude.to_str_radix(radix);
+        if self.negative {
+            let mut out = String::from("-");
+            out.push_str(&magnitude);
+            out
+        } else {
+            magnitude
+        }
+    }
+
+    /// Converts a string slice in a given base to an integer.
+    ///
+    /// The string is expected to be an optional `+` or `-` sign followed by digits, which are `0-9`, `a-z` or `A-Z` depending on `radix`. Leading and trailing whitespace represent an error.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 inclusive.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        assert_radix(radix);
+        let buf = src.as_bytes();
+        let (negative, digits) = match buf.first() {
+            None => {
+                return Err(ParseIntError {
+                    kind: IntErrorKind::Empty,
+                })
+            }
+            Some(b'-') => (true, &buf[1..]),
+            Some(b'+') => (false, &buf[1..]),
+            Some(_) => (false, buf),
+        };
+        let magnitude = BigUint::from_digits_radix(digits, radix)?;
+        Ok(Self::from_sign_magnitude(negative, magnitude))
+    }
+}
+
+macro_rules! fmt_trait {
+    ($Trait: ident, $radix: expr, $prefix: expr, $upper: expr) => {
+        impl $Trait for BigUint {
+            fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+                let mut s = self.to_str_radix($radix);
+                if $upper {
+                    s.make_ascii_uppercase();
+                }
+                f.pad_integral(true, $prefix, &s)
+            }
+        }
+
+        impl $Trait for BigInt {
+            fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+                let mut s = self.magnitude.to_str_radix($radix);
+                if $upper {
+                    s.make_ascii_uppercase();
+                }
+                f.pad_integral(!self.negative, $prefix, &s)
+            }
+        }
+    };
+}
+
+fmt_trait!(Display, 10, "", false);
+fmt_trait!(Binary, 2, "0b", false);
+fmt_trait!(Octal, 8, "0o", false);
+fmt_trait!(LowerHex, 16, "0x", false);
+fmt_trait!(UpperHex, 16, "0x", true);
+
+impl Debug for BigUint {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
+impl Debug for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseIntError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseIntError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigInt, BigUint};
+    use alloc::string::ToString;
+    use core::num::IntErrorKind;
+    use core::str::FromStr;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_fmt(a: i128) -> bool {
+            let (x, y) = (BigInt::from(a), BigUint::from(a as u128));
+            let u = a as u128;
+            x.to_string() == a.to_string()
+                && y.to_string() == u.to_string()
+                && format!("{:#x} {:X} {:o} {:b}", y, y, y, y) == format!("{:#x} {:X} {:o} {:b}", u, u, u, u)
+                && format!("{:+08}", x) == format!("{:+08}", a)
+        }
+
+        fn quickcheck_from_str_radix(a: i128, radix: u8) -> bool {
+            let radix = radix as u32 % 35 + 2;
+            let x = BigInt::from(a);
+            let s = x.to_str_radix(radix);
+            BigInt::from_str_radix(&s, radix) == Ok(x.clone())
+                && BigInt::from_str_radix(&s.to_uppercase(), radix) == Ok(x.clone())
+                && BigUint::from_str_radix(x.magnitude().to_str_radix(radix).as_str(), radix) == Ok(x.unsigned_abs())
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let s = "123456789012345678901234567890123456789012345678901234567890";
+        assert_eq!(BigUint::from_str(s).unwrap().to_string(), s);
+        assert_eq!(BigInt::from_str("-0"), Ok(BigInt::ZERO));
+        assert_eq!(BigUint::from_str("+15"), Ok(BigUint::from(15u8)));
+        assert_eq!(BigInt::from_str_radix("-zz", 36), Ok(BigInt::from(-1295)));
+        assert_eq!(
+            BigUint::from_str("").unwrap_err().kind(),
+            &IntErrorKind::Empty
+        );
+        assert_eq!(
+            BigUint::from_str("+").unwrap_err().kind(),
+            &IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            BigUint::from_str("-1").unwrap_err().kind(),
+            &IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            BigInt::from_str("12a").unwrap_err().kind(),
+            &IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            BigInt::from_str(" 1").unwrap_err().kind(),
+            &IntErrorKind::InvalidDigit
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Allocating wrappers around the [`limbs`](crate::limbs) kernels, used by the heap-allocated integers. Unless stated otherwise, the input slices must be normalised (they must have no trailing zero limbs) and the returned vectors are normalised.
+
+use crate::digit::u64 as digit;
+use crate::limbs::u64 as kernels;
+use crate::ExpType;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Removes the trailing zero limbs of `limbs`.
+#[inline]
+pub fn normalise(limbs: &mut Vec<u64>) {
+    while let Some(&0) = limbs.last() {
+        limbs.pop();
+    }
+}
+
+#[inline]
+pub fn cmp(a: &[u64], b: &[u64]) -> Ordering {
+    kernels::cmp(a, b)
+}
+
+pub fn bits(a: &[u64]) -> ExpType {
+    match a.last() {
+        Some(last) => (a.len() as ExpType) * digit::BITS - last.leading_zeros() as ExpType,
+        None => 0,
+    }
+}
+
+pub fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(a.len() + 1);
+    out.extend_from_slice(a);
+    if kernels::add_assign(&mut out, b) {
+        out.push(1);
+    }
+    out
+}
+
+/// Computes `a - b`. `a` must be greater than or equal to `b`.
+pub fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = a.to_vec();
+    let borrow = kernels::sub_assign(&mut out, b);
+    debug_assert!(!borrow);
+    normalise(&mut out);
+    out
+}
+
+/// Computes `a * m + c`.
+pub fn mul_add_digit(a: &[u64], m: u64, c: u64) -> Vec<u64> {
+    let mut out = Vec::with_capacity(a.len() + 1);
+    out.extend_from_slice(a);
+    let carry = kernels::mul_add_digit(&mut out, m, c);
+    out.push(carry);
+    normalise(&mut out);
+    out
+}
+
+pub fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    // TODO: implement a faster multiplication algorithm for large inputs
+    let mut out = vec![0; a.len() + b.len()];
+    kernels::mul(a, b, &mut out);
+    normalise(&mut out);
+    out
+}
+
+/// Computes the quotient and remainder of `a` divided by the non-zero digit `d`.
+pub fn div_rem_digit(a: &[u64], d: u64) -> (Vec<u64>, u64) {
+    let mut out = a.to_vec();
+    let rem = kernels::div_rem_digit(&mut out, d);
+    normalise(&mut out);
+    (out, rem)
+}
+
+/// Computes the quotient and remainder of `u` divided by the non-zero `v`.
+pub fn div_rem(u: &[u64], v: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    debug_assert!(!v.is_empty());
+    if u.len() < v.len() {
+        return (Vec::new(), u.to_vec());
+    }
+    let mut rem = u.to_vec();
+    let mut v = v.to_vec();
+    let mut q = vec![0; u.len() - v.len() + 1];
+    kernels::div_rem(&mut rem, &mut v, &mut q);
+    normalise(&mut q);
+    normalise(&mut rem);
+    (q, rem)
+}
+
+pub fn shl(a: &[u64], rhs: ExpType) -> Vec<u64> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let mut out = a.to_vec();
+    out.resize(a.len() + (rhs / digit::BITS) as usize + 1, 0);
+    kernels::shl(&mut out, rhs);
+    normalise(&mut out);
+    out
+}
+
+pub fn shr(a: &[u64], rhs: ExpType) -> Vec<u64> {
+    let mut out = a.to_vec();
+    kernels::shr(&mut out, rhs);
+    normalise(&mut out);
+    out
+}
+
+/// Returns whether any of the bits of `a` below `index` are set.
+pub fn any_below(a: &[u64], index: ExpType) -> bool {
+    let digit_index = (index / digit::BITS) as usize;
+    let bit_index = index % digit::BITS;
+    if a.iter().take(digit_index).any(|&d| d != 0) {
+        return true;
+    }
+    match a.get(digit_index) {
+        Some(&d) => bit_index != 0 && d << (digit::BITS - bit_index) != 0,
+        None => false,
+    }
+}
@@ -0,0 +1,393 @@
+//! Heap-allocated integers whose size is chosen at runtime.
+//!
+//! [`BigUint`] and [`BigInt`] are arbitrary precision integers stored as a [`Vec`] of `u64` limbs, for when the required width is only known at runtime. They use the same digit-level carry propagation, long multiplication, long division (Knuth's Algorithm D) and radix conversion as the fixed size integers, and can be converted losslessly to and from any [`BUint<N>`](crate::BUint) and [`BInt<N>`](crate::BInt) with [`From`] and [`BTryFrom`](crate::BTryFrom).
+//!
+//! Since they can't overflow, they don't have `checked_`, `wrapping_`, `saturating_` or `overflowing_` variants of most arithmetic methods. The arithmetic operators panic only when dividing by zero, or when subtracting a larger [`BigUint`] from a smaller one.
+
+use crate::errors::div_zero;
+use crate::ExpType;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+mod convert;
+mod fmt;
+pub(crate) mod limbs;
+mod ops;
+
+/// An unsigned integer of arbitrary size, stored on the heap.
+///
+/// The value is stored as a little-endian [`Vec`] of `u64` limbs with no trailing zero limbs, so zero is stored as an empty vector.
+#[derive(Clone, Hash, PartialEq, Eq, Default)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+/// A signed integer of arbitrary size, stored on the heap.
+///
+/// The value is stored as a sign and a [`BigUint`] magnitude. Zero is never negative.
+#[derive(Clone, Hash, PartialEq, Eq, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigUint {
+    /// The value `0`.
+    pub const ZERO: Self = Self { limbs: Vec::new() };
+
+    /// Creates an integer from its little-endian limbs. Trailing zero limbs are removed.
+    #[must_use]
+    #[inline]
+    pub fn from_limbs(mut limbs: Vec<u64>) -> Self {
+        limbs::normalise(&mut limbs);
+        Self { limbs }
+    }
+
+    /// Creates an integer from a slice of its little-endian limbs.
+    #[must_use]
+    #[inline]
+    pub fn from_slice(limbs: &[u64]) -> Self {
+        Self::from_limbs(limbs.to_vec())
+    }
+
+    /// Returns the little-endian limbs of `self`, which have no trailing zeros.
+    #[must_use]
+    #[inline]
+    pub fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    /// Returns the little-endian limbs of `self`, which have no trailing zeros.
+    #[must_use]
+    #[inline]
+    pub fn into_limbs(self) -> Vec<u64> {
+        self.limbs
+    }
+
+    /// Returns whether `self` is zero.
+    #[must_use]
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Returns whether `self` is one.
+    #[must_use]
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.limbs == [1]
+    }
+
+    /// Returns the smallest number of bits needed to represent `self`.
+    #[must_use]
+    #[inline]
+    pub fn bits(&self) -> ExpType {
+        limbs::bits(&self.limbs)
+    }
+
+    /// Returns whether the bit at position `index` is set.
+    #[must_use]
+    #[inline]
+    pub fn bit(&self, index: ExpType) -> bool {
+        let limb = self
+            .limbs
+            .get((index / u64::BITS) as usize)
+            .copied()
+            .unwrap_or(0);
+        limb & (1 << (index % u64::BITS)) != 0
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[must_use]
+    #[inline]
+    pub fn count_ones(&self) -> ExpType {
+        self.limbs
+            .iter()
+            .map(|limb| limb.count_ones() as ExpType)
+            .sum()
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `self`, or `None` if `self` is zero.
+    #[must_use]
+    #[inline]
+    pub fn trailing_zeros(&self) -> Option<ExpType> {
+        let i = self.limbs.iter().position(|&limb| limb != 0)?;
+        Some(i as ExpType * u64::BITS + self.limbs[i].trailing_zeros() as ExpType)
+    }
+
+    /// Returns whether `self` is a power of two.
+    #[must_use]
+    #[inline]
+    pub fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if `rhs` is greater than `self`.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if limbs::cmp(&self.limbs, &rhs.limbs) == Ordering::Less {
+            None
+        } else {
+            Some(Self {
+                limbs: limbs::sub(&self.limbs, &rhs.limbs),
+            })
+        }
+    }
+
+    /// Checked division. Computes `self / rhs`, returning `None` if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(q, _)| q)
+    }
+
+    /// Checked remainder. Computes `self % rhs`, returning `None` if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(_, r)| r)
+    }
+
+    /// Computes the quotient and remainder of `self` divided by `rhs`, returning `None` if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (q, r) = limbs::div_rem(&self.limbs, &rhs.limbs);
+        Some((Self { limbs: q }, Self { limbs: r }))
+    }
+
+    /// Computes the quotient and remainder of `self` divided by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        match self.checked_div_rem(rhs) {
+            Some(qr) => qr,
+            None => div_zero!(),
+        }
+    }
+
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    #[must_use = crate::doc::must_use_op!()]
+    pub fn pow(&self, mut exp: ExpType) -> Self {
+        let mut out = Self::from(1u8);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out = &out * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        out
+    }
+}
+
+impl PartialOrd for BigUint {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        limbs::cmp(&self.limbs, &other.limbs)
+    }
+}
+
+impl BigInt {
+    /// The value `0`.
+    pub const ZERO: Self = Self {
+        negative: false,
+        magnitude: BigUint::ZERO,
+    };
+
+    /// Creates the integer `(-1)^negative * magnitude`.
+    #[must_use]
+    #[inline]
+    pub fn from_sign_magnitude(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Returns the absolute value of `self`.
+    #[must_use]
+    #[inline]
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// Returns the absolute value of `self` as a [`BigUint`].
+    #[must_use]
+    #[inline]
+    pub fn unsigned_abs(self) -> BigUint {
+        self.magnitude
+    }
+
+    /// Returns the absolute value of `self`.
+    #[must_use]
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            negative: false,
+            magnitude: self.magnitude,
+        }
+    }
+
+    /// Returns whether `self` is zero.
+    #[must_use]
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns whether `self` is less than zero.
+    #[must_use]
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns whether `self` is greater than zero.
+    #[must_use]
+    #[inline]
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    /// Returns the smallest number of bits needed to represent the absolute value of `self`.
+    #[must_use]
+    #[inline]
+    pub fn bits(&self) -> ExpType {
+        self.magnitude.bits()
+    }
+
+    /// Checked division. Computes `self / rhs`, rounding toward zero, returning `None` if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(q, _)| q)
+    }
+
+    /// Checked remainder. Computes `self % rhs`, which has the same sign as `self`, returning `None` if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(_, r)| r)
+    }
+
+    /// Computes the quotient and remainder of `self` divided by `rhs`, with the quotient rounded toward zero, as for the primitive integer types. Returns `None` if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        let (q, r) = self.magnitude.checked_div_rem(&rhs.magnitude)?;
+        Some((
+            Self::from_sign_magnitude(self.negative != rhs.negative, q),
+            Self::from_sign_magnitude(self.negative, r),
+        ))
+    }
+
+    /// Computes the quotient and remainder of `self` divided by `rhs`, with the quotient rounded toward zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        match self.checked_div_rem(rhs) {
+            Some(qr) => qr,
+            None => div_zero!(),
+        }
+    }
+
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    #[must_use = crate::doc::must_use_op!()]
+    #[inline]
+    pub fn pow(&self, exp: ExpType) -> Self {
+        Self::from_sign_magnitude(self.negative && exp & 1 == 1, self.magnitude.pow(exp))
+    }
+}
+
+impl PartialOrd for BigInt {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigInt, BigUint};
+
+    quickcheck::quickcheck! {
+        fn quickcheck_cmp(a: i128, b: i128) -> bool {
+            BigInt::from(a).cmp(&BigInt::from(b)) == a.cmp(&b)
+                && BigUint::from(a as u128).cmp(&BigUint::from(b as u128)) == (a as u128).cmp(&(b as u128))
+        }
+
+        fn quickcheck_pow(a: i8, exp: u8) -> bool {
+            let exp = exp % 10;
+            BigInt::from(a).pow(exp as u32) == BigInt::from((a as i128).pow(exp as u32))
+        }
+
+        fn quickcheck_bits(a: u128) -> bool {
+            let a_big = BigUint::from(a);
+            a_big.bits() == 128 - a.leading_zeros()
+                && a_big.count_ones() == a.count_ones()
+                && a_big.trailing_zeros() == if a == 0 { None } else { Some(a.trailing_zeros()) }
+                && (0..130).all(|i| a_big.bit(i) == (i < 128 && a & (1 << i) != 0))
+        }
+    }
+
+    #[test]
+    fn limbs() {
+        let a = BigUint::from_limbs(vec![1, 2, 0, 0]);
+        assert_eq!(a.limbs(), &[1, 2]);
+        assert_eq!(a, BigUint::from(1u128 | (2 << 64)));
+        assert_eq!(BigUint::from_slice(&[0, 0]), BigUint::ZERO);
+        assert!(BigUint::from(1u8).is_one());
+        assert!(BigUint::from(1u128 << 100).is_power_of_two());
+        assert_eq!(
+            BigInt::from_sign_magnitude(true, BigUint::ZERO),
+            BigInt::ZERO
+        );
+        assert!(!BigInt::from(-0i8).is_negative());
+    }
+
+    #[test]
+    fn pow() {
+        let a = BigUint::from(3u8).pow(200);
+        assert_eq!(a.bits(), 317);
+        assert_eq!(
+            a.div_rem(&BigUint::from(3u8).pow(199)),
+            (BigUint::from(3u8), BigUint::ZERO)
+        );
+        assert_eq!(BigInt::from(-2).pow(129), -(BigInt::from(1) << 129));
+    }
+}
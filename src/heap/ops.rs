@@ -0,0 +1,359 @@
+use super::{limbs, BigInt, BigUint};
+use crate::errors::{div_zero, err_msg, rem_zero};
+use crate::ExpType;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// Implements a binary operator and its assigning version for all combinations of owned and borrowed operands, given its implementation for two borrowed operands.
+macro_rules! binop {
+    ($Int: ty, $Trait: ident, $method: ident, $AssignTrait: ident, $assign_method: ident, |$a: ident, $b: ident| $body: expr) => {
+        impl $Trait<&$Int> for &$Int {
+            type Output = $Int;
+
+            #[inline]
+            fn $method(self, rhs: &$Int) -> $Int {
+                let ($a, $b) = (self, rhs);
+                $body
+            }
+        }
+
+        impl $Trait<$Int> for $Int {
+            type Output = $Int;
+
+            #[inline]
+            fn $method(self, rhs: $Int) -> $Int {
+                $Trait::$method(&self, &rhs)
+            }
+        }
+
+        impl $Trait<&$Int> for $Int {
+            type Output = $Int;
+
+            #[inline]
+            fn $method(self, rhs: &$Int) -> $Int {
+                $Trait::$method(&self, rhs)
+            }
+        }
+
+        impl $Trait<$Int> for &$Int {
+            type Output = $Int;
+
+            #[inline]
+            fn $method(self, rhs: $Int) -> $Int {
+                $Trait::$method(self, &rhs)
+            }
+        }
+
+        impl $AssignTrait<$Int> for $Int {
+            #[inline]
+            fn $assign_method(&mut self, rhs: $Int) {
+                *self = $Trait::$method(&*self, &rhs);
+            }
+        }
+
+        impl $AssignTrait<&$Int> for $Int {
+            #[inline]
+            fn $assign_method(&mut self, rhs: &$Int) {
+                *self = $Trait::$method(&*self, rhs);
+            }
+        }
+    };
+}
+
+/// Implements a shift operator and its assigning version for owned and borrowed operands.
+macro_rules! shift {
+    ($Int: ty, $Trait: ident, $method: ident, $AssignTrait: ident, $assign_method: ident, |$a: ident, $b: ident| $body: expr) => {
+        impl $Trait<ExpType> for &$Int {
+            type Output = $Int;
+
+            #[inline]
+            fn $method(self, rhs: ExpType) -> $Int {
+                let ($a, $b) = (self, rhs);
+                $body
+            }
+        }
+
+        impl $Trait<ExpType> for $Int {
+            type Output = $Int;
+
+            #[inline]
+            fn $method(self, rhs: ExpType) -> $Int {
+                $Trait::$method(&self, rhs)
+            }
+        }
+
+        impl $AssignTrait<ExpType> for $Int {
+            #[inline]
+            fn $assign_method(&mut self, rhs: ExpType) {
+                *self = $Trait::$method(&*self, rhs);
+            }
+        }
+    };
+}
+
+/// Applies `f` to each pair of limbs of `a` and `b`, treating missing limbs as zero.
+fn bitwise(a: &BigUint, b: &BigUint, f: impl Fn(u64, u64) -> u64) -> BigUint {
+    let len = a.limbs.len().max(b.limbs.len());
+    let limbs: Vec<u64> = (0..len)
+        .map(|i| {
+            f(
+                a.limbs.get(i).copied().unwrap_or(0),
+                b.limbs.get(i).copied().unwrap_or(0),
+            )
+        })
+        .collect();
+    BigUint::from_limbs(limbs)
+}
+
+binop!(BigUint, Add, add, AddAssign, add_assign, |a, b| BigUint {
+    limbs: limbs::add(&a.limbs, &b.limbs)
+});
+binop!(
+    BigUint,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    |a, b| crate::errors::option_expect!(
+        a.checked_sub(b),
+        err_msg!("attempt to subtract with overflow")
+    )
+);
+binop!(BigUint, Mul, mul, MulAssign, mul_assign, |a, b| BigUint {
+    limbs: limbs::mul(&a.limbs, &b.limbs)
+});
+binop!(
+    BigUint,
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    |a, b| match a.checked_div(b) {
+        Some(q) => q,
+        None => div_zero!(),
+    }
+);
+binop!(
+    BigUint,
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    |a, b| match a.checked_rem(b) {
+        Some(r) => r,
+        None => rem_zero!(),
+    }
+);
+binop!(
+    BigUint,
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    |a, b| bitwise(a, b, |x, y| x & y)
+);
+binop!(BigUint, BitOr, bitor, BitOrAssign, bitor_assign, |a, b| {
+    bitwise(a, b, |x, y| x | y)
+});
+binop!(
+    BigUint,
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    |a, b| bitwise(a, b, |x, y| x ^ y)
+);
+shift!(BigUint, Shl, shl, ShlAssign, shl_assign, |a, rhs| BigUint {
+    limbs: limbs::shl(&a.limbs, rhs)
+});
+shift!(BigUint, Shr, shr, ShrAssign, shr_assign, |a, rhs| BigUint {
+    limbs: limbs::shr(&a.limbs, rhs)
+});
+
+/// Computes `a + (-1)^negate * b`.
+fn add_signed(a: &BigInt, b: &BigInt, negate: bool) -> BigInt {
+    let b_negative = b.negative != negate;
+    if a.negative == b_negative {
+        return BigInt::from_sign_magnitude(a.negative, &a.magnitude + &b.magnitude);
+    }
+    match a.magnitude.cmp(&b.magnitude) {
+        Ordering::Less => BigInt::from_sign_magnitude(b_negative, &b.magnitude - &a.magnitude),
+        _ => BigInt::from_sign_magnitude(a.negative, &a.magnitude - &b.magnitude),
+    }
+}
+
+binop!(BigInt, Add, add, AddAssign, add_assign, |a, b| add_signed(
+    a, b, false
+));
+binop!(BigInt, Sub, sub, SubAssign, sub_assign, |a, b| add_signed(
+    a, b, true
+));
+binop!(BigInt, Mul, mul, MulAssign, mul_assign, |a, b| {
+    BigInt::from_sign_magnitude(a.negative != b.negative, &a.magnitude * &b.magnitude)
+});
+binop!(
+    BigInt,
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    |a, b| match a.checked_div(b) {
+        Some(q) => q,
+        None => div_zero!(),
+    }
+);
+binop!(
+    BigInt,
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    |a, b| match a.checked_rem(b) {
+        Some(r) => r,
+        None => rem_zero!(),
+    }
+);
+shift!(BigInt, Shl, shl, ShlAssign, shl_assign, |a, rhs| {
+    BigInt::from_sign_magnitude(a.negative, &a.magnitude << rhs)
+});
+// as for the primitive integer types, this is an arithmetic shift, so it rounds toward negative infinity
+shift!(BigInt, Shr, shr, ShrAssign, shr_assign, |a, rhs| {
+    let magnitude = &a.magnitude >> rhs;
+    if a.negative && limbs::any_below(&a.magnitude.limbs, rhs) {
+        BigInt::from_sign_magnitude(true, magnitude + BigUint::from(1u8))
+    } else {
+        BigInt::from_sign_magnitude(a.negative, magnitude)
+    }
+});
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_sign_magnitude(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigInt, BigUint};
+    use crate::BUint;
+    use alloc::vec::Vec;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_biguint_ops(a: u128, b: u128) -> bool {
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+            let wide = |n: BUint<4>| BigUint::from(n);
+            let (a4, b4) = (BUint::<4>::from(a), BUint::<4>::from(b));
+            &x + &y == wide(a4 + b4)
+                && &x * &y == wide(a4 * b4)
+                && x.checked_sub(&y) == a.checked_sub(b).map(BigUint::from)
+                && x.checked_div(&y) == a.checked_div(b).map(BigUint::from)
+                && x.checked_rem(&y) == a.checked_rem(b).map(BigUint::from)
+                && &x & &y == BigUint::from(a & b)
+                && &x | &y == BigUint::from(a | b)
+                && &x ^ &y == BigUint::from(a ^ b)
+        }
+
+        fn quickcheck_biguint_div_rem(a: Vec<u64>, b: Vec<u64>) -> quickcheck::TestResult {
+            let (x, y) = (BigUint::from_limbs(a), BigUint::from_limbs(b));
+            if y.is_zero() {
+                return quickcheck::TestResult::discard();
+            }
+            let (q, r) = x.div_rem(&y);
+            quickcheck::TestResult::from_bool(r < y && &q * &y + r == x)
+        }
+
+        fn quickcheck_biguint_shifts(a: u128, shift: u8) -> bool {
+            let x = BigUint::from(a);
+            let shift = shift as u32;
+            let shl = &x << shift;
+            (&shl >> shift) == x
+                && shl == BigUint::from(BUint::<8>::from(a) << shift)
+                && (&x >> shift) == BigUint::from(a.checked_shr(shift).unwrap_or(0))
+        }
+
+        fn quickcheck_bigint_ops(a: i64, b: i64) -> bool {
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            let (a, b) = (a as i128, b as i128);
+            &x + &y == BigInt::from(a + b)
+                && &x - &y == BigInt::from(a - b)
+                && &x * &y == BigInt::from(a * b)
+                && x.checked_div(&y) == a.checked_div(b).map(BigInt::from)
+                && x.checked_rem(&y) == a.checked_rem(b).map(BigInt::from)
+                && -&x == BigInt::from(-a)
+        }
+
+        fn quickcheck_bigint_shifts(a: i64, shift: u8) -> bool {
+            let x = BigInt::from(a);
+            let shift = shift as u32 % 100;
+            (&x << shift) == BigInt::from(crate::BInt::<4>::from(a) << shift)
+                && (&x >> shift) == BigInt::from((a as i128) >> shift.min(127))
+        }
+    }
+
+    #[test]
+    fn div_rem_edge_cases() {
+        // limbs near the extremes exercise the quotient digit corrections and the add back step of Algorithm D
+        const LIMBS: [u64; 5] = [0, 1, 1 << 63, u64::MAX - 1, u64::MAX];
+        let all = |len: u32| {
+            (0..5usize.pow(len)).map(move |mut i| {
+                let mut limbs = Vec::new();
+                for _ in 0..len {
+                    limbs.push(LIMBS[i % 5]);
+                    i /= 5;
+                }
+                BigUint::from_limbs(limbs)
+            })
+        };
+        for x in all(4) {
+            for y in all(2).filter(|y| !y.is_zero()) {
+                let (q, r) = x.div_rem(&y);
+                assert!(r < y);
+                assert_eq!(&q * &y + r, x);
+            }
+        }
+    }
+
+    #[test]
+    fn assign() {
+        let mut a = BigUint::from(u64::MAX);
+        a += BigUint::from(1u8);
+        assert_eq!(a.limbs(), &[0, 1]);
+        a -= &BigUint::from(1u8);
+        assert_eq!(a.limbs(), &[u64::MAX]);
+        a <<= 100;
+        a >>= 36;
+        assert_eq!(a.limbs(), &[0, u64::MAX]);
+        let mut b = BigInt::from(-5);
+        b *= BigInt::from(-3);
+        b /= &BigInt::from(4);
+        assert_eq!(b, BigInt::from(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn sub_overflow() {
+        let _ = BigUint::from(1u8) - BigUint::from(2u8);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = BigInt::from(1) / BigInt::ZERO;
+    }
+}
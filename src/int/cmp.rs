@@ -71,13 +71,13 @@ macro_rules! tests {
         use core::cmp::Ord;
 
         test_bignum! {
-            function: <$int>::eq(a: ref &$int, b: ref &$int)
+            function: <$int as PartialEq<Self>>::eq(a: ref &$int, b: ref &$int)
         }
         test_bignum! {
             function: <$int as PartialEq>::eq(a: ref &$int, b: ref &$int)
         }
         test_bignum! {
-            function: <$int>::partial_cmp(a: ref &$int, b: ref &$int)
+            function: <$int as PartialOrd<Self>>::partial_cmp(a: ref &$int, b: ref &$int)
         }
         test_bignum! {
             function: <$int as PartialOrd>::lt(a: ref &$int, b: ref &$int)
@@ -1,3 +1,14 @@
+/// Returns whether `bytes` are the contents of a valid ASN.1 DER encoding of an `INTEGER`, which must be non-empty and must not start with nine zero bits or nine one bits.
+#[inline]
+pub const fn is_minimal_der_integer(bytes: &[u8]) -> bool {
+    match bytes {
+        [] => false,
+        [0x00, next, ..] => *next & 0x80 != 0,
+        [0xff, next, ..] => *next & 0x80 == 0,
+        _ => true,
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "nightly")]
 macro_rules! test_from_endian_slice {
@@ -73,6 +84,48 @@ use alloc::vec::Vec;
 #[cfg(feature = "nightly")]
 use core::ops::{Range, RangeFrom};
 
+#[cfg(test)]
+/// Strip redundant leading bytes from big-endian two's complement bytes, keeping one sign byte if needed
+pub fn der_integer_bytes(be_bytes: &[u8], negative: bool) -> alloc::vec::Vec<u8> {
+    let pad = if negative { 0xff } else { 0 };
+    let mut bytes = vec![pad];
+    bytes.extend_from_slice(be_bytes);
+    while bytes.len() > 1 && bytes[0] == pad && (bytes[1] & 0x80 != 0) == negative {
+        bytes.remove(0);
+    }
+    bytes
+}
+
+#[cfg(test)]
+/// Unsigned LEB128 encoding of a primitive integer
+pub fn uleb128(mut value: u128) -> alloc::vec::Vec<u8> {
+    let mut bytes = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+/// Signed LEB128 encoding of a primitive integer
+pub fn sleb128(mut value: i128) -> alloc::vec::Vec<u8> {
+    let mut bytes = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
 #[cfg(feature = "nightly")]
 #[cfg(test)]
 /// Pad a slice of bytes with leading pad bits so that the resulting vector of bytes represents the same integer as the original slice
@@ -157,6 +210,23 @@ macro_rules! tests {
             function: <$int>::from_ne_bytes(a: U8ArrayWrapper<{<$int>::BITS as usize / 8}>)
         }
 
+        paste::paste! {
+            quickcheck::quickcheck! {
+                fn quickcheck_der_integer_bytes(a: $int) -> bool {
+                    type Big = [<$int:upper>];
+
+                    let negative = <$int>::MIN != 0 && a.leading_zeros() == 0;
+                    let bytes = Big::from(a).to_der_integer_bytes();
+                    let mut padded = vec![if negative { 0xff } else { 0 }];
+                    padded.extend_from_slice(&bytes);
+
+                    bytes == crate::int::endian::der_integer_bytes(&a.to_be_bytes(), negative)
+                        && Big::from_der_integer_bytes(&bytes) == Some(Big::from(a))
+                        && Big::from_der_integer_bytes(&padded).is_none()
+                }
+            }
+        }
+
         #[cfg(feature = "nightly")]
         crate::int::endian::test_from_endian_slice!($int, be);
 
@@ -10,6 +10,7 @@ pub mod numtraits;
 
 pub mod ops;
 pub mod radix;
+pub mod resize;
 pub mod strict;
 pub mod unchecked;
 
@@ -0,0 +1,30 @@
+use crate::errors::err_msg;
+
+/// Compile time checks on the digit counts of the resizing methods. Evaluating one of these constants in a method body causes a compile error when the method is instantiated with digit counts for which the check fails.
+pub struct AssertDigits<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> AssertDigits<A, B, C> {
+    /// Asserts that an integer with `A` digits can be extended to `B` digits.
+    pub const EXTEND: () = assert!(
+        A <= B,
+        err_msg!("cannot extend to a smaller number of digits")
+    );
+
+    /// Asserts that an integer with `A` digits can be truncated to `B` digits.
+    pub const TRUNCATE: () = assert!(
+        A >= B,
+        err_msg!("cannot truncate to a larger number of digits")
+    );
+
+    /// Asserts that an integer with `C` digits can be split into parts with `A` and `B` digits.
+    pub const SPLIT: () = assert!(
+        A + B == C,
+        err_msg!("number of digits of the parts must add up to the number of digits of the whole")
+    );
+
+    /// Asserts that an integer with `C` digits can hold a result which has `A + B` digits.
+    pub const WIDEN: () = assert!(
+        A + B <= C,
+        err_msg!("number of digits of the output must be at least the number of digits of the full result")
+    );
+}
@@ -0,0 +1,95 @@
+use crate::errors::TryFromIntError;
+use crate::BTryFrom;
+use ::crypto_bigint::Uint;
+
+/// The integer type whose digits have the same width as the limbs of `crypto_bigint::Uint` on the target.
+#[cfg(target_pointer_width = "32")]
+type Words<const LIMBS: usize> = crate::BUintD32<LIMBS>;
+
+/// The integer type whose digits have the same width as the limbs of `crypto_bigint::Uint` on the target.
+#[cfg(target_pointer_width = "64")]
+type Words<const LIMBS: usize> = crate::BUint<LIMBS>;
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for Words<LIMBS> {
+    #[inline]
+    fn from(uint: Uint<LIMBS>) -> Self {
+        Self::from_digits(uint.to_words())
+    }
+}
+
+impl<const LIMBS: usize> From<Words<LIMBS>> for Uint<LIMBS> {
+    #[inline]
+    fn from(uint: Words<LIMBS>) -> Self {
+        Self::from_words(uint.digits)
+    }
+}
+
+macro_rules! crypto_bigint {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const LIMBS: usize, const N: usize> BTryFrom<Uint<LIMBS>> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: Uint<LIMBS>) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(Words::<LIMBS>::from(uint))
+            }
+        }
+
+        impl<const LIMBS: usize, const N: usize> BTryFrom<Uint<LIMBS>> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: Uint<LIMBS>) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(Words::<LIMBS>::from(uint))
+            }
+        }
+
+        impl<const LIMBS: usize, const N: usize> BTryFrom<$BUint<N>> for Uint<LIMBS> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: $BUint<N>) -> Result<Self, Self::Error> {
+                <Words<LIMBS> as BTryFrom<$BUint<N>>>::try_from(uint).map(Self::from)
+            }
+        }
+
+        impl<const LIMBS: usize, const N: usize> BTryFrom<$BInt<N>> for Uint<LIMBS> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: $BInt<N>) -> Result<Self, Self::Error> {
+                <Words<LIMBS> as BTryFrom<$BInt<N>>>::try_from(int).map(Self::from)
+            }
+        }
+    };
+}
+
+crate::macro_impl!(crypto_bigint);
+
+#[cfg(test)]
+mod tests {
+    use super::{Uint, Words};
+    use crate::{BInt, BTryFrom, BUint, BUintD8};
+
+    type U256 = Uint<{ 256 / crypto_bigint::Word::BITS as usize }>;
+    type W256 = Words<{ 256 / crypto_bigint::Word::BITS as usize }>;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: BUint<4>, b: BUintD8<32>) -> bool {
+            let x: U256 = BTryFrom::try_from(a).unwrap();
+            let y: U256 = BTryFrom::try_from(b).unwrap();
+            let narrow: Result<Uint<1>, _> = BTryFrom::try_from(a);
+            <BUint<4> as BTryFrom<U256>>::try_from(x) == Ok(a)
+                && <BUintD8<32> as BTryFrom<U256>>::try_from(y) == Ok(b)
+                && W256::from(x.wrapping_mul(&y)) == W256::from(x).wrapping_mul(W256::from(y))
+                && U256::from(W256::from(x)) == x
+                && narrow.is_ok() == (a.bits() <= crypto_bigint::Word::BITS)
+        }
+
+        fn quickcheck_signed(a: BInt<4>) -> bool {
+            let x: Result<U256, _> = BTryFrom::try_from(a);
+            x.is_ok() == !a.is_negative()
+                && x.map_or(true, |x| <BInt<4> as BTryFrom<U256>>::try_from(x) == Ok(a))
+        }
+    }
+}
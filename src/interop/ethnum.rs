@@ -0,0 +1,136 @@
+use crate::errors::TryFromIntError;
+use crate::{BInt, BTryFrom, BUint};
+use ::ethnum::{I256, U256};
+
+impl From<U256> for BUint<4> {
+    #[inline]
+    fn from(uint: U256) -> Self {
+        let (hi, lo) = uint.into_words();
+        Self::from_digits([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+    }
+}
+
+impl From<BUint<4>> for U256 {
+    #[inline]
+    fn from(uint: BUint<4>) -> Self {
+        let d = uint.digits;
+        Self::from_words(
+            d[2] as u128 | (d[3] as u128) << 64,
+            d[0] as u128 | (d[1] as u128) << 64,
+        )
+    }
+}
+
+impl From<I256> for BInt<4> {
+    #[inline]
+    fn from(int: I256) -> Self {
+        Self::from_bits(BUint::from(int.as_u256()))
+    }
+}
+
+impl From<BInt<4>> for I256 {
+    #[inline]
+    fn from(int: BInt<4>) -> Self {
+        U256::from(int.to_bits()).as_i256()
+    }
+}
+
+macro_rules! ethnum {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> BTryFrom<U256> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: U256) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(BUint::<4>::from(uint))
+            }
+        }
+
+        impl<const N: usize> BTryFrom<U256> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: U256) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(BUint::<4>::from(uint))
+            }
+        }
+
+        impl<const N: usize> BTryFrom<I256> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: I256) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(BInt::<4>::from(int))
+            }
+        }
+
+        impl<const N: usize> BTryFrom<I256> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: I256) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(BInt::<4>::from(int))
+            }
+        }
+
+        impl<const N: usize> BTryFrom<$BUint<N>> for U256 {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: $BUint<N>) -> Result<Self, Self::Error> {
+                <BUint<4> as BTryFrom<$BUint<N>>>::try_from(uint).map(Self::from)
+            }
+        }
+
+        impl<const N: usize> BTryFrom<$BInt<N>> for U256 {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: $BInt<N>) -> Result<Self, Self::Error> {
+                <BUint<4> as BTryFrom<$BInt<N>>>::try_from(int).map(Self::from)
+            }
+        }
+
+        impl<const N: usize> BTryFrom<$BUint<N>> for I256 {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: $BUint<N>) -> Result<Self, Self::Error> {
+                <BInt<4> as BTryFrom<$BUint<N>>>::try_from(uint).map(Self::from)
+            }
+        }
+
+        impl<const N: usize> BTryFrom<$BInt<N>> for I256 {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: $BInt<N>) -> Result<Self, Self::Error> {
+                <BInt<4> as BTryFrom<$BInt<N>>>::try_from(int).map(Self::from)
+            }
+        }
+    };
+}
+
+crate::macro_impl!(ethnum);
+
+#[cfg(test)]
+mod tests {
+    use super::{I256, U256};
+    use crate::{BInt, BIntD8, BTryFrom, BUint, BUintD32};
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: BUint<4>, b: BInt<4>, c: i128) -> bool {
+            let (x, y) = (U256::from(a), I256::from(b));
+            BUint::from(x) == a
+                && BInt::from(y) == b
+                && I256::from(BInt::<4>::from(c)) == I256::new(c)
+                && BUint::from(x.wrapping_mul(x)) == a.wrapping_mul(a)
+                && BInt::from(y.wrapping_mul(y).wrapping_sub(I256::ONE)) == b.wrapping_mul(b).wrapping_sub(BInt::ONE)
+                && BInt::from(y >> 7u32) == b >> 7u32
+                && <BIntD8<32> as BTryFrom<I256>>::try_from(y) == Ok(BTryFrom::try_from(b).unwrap())
+                && <BUintD32<4> as BTryFrom<U256>>::try_from(x).is_ok() == (a.bits() <= 128)
+                && <U256 as BTryFrom<BInt<4>>>::try_from(b).is_ok() == !b.is_negative()
+                && <I256 as BTryFrom<BUint<4>>>::try_from(a).is_ok() == (a.bits() < 256)
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Conversions between the integer types in this crate and the integer types of other crates, each enabled by the crate feature of the same name.
+
+#[cfg(feature = "crypto-bigint")]
+mod crypto_bigint;
+#[cfg(feature = "ethnum")]
+mod ethnum;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "primitive-types")]
+mod primitive_types;
+#[cfg(feature = "ruint")]
+mod ruint;
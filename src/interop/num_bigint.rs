@@ -0,0 +1,200 @@
+use crate::errors::TryFromIntError;
+use crate::BTryFrom;
+use ::num_bigint::{BigInt, BigUint, Sign};
+use alloc::vec;
+
+macro_rules! num_bigint {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> From<$BUint<N>> for BigUint {
+            #[inline]
+            fn from(uint: $BUint<N>) -> Self {
+                const DIGIT_BITS: usize = $Digit::BITS as usize;
+                let mut words = vec![0u32; (N * DIGIT_BITS + 31) / 32];
+                for (i, &digit) in uint.digits.iter().enumerate() {
+                    let mut j = 0;
+                    while j < DIGIT_BITS {
+                        let bit = i * DIGIT_BITS + j;
+                        words[bit / 32] |= ((digit >> j) as u32) << (bit % 32);
+                        j += 32;
+                    }
+                }
+                Self::new(words)
+            }
+        }
+
+        impl<const N: usize> From<$BUint<N>> for BigInt {
+            #[inline]
+            fn from(uint: $BUint<N>) -> Self {
+                Self::from(BigUint::from(uint))
+            }
+        }
+
+        impl<const N: usize> From<$BInt<N>> for BigInt {
+            #[inline]
+            fn from(int: $BInt<N>) -> Self {
+                let sign = if int.is_negative() {
+                    Sign::Minus
+                } else {
+                    Sign::Plus
+                };
+                Self::from_biguint(sign, BigUint::from(int.unsigned_abs()))
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigUint> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: BigUint) -> Result<Self, Self::Error> {
+                const DIGIT_BITS: usize = $Digit::BITS as usize;
+                if uint.bits() > Self::BITS as u64 {
+                    return Err(TryFromIntError(()));
+                }
+                let mut out = Self::ZERO;
+                for (i, word) in uint.iter_u32_digits().enumerate() {
+                    let mut j = 0;
+                    // the value is in range, so any bits beyond the width of the integer are zero
+                    while j < 32 && i * 32 + j < N * DIGIT_BITS {
+                        let bit = i * 32 + j;
+                        out.digits[bit / DIGIT_BITS] |= ((word >> j) as $Digit) << (bit % DIGIT_BITS);
+                        j += DIGIT_BITS;
+                    }
+                }
+                Ok(out)
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigInt> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: BigInt) -> Result<Self, Self::Error> {
+                match int.into_parts() {
+                    (Sign::Minus, _) => Err(TryFromIntError(())),
+                    (_, magnitude) => <Self as BTryFrom<BigUint>>::try_from(magnitude),
+                }
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigUint> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: BigUint) -> Result<Self, Self::Error> {
+                let out = $BInt::from_bits(<$BUint<N> as BTryFrom<BigUint>>::try_from(uint)?);
+                if out.is_negative() {
+                    return Err(TryFromIntError(()));
+                }
+                Ok(out)
+            }
+        }
+
+        impl<const N: usize> BTryFrom<BigInt> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: BigInt) -> Result<Self, Self::Error> {
+                let (sign, magnitude) = int.into_parts();
+                let magnitude = <$BUint<N> as BTryFrom<BigUint>>::try_from(magnitude)?;
+                if sign == Sign::Minus {
+                    // `-magnitude` is in range if and only if `magnitude.wrapping_neg()` is negative or zero
+                    let out = $BInt::from_bits(magnitude.wrapping_neg());
+                    if out.is_positive() {
+                        return Err(TryFromIntError(()));
+                    }
+                    Ok(out)
+                } else {
+                    let out = $BInt::from_bits(magnitude);
+                    if out.is_negative() {
+                        return Err(TryFromIntError(()));
+                    }
+                    Ok(out)
+                }
+            }
+        }
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use super::{BigInt, BigUint};
+                use crate::{$BInt, $BUint, BTryFrom};
+                use alloc::string::ToString;
+
+                type U = $BUint<{ 192 / $Digit::BITS as usize }>;
+                type I = $BInt<{ 192 / $Digit::BITS as usize }>;
+
+                fn agrees<T: BTryFrom<BigUint> + PartialEq>(result: Option<T>, big: BigUint) -> bool {
+                    result == <T as BTryFrom<BigUint>>::try_from(big).ok()
+                }
+
+                fn agrees_signed<T: BTryFrom<BigInt> + PartialEq>(result: Option<T>, big: BigInt) -> bool {
+                    result == <T as BTryFrom<BigInt>>::try_from(big).ok()
+                }
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_round_trip(a: U, b: I) -> bool {
+                        <U as BTryFrom<BigUint>>::try_from(BigUint::from(a)) == Ok(a)
+                            && <U as BTryFrom<BigInt>>::try_from(BigInt::from(a)) == Ok(a)
+                            && <I as BTryFrom<BigInt>>::try_from(BigInt::from(b)) == Ok(b)
+                            && BigUint::from(a).to_string() == a.to_string()
+                            && BigInt::from(b).to_string() == b.to_string()
+                            && <I as BTryFrom<BigUint>>::try_from(BigUint::from(a)).is_ok() == (a.bits() < U::BITS)
+                            && <U as BTryFrom<BigInt>>::try_from(BigInt::from(b)).is_ok() == !b.is_negative()
+                    }
+
+                    fn quickcheck_round_trip_odd_width(a: $BUint<3>, b: $BInt<3>) -> bool {
+                        <$BUint<3> as BTryFrom<BigUint>>::try_from(BigUint::from(a)) == Ok(a)
+                            && <$BUint<3> as BTryFrom<BigInt>>::try_from(BigInt::from(a)) == Ok(a)
+                            && <$BInt<3> as BTryFrom<BigInt>>::try_from(BigInt::from(b)) == Ok(b)
+                            && <$BInt<3> as BTryFrom<BigUint>>::try_from(BigUint::from(a)).is_ok() == (a.bits() < $BUint::<3>::BITS)
+                    }
+
+                    fn quickcheck_unsigned_arithmetic(a: U, b: U, e: u8) -> bool {
+                        let (x, y) = (BigUint::from(a), BigUint::from(b));
+                        let e = e % 8;
+                        agrees(a.checked_add(b), &x + &y)
+                            && agrees(a.checked_mul(b), &x * &y)
+                            && agrees(a.checked_pow(e as u32), x.pow(e as u32))
+                            && (b.is_zero() || (agrees(a.checked_div(b), &x / &y) && agrees(a.checked_rem(b), &x % &y)))
+                            && (a < b || agrees(a.checked_sub(b), &x - &y))
+                            && agrees(Some(a ^ b), &x ^ &y)
+                            && agrees(Some(a.wrapping_shl(e as u32 * 3)), (&x << (e as u32 * 3)) % (BigUint::from(1u8) << U::BITS))
+                    }
+
+                    fn quickcheck_signed_arithmetic(a: I, b: I, e: u8) -> bool {
+                        let (x, y) = (BigInt::from(a), BigInt::from(b));
+                        let e = e % 8;
+                        agrees_signed(a.checked_add(b), &x + &y)
+                            && agrees_signed(a.checked_sub(b), &x - &y)
+                            && agrees_signed(a.checked_mul(b), &x * &y)
+                            && agrees_signed(a.checked_pow(e as u32), x.pow(e as u32))
+                            && (b.is_zero() || (agrees_signed(a.checked_div(b), &x / &y) && agrees_signed(a.checked_rem(b), &x % &y)))
+                            && agrees_signed(Some(a >> (e as u32 * 3)), &x >> (e as u32 * 3))
+                    }
+                }
+
+                #[test]
+                fn limits() {
+                    let min = BigInt::from(I::MIN);
+                    assert_eq!(<I as BTryFrom<BigInt>>::try_from(min.clone()), Ok(I::MIN));
+                    assert!(<I as BTryFrom<BigInt>>::try_from(min - 1).is_err());
+                    let max = BigInt::from(I::MAX);
+                    assert!(<I as BTryFrom<BigInt>>::try_from(max + 1).is_err());
+                    let umax = BigUint::from(U::MAX);
+                    assert_eq!(<U as BTryFrom<BigUint>>::try_from(umax.clone()), Ok(U::MAX));
+                    assert!(<U as BTryFrom<BigUint>>::try_from(umax + 1u8).is_err());
+                    assert_eq!(<U as BTryFrom<BigUint>>::try_from(BigUint::default()), Ok(U::ZERO));
+
+                    let min = BigInt::from($BInt::<3>::MIN);
+                    assert_eq!(<$BInt<3> as BTryFrom<BigInt>>::try_from(min.clone()), Ok($BInt::<3>::MIN));
+                    assert!(<$BInt<3> as BTryFrom<BigInt>>::try_from(min - 1).is_err());
+                    let umax = BigUint::from($BUint::<3>::MAX);
+                    assert_eq!(<$BUint<3> as BTryFrom<BigUint>>::try_from(umax.clone()), Ok($BUint::<3>::MAX));
+                    assert!(<$BUint<3> as BTryFrom<BigUint>>::try_from(umax + 1u8).is_err());
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(num_bigint);
@@ -0,0 +1,103 @@
+use crate::errors::TryFromIntError;
+use crate::{BTryFrom, BUint};
+use ::primitive_types::{U128, U256, U512};
+
+macro_rules! from {
+    ($($U: ident: $N: literal), *) => {
+        $(
+            impl From<$U> for BUint<$N> {
+                #[inline]
+                fn from(uint: $U) -> Self {
+                    Self::from_digits(uint.0)
+                }
+            }
+
+            impl From<BUint<$N>> for $U {
+                #[inline]
+                fn from(uint: BUint<$N>) -> Self {
+                    Self(uint.digits)
+                }
+            }
+        )*
+    };
+}
+
+from!(U128: 2, U256: 4, U512: 8);
+
+macro_rules! try_from {
+    ($BUint: ident, $BInt: ident; $($U: ident: $N: literal), *) => {
+        $(
+            impl<const N: usize> BTryFrom<$U> for $BUint<N> {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(uint: $U) -> Result<Self, Self::Error> {
+                    BTryFrom::try_from(BUint::<$N>::from(uint))
+                }
+            }
+
+            impl<const N: usize> BTryFrom<$U> for $BInt<N> {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(uint: $U) -> Result<Self, Self::Error> {
+                    BTryFrom::try_from(BUint::<$N>::from(uint))
+                }
+            }
+
+            impl<const N: usize> BTryFrom<$BUint<N>> for $U {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(uint: $BUint<N>) -> Result<Self, Self::Error> {
+                    <BUint<$N> as BTryFrom<$BUint<N>>>::try_from(uint).map(Self::from)
+                }
+            }
+
+            impl<const N: usize> BTryFrom<$BInt<N>> for $U {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(int: $BInt<N>) -> Result<Self, Self::Error> {
+                    <BUint<$N> as BTryFrom<$BInt<N>>>::try_from(int).map(Self::from)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! primitive_types {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        try_from!($BUint, $BInt; U128: 2, U256: 4, U512: 8);
+    };
+}
+
+crate::macro_impl!(primitive_types);
+
+#[cfg(test)]
+mod tests {
+    use super::{U128, U256, U512};
+    use crate::{BInt, BTryFrom, BUint, BUintD16, BUintD8};
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: BUint<4>, b: BUintD8<32>, c: u128) -> bool {
+            let x = U256::from(a);
+            let y: U256 = BTryFrom::try_from(b).unwrap();
+            let b64: BUint<4> = BTryFrom::try_from(b).unwrap();
+            let narrow: Result<U128, _> = BTryFrom::try_from(a);
+            BUint::from(x) == a
+                && <BUintD8<32> as BTryFrom<U256>>::try_from(y) == Ok(b)
+                && BUint::from(x.overflowing_mul(y).0) == a.wrapping_mul(b64)
+                && BUint::from(x.overflowing_add(y).0) == a.wrapping_add(b64)
+                && narrow.is_ok() == (a.bits() <= 128)
+                && BUint::<2>::from(U128::from(c)) == BUint::from(c)
+                && <BUintD16<32> as BTryFrom<U512>>::try_from(U512::from(x)) == BTryFrom::try_from(a)
+        }
+
+        fn quickcheck_signed(a: BInt<4>) -> bool {
+            let x: Result<U256, _> = BTryFrom::try_from(a);
+            x.is_ok() == !a.is_negative()
+                && x.map_or(true, |x| <BInt<4> as BTryFrom<U256>>::try_from(x) == Ok(a))
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::errors::TryFromIntError;
+use crate::{BTryFrom, BUint};
+use ::ruint::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for BUint<LIMBS> {
+    #[inline]
+    fn from(uint: Uint<BITS, LIMBS>) -> Self {
+        Self::from_digits(uint.into_limbs())
+    }
+}
+
+macro_rules! ruint {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const BITS: usize, const LIMBS: usize, const N: usize> BTryFrom<Uint<BITS, LIMBS>> for $BUint<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(BUint::<LIMBS>::from(uint))
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const N: usize> BTryFrom<Uint<BITS, LIMBS>> for $BInt<N> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+                BTryFrom::try_from(BUint::<LIMBS>::from(uint))
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const N: usize> BTryFrom<$BUint<N>> for Uint<BITS, LIMBS> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(uint: $BUint<N>) -> Result<Self, Self::Error> {
+                let limbs: BUint<LIMBS> = BTryFrom::try_from(uint)?;
+                Self::checked_from_limbs_slice(limbs.digits()).ok_or(TryFromIntError(()))
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const N: usize> BTryFrom<$BInt<N>> for Uint<BITS, LIMBS> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(int: $BInt<N>) -> Result<Self, Self::Error> {
+                let limbs: BUint<LIMBS> = BTryFrom::try_from(int)?;
+                Self::checked_from_limbs_slice(limbs.digits()).ok_or(TryFromIntError(()))
+            }
+        }
+    };
+}
+
+crate::macro_impl!(ruint);
+
+#[cfg(test)]
+mod tests {
+    use super::Uint;
+    use crate::{BInt, BTryFrom, BUint, BUintD8};
+
+    type U256 = Uint<256, 4>;
+    type U100 = Uint<100, 2>;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: BUint<4>, b: BUintD8<32>) -> bool {
+            let x: U256 = BTryFrom::try_from(a).unwrap();
+            let y: U256 = BTryFrom::try_from(b).unwrap();
+            let narrow: Result<U100, _> = BTryFrom::try_from(a);
+            BUint::from(x) == a
+                && <BUintD8<32> as BTryFrom<U256>>::try_from(y) == Ok(b)
+                && BUint::from(x.wrapping_mul(y)) == a.wrapping_mul(BTryFrom::try_from(b).unwrap())
+                && narrow.is_ok() == (a.bits() <= 100)
+        }
+
+        fn quickcheck_signed(a: BInt<4>) -> bool {
+            let x: Result<U256, _> = BTryFrom::try_from(a);
+            x.is_ok() == !a.is_negative()
+                && x.map_or(true, |x| <BInt<4> as BTryFrom<U256>>::try_from(x) == Ok(a))
+        }
+    }
+}
@@ -11,7 +11,7 @@
 #![cfg_attr(
     test,
     feature(
-        bigint_helper_methods,
+        signed_bigint_helpers, widening_mul, unsigned_bigint_helpers,
         int_roundings,
         float_minimum_maximum,
         wrapping_next_power_of_two,
@@ -35,21 +35,52 @@ mod buint;
 pub mod cast;
 mod digit;
 mod doc;
+pub mod endian;
 pub mod errors;
+pub mod fixed;
+#[cfg(feature = "alloc")]
+mod heap;
 mod int;
+mod interop;
+pub mod limbs;
 mod nightly;
 pub mod prelude;
+mod ratio;
+mod words;
 
 #[cfg(feature = "rand")]
 pub mod random;
 
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+#[cfg(feature = "rlp")]
+mod rlp;
+
+#[cfg(feature = "der")]
+mod der;
+
+#[cfg(feature = "scale")]
+pub mod scale;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
 pub mod types;
 
-// #[cfg(feature = "nightly")]
-// mod float;
+#[cfg(feature = "nightly")]
+pub mod float;
+
+#[cfg(feature = "nightly")]
+pub use float::Float;
 
-// #[cfg(feature = "nightly")]
-// pub use float::Float;
+pub mod decimal;
+
+pub use decimal::Decimal;
+
+mod rounding;
+
+pub use rounding::{RoundingMode, Status};
 
 #[cfg(test)]
 mod test;
@@ -66,6 +97,11 @@ mod bigints {
 
 pub use bigints::*;
 
+pub use ratio::Ratio;
+
+#[cfg(feature = "alloc")]
+pub use heap::{BigInt, BigUint};
+
 macro_rules! macro_impl {
     ($name: ident) => {
         use crate::bigints::*;
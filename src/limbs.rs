@@ -0,0 +1,428 @@
+//! Arithmetic on slices of digits ("limbs"), for building custom integer representations such as stack buffers whose length is only known at runtime.
+//!
+//! There is a module for each of the digit types used by `bnum`'s integers. All slices are little-endian: the digit at index `0` is the least significant. Slices may have leading zero digits, and need not have the same length unless stated otherwise. None of the functions allocate.
+//!
+//! These functions are used by the heap-allocated `BigUint` and `BigInt` types of the `alloc` feature. They are separate from the arithmetic of `bnum`'s fixed size integers, which is `const` without the `nightly` feature and so can't operate on mutable slices, but they use the same digit operations and, in [`div_rem`](u64::div_rem), the same estimate for each quotient digit.
+//!
+//! The public functions are only `const` when the `nightly` feature is enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! use bnum::limbs::u64 as limbs;
+//!
+//! let mut a = [u64::MAX, 1, 0];
+//! let carry = limbs::add_assign(&mut a, &[1]);
+//! assert_eq!((a, carry), ([0, 2, 0], false));
+//!
+//! let mut q = [0; 3];
+//! let mut v = [3];
+//! limbs::div_rem(&mut a, &mut v, &mut q);
+//! assert_eq!(q, [0xAAAAAAAAAAAAAAAA, 0, 0]); // (2 * 2^64) / 3
+//! assert_eq!(a, [2, 0, 0]); // the remainder
+//! ```
+
+use core::cmp::Ordering;
+
+macro_rules! limbs_module {
+    ($Digit: ident) => {
+        #[doc = concat!("Arithmetic on little-endian slices of `", stringify!($Digit), "` digits.")]
+        pub mod $Digit {
+            use crate::digit::$Digit::{self as digit, Digit};
+            use crate::ExpType;
+            use super::Ordering;
+
+            /// Returns the number of digits of `a` up to and including its most significant non-zero digit.
+            #[inline]
+            const fn significant_len(a: &[Digit]) -> usize {
+                let mut len = a.len();
+                while len > 0 && a[len - 1] == 0 {
+                    len -= 1;
+                }
+                len
+            }
+
+            /// Returns the digit at `index` of the `len + 1` digit integer whose least significant `len` digits are `a` and whose most significant digit is `top`.
+            #[inline]
+            const fn get(a: &[Digit], top: Digit, index: usize) -> Digit {
+                if index == a.len() {
+                    top
+                } else {
+                    a[index]
+                }
+            }
+
+            crate::nightly::const_fns! {
+                /// Compares the integers represented by `a` and `b`.
+                #[must_use]
+                pub const fn cmp(a: &[Digit], b: &[Digit]) -> Ordering {
+                    let (a_len, b_len) = (significant_len(a), significant_len(b));
+                    if a_len != b_len {
+                        return if a_len < b_len { Ordering::Less } else { Ordering::Greater };
+                    }
+                    let mut i = a_len;
+                    while i > 0 {
+                        i -= 1;
+                        if a[i] != b[i] {
+                            return if a[i] < b[i] { Ordering::Less } else { Ordering::Greater };
+                        }
+                    }
+                    Ordering::Equal
+                }
+
+                /// Computes `a += b`, returning the carry out of the most significant digit of `a`.
+                ///
+                /// # Panics
+                ///
+                /// This function panics if `b` is longer than `a`.
+                pub const fn add_assign(a: &mut [Digit], b: &[Digit]) -> bool {
+                    assert!(b.len() <= a.len(), crate::errors::err_msg!("`b` must not be longer than `a`"));
+                    let mut carry = false;
+                    let mut i = 0;
+                    while i < b.len() {
+                        let (sum, c) = digit::carrying_add(a[i], b[i], carry);
+                        a[i] = sum;
+                        carry = c;
+                        i += 1;
+                    }
+                    while carry && i < a.len() {
+                        let (sum, c) = a[i].overflowing_add(1);
+                        a[i] = sum;
+                        carry = c;
+                        i += 1;
+                    }
+                    carry
+                }
+
+                /// Computes `a -= b`, returning the borrow out of the most significant digit of `a`. If the borrow is `true`, `a` is left holding `a - b` modulo `2^(BITS * a.len())`.
+                ///
+                /// # Panics
+                ///
+                /// This function panics if `b` is longer than `a`.
+                pub const fn sub_assign(a: &mut [Digit], b: &[Digit]) -> bool {
+                    assert!(b.len() <= a.len(), crate::errors::err_msg!("`b` must not be longer than `a`"));
+                    let mut borrow = false;
+                    let mut i = 0;
+                    while i < b.len() {
+                        let (diff, b) = digit::borrowing_sub(a[i], b[i], borrow);
+                        a[i] = diff;
+                        borrow = b;
+                        i += 1;
+                    }
+                    while borrow && i < a.len() {
+                        let (diff, b) = a[i].overflowing_sub(1);
+                        a[i] = diff;
+                        borrow = b;
+                        i += 1;
+                    }
+                    borrow
+                }
+
+                /// Computes `a = a * m + c`, returning the digit which is carried out of the most significant digit of `a`.
+                pub const fn mul_add_digit(a: &mut [Digit], m: Digit, c: Digit) -> Digit {
+                    let mut carry = c;
+                    let mut i = 0;
+                    while i < a.len() {
+                        let (prod, c) = digit::carrying_mul(a[i], m, carry, 0);
+                        a[i] = prod;
+                        carry = c;
+                        i += 1;
+                    }
+                    carry
+                }
+
+                /// Writes the product `a * b` into `out`, using long multiplication. Any digits of `out` above the product are set to zero.
+                ///
+                /// # Panics
+                ///
+                /// This function panics if `out` is shorter than `a.len() + b.len()`.
+                pub const fn mul(a: &[Digit], b: &[Digit], out: &mut [Digit]) -> () {
+                    assert!(out.len() >= a.len() + b.len(), crate::errors::err_msg!("`out` must have at least `a.len() + b.len()` digits"));
+                    let mut i = 0;
+                    while i < out.len() {
+                        out[i] = 0;
+                        i += 1;
+                    }
+                    i = 0;
+                    while i < a.len() {
+                        let mut carry = 0;
+                        let mut j = 0;
+                        while j < b.len() {
+                            let (prod, c) = digit::carrying_mul(a[i], b[j], carry, out[i + j]);
+                            out[i + j] = prod;
+                            carry = c;
+                            j += 1;
+                        }
+                        out[i + b.len()] = carry;
+                        i += 1;
+                    }
+                }
+
+                /// Divides `a` by `d` in place, returning the remainder.
+                ///
+                /// # Panics
+                ///
+                /// This function panics if `d` is zero.
+                pub const fn div_rem_digit(a: &mut [Digit], d: Digit) -> Digit {
+                    if d == 0 {
+                        crate::errors::div_zero!();
+                    }
+                    let mut rem = 0;
+                    let mut i = a.len();
+                    while i > 0 {
+                        i -= 1;
+                        let (q, r) = digit::div_rem_wide(a[i], rem, d);
+                        a[i] = q;
+                        rem = r;
+                    }
+                    rem
+                }
+
+                /// Divides `u` by `v` using Knuth's Algorithm D, writing the quotient into `q` and leaving the remainder in `u`. `v` is used as scratch space, but holds its original value when this function returns. Any digits of `q` above the quotient are set to zero.
+                ///
+                /// # Panics
+                ///
+                /// This function panics if `v` is zero, or if `q` is shorter than `u.len() - n + 1`, where `n` is the number of significant digits of `v`.
+                pub const fn div_rem(u: &mut [Digit], v: &mut [Digit], q: &mut [Digit]) -> () {
+                    // The Art of Computer Programming Volume 2 by Donald Knuth, Section 4.3.1, Algorithm D
+                    let n = significant_len(v);
+                    if n == 0 {
+                        crate::errors::div_zero!();
+                    }
+                    let mut i = 0;
+                    while i < q.len() {
+                        q[i] = 0;
+                        i += 1;
+                    }
+                    if u.len() < n {
+                        return;
+                    }
+                    let m = u.len() - n;
+                    assert!(q.len() > m, crate::errors::err_msg!("`q` must have at least `u.len() - n + 1` digits"));
+                    if n == 1 {
+                        let rem = div_rem_digit(u, v[0]);
+                        i = 0;
+                        while i <= m {
+                            q[i] = u[i];
+                            u[i] = 0;
+                            i += 1;
+                        }
+                        u[0] = rem;
+                        return;
+                    }
+
+                    // D1: normalise so that the most significant bit of the divisor is set. The shifted `u` has one more digit than `u`, which is held in `u_top`
+                    let shift = v[n - 1].leading_zeros() as ExpType;
+                    let mut u_top = if shift == 0 { 0 } else { u[u.len() - 1] >> (digit::BITS - shift) };
+                    shl(u, shift);
+                    shl(v, shift);
+
+                    let v_n_m1 = v[n - 1];
+                    let v_n_m2 = v[n - 2];
+                    let mut j = m + 1;
+                    while j > 0 {
+                        j -= 1;
+                        // D3: estimate the quotient digit, which is then at most one too large
+                        let mut q_hat = digit::estimate_quotient(get(u, u_top, j + n), get(u, u_top, j + n - 1), u[j + n - 2], v_n_m1, v_n_m2);
+
+                        // D4: multiply and subtract
+                        let mut carry = 0;
+                        let mut borrow = false;
+                        i = 0;
+                        while i < n {
+                            let (prod, c) = digit::carrying_mul(q_hat, v[i], carry, 0);
+                            carry = c;
+                            let (diff, b) = digit::borrowing_sub(u[i + j], prod, borrow);
+                            u[i + j] = diff;
+                            borrow = b;
+                            i += 1;
+                        }
+                        let (diff, b) = digit::borrowing_sub(get(u, u_top, j + n), carry, borrow);
+                        let mut top = diff;
+
+                        // D6: add back if the estimate was one too large
+                        if b {
+                            q_hat -= 1;
+                            let mut carry = false;
+                            i = 0;
+                            while i < n {
+                                let (sum, c) = digit::carrying_add(u[i + j], v[i], carry);
+                                u[i + j] = sum;
+                                carry = c;
+                                i += 1;
+                            }
+                            top = top.wrapping_add(carry as Digit);
+                        }
+                        if j + n == u.len() {
+                            u_top = top;
+                        } else {
+                            u[j + n] = top;
+                        }
+                        q[j] = q_hat;
+                    }
+
+                    // D8: unnormalise the remainder, and restore the divisor
+                    shr(u, shift);
+                    shr(v, shift);
+                }
+
+                /// Shifts `a` left by `rhs` bits in place. The bits shifted out of the most significant digit are lost.
+                pub const fn shl(a: &mut [Digit], rhs: ExpType) -> () {
+                    let len = a.len();
+                    let digit_shift = (rhs / digit::BITS) as usize;
+                    let bit_shift = rhs % digit::BITS;
+                    let mut i = len;
+                    while i > 0 {
+                        i -= 1;
+                        a[i] = if i < digit_shift {
+                            0
+                        } else {
+                            let d = a[i - digit_shift] << bit_shift;
+                            if bit_shift != 0 && i > digit_shift {
+                                d | (a[i - digit_shift - 1] >> (digit::BITS - bit_shift))
+                            } else {
+                                d
+                            }
+                        };
+                    }
+                }
+
+                /// Shifts `a` right by `rhs` bits in place. The bits shifted out of the least significant digit are lost.
+                pub const fn shr(a: &mut [Digit], rhs: ExpType) -> () {
+                    let len = a.len();
+                    let digit_shift = (rhs / digit::BITS) as usize;
+                    let bit_shift = rhs % digit::BITS;
+                    let mut i = 0;
+                    while i < len {
+                        a[i] = if i + digit_shift >= len {
+                            0
+                        } else {
+                            let d = a[i + digit_shift] >> bit_shift;
+                            if bit_shift != 0 && i + digit_shift + 1 < len {
+                                d | (a[i + digit_shift + 1] << (digit::BITS - bit_shift))
+                            } else {
+                                d
+                            }
+                        };
+                        i += 1;
+                    }
+                }
+            }
+        }
+    };
+}
+
+limbs_module!(u8);
+limbs_module!(u16);
+limbs_module!(u32);
+limbs_module!(u64);
+
+#[cfg(test)]
+mod tests {
+    use super::Ordering;
+
+    macro_rules! tests {
+        ($Digit: ident, $Double: ident) => {
+            mod $Digit {
+                use super::super::$Digit as limbs;
+                use super::Ordering;
+
+                // a double digit integer gives the expected results of operations on two digit slices
+                fn join(a: [$Digit; 2]) -> $Double {
+                    a[0] as $Double | (a[1] as $Double) << $Digit::BITS
+                }
+
+                fn split(a: $Double) -> [$Digit; 2] {
+                    [a as $Digit, (a >> $Digit::BITS) as $Digit]
+                }
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_add_sub_assign(a: $Double, b: $Double) -> bool {
+                        let (mut x, mut y) = (split(a), split(a));
+                        let carry = limbs::add_assign(&mut x, &split(b));
+                        let borrow = limbs::sub_assign(&mut y, &split(b));
+                        (join(x), carry) == a.overflowing_add(b) && (join(y), borrow) == a.overflowing_sub(b)
+                            && limbs::cmp(&split(a), &split(b)) == a.cmp(&b)
+                    }
+
+                    fn quickcheck_mul(a: $Double, b: $Digit, c: $Digit) -> bool {
+                        let mut x = split(a);
+                        let carry = limbs::mul_add_digit(&mut x, b, c);
+                        let mut out = [0; 5];
+                        limbs::mul(&split(a), &[b, c], &mut out);
+                        // the full product fits in a `BUint<5>`
+                        let big = |digits: &[$Digit]| digits.iter().enumerate().fold(crate::BUint::<5>::ZERO, |acc, (i, &d)| acc | crate::BUint::<5>::from(d) << (i as u32 * $Digit::BITS));
+                        let mul_add = crate::BUint::<5>::from(a) * crate::BUint::<5>::from(b) + crate::BUint::<5>::from(c);
+                        big(&[x[0], x[1], carry]) == mul_add
+                            && big(&out) == crate::BUint::<5>::from(a) * crate::BUint::<5>::from(join([b, c]))
+                    }
+
+                    fn quickcheck_div_rem(a: $Double, b: $Double) -> quickcheck::TestResult {
+                        if b == 0 {
+                            return quickcheck::TestResult::discard();
+                        }
+                        let (mut u, mut v, mut q) = (split(a), split(b), [0; 2]);
+                        limbs::div_rem(&mut u, &mut v, &mut q);
+                        quickcheck::TestResult::from_bool(join(q) == a / b && join(u) == a % b && join(v) == b)
+                    }
+
+                    fn quickcheck_shifts(a: $Double, rhs: u8) -> bool {
+                        let rhs = rhs as u32 % (2 * $Digit::BITS + 2);
+                        let (mut x, mut y) = (split(a), split(a));
+                        limbs::shl(&mut x, rhs);
+                        limbs::shr(&mut y, rhs);
+                        join(x) == a.checked_shl(rhs).unwrap_or(0) && join(y) == a.checked_shr(rhs).unwrap_or(0)
+                    }
+                }
+
+                #[test]
+                fn cmp() {
+                    assert_eq!(limbs::cmp(&[1, 0, 0], &[1]), Ordering::Equal);
+                    assert_eq!(limbs::cmp(&[0, 1], &[$Digit::MAX]), Ordering::Greater);
+                    assert_eq!(limbs::cmp(&[], &[0, 1]), Ordering::Less);
+                }
+            }
+        };
+    }
+
+    tests!(u8, u16);
+    tests!(u16, u32);
+    tests!(u32, u64);
+    tests!(u64, u128);
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn const_eval() {
+        use super::u64 as limbs;
+
+        const QUOTIENT: [u64; 2] = {
+            let (mut u, mut v, mut q) = ([7, 5], [0, 2], [0; 2]);
+            limbs::div_rem(&mut u, &mut v, &mut q);
+            let _ = limbs::add_assign(&mut q, &[1]);
+            q
+        };
+        assert_eq!(QUOTIENT, [3, 0]);
+    }
+
+    #[test]
+    fn div_rem_wide() {
+        use super::u64 as limbs;
+        use crate::BUint;
+
+        // dividing a four digit integer by a two digit integer, checked against `BUint`
+        let cases = [
+            ([0, 0, 0, 1 << 63], [1, 1 << 63]),
+            ([u64::MAX; 4], [u64::MAX, 1]),
+            ([3, u64::MAX - 1, u64::MAX, 0], [u64::MAX, u64::MAX]),
+            ([1, 2, 3, 4], [5, 0]),
+        ];
+        for (a, b) in cases {
+            let (mut u, mut v, mut q) = (a, b, [0; 4]);
+            limbs::div_rem(&mut u, &mut v, &mut q);
+            let (x, y) = (BUint::<4>::from_digits(a), BUint::<4>::from_digits([b[0], b[1], 0, 0]));
+            assert_eq!(BUint::from_digits(q), x / y);
+            assert_eq!(BUint::from_digits(u), x % y);
+            assert_eq!(v, b);
+        }
+    }
+}
@@ -9,7 +9,7 @@ Copyright 2018-2020 Developers of the Rand project.
 Copyright 2017 The Rust Project Developers.
 */
 
-//! Utilities for generating random bnum integers.
+//! Utilities for generating random bnum integers and floats.
 //!
 //! The `rand` feature must be enabled to use items from this module.
 
@@ -289,7 +289,7 @@ macro_rules! test_random {
 ///
 /// Implements the [`UniformSampler`](https://docs.rs/rand/latest/rand/distributions/uniform/trait.UniformSampler.html) trait from the [`rand`](https://docs.rs/rand/latest/rand/) crate. This struct should not be used directly; instead use the [`Uniform`](https://docs.rs/rand/latest/rand/distributions/struct.Uniform.html) struct from the [`rand`](https://docs.rs/rand/latest/rand/) crate.
 #[derive(Clone, Copy, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct UniformInt<X> {
     low: X,
     range: X,
@@ -346,3 +346,243 @@ macro_rules! random {
 }
 
 crate::macro_impl!(random);
+
+#[cfg(feature = "nightly")]
+mod float {
+    use super::{Distribution, Rng, SampleBorrow, SampleUniform, Standard, UniformSampler};
+    use crate::float::storage::{Storage, Width};
+    use crate::{BUintD8, Float};
+    use rand::distributions::{Open01, OpenClosed01};
+
+    /// Returns the `bits` most significant bits of a random `BUintD8<W>`, as the least significant bits of the result.
+    #[inline]
+    fn random_bits<R: Rng + ?Sized, const W: usize>(rng: &mut R, bits: usize) -> BUintD8<W> {
+        rng.gen::<BUintD8<W>>().shr(BUintD8::<W>::BITS - bits as crate::ExpType)
+    }
+
+    impl<const W: usize, const MB: usize> Distribution<Float<W, MB>> for Standard
+    where
+        Width<W>: Storage,
+    {
+        /// Generates a float uniformly distributed in the half-open interval `[0, 1)`, with `MB + 1` bits of precision.
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float<W, MB> {
+            let precision = MB + 1;
+            Float::from_scaled_int(random_bits(rng, precision), -(precision as i64))
+        }
+    }
+
+    impl<const W: usize, const MB: usize> Distribution<Float<W, MB>> for OpenClosed01
+    where
+        Width<W>: Storage,
+    {
+        /// Generates a float uniformly distributed in the half-open interval `(0, 1]`, with `MB + 1` bits of precision.
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float<W, MB> {
+            let precision = MB + 1;
+            let value = random_bits::<R, W>(rng, precision).add(BUintD8::ONE);
+            Float::from_scaled_int(value, -(precision as i64))
+        }
+    }
+
+    impl<const W: usize, const MB: usize> Distribution<Float<W, MB>> for Open01
+    where
+        Width<W>: Storage,
+    {
+        /// Generates a float uniformly distributed in the open interval `(0, 1)`, with `MB` bits of precision.
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float<W, MB> {
+            let value = random_bits::<R, W>(rng, MB).shl(1).bitor(BUintD8::ONE);
+            Float::from_scaled_int(value, -(MB as i64 + 1))
+        }
+    }
+
+    /// Used for generating random floats in a given range.
+    ///
+    /// Implements the [`UniformSampler`](https://docs.rs/rand/latest/rand/distributions/uniform/trait.UniformSampler.html) trait from the [`rand`](https://docs.rs/rand/latest/rand/) crate. This struct should not be used directly; instead use the [`Uniform`](https://docs.rs/rand/latest/rand/distributions/struct.Uniform.html) struct from the [`rand`](https://docs.rs/rand/latest/rand/) crate.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    pub struct UniformFloat<X> {
+        low: X,
+        scale: X,
+    }
+
+    impl<const W: usize, const MB: usize> SampleUniform for Float<W, MB>
+    where
+        Width<W>: Storage,
+        [(); (W + 3) / 4]:,
+    {
+        type Sampler = UniformFloat<Self>;
+    }
+
+    impl<const W: usize, const MB: usize> UniformFloat<Float<W, MB>>
+    where
+        Width<W>: Storage,
+    {
+        /// The largest value that `value0_1` can return, `1 - 2^(-MB)`.
+        #[inline]
+        fn max_rand() -> Float<W, MB> {
+            Float::from_scaled_int(BUintD8::MAX.shr(BUintD8::<W>::BITS - MB as crate::ExpType), -(MB as i64))
+        }
+
+        /// Generates a float uniformly distributed in `[0, 1)`, with `MB` bits of precision.
+        #[inline]
+        fn value0_1<R: Rng + ?Sized>(rng: &mut R) -> Float<W, MB> {
+            Float::from_scaled_int(random_bits(rng, MB), -(MB as i64))
+        }
+
+        /// Returns the next float towards zero from the positive float `scale`.
+        #[inline]
+        fn decrease(scale: Float<W, MB>) -> Float<W, MB> {
+            Float::from_bits(scale.to_bits().sub(BUintD8::ONE))
+        }
+    }
+
+    impl<const W: usize, const MB: usize> UniformSampler for UniformFloat<Float<W, MB>>
+    where
+        Width<W>: Storage,
+        [(); (W + 3) / 4]:,
+    {
+        type X = Float<W, MB>;
+
+        fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
+        where
+            B1: SampleBorrow<Self::X> + Sized,
+            B2: SampleBorrow<Self::X> + Sized,
+        {
+            let low = *low_b.borrow();
+            let high = *high_b.borrow();
+            debug_assert!(low.is_finite(), "Uniform::new called with `low` non-finite.");
+            debug_assert!(high.is_finite(), "Uniform::new called with `high` non-finite.");
+            assert!(low < high, "Uniform::new called with `low >= high`");
+            let max_rand = Self::max_rand();
+
+            let mut scale = high - low;
+            assert!(scale.is_finite(), "Uniform::new: range overflow");
+
+            while scale * max_rand + low >= high {
+                scale = Self::decrease(scale);
+            }
+
+            UniformFloat { low, scale }
+        }
+
+        fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Self
+        where
+            B1: SampleBorrow<Self::X> + Sized,
+            B2: SampleBorrow<Self::X> + Sized,
+        {
+            let low = *low_b.borrow();
+            let high = *high_b.borrow();
+            debug_assert!(low.is_finite(), "Uniform::new_inclusive called with `low` non-finite.");
+            debug_assert!(high.is_finite(), "Uniform::new_inclusive called with `high` non-finite.");
+            assert!(low <= high, "Uniform::new_inclusive called with `low > high`");
+            let max_rand = Self::max_rand();
+
+            let mut scale = (high - low) / max_rand;
+            assert!(scale.is_finite(), "Uniform::new_inclusive: range overflow");
+
+            while scale * max_rand + low > high {
+                scale = Self::decrease(scale);
+            }
+
+            UniformFloat { low, scale }
+        }
+
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+            Self::value0_1(rng) * self.scale + self.low
+        }
+
+        #[inline]
+        fn sample_single<R: Rng + ?Sized, B1, B2>(low_b: B1, high_b: B2, rng: &mut R) -> Self::X
+        where
+            B1: SampleBorrow<Self::X> + Sized,
+            B2: SampleBorrow<Self::X> + Sized,
+        {
+            let low = *low_b.borrow();
+            let high = *high_b.borrow();
+            debug_assert!(low.is_finite(), "UniformSampler::sample_single called with `low` non-finite.");
+            debug_assert!(high.is_finite(), "UniformSampler::sample_single called with `high` non-finite.");
+            assert!(low < high, "UniformSampler::sample_single: low >= high");
+            let mut scale = high - low;
+            assert!(scale.is_finite(), "UniformSampler::sample_single: range overflow");
+
+            loop {
+                let res = Self::value0_1(rng) * scale + low;
+                if res < high {
+                    return res;
+                }
+                // only reachable if `scale` is infinite, `low` or `high` is non-finite, or `res` rounds up to `high`
+                if !scale.is_finite() {
+                    assert!(
+                        low.is_finite() && high.is_finite(),
+                        "Uniform::sample_single: low and high must be finite"
+                    );
+                    scale = Self::decrease(scale);
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::test::convert;
+        use crate::test::types::{ftest, FTEST};
+        use rand::distributions::{Open01, OpenClosed01, Standard, Uniform};
+        use rand::rngs::{SmallRng, StdRng};
+        use rand::{Rng, SeedableRng};
+
+        fn seeded_rngs<R: SeedableRng + Clone>(seed: u64) -> (R, R) {
+            let rng = R::seed_from_u64(seed);
+            let rng2 = rng.clone();
+            (rng, rng2)
+        }
+
+        macro_rules! test_distribution {
+            ($($Distribution: ident), *) => {
+                paste::paste! {
+                    quickcheck::quickcheck! {
+                        $(
+                            fn [<quickcheck_ $Distribution:snake>](seed: u64) -> bool {
+                                let (mut rng, mut rng2) = seeded_rngs::<StdRng>(seed);
+                                let big: FTEST = rng.sample($Distribution);
+                                let primitive: ftest = rng2.sample($Distribution);
+                                convert::test_eq(big, primitive)
+                            }
+                        )*
+                    }
+                }
+            };
+        }
+
+        test_distribution!(Standard, Open01, OpenClosed01);
+
+        quickcheck::quickcheck! {
+            fn quickcheck_float_gen_range(seed: u64, a: ftest, b: ftest) -> quickcheck::TestResult {
+                // the inclusive range panics if `(b - a) / (1 - EPSILON)` overflows
+                if a.is_nan() || b.is_nan() || a >= b || b - a >= ftest::MAX / 2.0 {
+                    return quickcheck::TestResult::discard();
+                }
+                let (mut rng, mut rng2) = seeded_rngs::<SmallRng>(seed);
+                let (a_big, b_big) = (FTEST::from(a), FTEST::from(b));
+
+                let mut result = convert::test_eq(rng.gen_range(a_big..b_big), rng2.gen_range(a..b));
+                result &= convert::test_eq(rng.gen_range(a_big..=b_big), rng2.gen_range(a..=b));
+
+                let big = Uniform::new(a_big, b_big);
+                let primitive = Uniform::new(a, b);
+                result &= convert::test_eq(rng.sample(big), rng2.sample(primitive));
+
+                let big = Uniform::new_inclusive(a_big, b_big);
+                let primitive = Uniform::new_inclusive(a, b);
+                result &= convert::test_eq(rng.sample(big), rng2.sample(primitive));
+
+                quickcheck::TestResult::from_bool(result)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+pub use float::UniformFloat;
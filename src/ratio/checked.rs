@@ -0,0 +1,207 @@
+use super::Ratio;
+
+macro_rules! checked {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> Ratio<$BInt<N>> {
+            /// Computes `self + rhs` or `self - rhs`, depending on `sub`.
+            const fn checked_add_sub(self, rhs: Self, sub: bool) -> Option<Self> {
+                // Knuth, The Art of Computer Programming, Volume 2, section 4.5.1: the intermediate values are as small as possible, and the result is in lowest terms without a further division by the GCD of the numerator and denominator
+                let (b, d) = (self.denom.unsigned_abs(), rhs.denom.unsigned_abs());
+                let g = b.gcd(d);
+                let (b_g, d_g) = (b.div_rem(g).0, d.div_rem(g).0);
+                let x = crate::nightly::option_try!(self.numer.unsigned_abs().checked_mul(d_g));
+                let y = crate::nightly::option_try!(rhs.numer.unsigned_abs().checked_mul(b_g));
+                let x_negative = self.is_negative();
+                let y_negative = rhs.is_negative() != sub;
+                // `t = (-1)^negative * magnitude = a * (d / g) ± c * (b / g)`
+                let (negative, magnitude) = if x_negative == y_negative {
+                    (x_negative, crate::nightly::option_try!(x.checked_add(y)))
+                } else if x.ge(&y) {
+                    (x_negative, x.wrapping_sub(y))
+                } else {
+                    (y_negative, y.wrapping_sub(x))
+                };
+                if magnitude.is_zero() {
+                    return Some(Self::ZERO);
+                }
+                let g2 = magnitude.gcd(g);
+                let denom = crate::nightly::option_try!(b_g.checked_mul(d.div_rem(g2).0));
+                Self::from_reduced(negative, magnitude.div_rem(g2).0, denom)
+            }
+
+            /// Checked addition. Computes `self + rhs`, returning `None` if the result or an intermediate value overflows.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.checked_add_sub(rhs, false)
+            }
+
+            /// Checked subtraction. Computes `self - rhs`, returning `None` if the result or an intermediate value overflows.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.checked_add_sub(rhs, true)
+            }
+
+            /// Checked multiplication. Computes `self * rhs`, returning `None` if the result overflows.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                if self.is_zero() || rhs.is_zero() {
+                    return Some(Self::ZERO);
+                }
+                let (a, b) = (self.numer.unsigned_abs(), self.denom.unsigned_abs());
+                let (c, d) = (rhs.numer.unsigned_abs(), rhs.denom.unsigned_abs());
+                // cancelling the common factors first means the products are in lowest terms
+                let (g1, g2) = (a.gcd(d), c.gcd(b));
+                let numer =
+                    crate::nightly::option_try!(a.div_rem(g1).0.checked_mul(c.div_rem(g2).0));
+                let denom =
+                    crate::nightly::option_try!(b.div_rem(g2).0.checked_mul(d.div_rem(g1).0));
+                Self::from_reduced(self.is_negative() != rhs.is_negative(), numer, denom)
+            }
+
+            /// Checked division. Computes `self / rhs`, returning `None` if `rhs` is zero or if the result overflows.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() {
+                    return None;
+                }
+                if self.is_zero() {
+                    return Some(Self::ZERO);
+                }
+                let (a, b) = (self.numer.unsigned_abs(), self.denom.unsigned_abs());
+                let (c, d) = (rhs.numer.unsigned_abs(), rhs.denom.unsigned_abs());
+                let (g1, g2) = (a.gcd(c), b.gcd(d));
+                let numer =
+                    crate::nightly::option_try!(a.div_rem(g1).0.checked_mul(d.div_rem(g2).0));
+                let denom =
+                    crate::nightly::option_try!(b.div_rem(g2).0.checked_mul(c.div_rem(g1).0));
+                Self::from_reduced(self.is_negative() != rhs.is_negative(), numer, denom)
+            }
+
+            /// Checked negation. Computes `-self`, returning `None` if the numerator of `self` is `MIN`.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_neg(self) -> Option<Self> {
+                match self.numer.checked_neg() {
+                    Some(numer) => Some(Self {
+                        numer,
+                        denom: self.denom,
+                    }),
+                    None => None,
+                }
+            }
+
+            /// Checked absolute value. Computes `|self|`, returning `None` if the numerator of `self` is `MIN`.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_abs(self) -> Option<Self> {
+                if self.is_negative() {
+                    self.checked_neg()
+                } else {
+                    Some(self)
+                }
+            }
+
+            /// Checked reciprocal. Computes `1 / self`, returning `None` if `self` is zero or if the numerator of `self` is `MIN` and its denominator is greater than one.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn checked_recip(self) -> Option<Self> {
+                if self.is_zero() {
+                    return None;
+                }
+                Self::from_reduced(
+                    self.is_negative(),
+                    self.denom.unsigned_abs(),
+                    self.numer.unsigned_abs(),
+                )
+            }
+        }
+    };
+}
+
+crate::macro_impl!(checked);
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use crate::BInt;
+
+    type R = Ratio<BInt<2>>;
+
+    fn r(numer: i64, denom: i64) -> R {
+        R::new(BInt::from(numer), BInt::from(denom))
+    }
+
+    fn reference(numer: i128, denom: i128) -> Option<R> {
+        R::checked_new(BInt::from(numer), BInt::from(denom))
+    }
+
+    quickcheck::quickcheck! {
+        // the numerators and denominators are small enough that the operations are exact in `i128`
+        fn quickcheck_checked_add(a: i32, b: i32, c: i32, d: i32) -> quickcheck::TestResult {
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+            let x = r(a as i64, b as i64);
+            let y = r(c as i64, d as i64);
+            quickcheck::TestResult::from_bool(
+                x.checked_add(y) == reference(a * d + c * b, b * d) && x.checked_sub(y) == reference(a * d - c * b, b * d)
+            )
+        }
+
+        fn quickcheck_checked_mul_div(a: i32, b: i32, c: i32, d: i32) -> quickcheck::TestResult {
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+            let x = r(a as i64, b as i64);
+            let y = r(c as i64, d as i64);
+            let div = if c == 0 { None } else { reference(a * d, b * c) };
+            quickcheck::TestResult::from_bool(x.checked_mul(y) == reference(a * c, b * d) && x.checked_div(y) == div)
+        }
+
+        fn quickcheck_checked_add_overflow(a: i64, b: i64, c: i64, d: i64) -> quickcheck::TestResult {
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            // the exact result of `Ratio<BInt<1>>` arithmetic must agree with `Ratio<BInt<2>>` whenever it doesn't overflow
+            let small = |n: i64, d: i64| Ratio::<BInt<1>>::checked_new(BInt::from(n), BInt::from(d));
+            let widen = |x: Ratio<BInt<1>>| R::new(BInt::from(x.numer().to_bits().digits()[0] as i64), BInt::from(x.denom().to_bits().digits()[0] as i64));
+            let (x, y) = match (small(a, b), small(c, d)) {
+                (Some(x), Some(y)) => (x, y),
+                _ => return quickcheck::TestResult::discard(),
+            };
+            let results = [(x.checked_add(y), r(a, b).checked_add(r(c, d))), (x.checked_mul(y), r(a, b).checked_mul(r(c, d)))];
+            quickcheck::TestResult::from_bool(results.iter().all(|(small, wide)| small.map_or(true, |s| Some(widen(s)) == *wide)))
+        }
+    }
+
+    #[test]
+    fn checked_overflow() {
+        let max = R::from_integer(BInt::MAX);
+        let min = R::from_integer(BInt::MIN);
+        assert_eq!(max.checked_add(R::ONE), None);
+        assert_eq!(min.checked_sub(R::ONE), None);
+        assert_eq!(min.checked_add(max), Some(R::from_integer(BInt::NEG_ONE)));
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.checked_div(R::from_integer(BInt::NEG_ONE)), None);
+        assert_eq!(
+            min.checked_mul(r(-1, 2)),
+            Some(R::from_integer(BInt::MIN / BInt::from(-2)))
+        );
+        assert_eq!(min.checked_recip(), None);
+        assert_eq!(
+            r(1, -3).checked_recip(),
+            Some(R::from_integer(BInt::from(-3)))
+        );
+        assert_eq!(R::ZERO.checked_recip(), None);
+        assert_eq!(r(2, 3).checked_div(R::ZERO), None);
+        assert_eq!(r(-2, 3).checked_recip(), Some(r(-3, 2)));
+        assert_eq!(r(1, 6).checked_add(r(1, 3)), Some(r(1, 2)));
+        assert_eq!(r(1, 6).checked_sub(r(1, 6)), Some(R::ZERO));
+    }
+}
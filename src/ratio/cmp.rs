@@ -0,0 +1,91 @@
+use super::Ratio;
+use core::cmp::Ordering;
+
+macro_rules! cmp {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> Ratio<$BInt<N>> {
+            /// Compares `self` and `other` by cross-multiplying, which is exact since the products are computed with double width.
+            const fn cmp_ratio(&self, other: &Self) -> Ordering {
+                let (self_negative, other_negative) = (self.is_negative(), other.is_negative());
+                if self_negative != other_negative {
+                    return if self_negative {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    };
+                }
+                let (lo1, hi1) = self
+                    .numer
+                    .unsigned_abs()
+                    .widening_mul(other.denom.unsigned_abs());
+                let (lo2, hi2) = other
+                    .numer
+                    .unsigned_abs()
+                    .widening_mul(self.denom.unsigned_abs());
+                let ord = match hi1.cmp(&hi2) {
+                    Ordering::Equal => lo1.cmp(&lo2),
+                    ord => ord,
+                };
+                if self_negative {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            }
+        }
+
+        impl<const N: usize> PartialOrd for Ratio<$BInt<N>> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const N: usize> Ord for Ratio<$BInt<N>> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.cmp_ratio(other)
+            }
+        }
+    };
+}
+
+crate::macro_impl!(cmp);
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use crate::BInt;
+
+    type R = Ratio<BInt<1>>;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_cmp(a: i64, b: i64, c: i64, d: i64) -> quickcheck::TestResult {
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let (x, y) = match (R::checked_new(BInt::from(a), BInt::from(b)), R::checked_new(BInt::from(c), BInt::from(d))) {
+                (Some(x), Some(y)) => (x, y),
+                _ => return quickcheck::TestResult::discard(),
+            };
+            // multiplying `a / b` and `c / d` by the positive `|b * d|` doesn't change their order
+            let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+            let expected = (a * b.signum() * d.abs()).cmp(&(c * d.signum() * b.abs()));
+            quickcheck::TestResult::from_bool(x.cmp(&y) == expected)
+        }
+    }
+
+    #[test]
+    fn cmp() {
+        let min = R::from_integer(BInt::MIN);
+        let max = R::from_integer(BInt::MAX);
+        let tiny = R::new(BInt::ONE, BInt::MAX);
+        assert!(min < max);
+        assert!(R::ZERO < tiny);
+        assert!(tiny.checked_neg().unwrap() < R::ZERO);
+        assert!(
+            R::new(BInt::MAX, BInt::MAX - BInt::ONE)
+                < R::new(BInt::MAX - BInt::ONE, BInt::MAX - BInt::TWO)
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use super::Ratio;
+use crate::cast::{As, CastFrom};
+use crate::ExpType;
+
+/// Rounds `q * 2^exp` to the nearest primitive float, with ties to even, where the most significant bit of `q` is set and its least significant bit is sticky.
+macro_rules! round_to_primitive {
+    ($name: ident, $f: ty, $bits: ty) => {
+        fn $name(negative: bool, q: u64, exp: i64) -> $f {
+            const MB: i64 = <$f>::MANTISSA_DIGITS as i64 - 1;
+            const BIAS: i64 = <$f>::MAX_EXP as i64 - 1;
+
+            /// Returns `2^exp`, where `exp` is in the normal exponent range.
+            fn pow2(exp: i64) -> $f {
+                <$f>::from_bits(((exp + BIAS) as $bits) << MB)
+            }
+
+            let sign = if negative { -1.0 } else { 1.0 };
+            // the exponent of the most significant bit
+            let top = exp + 63;
+            if top > BIAS {
+                return sign * <$f>::INFINITY;
+            }
+            // the number of bits kept by rounding, which is fewer than the precision for subnormal results
+            let keep = if top >= 1 - BIAS {
+                MB + 1
+            } else {
+                MB + 1 - (1 - BIAS - top)
+            };
+            let shift = 64 - keep;
+            if shift > 64 {
+                // `q * 2^exp` is less than a quarter of the smallest subnormal
+                return sign * 0.0;
+            }
+            let q = q as u128;
+            let mut m = q >> shift;
+            let rem = q & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            if rem > half || (rem == half && m & 1 == 1) {
+                m += 1;
+            }
+            // `m` fits in the mantissa so the conversion is exact, and the result is representable, so the scaling is exact; it is split in two so that each power of two is a normal number
+            let scale = exp + shift as i64;
+            sign * (m as $f) * pow2(scale / 2) * pow2(scale - scale / 2)
+        }
+    };
+}
+
+round_to_primitive!(round_to_f32, f32, u32);
+round_to_primitive!(round_to_f64, f64, u64);
+
+macro_rules! convert {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> Ratio<$BInt<N>> {
+            /// Returns `q` and `exp` such that `q * 2^exp` is `a / b` rounded toward zero to `64 * M` significant bits, with the least significant bit of `q` set if this is inexact. Setting this sticky bit means that `q * 2^exp` rounds to the same value as `a / b` at any precision of at most `64 * M - 2` bits. `a` and `b` must be non-zero.
+            fn quotient_bits<const M: usize>(a: $BUint<N>, b: $BUint<N>) -> (crate::BUint<M>, i64) {
+                let mut rem = $BUint::<N>::ZERO;
+                let mut q = crate::BUint::<M>::ZERO;
+                // the index of the next bit of `a` to bring down, which is negative for the bits after the binary point
+                let mut i = a.bits() as i64 - 1;
+                loop {
+                    // `rem < b`, and `b` is the magnitude of a signed integer, so this can't overflow
+                    rem = rem.shl(1);
+                    if i >= 0 && a.bit(i as ExpType) {
+                        rem = rem.bitor($BUint::ONE);
+                    }
+                    q = q.shl(1);
+                    if rem.ge(&b) {
+                        rem = rem.wrapping_sub(b);
+                        q = q.bitor(crate::BUint::ONE);
+                    }
+                    if q.bit(crate::BUint::<M>::BITS - 1) {
+                        break;
+                    }
+                    i -= 1;
+                }
+                // the bits of `a` below index `i` haven't been brought down
+                if !rem.is_zero() || (i > 0 && (a.trailing_zeros() as i64) < i) {
+                    q = q.bitor(crate::BUint::ONE);
+                }
+                (q, i)
+            }
+
+            /// Returns the ratio equal to `(-1)^negative * mant * 2^exp`, or `None` if it is out of range.
+            fn from_binary<const M: usize>(
+                negative: bool,
+                mant: crate::BUint<M>,
+                exp: i64,
+            ) -> Option<Self> {
+                if mant.is_zero() {
+                    return Some(Self::ZERO);
+                }
+                let tz = mant.trailing_zeros();
+                // `mant` is now odd, so it is coprime to any power of two
+                let mant = mant.shr(tz);
+                let exp = exp + tz as i64;
+                let bits = mant.bits() as i64;
+                let max_bits = $BUint::<N>::BITS as i64;
+                if exp >= 0 {
+                    if bits + exp > max_bits {
+                        return None;
+                    }
+                    Self::from_reduced(
+                        negative,
+                        mant.as_::<$BUint<N>>().shl(exp as ExpType),
+                        $BUint::ONE,
+                    )
+                } else {
+                    // the denominator must be positive, so it can have at most `BITS - 2` trailing zeros
+                    if bits > max_bits || -exp > max_bits - 2 {
+                        return None;
+                    }
+                    Self::from_reduced(negative, mant.as_(), $BUint::power_of_two(-exp as ExpType))
+                }
+            }
+
+            /// Converts a primitive `f32` to the ratio which is exactly equal to it. Returns `None` if `f` is infinite or NaN, or if the ratio is out of range.
+            #[must_use]
+            #[inline]
+            pub fn from_f32(f: f32) -> Option<Self> {
+                Self::from_f64(f as f64)
+            }
+
+            /// Converts a primitive `f64` to the ratio which is exactly equal to it. Returns `None` if `f` is infinite or NaN, or if the ratio is out of range.
+            #[must_use]
+            pub fn from_f64(f: f64) -> Option<Self> {
+                if !f.is_finite() {
+                    return None;
+                }
+                const MB: u32 = f64::MANTISSA_DIGITS - 1;
+                let bits = f.to_bits();
+                let biased_exp = ((bits >> MB) & 0x7FF) as i64;
+                let mant = bits & ((1 << MB) - 1);
+                let (mant, exp) = if biased_exp == 0 {
+                    (mant, -1074)
+                } else {
+                    (mant | 1 << MB, biased_exp - 1075)
+                };
+                Self::from_binary(
+                    f.is_sign_negative(),
+                    crate::BUint::<1>::from_digit(mant),
+                    exp,
+                )
+            }
+
+            /// Converts a [`Float`](crate::Float) to the ratio which is exactly equal to it. Returns `None` if `f` is infinite or NaN, or if the ratio is out of range.
+            #[cfg(feature = "nightly")]
+            #[must_use]
+            pub fn from_float<const W: usize, const MB: usize>(
+                f: crate::Float<W, MB>,
+            ) -> Option<Self>
+            where
+                crate::float::storage::Width<W>: crate::float::storage::Storage,
+                [(); (W + 3) / 4]:,
+            {
+                if !f.is_finite() {
+                    return None;
+                }
+                let (negative, exp, mant) =
+                    crate::float::extended::Extended::<{ (W + 3) / 4 }>::from_float(f).to_parts();
+                Self::from_binary(negative, mant, exp)
+            }
+        }
+
+        impl<const N: usize> From<$BInt<N>> for Ratio<$BInt<N>> {
+            #[inline]
+            fn from(int: $BInt<N>) -> Self {
+                Self::from_integer(int)
+            }
+        }
+
+        impl<const N: usize> CastFrom<Ratio<$BInt<N>>> for f32 {
+            /// Converts the ratio to the nearest `f32`, with ties to even.
+            fn cast_from(from: Ratio<$BInt<N>>) -> Self {
+                if from.is_zero() {
+                    return 0.0;
+                }
+                let (q, exp) = Ratio::<$BInt<N>>::quotient_bits::<1>(
+                    from.numer.unsigned_abs(),
+                    from.denom.unsigned_abs(),
+                );
+                round_to_f32(from.is_negative(), q.digits()[0], exp)
+            }
+        }
+
+        impl<const N: usize> CastFrom<Ratio<$BInt<N>>> for f64 {
+            /// Converts the ratio to the nearest `f64`, with ties to even.
+            fn cast_from(from: Ratio<$BInt<N>>) -> Self {
+                if from.is_zero() {
+                    return 0.0;
+                }
+                let (q, exp) = Ratio::<$BInt<N>>::quotient_bits::<1>(
+                    from.numer.unsigned_abs(),
+                    from.denom.unsigned_abs(),
+                );
+                round_to_f64(from.is_negative(), q.digits()[0], exp)
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<const N: usize, const W: usize, const MB: usize> CastFrom<Ratio<$BInt<N>>>
+            for crate::Float<W, MB>
+        where
+            crate::float::storage::Width<W>: crate::float::storage::Storage,
+            [(); (W + 3) / 4]:,
+        {
+            /// Converts the ratio to the nearest `Float`, with ties to even.
+            fn cast_from(from: Ratio<$BInt<N>>) -> Self {
+                if from.is_zero() {
+                    return Self::ZERO;
+                }
+                let (q, exp) = Ratio::<$BInt<N>>::quotient_bits::<{ (W + 3) / 4 }>(
+                    from.numer.unsigned_abs(),
+                    from.denom.unsigned_abs(),
+                );
+                crate::float::extended::Extended::normalised(from.is_negative(), exp, q).to_float()
+            }
+        }
+    };
+}
+
+crate::macro_impl!(convert);
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use crate::cast::As;
+    use crate::{BInt, BIntD8};
+
+    type R = Ratio<BInt<2>>;
+
+    fn r(numer: i128, denom: i128) -> R {
+        R::new(BInt::from(numer), BInt::from(denom))
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_f64_round_trip(f: f64) -> quickcheck::TestResult {
+            // a 128 bit ratio can represent `f64`s with exponents from about -126 to 63
+            if !f.is_finite() || f.abs() > 1e18 || (f != 0.0 && f.abs() < 1e-20) {
+                return quickcheck::TestResult::discard();
+            }
+            let a = R::from_f64(f).unwrap();
+            quickcheck::TestResult::from_bool(a.as_::<f64>() == f && a.as_::<f32>() == f as f32)
+        }
+
+        fn quickcheck_to_f64(numer: i64, denom: i64) -> quickcheck::TestResult {
+            if denom == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let a = r(numer as i128, denom as i128);
+            // the quotient of two integers which are exactly representable is correctly rounded
+            let (numer, denom) = (numer >> 11, denom >> 11);
+            if denom == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let b = r(numer as i128, denom as i128);
+            quickcheck::TestResult::from_bool(b.as_::<f64>() == numer as f64 / denom as f64 && a.as_::<f64>().is_finite())
+        }
+    }
+
+    #[test]
+    fn to_primitive() {
+        assert_eq!(r(1, 3).as_::<f64>(), 1.0 / 3.0);
+        assert_eq!(r(-2, 3).as_::<f32>(), -2.0 / 3.0);
+        assert_eq!(R::ZERO.as_::<f64>(), 0.0);
+        assert_eq!(R::from_integer(BInt::MAX).as_::<f64>(), 2f64.powi(127));
+        assert_eq!(R::from_integer(BInt::MIN).as_::<f32>(), -2f32.powi(127));
+        let max = Ratio::<BInt<3>>::from_integer(BInt::MAX);
+        assert_eq!(max.as_::<f32>(), f32::INFINITY);
+        assert_eq!((-max).as_::<f32>(), f32::NEG_INFINITY);
+        // `(2^53 + 1) / 2^53` is halfway between `1` and the next `f64`, so it rounds to even
+        assert_eq!(r((1 << 53) + 1, 1 << 53).as_::<f64>(), 1.0);
+        assert_eq!(
+            r((1 << 53) + 3, 1 << 53).as_::<f64>(),
+            1.0 + 4.0 * f64::EPSILON / 2.0
+        );
+        // subnormal results are rounded once, to the reduced precision
+        let tiny = Ratio::<BIntD8<256>>::new(BIntD8::ONE, BIntD8::ONE << 1074u32);
+        assert_eq!(tiny.as_::<f64>(), f64::from_bits(1));
+        let half_tiny = Ratio::<BIntD8<256>>::new(BIntD8::ONE, BIntD8::ONE << 1075u32);
+        assert_eq!(half_tiny.as_::<f64>(), 0.0);
+        let three_halves_tiny = Ratio::<BIntD8<256>>::new(BIntD8::THREE, BIntD8::ONE << 1075u32);
+        assert_eq!(three_halves_tiny.as_::<f64>(), f64::from_bits(2));
+        let above_half_tiny = Ratio::<BIntD8<256>>::new(
+            (BIntD8::ONE << 100u32) + BIntD8::ONE,
+            BIntD8::ONE << 1175u32,
+        );
+        assert_eq!(above_half_tiny.as_::<f64>(), f64::from_bits(1));
+        assert_eq!(
+            Ratio::<BIntD8<256>>::from_f64(f64::MIN_POSITIVE / 3.0)
+                .unwrap()
+                .as_::<f64>(),
+            f64::MIN_POSITIVE / 3.0
+        );
+    }
+
+    #[test]
+    fn from_primitive() {
+        assert_eq!(R::from_f64(0.75), Some(r(3, 4)));
+        assert_eq!(
+            R::from_f64(-0.1),
+            Some(r(-3602879701896397, 36028797018963968))
+        );
+        assert_eq!(R::from_f32(-0.0), Some(R::ZERO));
+        assert_eq!(R::from_f64(f64::NAN), None);
+        assert_eq!(R::from_f64(f64::INFINITY), None);
+        assert_eq!(
+            R::from_f64(2f64.powi(126)),
+            Some(R::from_integer(BInt::ONE.shl(126)))
+        );
+        assert_eq!(R::from_f64(2f64.powi(127)), None);
+        assert_eq!(
+            R::from_f64(-2f64.powi(127)),
+            Some(R::from_integer(BInt::MIN))
+        );
+        assert_eq!(R::from_f64(2f64.powi(-126)), Some(r(1, 1 << 126)));
+        assert_eq!(R::from_f64(2f64.powi(-127)), None);
+        assert_eq!(R::from_f64(f64::MIN_POSITIVE), None);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn float() {
+        type F = crate::float::Float<8, 52>;
+
+        let a = r(1, 10);
+        assert_eq!(
+            a.as_::<F>().to_bits(),
+            crate::BUintD8::<8>::from(0.1f64.to_bits())
+        );
+        assert_eq!(R::from_float(F::from(0.1f64)), R::from_f64(0.1));
+        assert_eq!(R::from_float(F::NAN), None);
+        let f = F::from(-1.5e-7f64);
+        assert_eq!(R::from_float(f).unwrap().as_::<F>(), f);
+    }
+}
@@ -0,0 +1,115 @@
+use super::Ratio;
+use crate::errors::ParseRatioError;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::num::IntErrorKind;
+use core::str::FromStr;
+
+macro_rules! fmt {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> Display for Ratio<$BInt<N>> {
+            /// Formats the ratio as `numer/denom`, or as `numer` if the denominator is one. The width, fill, alignment and sign flags apply to the whole ratio.
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let magnitude = if self.is_integer() {
+                    format!("{}", self.numer.unsigned_abs())
+                } else {
+                    format!("{}/{}", self.numer.unsigned_abs(), self.denom)
+                };
+                f.pad_integral(!self.is_negative(), "", &magnitude)
+            }
+        }
+
+        impl<const N: usize> Debug for Ratio<$BInt<N>> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Display::fmt(self, f)
+            }
+        }
+
+        impl<const N: usize> FromStr for Ratio<$BInt<N>> {
+            type Err = ParseRatioError;
+
+            /// Parses a string of the form `numer/denom` or `numer`, where `numer` and `denom` are decimal integers with an optional sign. The result is reduced to lowest terms.
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                let parse = |s: &str| {
+                    $BInt::<N>::from_str_radix(s, 10)
+                        .map_err(|e| ParseRatioError { kind: *e.kind() })
+                };
+                let (numer, denom) = match src.split_once('/') {
+                    Some((numer, denom)) => (parse(numer)?, parse(denom)?),
+                    None => (parse(src)?, $BInt::ONE),
+                };
+                if denom.is_zero() {
+                    return Err(ParseRatioError {
+                        kind: IntErrorKind::Zero,
+                    });
+                }
+                Self::checked_new(numer, denom).ok_or(ParseRatioError {
+                    // the ratio can only overflow if its denominator is negative
+                    kind: if numer.is_negative() {
+                        IntErrorKind::PosOverflow
+                    } else {
+                        IntErrorKind::NegOverflow
+                    },
+                })
+            }
+        }
+    };
+}
+
+crate::macro_impl!(fmt);
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use crate::BInt;
+    use alloc::string::ToString;
+    use core::num::IntErrorKind;
+    use core::str::FromStr;
+
+    type R = Ratio<BInt<1>>;
+
+    fn kind(src: &str) -> IntErrorKind {
+        *R::from_str(src).unwrap_err().kind()
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: R) -> bool {
+            R::from_str(&a.to_string()) == Ok(a)
+        }
+    }
+
+    #[test]
+    fn display() {
+        let r = |n: i64, d: i64| R::new(BInt::from(n), BInt::from(d));
+        assert_eq!(r(3, 4).to_string(), "3/4");
+        assert_eq!(r(6, -8).to_string(), "-3/4");
+        assert_eq!(r(-10, 5).to_string(), "-2");
+        assert_eq!(R::ZERO.to_string(), "0");
+        assert_eq!(format!("{:>6}", r(1, 2)), "   1/2");
+        assert_eq!(format!("{:+}", r(1, 2)), "+1/2");
+        assert_eq!(format!("{:06}", r(-1, 2)), "-001/2");
+        assert_eq!(format!("{:?}", r(-1, 2)), "-1/2");
+    }
+
+    #[test]
+    fn from_str() {
+        let r = |n: i64, d: i64| R::new(BInt::from(n), BInt::from(d));
+        assert_eq!(R::from_str("3/4"), Ok(r(3, 4)));
+        assert_eq!(R::from_str("-6/8"), Ok(r(-3, 4)));
+        assert_eq!(R::from_str("6/-8"), Ok(r(-3, 4)));
+        assert_eq!(R::from_str("+7"), Ok(r(7, 1)));
+        assert_eq!(
+            R::from_str("-9223372036854775808/-9223372036854775808"),
+            Ok(R::ONE)
+        );
+        assert_eq!(kind("-9223372036854775808/-1"), IntErrorKind::PosOverflow);
+        assert_eq!(kind("1/-9223372036854775808"), IntErrorKind::NegOverflow);
+        assert_eq!(kind("9223372036854775808/2"), IntErrorKind::PosOverflow);
+        assert_eq!(kind("1/0"), IntErrorKind::Zero);
+        assert_eq!(kind(""), IntErrorKind::Empty);
+        assert_eq!(kind("1/"), IntErrorKind::Empty);
+        assert_eq!(kind("/"), IntErrorKind::Empty);
+        for src in ["1/2/3", "1.5", "1 / 2", "a/b"] {
+            assert_eq!(kind(src), IntErrorKind::InvalidDigit, "{}", src);
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//! An exact rational number type.
+
+mod checked;
+mod cmp;
+mod convert;
+mod fmt;
+mod ops;
+
+/// A rational number `numer / denom`.
+///
+/// `Ratio` is implemented for the signed bnum integer types. A `Ratio` is always stored in lowest terms with a positive denominator, so two ratios are equal if and only if their numerators and denominators are equal. All arithmetic is exact: the `checked_` methods return `None` if the result (or, for addition and subtraction, an intermediate value) can't be represented, and the arithmetic operators panic in this case.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T,
+}
+
+macro_rules! ratio {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> Ratio<$BInt<N>> {
+            /// The ratio `0 / 1`.
+            pub const ZERO: Self = Self::from_integer($BInt::ZERO);
+
+            /// The ratio `1 / 1`.
+            pub const ONE: Self = Self::from_integer($BInt::ONE);
+
+            /// Creates a ratio equal to the integer `int`.
+            #[must_use]
+            #[inline]
+            pub const fn from_integer(int: $BInt<N>) -> Self {
+                Self {
+                    numer: int,
+                    denom: $BInt::ONE,
+                }
+            }
+
+            /// Creates the ratio `numer / denom`, reduced to lowest terms.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `denom` is zero, or if the reduced ratio can't be represented with a positive denominator, which can only happen if `denom` is negative and the reduced numerator or denominator is `MIN`.
+            #[must_use]
+            #[inline]
+            pub const fn new(numer: $BInt<N>, denom: $BInt<N>) -> Self {
+                if denom.is_zero() {
+                    crate::errors::div_zero!()
+                }
+                crate::errors::option_expect!(
+                    Self::checked_new(numer, denom),
+                    crate::errors::err_msg!("attempt to create ratio with overflow")
+                )
+            }
+
+            /// Creates the ratio `numer / denom`, reduced to lowest terms, returning `None` if `denom` is zero or if the reduced ratio can't be represented with a positive denominator.
+            #[must_use]
+            #[inline]
+            pub const fn checked_new(numer: $BInt<N>, denom: $BInt<N>) -> Option<Self> {
+                if denom.is_zero() {
+                    return None;
+                }
+                Self::from_magnitudes(
+                    numer.is_negative() != denom.is_negative(),
+                    numer.unsigned_abs(),
+                    denom.unsigned_abs(),
+                )
+            }
+
+            /// Returns `(-1)^negative * numer / denom` reduced to lowest terms, or `None` if it is out of range. `denom` must be non-zero.
+            const fn from_magnitudes(
+                negative: bool,
+                numer: $BUint<N>,
+                denom: $BUint<N>,
+            ) -> Option<Self> {
+                let gcd = numer.gcd(denom);
+                Self::from_reduced(negative, numer.div_rem(gcd).0, denom.div_rem(gcd).0)
+            }
+
+            /// Returns `(-1)^negative * numer / denom`, or `None` if it is out of range. `numer` and `denom` must be coprime.
+            const fn from_reduced(
+                negative: bool,
+                numer: $BUint<N>,
+                denom: $BUint<N>,
+            ) -> Option<Self> {
+                if numer.is_zero() {
+                    return Some(Self::ZERO);
+                }
+                let denom = denom.cast_signed();
+                if denom.is_negative() {
+                    return None;
+                }
+                let numer = if negative {
+                    // the magnitude of `MIN` is one greater than the magnitude of `MAX`
+                    if numer.gt(&$BInt::<N>::MIN.unsigned_abs()) {
+                        return None;
+                    }
+                    numer.cast_signed().wrapping_neg()
+                } else {
+                    let numer = numer.cast_signed();
+                    if numer.is_negative() {
+                        return None;
+                    }
+                    numer
+                };
+                Some(Self { numer, denom })
+            }
+
+            /// Returns the numerator of `self` in lowest terms.
+            #[must_use]
+            #[inline(always)]
+            pub const fn numer(&self) -> $BInt<N> {
+                self.numer
+            }
+
+            /// Returns the denominator of `self` in lowest terms, which is always positive.
+            #[must_use]
+            #[inline(always)]
+            pub const fn denom(&self) -> $BInt<N> {
+                self.denom
+            }
+
+            /// Returns `true` if `self` is zero.
+            #[must_use]
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.numer.is_zero()
+            }
+
+            /// Returns `true` if `self` is an integer, i.e. its denominator is one.
+            #[must_use]
+            #[inline]
+            pub const fn is_integer(&self) -> bool {
+                self.denom.eq(&$BInt::ONE)
+            }
+
+            /// Returns `true` if `self` is negative and `false` if it is zero or positive.
+            #[must_use]
+            #[inline]
+            pub const fn is_negative(&self) -> bool {
+                self.numer.is_negative()
+            }
+
+            /// Returns `true` if `self` is positive and `false` if it is zero or negative.
+            #[must_use]
+            #[inline]
+            pub const fn is_positive(&self) -> bool {
+                self.numer.is_positive()
+            }
+
+            /// Returns the integer part of `self`, rounding toward zero.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn trunc(self) -> $BInt<N> {
+                self.numer.wrapping_div(self.denom)
+            }
+
+            /// Returns the fractional part of `self`, `self - self.trunc()`, which has the same sign as `self`.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn fract(self) -> Self {
+                // `numer % denom` is coprime to `denom`, since `numer` is
+                let numer = self.numer.wrapping_rem(self.denom);
+                if numer.is_zero() {
+                    Self::ZERO
+                } else {
+                    Self {
+                        numer,
+                        denom: self.denom,
+                    }
+                }
+            }
+
+            /// Returns the largest integer less than or equal to `self`.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn floor(self) -> $BInt<N> {
+                // the quotient can't overflow when it is adjusted, since it is only adjusted when `denom` is greater than one
+                let trunc = self.trunc();
+                if self.fract().is_negative() {
+                    trunc.sub($BInt::ONE)
+                } else {
+                    trunc
+                }
+            }
+
+            /// Returns the smallest integer greater than or equal to `self`.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn ceil(self) -> $BInt<N> {
+                let trunc = self.trunc();
+                if self.fract().is_positive() {
+                    trunc.add($BInt::ONE)
+                } else {
+                    trunc
+                }
+            }
+
+            /// Returns the nearest integer to `self`, rounding half-way cases away from zero.
+            #[must_use = crate::doc::must_use_op!()]
+            #[inline]
+            pub const fn round(self) -> $BInt<N> {
+                let trunc = self.trunc();
+                let rem = self.fract().numer.unsigned_abs();
+                // `|fract| >= 1/2` if and only if `|rem| >= denom - |rem|`
+                if rem.lt(&self.denom.unsigned_abs().wrapping_sub(rem)) {
+                    trunc
+                } else if self.is_negative() {
+                    trunc.sub($BInt::ONE)
+                } else {
+                    trunc.add($BInt::ONE)
+                }
+            }
+
+            /// Returns the closest ratio to `self` whose denominator is at most `max_denominator`.
+            ///
+            /// The result is found using the continued fraction expansion of `self`: it is either a convergent or a semiconvergent of `self`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `max_denominator` is not positive.
+            #[must_use = crate::doc::must_use_op!()]
+            pub const fn approximate(self, max_denominator: $BInt<N>) -> Self {
+                assert!(
+                    max_denominator.is_positive(),
+                    crate::errors::err_msg!("`max_denominator` must be positive")
+                );
+                let max = max_denominator.unsigned_abs();
+                let denom = self.denom.unsigned_abs();
+                if denom.le(&max) {
+                    return self;
+                }
+                // the convergents `p0 / q0` and `p1 / q1` of `|self|`, and the remainders `n` and `d` of the Euclidean algorithm, for which `|p0 * denom - q0 * numer| = n` and `|p1 * denom - q1 * numer| = d`
+                let (mut p0, mut q0, mut p1, mut q1) =
+                    ($BUint::ZERO, $BUint::ONE, $BUint::ONE, $BUint::ZERO);
+                let (mut n, mut d) = (self.numer.unsigned_abs(), denom);
+                // the loop always terminates before `d` is zero, since the last convergent is `|self|`, whose denominator is greater than `max`
+                loop {
+                    let (a, r) = n.div_rem(d);
+                    let q2 = match a.checked_mul(q1) {
+                        Some(aq) => match aq.checked_add(q0) {
+                            Some(q2) if q2.le(&max) => q2,
+                            _ => break,
+                        },
+                        None => break,
+                    };
+                    // convergents are in lowest terms and are at most `|self|` rounded up, so `p2` can't overflow
+                    let p2 = p0.add(a.mul(p1));
+                    p0 = p1;
+                    q0 = q1;
+                    p1 = p2;
+                    q1 = q2;
+                    n = d;
+                    d = r;
+                }
+                // the semiconvergent `(p0 + k * p1) / (q0 + k * q1)` with the largest denominator at most `max`, where `k` is less than the next partial quotient `n / d`
+                let k = max.wrapping_sub(q0).div_rem(q1).0;
+                let semi_denom = q0.add(k.mul(q1));
+                let semi_numer = p0.add(k.mul(p1));
+                // the distances of the convergent and semiconvergent from `|self|` are `d / (q1 * denom)` and `(n - k * d) / (semi_denom * denom)` respectively
+                let (lo1, hi1) = d.widening_mul(semi_denom);
+                let (lo2, hi2) = n.wrapping_sub(k.mul(d)).widening_mul(q1);
+                let (numer, denom) = if hi1.lt(&hi2) || (hi1.eq(&hi2) && lo1.le(&lo2)) {
+                    (p1, q1)
+                } else {
+                    (semi_numer, semi_denom)
+                };
+                // both are in lowest terms, and their magnitudes are at most those of `self`
+                let numer = if self.is_negative() {
+                    numer.cast_signed().wrapping_neg()
+                } else {
+                    numer.cast_signed()
+                };
+                Self {
+                    numer,
+                    denom: denom.cast_signed(),
+                }
+            }
+        }
+
+        impl<const N: usize> Default for Ratio<$BInt<N>> {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        #[cfg(any(test, feature = "quickcheck"))]
+        impl<const N: usize> quickcheck::Arbitrary for Ratio<$BInt<N>> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                loop {
+                    let numer = <$BInt<N> as quickcheck::Arbitrary>::arbitrary(g);
+                    let denom = <$BInt<N> as quickcheck::Arbitrary>::arbitrary(g);
+                    if let Some(ratio) = Self::checked_new(numer, denom) {
+                        return ratio;
+                    }
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(ratio);
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use crate::cast::As;
+    use crate::BInt;
+
+    type R = Ratio<BInt<2>>;
+
+    fn r(numer: i128, denom: i128) -> R {
+        R::new(BInt::from(numer), BInt::from(denom))
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_new(numer: i64, denom: i64) -> quickcheck::TestResult {
+            if denom == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let a = r(numer as i128, denom as i128);
+            // cross-multiplication is exact in `i128`
+            let reduced = a.denom().is_positive() && BInt::<2>::from(numer) * a.denom() == BInt::from(denom) * a.numer();
+            quickcheck::TestResult::from_bool(reduced && R::checked_new(a.numer(), a.denom()) == Some(a))
+        }
+
+        fn quickcheck_rounding(numer: i32, denom: i16) -> quickcheck::TestResult {
+            if denom == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let a = r(numer as i128, denom as i128);
+            // a non-integer quotient is at least `2^-15` away from an integer, which is much more than the rounding error of `x`, and a half-way quotient is exact
+            let x = numer as f64 / denom as f64;
+            let b = |f: f64| BInt::<2>::from(f as i64);
+            quickcheck::TestResult::from_bool(
+                a.floor() == b(x.floor()) && a.ceil() == b(x.ceil()) && a.round() == b(x.round()) && a.trunc() == b(x.trunc())
+            )
+        }
+
+        fn quickcheck_approximate(numer: i64, denom: i64, max: u8) -> quickcheck::TestResult {
+            if denom == 0 || max == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let (numer, denom) = if denom < 0 { (-(numer as i128), -(denom as i128)) } else { (numer as i128, denom as i128) };
+            let a = r(numer, denom).approximate(BInt::from(max));
+            let (p, q): (i128, i128) = (a.numer().as_(), a.denom().as_());
+            // the distance of `p / q` from `numer / denom` is `error(p, q) / (q * denom)`
+            let error = |p: i128, q: i128| (p * denom - numer * q).abs();
+            // the best approximation with denominator `q` has numerator `floor(numer * q / denom)` or one more than it
+            let best = (1..=max as i128)
+                .flat_map(|q| [(numer * q).div_euclid(denom), (numer * q).div_euclid(denom) + 1].map(|p| (p, q)))
+                .min_by(|&(p1, q1), &(p2, q2)| (error(p1, q1) * q2).cmp(&(error(p2, q2) * q1)))
+                .unwrap();
+            quickcheck::TestResult::from_bool(q <= max as i128 && error(p, q) * best.1 == error(best.0, best.1) * q)
+        }
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(r(6, -4), r(-3, 2));
+        assert_eq!(r(-6, -4).numer(), BInt::from(3));
+        assert_eq!(r(0, -5), R::ZERO);
+        assert_eq!(R::checked_new(BInt::ONE, BInt::ZERO), None);
+        assert_eq!(R::checked_new(BInt::ONE, BInt::MIN), None);
+        assert_eq!(R::checked_new(BInt::MIN, BInt::NEG_ONE), None);
+        assert_eq!(R::checked_new(BInt::MIN, BInt::MIN), Some(R::ONE));
+        assert_eq!(
+            R::checked_new(BInt::MIN, BInt::ONE).map(|a| a.numer()),
+            Some(BInt::MIN)
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(r(7, 2).round(), BInt::from(4));
+        assert_eq!(r(-7, 2).round(), BInt::from(-4));
+        assert_eq!(r(5, 2).round(), BInt::from(3));
+        assert_eq!(r(-7, 3).floor(), BInt::from(-3));
+        assert_eq!(r(-7, 3).ceil(), BInt::from(-2));
+        assert_eq!(r(-7, 3).fract(), r(-1, 3));
+        assert_eq!(
+            R::new(BInt::MIN, BInt::from(3)).floor(),
+            BInt::MIN / BInt::from(3) - BInt::ONE
+        );
+    }
+
+    #[test]
+    fn approximate() {
+        let pi = r(3141592653589793, 1000000000000000);
+        assert_eq!(pi.approximate(BInt::from(10)), r(22, 7));
+        assert_eq!(pi.approximate(BInt::from(100)), r(311, 99));
+        assert_eq!(pi.approximate(BInt::from(1000)), r(355, 113));
+        assert_eq!(pi.approximate(BInt::ONE), r(3, 1));
+        assert_eq!(r(-1, 3).approximate(BInt::ONE), R::ZERO);
+        assert_eq!(r(-2, 3).approximate(BInt::ONE), r(-1, 1));
+        assert_eq!(r(1, 2).approximate(BInt::ONE), R::ZERO);
+        assert_eq!(r(3, 7).approximate(BInt::from(7)), r(3, 7));
+    }
+}
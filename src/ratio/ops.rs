@@ -0,0 +1,100 @@
+use super::Ratio;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+macro_rules! op_trait {
+    ($BInt: ident; $($Trait: ident, $method: ident, $AssignTrait: ident, $assign: ident, $checked: ident, $msg: literal); *) => {
+        $(
+            impl<const N: usize> $Trait for Ratio<$BInt<N>> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    crate::errors::option_expect!(self.$checked(rhs), crate::errors::err_msg!($msg))
+                }
+            }
+
+            impl<const N: usize> $AssignTrait for Ratio<$BInt<N>> {
+                #[inline]
+                fn $assign(&mut self, rhs: Self) {
+                    *self = $Trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! ops {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        op_trait!(
+            $BInt;
+            Add, add, AddAssign, add_assign, checked_add, "attempt to add with overflow";
+            Sub, sub, SubAssign, sub_assign, checked_sub, "attempt to subtract with overflow";
+            Mul, mul, MulAssign, mul_assign, checked_mul, "attempt to multiply with overflow"
+        );
+
+        impl<const N: usize> Div for Ratio<$BInt<N>> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                if rhs.is_zero() {
+                    crate::errors::div_zero!()
+                }
+                crate::errors::option_expect!(self.checked_div(rhs), crate::errors::err_msg!("attempt to divide with overflow"))
+            }
+        }
+
+        impl<const N: usize> DivAssign for Ratio<$BInt<N>> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = Div::div(*self, rhs);
+            }
+        }
+
+        impl<const N: usize> Neg for Ratio<$BInt<N>> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                crate::errors::option_expect!(self.checked_neg(), crate::errors::err_msg!("attempt to negate with overflow"))
+            }
+        }
+    };
+}
+
+crate::macro_impl!(ops);
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use crate::BIntD8;
+
+    type R = Ratio<BIntD8<4>>;
+
+    fn r(numer: i32, denom: i32) -> R {
+        R::new(BIntD8::from(numer), BIntD8::from(denom))
+    }
+
+    #[test]
+    fn ops() {
+        let mut x = r(1, 2) + r(1, 3);
+        assert_eq!(x, r(5, 6));
+        x -= r(1, 6);
+        x *= r(-3, 4);
+        assert_eq!(x, r(-1, 2));
+        x /= r(1, 4);
+        assert_eq!(-x, r(2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = R::ONE / R::ZERO;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_overflow() {
+        let _ = R::from_integer(BIntD8::MAX) + R::ONE;
+    }
+}
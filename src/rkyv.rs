@@ -0,0 +1,91 @@
+use crate::endian::{Integer, LittleEndian};
+use ::rkyv::bytecheck::CheckBytes;
+use ::rkyv::rancor::Fallible;
+use ::rkyv::traits::{CopyOptimization, NoUndef, Portable};
+use ::rkyv::{Archive, Deserialize, Place, Serialize};
+
+// SAFETY: `LittleEndian<T>` is an array of bytes, so it has no padding and the same layout on every target
+unsafe impl<T: Integer> Portable for LittleEndian<T> {}
+
+// SAFETY: `LittleEndian<T>` is an array of bytes, so it has no padding
+unsafe impl<T: Integer> NoUndef for LittleEndian<T> {}
+
+// SAFETY: every bit pattern of a `LittleEndian<T>` is a valid integer
+unsafe impl<T: Integer, C: Fallible + ?Sized> CheckBytes<C> for LittleEndian<T> {
+    #[inline]
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl<T: Integer, D: Fallible + ?Sized> Deserialize<T, D> for LittleEndian<T> {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<T, D::Error> {
+        Ok(self.get())
+    }
+}
+
+macro_rules! archive {
+    ($Int: ident) => {
+        impl<const N: usize> Archive for $Int<N> {
+            // SAFETY: on little endian targets, the archived bytes are the same as the bytes of the integer
+            const COPY_OPTIMIZATION: CopyOptimization<Self> =
+                unsafe { CopyOptimization::enable_if(cfg!(target_endian = "little")) };
+
+            type Archived = LittleEndian<Self>;
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+                out.write(LittleEndian::new(*self));
+            }
+        }
+
+        impl<S: Fallible + ?Sized, const N: usize> Serialize<S> for $Int<N> {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+    };
+}
+
+macro_rules! rkyv {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        archive!($BUint);
+        archive!($BInt);
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use crate::test::types::big_types::$Digit::*;
+                use crate::test::types::{itest, utest};
+                use ::rkyv::rancor::Error;
+                use ::rkyv::Archived;
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_round_trip(a: utest, b: itest) -> bool {
+                        let value: (UTEST, ITEST) = (a.into(), b.into());
+                        let bytes = ::rkyv::to_bytes::<Error>(&value).unwrap();
+                        let archived = ::rkyv::access::<Archived<(UTEST, ITEST)>, Error>(&bytes).unwrap();
+                        archived.0.as_bytes() == a.to_le_bytes()
+                            && archived.1.as_bytes() == b.to_le_bytes()
+                            && archived.0 == value.0
+                            && archived.1 == value.1
+                            && archived.1 <= value.1
+                            && ::rkyv::deserialize::<(UTEST, ITEST), Error>(archived).unwrap() == value
+                    }
+                }
+
+                #[test]
+                fn truncated() {
+                    let bytes = ::rkyv::to_bytes::<Error>(&UTEST::MAX).unwrap();
+                    assert_eq!(bytes.len(), UTEST::BYTES as usize);
+                    assert!(::rkyv::access::<Archived<UTEST>, Error>(&bytes[1..]).is_err());
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(rkyv);
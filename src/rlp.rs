@@ -0,0 +1,76 @@
+use ::rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+macro_rules! rlp {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        /// The integer is encoded as its big-endian bytes with leading zeros removed, so zero is encoded as the empty string.
+        impl<const N: usize> Encodable for $BUint<N> {
+            fn rlp_append(&self, s: &mut RlpStream) {
+                let len = (self.bits() as usize + 7) / 8;
+                let bytes = (0..len).rev().map(|i| {
+                    let bit = i * 8;
+                    (self.digits[bit / $Digit::BITS as usize] >> (bit % $Digit::BITS as usize)) as u8
+                });
+                s.encoder().encode_iter(bytes);
+            }
+        }
+
+        /// Only canonical encodings are accepted: decoding fails if the bytes have leading zeros, if the length prefix is longer than necessary, or if the value is too large to fit in the integer.
+        impl<const N: usize> Decodable for $BUint<N> {
+            fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+                rlp.decoder().decode_value(|bytes| {
+                    if bytes.first() == Some(&0) {
+                        return Err(DecoderError::RlpInvalidIndirection);
+                    }
+                    // strings shorter than 56 bytes must have a single byte prefix
+                    if bytes.len() < 56 && rlp.as_raw()[0] > 0xb7 {
+                        return Err(DecoderError::RlpInvalidIndirection);
+                    }
+                    Self::from_be_slice(bytes).ok_or(DecoderError::RlpIsTooBig)
+                })
+            }
+        }
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use crate::test::types::big_types::$Digit::*;
+                use crate::test::types::utest;
+                use crate::$BUint;
+                use ::rlp::DecoderError;
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_encode(a: utest, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % utest::BITS);
+                        let x: UTEST = a.into();
+                        let bytes = ::rlp::encode(&x);
+                        bytes == ::rlp::encode(&a) && ::rlp::decode::<UTEST>(&bytes) == Ok(x)
+                    }
+
+                    fn quickcheck_encode_long(a: $BUint<{ 512 / $Digit::BITS as usize }>) -> bool {
+                        ::rlp::decode(&::rlp::encode(&a)) == Ok(a)
+                    }
+                }
+
+                #[test]
+                fn decode_invalid() {
+                    let decode = |bytes: &[u8]| ::rlp::decode::<UTEST>(bytes);
+                    assert_eq!(decode(&[0x80]), Ok(UTEST::ZERO));
+                    assert_eq!(decode(&[0x00]), Err(DecoderError::RlpInvalidIndirection));
+                    assert_eq!(decode(&[0x82, 0x00, 0x01]), Err(DecoderError::RlpInvalidIndirection));
+                    assert_eq!(decode(&[0x81, 0x05]), Err(DecoderError::RlpInvalidIndirection));
+                    assert_eq!(decode(&[0xb8, 0x01, 0xff]), Err(DecoderError::RlpInvalidIndirection));
+                    assert_eq!(decode(&[0xc1, 0x01]), Err(DecoderError::RlpExpectedToBeData));
+
+                    let mut too_big = vec![0x80 + UTEST::BYTES as u8 + 1];
+                    too_big.extend(core::iter::repeat(0xff).take(UTEST::BYTES as usize + 1));
+                    assert_eq!(decode(&too_big), Err(DecoderError::RlpIsTooBig));
+                    assert_eq!(decode(&too_big[..too_big.len() - 1]), Err(DecoderError::RlpInconsistentLengthAndData));
+                    too_big[0] -= 1;
+                    assert_eq!(decode(&too_big[..too_big.len() - 1]), Ok(UTEST::MAX));
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(rlp);
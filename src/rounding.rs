@@ -0,0 +1,92 @@
+//! The rounding modes and exception flags shared by `Float` and `Decimal`.
+
+use crate::doc;
+use core::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// Determines how the result of a `Float` or [`Decimal`](crate::Decimal) operation is rounded when it cannot be represented exactly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest representable value. If the result lies exactly halfway between two representable values, round to the one with an even least significant bit. This is the rounding mode used by the arithmetic operators.
+    #[default]
+    NearestTiesToEven,
+    /// Round to the nearest representable value. If the result lies exactly halfway between two representable values, round to the one with the larger magnitude.
+    NearestTiesToAway,
+    /// Round to the nearest representable value which is not larger in magnitude than the exact result.
+    TowardZero,
+    /// Round to the nearest representable value which is not less than the exact result.
+    TowardPositive,
+    /// Round to the nearest representable value which is not greater than the exact result.
+    TowardNegative,
+}
+
+/// A set of IEEE 754 exception flags, returned alongside the result of the `Float` and [`Decimal`](crate::Decimal) methods which take a [`RoundingMode`].
+///
+/// Flags can be combined with the `|` operator and tested for with [`contains`](Self::contains).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Status(u8);
+
+impl Status {
+    /// No flags are raised: the result is exact.
+    pub const OK: Self = Self(0);
+
+    /// The operation has no meaningful result, for example `0 / 0` or the square root of a negative number, or one of the operands was a signalling NaN. The result is NaN.
+    pub const INVALID_OP: Self = Self(1);
+
+    /// A finite non-zero number was divided by zero. The result is infinite.
+    pub const DIV_BY_ZERO: Self = Self(1 << 1);
+
+    /// The rounded result is too large in magnitude to be represented as a finite number. The result is either infinite or the largest finite value, depending on the rounding mode.
+    pub const OVERFLOW: Self = Self(1 << 2);
+
+    /// The result is tiny (smaller in magnitude than the smallest positive normal number) and inexact. Tininess is detected before rounding.
+    pub const UNDERFLOW: Self = Self(1 << 3);
+
+    /// The result is not equal to the exact result of the operation.
+    pub const INEXACT: Self = Self(1 << 4);
+
+    /// Returns the set of flags which are raised in either `self` or `other`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns `true` if every flag raised in `other` is also raised in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no flags are raised.
+    #[must_use]
+    #[inline]
+    pub const fn is_ok(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for Status {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Status {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for Status {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
@@ -0,0 +1,311 @@
+//! Compact [SCALE](https://docs.rs/parity-scale-codec/latest/parity_scale_codec/) encoding of unsigned integers.
+//!
+//! With the `scale` feature, `BUint` and `BInt` implement `Encode`, `Decode`, `MaxEncodedLen` and `EncodeLike` from [`parity-scale-codec`](https://docs.rs/parity-scale-codec/latest/parity_scale_codec/), and `TypeInfo` from [`scale-info`](https://docs.rs/scale-info/latest/scale_info/). Integers are encoded as their fixed-width little-endian bytes (the two's complement bytes, for signed integers), which does not depend on the digit type.
+//!
+//! The compact encoding of `parity-scale-codec` is only implemented for primitive integers, and its `CompactAs` trait can only map to those, so it can't represent integers wider than `u128`. Instead, `BUint` implements `HasCompact` with [`Compact`] as its compact type, so fields marked `#[codec(compact)]` use the compact encoding. This encodes integers of up to 536 bits, with the same format as the compact encoding of primitive integers. See [`Compact`] for the limits of the compact encoding.
+//!
+//! # Examples
+//!
+//! ```
+//! use bnum::types::U256;
+//! use parity_scale_codec::{Decode, Encode};
+//!
+//! #[derive(Encode, Decode, PartialEq, Debug)]
+//! struct Transfer {
+//!     #[codec(compact)]
+//!     amount: U256,
+//!     fee: U256,
+//! }
+//!
+//! let transfer = Transfer {
+//!     amount: U256::from(1000u16),
+//!     fee: U256::ONE,
+//! };
+//! let bytes = transfer.encode();
+//! assert_eq!(bytes.len(), 2 + 32);
+//! assert_eq!(bytes[..2], [0xa1, 0x0f]); // compact encoding of 1000
+//! assert_eq!(Transfer::decode(&mut &bytes[..]).unwrap(), transfer);
+//! ```
+//!
+use ::parity_scale_codec::{Decode, Encode, EncodeAsRef, EncodeLike, Error, HasCompact, Input, MaxEncodedLen, Output};
+use ::scale_info::{Type, TypeDefCompact, TypeInfo, MetaType};
+
+/// The largest number of bits that can be represented by the compact encoding.
+const MAX_BITS: usize = 536;
+
+const OUT_OF_RANGE: &str = "out of range compact integer value";
+
+/// Wrapper for an unsigned integer which is encoded with the compact encoding. See the [module-level documentation](self) for details.
+///
+/// The compact encoding can only represent integers of up to 536 bits. `HasCompact` is implemented for `BUint`s of every width, but encoding a `Compact<T>` where `T` is wider than 536 bits, or calling its `max_encoded_len`, is a build error. This includes encoding a struct with a `#[codec(compact)]` field of such a type. As the error is only raised when the encoding code is generated, it is reported by `cargo build` but not by `cargo check`.
+///
+/// ```compile_fail
+/// use bnum::types::U1024;
+/// use parity_scale_codec::Encode;
+///
+/// #[derive(Encode)]
+/// struct Wide {
+///     #[codec(compact)]
+///     value: U1024, // U1024 is too wide to be compact encoded
+/// }
+///
+/// let bytes = Wide { value: U1024::ONE }.encode();
+/// ```
+///
+/// Signed integers are not supported, as `parity-scale-codec` only defines the compact encoding for unsigned integers. `BInt` does not implement `HasCompact`, and `Compact<BInt<N>>` does not implement `Encode` or `Decode`.
+///
+/// ```compile_fail
+/// use bnum::scale::Compact;
+/// use bnum::types::I256;
+/// use parity_scale_codec::Encode;
+///
+/// let bytes = Compact(I256::ONE).encode(); // signed integers can't be compact encoded
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Compact<T>(pub T);
+
+/// Reference to an unsigned integer which is encoded with the compact encoding.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CompactRef<'a, T>(pub &'a T);
+
+impl<T> From<T> for Compact<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<'a, T> From<&'a T> for CompactRef<'a, T> {
+    #[inline]
+    fn from(value: &'a T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: TypeInfo + 'static> TypeInfo for Compact<T> {
+    type Identity = Self;
+
+    #[inline]
+    fn type_info() -> Type {
+        TypeDefCompact::new(MetaType::new::<T>()).into()
+    }
+}
+
+/// Returns the length of the compact encoding of an integer with `bits` significant bits.
+#[inline]
+const fn encoded_len(bits: usize) -> usize {
+    match bits {
+        0..=6 => 1,
+        7..=14 => 2,
+        15..=30 => 4,
+        _ => 1 + (bits + 7) / 8,
+    }
+}
+
+macro_rules! scale {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> Compact<$BUint<N>> {
+            const FITS: () = assert!(
+                $BUint::<N>::BITS as usize <= MAX_BITS,
+                "integers wider than 536 bits can't be compact encoded"
+            );
+
+            #[inline]
+            fn le_byte(value: &$BUint<N>, index: usize) -> u8 {
+                let bit = index * 8;
+                if bit >= $BUint::<N>::BITS as usize {
+                    return 0;
+                }
+                (value.digits[bit / $Digit::BITS as usize] >> (bit % $Digit::BITS as usize)) as u8
+            }
+
+            #[inline]
+            fn set_le_byte(value: &mut $BUint<N>, index: usize, byte: u8) -> Result<(), Error> {
+                let bit = index * 8;
+                if bit >= $BUint::<N>::BITS as usize {
+                    return if byte == 0 { Ok(()) } else { Err(OUT_OF_RANGE.into()) };
+                }
+                value.digits[bit / $Digit::BITS as usize] |= (byte as $Digit) << (bit % $Digit::BITS as usize);
+                Ok(())
+            }
+        }
+
+        impl<const N: usize> Encode for CompactRef<'_, $BUint<N>> {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                encoded_len(self.0.bits() as usize)
+            }
+
+            fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+                #[allow(clippy::let_unit_value)]
+                let () = Compact::<$BUint<N>>::FITS;
+
+                let bits = self.0.bits() as usize;
+                if bits <= 30 {
+                    let mut bytes = [0; 4];
+                    for (i, byte) in bytes.iter_mut().enumerate() {
+                        *byte = Compact::<$BUint<N>>::le_byte(self.0, i);
+                    }
+                    let small = u32::from_le_bytes(bytes);
+                    match bits {
+                        0..=6 => dest.push_byte((small as u8) << 2),
+                        7..=14 => dest.write(&(((small as u16) << 2) | 0b01).to_le_bytes()),
+                        _ => dest.write(&((small << 2) | 0b10).to_le_bytes()),
+                    }
+                } else {
+                    let len = (bits + 7) / 8;
+                    dest.push_byte((((len - 4) as u8) << 2) | 0b11);
+                    for i in 0..len {
+                        dest.push_byte(Compact::<$BUint<N>>::le_byte(self.0, i));
+                    }
+                }
+            }
+        }
+
+        impl<const N: usize> EncodeLike for CompactRef<'_, $BUint<N>> {}
+
+        impl<const N: usize> Encode for Compact<$BUint<N>> {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                CompactRef(&self.0).size_hint()
+            }
+
+            #[inline]
+            fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+                CompactRef(&self.0).encode_to(dest)
+            }
+        }
+
+        impl<const N: usize> EncodeLike for Compact<$BUint<N>> {}
+
+        impl<const N: usize> Decode for Compact<$BUint<N>> {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                let prefix = input.read_byte()?;
+                let mut out = $BUint::<N>::ZERO;
+                let min_bits = if prefix & 0b11 == 0b11 {
+                    let len = (prefix >> 2) as usize + 4;
+                    for i in 0..len {
+                        let byte = input.read_byte()?;
+                        if i == len - 1 && byte == 0 {
+                            return Err(OUT_OF_RANGE.into());
+                        }
+                        Self::set_le_byte(&mut out, i, byte)?;
+                    }
+                    31
+                } else {
+                    let mut bytes = [prefix, 0, 0, 0];
+                    let len = 1 << (prefix & 0b11);
+                    input.read(&mut bytes[1..len])?;
+                    let small = u32::from_le_bytes(bytes) >> 2;
+                    for (i, byte) in small.to_le_bytes().into_iter().enumerate() {
+                        Self::set_le_byte(&mut out, i, byte)?;
+                    }
+                    match len {
+                        1 => 0,
+                        2 => 7,
+                        _ => 15,
+                    }
+                };
+                // reject encodings which are longer than necessary
+                if (out.bits() as usize) < min_bits {
+                    return Err(OUT_OF_RANGE.into());
+                }
+                Ok(Self(out))
+            }
+        }
+
+        impl<const N: usize> MaxEncodedLen for Compact<$BUint<N>> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                #[allow(clippy::let_unit_value)]
+                let () = Compact::<$BUint<N>>::FITS;
+
+                encoded_len($BUint::<N>::BITS as usize)
+            }
+        }
+
+        impl<'a, const N: usize> EncodeAsRef<'a, $BUint<N>> for Compact<$BUint<N>> {
+            type RefType = CompactRef<'a, $BUint<N>>;
+        }
+
+        impl<const N: usize> From<Compact<$BUint<N>>> for $BUint<N> {
+            #[inline]
+            fn from(compact: Compact<Self>) -> Self {
+                compact.0
+            }
+        }
+
+        impl<const N: usize> HasCompact for $BUint<N> {
+            type Type = Compact<Self>;
+        }
+
+        #[cfg(test)]
+        paste::paste! {
+            mod [<$Digit _digit_tests>] {
+                use super::Compact;
+                use crate::test::types::big_types::$Digit::*;
+                use crate::test::types::{itest, utest};
+                use crate::$BUint;
+                use ::parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+                #[derive(Encode, Decode, PartialEq, Debug)]
+                struct Fields {
+                    #[codec(compact)]
+                    compact: UTEST,
+                    unsigned: UTEST,
+                    signed: ITEST,
+                }
+
+                quickcheck::quickcheck! {
+                    fn quickcheck_encode(a: utest, b: itest, shift: u8) -> bool {
+                        let a = a >> (shift as u32 % utest::BITS);
+                        let (x, y): (UTEST, ITEST) = (a.into(), b.into());
+                        let fields = Fields { compact: x, unsigned: x, signed: y };
+                        let bytes = fields.encode();
+                        let compact = ::parity_scale_codec::Compact(a).encode();
+                        Compact(x).encode() == compact
+                            && x.encode() == a.encode()
+                            && y.encode() == b.encode()
+                            && bytes == [compact, a.encode(), b.encode()].concat()
+                            && Fields::decode(&mut &bytes[..]) == Ok(fields)
+                    }
+                }
+
+                #[test]
+                fn max_encoded_len() {
+                    assert_eq!(Compact::<UTEST>::max_encoded_len(), ::parity_scale_codec::Compact::<utest>::max_encoded_len());
+                    assert_eq!(UTEST::max_encoded_len(), UTEST::BYTES as usize);
+                    assert_eq!(Compact::<$BUint<1>>::max_encoded_len(), ::parity_scale_codec::Compact::<$Digit>::max_encoded_len());
+                    assert_eq!(Compact(UTEST::MAX).encode().len(), Compact::<UTEST>::max_encoded_len());
+                }
+
+                #[test]
+                fn type_info() {
+                    use ::scale_info::{TypeDef, TypeInfo};
+
+                    assert!(matches!(Compact::<UTEST>::type_info().type_def, TypeDef::Compact(_)));
+                    assert!(matches!(UTEST::type_info().type_def, TypeDef::Composite(_)));
+                }
+
+                #[test]
+                fn decode_invalid() {
+                    let decode = |bytes: &[u8]| Compact::<UTEST>::decode(&mut &bytes[..]);
+                    assert!(decode(&[0b01, 0]).is_err());
+                    assert!(decode(&[0b110, 0, 0, 0]).is_err());
+                    assert!(decode(&[0b11, 0xff, 0xff, 0xff, 0x3f]).is_err());
+                    assert!(decode(&[0b11, 0, 0, 0, 0x40]).is_ok());
+                    assert!(decode(&[0b0111, 0, 0, 0, 0x40, 0]).is_err());
+
+                    let mut too_wide = vec![((UTEST::BYTES as u8 + 1 - 4) << 2) | 0b11];
+                    too_wide.extend(core::iter::repeat(0).take(UTEST::BYTES as usize));
+                    too_wide.push(1);
+                    assert!(decode(&too_wide).is_err());
+                    let max_u30 = ::parity_scale_codec::Compact(u32::MAX >> 2).encode();
+                    assert_eq!(Compact::<$BUint<1>>::decode(&mut &max_u30[..]).is_ok(), $Digit::BITS >= 32);
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(scale);
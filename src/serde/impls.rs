@@ -0,0 +1,178 @@
+use super::{decimal, le_bytes, sealed::Sealed, Integer};
+use crate::errors::ParseIntError;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt::{self, Formatter};
+use core::num::IntErrorKind;
+
+macro_rules! impls {
+    ($BUint: ident, $BInt: ident, $Digit: ident) => {
+        impl<const N: usize> Sealed for $BUint<N> {
+            const SIGNED: bool = false;
+            const BYTES: usize = Self::BYTES as usize;
+
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                false
+            }
+
+            #[inline]
+            fn le_byte(&self, index: usize) -> u8 {
+                let bit = index * 8;
+                (self.digits[bit / $Digit::BITS as usize] >> (bit % $Digit::BITS as usize)) as u8
+            }
+
+            #[inline]
+            fn set_le_byte(&mut self, index: usize, byte: u8) {
+                let bit = index * 8;
+                let shift = bit % $Digit::BITS as usize;
+                let digit = &mut self.digits[bit / $Digit::BITS as usize];
+                *digit = (*digit & !((0xff as $Digit) << shift)) | ((byte as $Digit) << shift);
+            }
+
+            #[inline]
+            fn fmt_hex(&self, f: &mut Formatter, prefixed: bool) -> fmt::Result {
+                if prefixed {
+                    write!(f, "{:#x}", self)
+                } else {
+                    write!(f, "{:x}", self)
+                }
+            }
+
+            fn from_sign_magnitude_str(
+                negative: bool,
+                digits: &str,
+                radix: u32,
+            ) -> Result<Self, ParseIntError> {
+                if negative {
+                    return Err(ParseIntError {
+                        kind: IntErrorKind::InvalidDigit,
+                    });
+                }
+                Self::from_str_radix(digits, radix)
+            }
+        }
+
+        impl<const N: usize> Integer for $BUint<N> {}
+
+        impl<const N: usize> Sealed for $BInt<N> {
+            const SIGNED: bool = true;
+            const BYTES: usize = Self::BYTES as usize;
+
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                Self::is_negative(*self)
+            }
+
+            #[inline]
+            fn le_byte(&self, index: usize) -> u8 {
+                self.bits.le_byte(index)
+            }
+
+            #[inline]
+            fn set_le_byte(&mut self, index: usize, byte: u8) {
+                self.bits.set_le_byte(index, byte)
+            }
+
+            #[inline]
+            fn fmt_hex(&self, f: &mut Formatter, prefixed: bool) -> fmt::Result {
+                if Self::is_negative(*self) {
+                    f.write_str("-")?;
+                }
+                self.unsigned_abs().fmt_hex(f, prefixed)
+            }
+
+            fn from_sign_magnitude_str(
+                negative: bool,
+                digits: &str,
+                radix: u32,
+            ) -> Result<Self, ParseIntError> {
+                let overflow = ParseIntError {
+                    kind: if negative {
+                        IntErrorKind::NegOverflow
+                    } else {
+                        IntErrorKind::PosOverflow
+                    },
+                };
+                let magnitude = match $BUint::<N>::from_str_radix(digits, radix) {
+                    Ok(magnitude) => magnitude,
+                    Err(e) if matches!(e.kind(), IntErrorKind::PosOverflow) => {
+                        return Err(overflow)
+                    }
+                    Err(e) => return Err(e),
+                };
+                if negative {
+                    // `-magnitude` is in range if and only if `magnitude.wrapping_neg()` is negative or zero
+                    let out = Self::from_bits(magnitude.wrapping_neg());
+                    if out.is_positive() {
+                        return Err(overflow);
+                    }
+                    Ok(out)
+                } else {
+                    let out = Self::from_bits(magnitude);
+                    if Self::is_negative(out) {
+                        return Err(overflow);
+                    }
+                    Ok(out)
+                }
+            }
+        }
+
+        impl<const N: usize> Integer for $BInt<N> {}
+
+        /// Human readable formats serialise the integer as a decimal string, and other formats serialise it as a fixed-width tuple of its little-endian bytes. See the [`serde`](crate::serde) module for details.
+        impl<const N: usize> Serialize for $BUint<N> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    decimal::serialize(self, serializer)
+                } else {
+                    le_bytes::serialize(self, serializer)
+                }
+            }
+        }
+
+        /// Human readable formats deserialise the integer from a decimal or `0x` prefixed hexadecimal string or an integer literal, and other formats deserialise it from a fixed-width tuple of its little-endian bytes. See the [`serde`](crate::serde) module for details.
+        impl<'de, const N: usize> Deserialize<'de> for $BUint<N> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    decimal::deserialize(deserializer)
+                } else {
+                    le_bytes::deserialize(deserializer)
+                }
+            }
+        }
+
+        /// Human readable formats serialise the integer as a decimal string, and other formats serialise it as a fixed-width tuple of its little-endian two's complement bytes. See the [`serde`](crate::serde) module for details.
+        impl<const N: usize> Serialize for $BInt<N> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    decimal::serialize(self, serializer)
+                } else {
+                    le_bytes::serialize(self, serializer)
+                }
+            }
+        }
+
+        /// Human readable formats deserialise the integer from a decimal or `0x` prefixed hexadecimal string or an integer literal, and other formats deserialise it from a fixed-width tuple of its little-endian two's complement bytes. See the [`serde`](crate::serde) module for details.
+        impl<'de, const N: usize> Deserialize<'de> for $BInt<N> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    decimal::deserialize(deserializer)
+                } else {
+                    le_bytes::deserialize(deserializer)
+                }
+            }
+        }
+    };
+}
+
+crate::macro_impl!(impls);
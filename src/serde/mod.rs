@@ -0,0 +1,622 @@
+//! Helpers for customising how integers are serialised with [`serde`](https://docs.rs/serde/latest/serde/).
+//!
+//! By default, formats which are human readable (as reported by [`Serializer::is_human_readable`](::serde::Serializer::is_human_readable)) represent an integer as a decimal string, and other formats represent it as a fixed-width tuple of its `Self::BYTES` little-endian bytes. When deserialising from a human readable format, decimal strings, hexadecimal strings with a `0x` prefix and integer literals (such as JSON numbers) are all accepted. Neither representation depends on the digit type of the integer, so for example a [`BUint<4>`](crate::BUint) and a [`BUintD8<32>`](crate::BUintD8) are serialised identically.
+//!
+//! The modules below can be used with the `#[serde(with = "...")]` attribute to choose a representation explicitly:
+//!
+//! - [`decimal`]: a decimal string, in every format.
+//! - [`hex`]: a lowercase hexadecimal string without a prefix, such as `"ff"` or `"-ff"`.
+//! - [`hex_prefixed`]: a lowercase hexadecimal string with a `0x` prefix, such as `"0xff"` or `"-0xff"`.
+//! - [`le_bytes`]: a fixed-width tuple of the little-endian bytes, in every format.
+//! - [`be_bytes`]: a fixed-width tuple of the big-endian bytes, in every format.
+//! - [`compact`]: a byte string of the shortest little-endian representation, with the most significant bytes which are implied by the sign (or which are zero, for unsigned integers) removed.
+//!
+//! Signed integers are written in hexadecimal as a `-` sign followed by the magnitude, rather than as their two's complement. The byte representations of signed integers are their two's complement.
+//!
+//! # Examples
+//!
+//! ```
+//! use bnum::types::{I256, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Transfer {
+//!     amount: U256,
+//!     #[serde(with = "bnum::serde::hex_prefixed")]
+//!     account: U256,
+//!     #[serde(with = "bnum::serde::hex")]
+//!     delta: I256,
+//! }
+//!
+//! let transfer = Transfer {
+//!     amount: U256::from(1000u16),
+//!     account: U256::from(0xabcdu16),
+//!     delta: I256::from(-255i16),
+//! };
+//! let json = serde_json::to_string(&transfer).unwrap();
+//! assert_eq!(json, r#"{"amount":"1000","account":"0xabcd","delta":"-ff"}"#);
+//! assert_eq!(serde_json::from_str::<Transfer>(&json).unwrap(), transfer);
+//!
+//! let from_number: Transfer = serde_json::from_str(r#"{"amount":1000,"account":"abcd","delta":"-0xff"}"#).unwrap();
+//! assert_eq!(from_number, transfer);
+//! ```
+
+use crate::errors::{ParseIntError, TryFromIntError};
+use ::serde::de::{self, SeqAccess, Unexpected, Visitor};
+use ::serde::ser::{SerializeTuple, Serializer};
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::num::IntErrorKind;
+
+mod impls;
+
+mod sealed {
+    use crate::errors::ParseIntError;
+    use core::fmt::{self, Formatter};
+
+    pub trait Sealed: Sized + fmt::Display {
+        const SIGNED: bool;
+        const BYTES: usize;
+
+        fn zero() -> Self;
+
+        fn is_negative(&self) -> bool;
+
+        fn le_byte(&self, index: usize) -> u8;
+
+        fn set_le_byte(&mut self, index: usize, byte: u8);
+
+        fn fmt_hex(&self, f: &mut Formatter, prefixed: bool) -> fmt::Result;
+
+        /// Parses `digits` as the magnitude of an integer in the given radix, with the given sign. `digits` must not start with a sign.
+        fn from_sign_magnitude_str(
+            negative: bool,
+            digits: &str,
+            radix: u32,
+        ) -> Result<Self, ParseIntError>;
+    }
+}
+
+/// The integer types which can be used with the helper modules in this module.
+///
+/// This trait is implemented for all of the integer types in this crate and cannot be implemented outside of it.
+pub trait Integer: sealed::Sealed {}
+
+/// Parses a string with an optional sign, and an optional `0x` or `0X` prefix. The digits are read in hexadecimal if there is a prefix or `hex` is true, and in decimal otherwise.
+fn parse<T: Integer>(src: &str, hex: bool) -> Result<T, ParseIntError> {
+    let (negative, rest) = match src.as_bytes().first() {
+        None => {
+            return Err(ParseIntError {
+                kind: IntErrorKind::Empty,
+            })
+        }
+        Some(b'-') => (true, &src[1..]),
+        Some(b'+') => (false, &src[1..]),
+        Some(_) => (false, src),
+    };
+    let (radix, digits) = match rest.strip_prefix("0x").or_else(|| rest.strip_prefix("0X")) {
+        Some(digits) => (16, digits),
+        None if hex => (16, rest),
+        None => (10, rest),
+    };
+    match digits.as_bytes().first() {
+        Some(byte) if byte.is_ascii_alphanumeric() => {
+            T::from_sign_magnitude_str(negative, digits, radix)
+        }
+        _ => Err(ParseIntError {
+            kind: IntErrorKind::InvalidDigit,
+        }),
+    }
+}
+
+/// Creates an integer from its little-endian bytes, which may be fewer or more than `T::BYTES`. For signed integers, the bytes are read as two's complement, so the most significant bit of the last byte is the sign bit. Missing bytes are filled in from the sign, and surplus bytes must be implied by the sign.
+fn from_le_bytes<T: Integer, E: de::Error, I: Iterator<Item = Result<u8, E>>>(
+    bytes: I,
+) -> Result<T, E> {
+    let mut out = T::zero();
+    let mut len = 0;
+    let mut surplus = None;
+    let mut last = 0;
+    for byte in bytes {
+        let byte = byte?;
+        if len < T::BYTES {
+            out.set_le_byte(len, byte);
+        } else {
+            match surplus {
+                None if byte == 0 || byte == u8::MAX => surplus = Some(byte),
+                Some(surplus) if surplus == byte => {}
+                _ => return Err(E::custom(TryFromIntError(()))),
+            }
+        }
+        last = byte;
+        len += 1;
+    }
+    let negative = T::SIGNED && last >> 7 == 1;
+    let fill = if negative { u8::MAX } else { 0 };
+    if let Some(surplus) = surplus {
+        if surplus != fill || out.is_negative() != negative {
+            return Err(E::custom(TryFromIntError(())));
+        }
+    }
+    while len < T::BYTES {
+        out.set_le_byte(len, fill);
+        len += 1;
+    }
+    Ok(out)
+}
+
+struct Hex<'a, T> {
+    int: &'a T,
+    prefixed: bool,
+}
+
+impl<'a, T: Integer> Display for Hex<'a, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.int.fmt_hex(f, self.prefixed)
+    }
+}
+
+struct StrVisitor<T> {
+    hex: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T> StrVisitor<T> {
+    const fn new(hex: bool) -> Self {
+        Self {
+            hex,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Integer> Visitor<'de> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        if self.hex {
+            formatter.write_str("a hexadecimal string or an integer")
+        } else {
+            formatter.write_str("a decimal or hexadecimal string or an integer")
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        parse(v, self.hex).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.visit_u128(v as u128)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        self.visit_i128(v as i128)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        // the extra zero byte makes sure that the bytes are read as a positive integer
+        from_le_bytes(v.to_le_bytes().into_iter().chain([0]).map(Ok))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        if v >= 0 {
+            self.visit_u128(v as u128)
+        } else if T::SIGNED {
+            from_le_bytes(v.to_le_bytes().into_iter().map(Ok))
+        } else if let Ok(v) = i64::try_from(v) {
+            Err(E::invalid_value(Unexpected::Signed(v), &self))
+        } else {
+            Err(E::custom(TryFromIntError(())))
+        }
+    }
+}
+
+struct FixedBytesVisitor<T> {
+    big_endian: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T> FixedBytesVisitor<T> {
+    const fn new(big_endian: bool) -> Self {
+        Self {
+            big_endian,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Integer> FixedBytesVisitor<T> {
+    #[inline]
+    fn index(&self, i: usize) -> usize {
+        if self.big_endian {
+            T::BYTES - 1 - i
+        } else {
+            i
+        }
+    }
+}
+
+impl<'de, T: Integer> Visitor<'de> for FixedBytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{} bytes", T::BYTES)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut out = T::zero();
+        for i in 0..T::BYTES {
+            match seq.next_element()? {
+                Some(byte) => out.set_le_byte(self.index(i), byte),
+                None => return Err(de::Error::invalid_length(i, &self)),
+            }
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(T::BYTES + 1, &self));
+        }
+        Ok(out)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        if v.len() != T::BYTES {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        let mut out = T::zero();
+        for (i, &byte) in v.iter().enumerate() {
+            out.set_le_byte(self.index(i), byte);
+        }
+        Ok(out)
+    }
+}
+
+struct CompactVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Integer> Visitor<'de> for CompactVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a byte string")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        from_le_bytes(core::iter::from_fn(|| seq.next_element().transpose()))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        from_le_bytes(v.iter().copied().map(Ok))
+    }
+}
+
+fn serialize_fixed_bytes<T: Integer, S: Serializer>(
+    int: &T,
+    serializer: S,
+    big_endian: bool,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(T::BYTES)?;
+    for i in 0..T::BYTES {
+        let index = if big_endian { T::BYTES - 1 - i } else { i };
+        tuple.serialize_element(&int.le_byte(index))?;
+    }
+    tuple.end()
+}
+
+/// Serialises an integer as a decimal string, and deserialises it from a decimal string, a hexadecimal string with a `0x` prefix, or an integer literal in self-describing formats.
+pub mod decimal {
+    use super::{Integer, StrVisitor};
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialises `int` as a decimal string.
+    pub fn serialize<T: Integer, S: Serializer>(int: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(int)
+    }
+
+    /// Deserialises an integer from a decimal string, a hexadecimal string with a `0x` prefix, or an integer literal in self-describing human readable formats.
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StrVisitor::new(false))
+        } else {
+            deserializer.deserialize_str(StrVisitor::new(false))
+        }
+    }
+}
+
+macro_rules! hex_module {
+    ($name: ident, $prefixed: literal, $desc: literal) => {
+        #[doc = concat!("Serialises an integer as a lowercase hexadecimal string ", $desc, ", and deserialises it from a hexadecimal string with or without a `0x` prefix.")]
+        pub mod $name {
+            use super::{Hex, Integer, StrVisitor};
+            use ::serde::{Deserializer, Serializer};
+
+            #[doc = concat!("Serialises `int` as a lowercase hexadecimal string ", $desc, ".")]
+            pub fn serialize<T: Integer, S: Serializer>(int: &T, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&Hex {
+                    int,
+                    prefixed: $prefixed,
+                })
+            }
+
+            /// Deserialises an integer from a hexadecimal string with or without a `0x` prefix.
+            pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+                deserializer.deserialize_str(StrVisitor::new(true))
+            }
+        }
+    };
+}
+
+hex_module!(hex, false, "without a prefix");
+hex_module!(hex_prefixed, true, "with a `0x` prefix");
+
+macro_rules! bytes_module {
+    ($name: ident, $big_endian: literal, $endian: literal) => {
+        #[doc = concat!("Serialises an integer as a fixed-width tuple of its ", $endian, " bytes.")]
+        pub mod $name {
+            use super::{FixedBytesVisitor, Integer};
+            use ::serde::{Deserializer, Serializer};
+
+            #[doc = concat!("Serialises `int` as a fixed-width tuple of its ", $endian, " bytes.")]
+            pub fn serialize<T: Integer, S: Serializer>(int: &T, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize_fixed_bytes(int, serializer, $big_endian)
+            }
+
+            #[doc = concat!("Deserialises an integer from a fixed-width tuple of its ", $endian, " bytes.")]
+            pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+                deserializer.deserialize_tuple(T::BYTES, FixedBytesVisitor::new($big_endian))
+            }
+        }
+    };
+}
+
+bytes_module!(le_bytes, false, "little-endian");
+bytes_module!(be_bytes, true, "big-endian");
+
+/// Serialises an integer as a byte string of its shortest little-endian representation.
+///
+/// Unsigned integers have their most significant zero bytes removed. Signed integers are written in two's complement and have their most significant bytes removed while the remaining bytes still have the same sign, so for example `-1` is written as `[0xff]` and `128` as `[0x80, 0x00]`. Zero is written as an empty byte string. When deserialising, the byte string may be of any length as long as its value fits in the integer type.
+pub mod compact {
+    use super::{CompactVisitor, Integer};
+    use ::serde::{Deserializer, Serializer};
+    use alloc::vec::Vec;
+    use core::marker::PhantomData;
+
+    /// Serialises `int` as a byte string of its shortest little-endian representation.
+    pub fn serialize<T: Integer, S: Serializer>(int: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let negative = int.is_negative();
+        let fill = if negative { u8::MAX } else { 0 };
+        let mut len = T::BYTES;
+        while len > 0 && int.le_byte(len - 1) == fill {
+            // for signed integers, the byte below the removed byte must have the same sign
+            let sign_kept = match len {
+                1 => !negative,
+                _ => !T::SIGNED || (int.le_byte(len - 2) >> 7 == 1) == negative,
+            };
+            if !sign_kept {
+                break;
+            }
+            len -= 1;
+        }
+        let bytes: Vec<u8> = (0..len).map(|i| int.le_byte(i)).collect();
+        serializer.serialize_bytes(&bytes)
+    }
+
+    /// Deserialises an integer from a little-endian byte string of any length.
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_bytes(CompactVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BInt, BIntD8, BUint, BUintD16, BUintD8};
+    use ::serde::{Deserialize, Serialize};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    macro_rules! with {
+        ($($name: ident: $module: literal),*) => {
+            $(
+                #[derive(Serialize, Deserialize, PartialEq, Debug)]
+                struct $name<T: super::Integer>(#[serde(with = $module)] T);
+            )*
+        };
+    }
+
+    with!(
+        Decimal: "super::decimal",
+        Hex: "super::hex",
+        HexPrefixed: "super::hex_prefixed",
+        LeBytes: "super::le_bytes",
+        BeBytes: "super::be_bytes",
+        Compact: "super::compact"
+    );
+
+    fn json<T: Serialize>(value: &T) -> String {
+        serde_json::to_string(value).unwrap()
+    }
+
+    fn from_json<'de, T: Deserialize<'de>>(s: &'de str) -> Option<T> {
+        serde_json::from_str(s).ok()
+    }
+
+    fn cbor<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut out = Vec::new();
+        ciborium::into_writer(value, &mut out).unwrap();
+        out
+    }
+
+    fn from_cbor<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Option<T> {
+        ciborium::from_reader(bytes).ok()
+    }
+
+    /// Returns the shortest little-endian two's complement bytes of `a`.
+    fn compact_bytes(a: i128, signed: bool) -> Vec<u8> {
+        let bytes = a.to_le_bytes();
+        (0..=16)
+            .map(|len| bytes[..len].to_vec())
+            .find(|b| {
+                let negative = signed && b.last().map_or(false, |byte| byte >> 7 == 1);
+                let fill = if negative { u8::MAX } else { 0 };
+                bytes[b.len()..].iter().all(|&byte| byte == fill) && (negative == (a < 0))
+            })
+            .unwrap()
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_human_readable(a: u128, b: i128) -> bool {
+            let (x, y) = (BUintD8::<16>::from(a), BInt::<2>::from(b));
+            json(&x) == format!("\"{}\"", a)
+                && json(&y) == format!("\"{}\"", b)
+                && from_json::<BUintD16<8>>(&json(&x)) == Some(BUintD16::from(a))
+                && from_json::<BInt<2>>(&json(&y)) == Some(y)
+                && from_json::<BUintD8<16>>(&format!("\"0x{:x}\"", a)) == Some(x)
+                && from_json::<BInt<2>>(&format!("{}", b as i64)) == Some(BInt::from(b as i64))
+                && from_json::<BUint<2>>(&format!("{}", b as i64)).is_some() == (b as i64 >= 0)
+                && from_json::<BIntD8<8>>(&json(&y)).is_some() == i64::try_from(b).is_ok()
+        }
+
+        fn quickcheck_binary(a: u128, b: i128) -> bool {
+            let (x, y) = (BUintD8::<16>::from(a), BInt::<2>::from(b));
+            cbor(&x) == cbor(&BUint::<2>::from(a))
+                && cbor(&x) == cbor(&a.to_le_bytes())
+                && cbor(&y) == cbor(&BIntD8::<16>::from(b))
+                && from_cbor::<BUintD16<8>>(&cbor(&x)) == Some(BUintD16::from(a))
+                && from_cbor::<BIntD8<16>>(&cbor(&y)) == Some(BIntD8::from(b))
+                && from_cbor::<BUint<1>>(&cbor(&x)).is_none()
+                && from_cbor::<BUint<3>>(&cbor(&x)).is_none()
+        }
+
+        fn quickcheck_hex(a: u128, b: i128) -> bool {
+            let (x, y) = (BUintD8::<16>::from(a), BInt::<2>::from(b));
+            let sign = if b < 0 { "-" } else { "" };
+            json(&Hex(x)) == format!("\"{:x}\"", a)
+                && json(&HexPrefixed(x)) == format!("\"{:#x}\"", a)
+                && json(&Hex(y)) == format!("\"{}{:x}\"", sign, b.unsigned_abs())
+                && json(&HexPrefixed(y)) == format!("\"{}{:#x}\"", sign, b.unsigned_abs())
+                && from_json(&json(&Hex(x))) == Some(Hex(x))
+                && from_json(&json(&HexPrefixed(x))) == Some(Hex(x))
+                && from_json(&json(&Hex(y))) == Some(HexPrefixed(y))
+                && from_json(&json(&HexPrefixed(y))) == Some(Hex(y))
+                && from_cbor(&cbor(&Hex(y))) == Some(Hex(y))
+                && from_json::<Decimal<BInt<2>>>(&json(&Decimal(y))) == Some(Decimal(y))
+        }
+
+        fn quickcheck_fixed_bytes(a: u128, b: i128) -> bool {
+            let (x, y) = (BUintD16::<8>::from(a), BIntD8::<16>::from(b));
+            let mut be = b.to_le_bytes();
+            be.reverse();
+            json(&LeBytes(x)) == json(&a.to_le_bytes())
+                && json(&BeBytes(x)) == json(&a.to_be_bytes())
+                && json(&BeBytes(y)) == json(&be)
+                && from_json(&json(&LeBytes(x))) == Some(LeBytes(x))
+                && from_json(&json(&BeBytes(y))) == Some(BeBytes(y))
+                && from_cbor(&cbor(&BeBytes(x))) == Some(BeBytes(x))
+                && from_json::<LeBytes<BUint<1>>>(&json(&LeBytes(x))).is_none()
+        }
+
+        fn quickcheck_compact(a: u128, b: i128) -> bool {
+            let (x, y) = (BUintD8::<16>::from(a), BInt::<2>::from(b));
+            let unsigned = {
+                let bytes = a.to_le_bytes();
+                let len = bytes.iter().rposition(|&byte| byte != 0).map_or(0, |i| i + 1);
+                bytes[..len].to_vec()
+            };
+            json(&Compact(x)) == json(&unsigned)
+                && json(&Compact(y)) == json(&compact_bytes(b, true))
+                && from_json(&json(&Compact(x))) == Some(Compact(x))
+                && from_cbor(&cbor(&Compact(y))) == Some(Compact(y))
+                && from_json::<Compact<BIntD8<8>>>(&json(&Compact(y))).is_some() == i64::try_from(b).is_ok()
+                && from_cbor::<Compact<BUintD16<4>>>(&cbor(&Compact(x))).is_some() == u64::try_from(a).is_ok()
+        }
+    }
+
+    #[test]
+    fn parse() {
+        type I128 = BInt<2>;
+        assert_eq!(
+            from_json::<I128>(&format!("\"{}\"", i128::MIN)),
+            Some(I128::MIN)
+        );
+        assert_eq!(
+            from_json::<I128>(&format!("\"{}\"", i128::MAX)),
+            Some(I128::MAX)
+        );
+        assert_eq!(
+            from_json::<I128>("\"-0x80000000000000000000000000000000\""),
+            Some(I128::MIN)
+        );
+        assert_eq!(
+            from_json::<I128>("\"-0x80000000000000000000000000000001\""),
+            None
+        );
+        assert_eq!(
+            from_json::<I128>("\"0x80000000000000000000000000000000\""),
+            None
+        );
+        assert_eq!(
+            from_json::<I128>("\"170141183460469231731687303715884105728\""),
+            None
+        );
+        assert_eq!(from_json::<I128>("\"+12\""), Some(I128::from(12)));
+        assert_eq!(from_json::<I128>("\"-0\""), Some(I128::ZERO));
+        assert_eq!(
+            from_json::<Hex<I128>>("\"-0XfF\""),
+            Some(Hex(I128::from(-255)))
+        );
+        for s in [
+            "\"\"", "\"-\"", "\"0x\"", "\"--1\"", "\"-+1\"", "\"0x-1\"", "\" 1\"", "\"1.0\"",
+            "1.0", "\"ff\"",
+        ] {
+            assert_eq!(from_json::<I128>(s), None, "{}", s);
+        }
+        assert_eq!(from_json::<BUint<2>>("\"-1\""), None);
+        assert_eq!(from_json::<BUint<2>>("-1"), None);
+        assert_eq!(
+            from_json::<BUint<2>>("18446744073709551615"),
+            Some(BUint::from(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(json(&Compact(BInt::<2>::from(-1))), "[255]");
+        assert_eq!(json(&Compact(BInt::<2>::from(128))), "[128,0]");
+        assert_eq!(json(&Compact(BInt::<2>::from(-128))), "[128]");
+        assert_eq!(json(&Compact(BInt::<2>::ZERO)), "[]");
+        assert_eq!(json(&Compact(BUint::<2>::from(128u8))), "[128]");
+        assert_eq!(
+            from_json::<Compact<BIntD8<1>>>("[255,255,255]"),
+            Some(Compact(BIntD8::from(-1i8)))
+        );
+        assert_eq!(from_json::<Compact<BIntD8<1>>>("[128,0]"), None);
+        assert_eq!(
+            from_json::<Compact<BIntD8<1>>>("[127,0,0]"),
+            Some(Compact(BIntD8::from(127i8)))
+        );
+        assert_eq!(
+            from_json::<Compact<BIntD8<2>>>("[128]"),
+            Some(Compact(BIntD8::from(-128i8)))
+        );
+        assert_eq!(
+            from_json::<Compact<BUintD8<2>>>("[128]"),
+            Some(Compact(BUintD8::from(128u8)))
+        );
+        assert_eq!(from_json::<Compact<BUintD8<1>>>("[1,1]"), None);
+        assert_eq!(from_json::<Compact<BUintD8<1>>>("[1,255]"), None);
+        assert_eq!(from_json::<Compact<BIntD8<1>>>("[1,255]"), None);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(
+            json(&HexPrefixed(BInt::<2>::MIN)),
+            format!("\"-{:#x}\"", i128::MIN.unsigned_abs())
+        );
+        assert_eq!(
+            json(&Decimal(BUint::<2>::MAX)),
+            format!("\"{}\"", u128::MAX)
+        );
+    }
+}
@@ -64,6 +64,10 @@ impl TestConvert for f64 {
 
     #[inline]
     fn into(self) -> Self::Output {
+        // the sign and payload of NaNs produced by arithmetic depend on the platform, so all NaNs compare equal
+        if self.is_nan() {
+            return f64::NAN.to_bits();
+        }
         self.to_bits()
     }
 }
@@ -73,33 +77,43 @@ impl TestConvert for f32 {
 
     #[inline]
     fn into(self) -> Self::Output {
+        // the sign and payload of NaNs produced by arithmetic depend on the platform, so all NaNs compare equal
+        if self.is_nan() {
+            return f32::NAN.to_bits();
+        }
         self.to_bits()
     }
 }
 
-// #[cfg(feature = "nightly")]
-// impl TestConvert for crate::float::F64 {
-//     type Output = u64;
+#[cfg(feature = "nightly")]
+impl TestConvert for crate::float::F64 {
+    type Output = u64;
 
-//     #[inline]
-//     fn into(self) -> Self::Output {
-//         use crate::cast::As;
+    #[inline]
+    fn into(self) -> Self::Output {
+        use crate::cast::As;
 
-//         self.to_bits().as_()
-//     }
-// }
+        if self.is_nan() {
+            return f64::NAN.to_bits();
+        }
+        self.to_bits().as_()
+    }
+}
 
-// #[cfg(feature = "nightly")]
-// impl TestConvert for crate::float::F32 {
-//     type Output = u32;
+#[cfg(feature = "nightly")]
+impl TestConvert for crate::float::F32 {
+    type Output = u32;
 
-//     #[inline]
-//     fn into(self) -> Self::Output {
-//         use crate::cast::As;
+    #[inline]
+    fn into(self) -> Self::Output {
+        use crate::cast::As;
 
-//         self.to_bits().as_()
-//     }
-// }
+        if self.is_nan() {
+            return f32::NAN.to_bits();
+        }
+        self.to_bits().as_()
+    }
+}
 
 impl<T: TestConvert, U: TestConvert> TestConvert for (T, U) {
     type Output = (<T as TestConvert>::Output, <U as TestConvert>::Output);
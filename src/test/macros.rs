@@ -1,4 +1,18 @@
 macro_rules! test_bignum {
+    {
+        function: <$primitive: ty as $Trait: ident<Self>> :: $function: ident ($($param: ident : $(ref $re: tt)? $ty: ty), *)
+    } => {
+        paste::paste! {
+            quickcheck::quickcheck! {
+                #[allow(non_snake_case)]
+                fn [<quickcheck_ $primitive _ $Trait _Self_ $function>]($($param : $ty), *) -> quickcheck::TestResult {
+                    let (big, primitive) = crate::test::results!(<$primitive as $Trait<Self>>::$function ($($($re)? Into::into($param)), *));
+
+                    quickcheck::TestResult::from_bool(big == primitive)
+                }
+            }
+        }
+    };
     {
         function: $($unsafe: ident)? <$primitive: ty $(as $Trait: ident $(<$($gen: ty), *>)?)?> :: $function: ident ($($param: ident : $(ref $re: tt)? $ty: ty), *)
         $(, skip: $skip: expr)?
@@ -74,7 +88,76 @@ macro_rules! test_bignum {
 
 pub(crate) use test_bignum;
 
+#[cfg(feature = "nightly")]
+macro_rules! test_float_ulps {
+    {
+        function: <$primitive: ty> :: $function: ident ($($param: ident : $ty: ty), *),
+        ulps: $ulps: expr
+        $(, skip: $skip: expr)?
+    } => {
+        paste::paste! {
+            quickcheck::quickcheck! {
+                fn [<quickcheck_ $primitive _ $function>]($($param : $ty), *) -> quickcheck::TestResult {
+                    $(if $skip {
+                        return quickcheck::TestResult::discard();
+                    })?
+
+                    let (big, primitive) = crate::test::results!(<$primitive>::$function ($(Into::into($param)), *));
+
+                    quickcheck::TestResult::from_bool(crate::test::UlpsEq::ulps_eq(big, primitive, $ulps))
+                }
+            }
+        }
+    };
+    {
+        function: <$primitive: ty> :: $function: ident ($($param: ident : $ty: ty), *),
+        ulps: $ulps: expr
+        $(, skip: $skip: expr)?
+        , cases: [
+            $(($($arg: expr), *)), *
+        ]
+    } => {
+        paste::paste! {
+            #[test]
+            fn [<cases_ $primitive _ $function>]() {
+                fn check($($param : $ty), *) {
+                    let (big, primitive) = crate::test::results!(<$primitive>::$function ($(Into::into($param)), *));
+                    assert!(crate::test::UlpsEq::ulps_eq(big, primitive, $ulps), "{:?} != {:?}", big, primitive);
+                }
+                $(
+                    check($($arg), *);
+                )*
+            }
+        }
+        crate::test::test_float_ulps! {
+            function: <$primitive>::$function ($($param : $ty), *),
+            ulps: $ulps
+            $(, skip: $skip)?
+        }
+    };
+}
+
+#[cfg(feature = "nightly")]
+pub(crate) use test_float_ulps;
+
 macro_rules! results {
+    (<$primitive: ty as $Trait: ident<Self>> :: $function: ident ($($arg: expr), *)) => {
+        paste::paste! {
+            {
+                use crate::test::types;
+                // the right hand side type is given explicitly, as other crates may implement the trait for the primitive with other right hand side types
+                let big_result = <[<$primitive:upper>] as $Trait<[<$primitive:upper>]>>::$function(
+                    $($arg), *
+                );
+                let prim_result = <types::$primitive as $Trait<types::$primitive>>::$function(
+                    $($arg), *
+                );
+
+                use crate::test::TestConvert;
+                (TestConvert::into(big_result), TestConvert::into(prim_result))
+            }
+        }
+    };
     (<$primitive: ty $(as $Trait: ty)?> :: $function: ident ($($arg: expr), *)) => {
         paste::paste! {
             {
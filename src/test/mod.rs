@@ -8,6 +8,48 @@ pub use macros::*;
 
 pub mod types;
 
+/// Approximate equality of the outputs of `TestConvert` for floats, which are their bit patterns. All NaNs are considered equal, and otherwise the floats must be within `ulps` units in the last place of each other.
+#[cfg(feature = "nightly")]
+pub trait UlpsEq {
+    fn ulps_eq(self, other: Self, ulps: u64) -> bool;
+}
+
+#[cfg(feature = "nightly")]
+macro_rules! ulps_eq_float_bits {
+    ($($bits: ty, $float: ty); *) => {
+        $(
+            impl UlpsEq for $bits {
+                fn ulps_eq(self, other: Self, ulps: u64) -> bool {
+                    let (a, b) = (<$float>::from_bits(self), <$float>::from_bits(other));
+                    if a.is_nan() || b.is_nan() {
+                        return a.is_nan() && b.is_nan();
+                    }
+                    // map the bit patterns to integers which are monotonic in the value of the float
+                    let ordered = |bits: $bits| -> i128 {
+                        let magnitude = (bits & (<$bits>::MAX >> 1)) as i128;
+                        if bits >> (<$bits>::BITS - 1) == 1 {
+                            -magnitude
+                        } else {
+                            magnitude
+                        }
+                    };
+                    ordered(self).abs_diff(ordered(other)) <= ulps as u128
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "nightly")]
+ulps_eq_float_bits!(u32, f32; u64, f64);
+
+#[cfg(feature = "nightly")]
+impl<T: UlpsEq, U: UlpsEq> UlpsEq for (T, U) {
+    fn ulps_eq(self, other: Self, ulps: u64) -> bool {
+        self.0.ulps_eq(other.0, ulps) && self.1.ulps_eq(other.1, ulps)
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct U8ArrayWrapper<const N: usize>(pub [u8; N]);
 
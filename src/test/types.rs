@@ -48,18 +48,18 @@ mod small_types {
 pub use core::primitive::*;
 pub use small_types::*;
 
-// #[cfg(test_int_bits = "64")]
-// #[allow(non_camel_case_types)]
-// pub type ftest = f64;
+#[cfg(test_int_bits = "64")]
+#[allow(non_camel_case_types)]
+pub type ftest = f64;
 
-// #[cfg(not(test_int_bits = "64"))]
-// #[allow(non_camel_case_types)]
-// pub type ftest = f32;
+#[cfg(not(test_int_bits = "64"))]
+#[allow(non_camel_case_types)]
+pub type ftest = f32;
 
-// #[cfg(feature = "nightly")]
-// #[cfg(test_int_bits = "64")]
-// pub type FTEST = crate::float::Float<8, 52>;
+#[cfg(feature = "nightly")]
+#[cfg(test_int_bits = "64")]
+pub type FTEST = crate::float::Float<8, 52>;
 
-// #[cfg(feature = "nightly")]
-// #[cfg(not(test_int_bits = "64"))]
-// pub type FTEST = crate::float::Float<4, 23>;
\ No newline at end of file
+#[cfg(feature = "nightly")]
+#[cfg(not(test_int_bits = "64"))]
+pub type FTEST = crate::float::Float<4, 23>;
\ No newline at end of file
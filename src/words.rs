@@ -0,0 +1,51 @@
+//! Conversions between integers with 8 bit digits and integers with 64 bit digits, and between them and primitive integers.
+
+use crate::{BUint, BUintD8};
+
+/// Packs the bytes of `u` into 64 bit words, truncating to the least significant `8 * M` bytes.
+#[inline]
+pub(crate) const fn bytes_to_words<const N: usize, const M: usize>(u: BUintD8<N>) -> BUint<M> {
+    let mut out = BUint::ZERO;
+    let mut i = 0;
+    while i < N && i < M * 8 {
+        out.digits[i / 8] |= (u.digits[i] as u64) << ((i % 8) * 8);
+        i += 1;
+    }
+    out
+}
+
+/// Unpacks the 64 bit words of `u` into bytes, truncating to the least significant `M` bytes.
+#[inline]
+pub(crate) const fn words_to_bytes<const N: usize, const M: usize>(u: BUint<N>) -> BUintD8<M> {
+    let mut out = BUintD8::ZERO;
+    let mut i = 0;
+    while i < N * 8 && i < M {
+        out.digits[i] = (u.digits[i / 8] >> ((i % 8) * 8)) as u8;
+        i += 1;
+    }
+    out
+}
+
+/// Converts `n` to an integer with 8 bit digits, truncating to the least significant `N` bytes.
+#[inline]
+pub(crate) const fn buint_from_u64<const N: usize>(n: u64) -> BUintD8<N> {
+    let mut out = BUintD8::ZERO;
+    let mut i = 0;
+    while i < N && i < 8 {
+        out.digits[i] = (n >> (i * 8)) as u8;
+        i += 1;
+    }
+    out
+}
+
+/// Returns the value of the least significant 63 bits of `u`.
+#[inline]
+pub(crate) const fn buint_to_i64<const N: usize>(u: BUintD8<N>) -> i64 {
+    let mut out = 0u64;
+    let mut i = 0;
+    while i < N && i < 8 {
+        out |= (u.digits[i] as u64) << (i * 8);
+        i += 1;
+    }
+    (out & (i64::MAX as u64)) as i64
+}